@@ -0,0 +1,405 @@
+//! The advancement progress of a player, stored in `advancements/<uuid>.json`.
+//! [Minecraft Wiki](https://minecraft.wiki/w/Advancement#Advancement_progress)
+//!
+//! ```rust
+//! use sculk::advancements::Advancements;
+//!
+//! let advancements = Advancements::from_json(r#"{
+//!     "minecraft:story/mine_stone": {
+//!         "criteria": { "get_stone": "2024-06-15 18:30:12 +0200" },
+//!         "done": true
+//!     },
+//!     "DataVersion": 3953
+//! }"#).unwrap();
+//!
+//! assert_eq!(advancements.completed().count(), 1);
+//! ```
+
+use crate::error::SculkParseError;
+use serde_json::{Map, Value};
+use std::{collections::HashMap, path::Path, str::FromStr};
+
+/// Every advancement and recipe a player has made progress on.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Advancements {
+    /// Version of the advancement file.
+    /// `DataVersion`
+    pub data_version: i32,
+
+    /// The progress of every advancement, keyed by its resource location, like `minecraft:story/mine_stone`.
+    /// Unlocked recipes are stored as advancements as well, under `minecraft:recipes/`.
+    pub advancements: HashMap<String, AdvancementProgress>,
+}
+
+/// The progress of a single advancement.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AdvancementProgress {
+    /// Every completed criterion and when it was completed.
+    /// Criteria that haven't been completed yet are not stored.
+    /// `criteria`
+    pub criteria: HashMap<String, Timestamp>,
+
+    /// If every required criterion is completed.
+    /// `done`
+    pub done: bool,
+}
+
+/// A point in time with its UTC offset, stored as `yyyy-MM-dd HH:mm:ss Z`, like `2024-06-15 18:30:12 +0200`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Timestamp {
+    /// Seconds since the unix epoch.
+    pub unix_seconds: i64,
+
+    /// The UTC offset of the local time it was written in, in minutes.
+    pub offset_minutes: i32,
+}
+
+impl Advancements {
+    /// Parses an advancement file.
+    pub fn from_json(json: &str) -> Result<Self, SculkParseError> {
+        let value: Value =
+            serde_json::from_str(json).map_err(|e| SculkParseError::InvalidJson(e.to_string()))?;
+        let map = value
+            .as_object()
+            .ok_or(SculkParseError::InvalidJson("Expected an object".into()))?;
+
+        let data_version =
+            map.get("DataVersion")
+                .and_then(Value::as_i64)
+                .ok_or(SculkParseError::MissingField("DataVersion".into()))? as i32;
+
+        let mut advancements = HashMap::new();
+        for (key, value) in map {
+            if key == "DataVersion" {
+                continue;
+            }
+
+            let progress = value
+                .as_object()
+                .ok_or(SculkParseError::InvalidField(key.clone()))?;
+            advancements.insert(key.clone(), AdvancementProgress::from_map(progress)?);
+        }
+
+        Ok(Advancements {
+            data_version,
+            advancements,
+        })
+    }
+
+    /// Reads and parses an advancement file.
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self, SculkParseError> {
+        Advancements::from_json(&std::fs::read_to_string(path)?)
+    }
+
+    /// Converts the advancements back into the JSON of an advancement file.
+    pub fn to_json(&self) -> String {
+        let mut map = Map::new();
+
+        let mut keys: Vec<&String> = self.advancements.keys().collect();
+        keys.sort();
+        for key in keys {
+            map.insert(key.clone(), self.advancements[key].to_value());
+        }
+        map.insert("DataVersion".into(), self.data_version.into());
+
+        Value::Object(map).to_string()
+    }
+
+    /// Gets the progress of an advancement.
+    pub fn get(&self, key: &str) -> Option<&AdvancementProgress> {
+        self.advancements.get(key)
+    }
+
+    /// Iterates over the completed advancements, leaving out recipes.
+    pub fn completed(&self) -> impl Iterator<Item = (&str, &AdvancementProgress)> {
+        self.advancements().filter(|(_, progress)| progress.done)
+    }
+
+    /// Iterates over the advancements, leaving out recipes.
+    pub fn advancements(&self) -> impl Iterator<Item = (&str, &AdvancementProgress)> {
+        self.advancements
+            .iter()
+            .filter(|(key, _)| !is_recipe(key))
+            .map(|(key, progress)| (key.as_str(), progress))
+    }
+
+    /// Iterates over the unlocked recipes.
+    pub fn recipes(&self) -> impl Iterator<Item = &str> {
+        self.advancements
+            .iter()
+            .filter(|(key, progress)| is_recipe(key) && progress.done)
+            .map(|(key, _)| key.as_str())
+    }
+
+    /// The completed criteria of an advancement, in the order they were completed.
+    pub fn criteria_progress(&self, key: &str) -> Option<Vec<(&str, Timestamp)>> {
+        self.get(key).map(AdvancementProgress::criteria_in_order)
+    }
+
+    /// When an advancement was completed, `None` if it isn't done.
+    pub fn completed_at(&self, key: &str) -> Option<Timestamp> {
+        self.get(key)?.completed_at()
+    }
+
+    /// The advancement that was completed first, leaving out recipes.
+    pub fn first_completed(&self) -> Option<(&str, Timestamp)> {
+        self.completed()
+            .filter_map(|(key, progress)| Some((key, progress.completed_at()?)))
+            .min_by_key(|(_, time)| *time)
+    }
+
+    /// Every completed advancement and when it was completed, oldest first, leaving out recipes.
+    pub fn completion_order(&self) -> Vec<(&str, Timestamp)> {
+        let mut completed: Vec<(&str, Timestamp)> = self
+            .completed()
+            .filter_map(|(key, progress)| Some((key, progress.completed_at()?)))
+            .collect();
+        completed.sort_by_key(|(key, time)| (*time, *key));
+
+        completed
+    }
+}
+
+impl AdvancementProgress {
+    fn from_map(map: &Map<String, Value>) -> Result<Self, SculkParseError> {
+        let mut criteria = HashMap::new();
+        if let Some(values) = map.get("criteria") {
+            let values = values
+                .as_object()
+                .ok_or(SculkParseError::InvalidField("criteria".into()))?;
+
+            for (name, time) in values {
+                let time = time
+                    .as_str()
+                    .ok_or(SculkParseError::InvalidField(name.clone()))?;
+                criteria.insert(name.clone(), time.parse()?);
+            }
+        }
+
+        Ok(AdvancementProgress {
+            criteria,
+            done: map.get("done").and_then(Value::as_bool).unwrap_or(false),
+        })
+    }
+
+    fn to_value(&self) -> Value {
+        let mut criteria = Map::new();
+        for (name, time) in self.criteria_in_order() {
+            criteria.insert(name.to_string(), time.to_string().into());
+        }
+
+        let mut map = Map::new();
+        map.insert("criteria".into(), Value::Object(criteria));
+        map.insert("done".into(), self.done.into());
+
+        Value::Object(map)
+    }
+
+    /// The completed criteria, in the order they were completed.
+    pub fn criteria_in_order(&self) -> Vec<(&str, Timestamp)> {
+        let mut criteria: Vec<(&str, Timestamp)> = self
+            .criteria
+            .iter()
+            .map(|(name, time)| (name.as_str(), *time))
+            .collect();
+        criteria.sort_by_key(|(name, time)| (*time, *name));
+
+        criteria
+    }
+
+    /// When the advancement was completed, the time of its last criterion.
+    /// `None` if it isn't done.
+    pub fn completed_at(&self) -> Option<Timestamp> {
+        if !self.done {
+            return None;
+        }
+
+        self.criteria.values().copied().max()
+    }
+}
+
+/// true if the advancement is an unlocked recipe instead of an actual advancement.
+pub fn is_recipe(key: &str) -> bool {
+    key.split_once(':')
+        .map_or(key, |(_, path)| path)
+        .starts_with("recipes/")
+}
+
+impl PartialOrd for Timestamp {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+/// Timestamps are ordered by the moment in time, regardless of their offset.
+impl Ord for Timestamp {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.unix_seconds
+            .cmp(&other.unix_seconds)
+            .then(self.offset_minutes.cmp(&other.offset_minutes))
+    }
+}
+
+impl FromStr for Timestamp {
+    type Err = SculkParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = || SculkParseError::InvalidField(format!("Invalid timestamp: {s}"));
+
+        let mut parts = s.split(' ');
+        let (date, time, offset) = match (parts.next(), parts.next(), parts.next(), parts.next()) {
+            (Some(date), Some(time), Some(offset), None) => (date, time, offset),
+            _ => return Err(invalid()),
+        };
+
+        let numbers = |s: &str, separator: char| -> Option<Vec<i64>> {
+            s.split(separator).map(|n| n.parse().ok()).collect()
+        };
+        let (year, month, day) = match numbers(date, '-').as_deref() {
+            Some(&[year, month, day]) if (1..=12).contains(&month) && (1..=31).contains(&day) => {
+                (year, month, day)
+            }
+            _ => return Err(invalid()),
+        };
+        let (hour, minute, second) = match numbers(time, ':').as_deref() {
+            Some(&[hour, minute, second]) if hour < 24 && minute < 60 && second < 61 => {
+                (hour, minute, second)
+            }
+            _ => return Err(invalid()),
+        };
+
+        let sign = match offset.chars().next() {
+            Some('+') => 1,
+            Some('-') => -1,
+            _ => return Err(invalid()),
+        };
+        let offset = &offset[1..];
+        if offset.len() != 4 || !offset.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(invalid());
+        }
+        let offset_minutes = sign
+            * (offset[0..2].parse::<i32>().unwrap_or(0) * 60
+                + offset[2..4].parse::<i32>().unwrap_or(0));
+
+        let local = days_from_civil(year, month, day) * 86400 + hour * 3600 + minute * 60 + second;
+
+        Ok(Timestamp {
+            unix_seconds: local - offset_minutes as i64 * 60,
+            offset_minutes,
+        })
+    }
+}
+
+impl std::fmt::Display for Timestamp {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let local = self.unix_seconds + self.offset_minutes as i64 * 60;
+        let (year, month, day) = civil_from_days(local.div_euclid(86400));
+        let seconds = local.rem_euclid(86400);
+        let offset = self.offset_minutes.abs();
+
+        write!(
+            f,
+            "{year:04}-{month:02}-{day:02} {:02}:{:02}:{:02} {}{:02}{:02}",
+            seconds / 3600,
+            seconds / 60 % 60,
+            seconds % 60,
+            if self.offset_minutes < 0 { '-' } else { '+' },
+            offset / 60,
+            offset % 60
+        )
+    }
+}
+
+/// Days since the unix epoch of a date in the proleptic Gregorian calendar.
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+
+    era * 146097 + day_of_era - 719468
+}
+
+/// The date of a day since the unix epoch, the inverse of [`days_from_civil`].
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719468;
+    let era = days.div_euclid(146097);
+    let day_of_era = days - era * 146097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    (year, month, day)
+}
+
+#[cfg(test)]
+#[test]
+fn advancements_test() {
+    let json = r#"{
+        "minecraft:recipes/decorations/crafting_table": {
+            "criteria": { "unlock_right_away": "2024-06-15 18:00:00 +0200" },
+            "done": true
+        },
+        "minecraft:story/mine_stone": {
+            "criteria": { "get_stone": "2024-06-15 18:30:12 +0200" },
+            "done": true
+        },
+        "minecraft:adventure/adventuring_time": {
+            "criteria": {
+                "minecraft:plains": "2024-06-15 18:00:05 +0200",
+                "minecraft:desert": "2024-06-14 10:00:00 -0500"
+            },
+            "done": false
+        },
+        "minecraft:story/root": {
+            "criteria": { "crafting_table": "2024-06-15 16:05:00 +0000" },
+            "done": true
+        },
+        "DataVersion": 3953
+    }"#;
+    let advancements = Advancements::from_json(json).unwrap();
+
+    assert_eq!(advancements.data_version, 3953);
+    assert_eq!(advancements.completed().count(), 2);
+    assert_eq!(
+        advancements.recipes().collect::<Vec<_>>(),
+        vec!["minecraft:recipes/decorations/crafting_table"]
+    );
+
+    let (first, time) = advancements.first_completed().unwrap();
+    assert_eq!(first, "minecraft:story/root");
+    assert_eq!(time.to_string(), "2024-06-15 16:05:00 +0000");
+    assert_eq!(
+        advancements
+            .completion_order()
+            .iter()
+            .map(|(key, _)| *key)
+            .collect::<Vec<_>>(),
+        vec!["minecraft:story/root", "minecraft:story/mine_stone"]
+    );
+    assert_eq!(
+        advancements.completed_at("minecraft:adventure/adventuring_time"),
+        None
+    );
+
+    let criteria = advancements
+        .criteria_progress("minecraft:adventure/adventuring_time")
+        .unwrap();
+    assert_eq!(criteria[0].0, "minecraft:desert");
+    assert_eq!(criteria[0].1.unix_seconds, 1718377200);
+    assert_eq!(criteria[0].1.to_string(), "2024-06-14 10:00:00 -0500");
+
+    assert_eq!(
+        Advancements::from_json(&advancements.to_json()).unwrap(),
+        advancements
+    );
+    assert!("2024-13-01 00:00:00 +0000".parse::<Timestamp>().is_err());
+}
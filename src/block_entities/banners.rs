@@ -1,8 +1,9 @@
 use crate::{
     components::banner_patterns::BannerPattern,
-    traits::FromCompoundNbt,
-    util::{get_optional_name, get_t_compound_vec},
+    traits::{FromCompoundNbt, ToCompoundNbt},
+    util::{compound_list, get_optional_name, get_t_compound_vec, insert_optional},
 };
+use simdnbt::owned;
 
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -34,3 +35,14 @@ impl FromCompoundNbt for Banner {
         })
     }
 }
+
+impl ToCompoundNbt for Banner {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        insert_optional(&mut nbt, "CustomName", self.custom_name.clone());
+        nbt.insert("patterns", compound_list(&self.patterns));
+
+        nbt
+    }
+}
@@ -1,7 +1,8 @@
 use crate::{
-    traits::FromCompoundNbt,
-    util::{get_optional_lock, get_optional_name, get_owned_optional_string},
+    traits::{FromCompoundNbt, ToCompoundNbt},
+    util::{get_optional_lock, get_optional_name, get_owned_optional_string, insert_optional},
 };
+use simdnbt::owned;
 
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -44,3 +45,16 @@ impl FromCompoundNbt for Beacon {
         })
     }
 }
+
+impl ToCompoundNbt for Beacon {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        insert_optional(&mut nbt, "CustomName", self.custom_name.clone());
+        insert_optional(&mut nbt, "Lock", self.lock.clone());
+        insert_optional(&mut nbt, "primary_effect", self.primary_effect.clone());
+        insert_optional(&mut nbt, "secondary_effect", self.secondary_effect.clone());
+
+        nbt
+    }
+}
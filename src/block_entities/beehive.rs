@@ -1,8 +1,9 @@
 use crate::{
     components::bees::Bee,
-    traits::FromCompoundNbt,
-    util::{get_int_array, get_t_compound_vec},
+    traits::{FromCompoundNbt, ToCompoundNbt},
+    util::{compound_list, get_int_array, get_t_compound_vec, int_list},
 };
+use simdnbt::owned;
 
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -27,3 +28,16 @@ impl FromCompoundNbt for Beehive {
         Ok(Beehive { bees, flower_pos })
     }
 }
+
+impl ToCompoundNbt for Beehive {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        nbt.insert("bees", compound_list(&self.bees));
+        if !self.flower_pos.is_empty() {
+            nbt.insert("flower_pos", int_list(&self.flower_pos));
+        }
+
+        nbt
+    }
+}
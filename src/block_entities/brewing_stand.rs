@@ -1,9 +1,12 @@
 use crate::{
     error::SculkParseError,
     item::Item,
-    traits::FromCompoundNbt,
-    util::{get_optional_lock, get_optional_name, get_t_compound_vec},
+    traits::{FromCompoundNbt, ToCompoundNbt},
+    util::{
+        compound_list, get_optional_lock, get_optional_name, get_t_compound_vec, insert_optional,
+    },
 };
+use simdnbt::owned;
 
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -66,3 +69,17 @@ impl FromCompoundNbt for BrewingStand {
         })
     }
 }
+
+impl ToCompoundNbt for BrewingStand {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        nbt.insert("BrewTime", self.brew_time);
+        insert_optional(&mut nbt, "CustomName", self.custom_name.clone());
+        nbt.insert("Fuel", self.fuel);
+        nbt.insert("Items", compound_list(&self.items));
+        insert_optional(&mut nbt, "Lock", self.lock.clone());
+
+        nbt
+    }
+}
@@ -1,9 +1,10 @@
 use crate::{
     error::SculkParseError,
-    traits::FromCompoundNbt,
-    util::{get_doubles_array, get_owned_string},
+    traits::{FromCompoundNbt, ToCompoundNbt},
+    util::{get_doubles_array, get_owned_string, insert_optional},
     uuid::Uuid,
 };
+use simdnbt::owned;
 
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -162,3 +163,55 @@ impl FromCompoundNbt for Event {
         })
     }
 }
+
+impl ToCompoundNbt for CalibratedSculkSensor {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        nbt.insert("last_vibration_frequency", self.last_vibration_frequency);
+        nbt.insert("listener", self.listener.to_compound_nbt());
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for Listener {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        if let Some(event) = &self.event {
+            nbt.insert("event", event.to_compound_nbt());
+        }
+        nbt.insert("event_delay", self.event_delay);
+        nbt.insert("selector", self.selector.to_compound_nbt());
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for Selector {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        nbt.insert("tick", self.tick);
+        if let Some(event) = &self.event {
+            nbt.insert("event", event.to_compound_nbt());
+        }
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for Event {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        nbt.insert("distance", self.distance);
+        nbt.insert("game_event", self.game_event.as_str());
+        nbt.insert("pos", owned::NbtList::Double(self.pos.to_vec()));
+        insert_optional(&mut nbt, "projectile_owner", self.projectile_owner);
+        insert_optional(&mut nbt, "source", self.source);
+
+        nbt
+    }
+}
@@ -1,8 +1,9 @@
 use crate::{
     item::Item,
-    traits::FromCompoundNbt,
-    util::{get_int_array, get_t_compound_vec},
+    traits::{FromCompoundNbt, ToCompoundNbt},
+    util::{compound_list, get_int_array, get_t_compound_vec, int_list},
 };
+use simdnbt::owned;
 
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -42,3 +43,15 @@ impl FromCompoundNbt for Campfire {
         })
     }
 }
+
+impl ToCompoundNbt for Campfire {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        nbt.insert("CookingTimes", int_list(&self.cooking_times));
+        nbt.insert("CookingTotalTimes", int_list(&self.cooking_total_times));
+        nbt.insert("Items", compound_list(&self.items));
+
+        nbt
+    }
+}
@@ -1,6 +1,10 @@
 use crate::{
-    error::SculkParseError, item::Item, traits::FromCompoundNbt, util::get_t_compound_vec,
+    error::SculkParseError,
+    item::Item,
+    traits::{FromCompoundNbt, ToCompoundNbt},
+    util::{compound_list, get_t_compound_vec},
 };
+use simdnbt::owned;
 
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -34,3 +38,14 @@ impl FromCompoundNbt for ChiseledBookshelf {
         })
     }
 }
+
+impl ToCompoundNbt for ChiseledBookshelf {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        nbt.insert("Items", compound_list(&self.items));
+        nbt.insert("last_interacted_slot", self.last_interacted_slot);
+
+        nbt
+    }
+}
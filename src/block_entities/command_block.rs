@@ -1,8 +1,9 @@
 use crate::{
     error::SculkParseError,
-    traits::FromCompoundNbt,
-    util::{get_bool, get_owned_optional_string, get_owned_string},
+    traits::{FromCompoundNbt, ToCompoundNbt},
+    util::{get_bool, get_owned_optional_string, get_owned_string, insert_optional},
 };
+use simdnbt::owned;
 
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -90,3 +91,22 @@ impl FromCompoundNbt for CommandBlock {
         })
     }
 }
+
+impl ToCompoundNbt for CommandBlock {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        nbt.insert("auto", self.auto);
+        nbt.insert("Command", self.command.as_str());
+        nbt.insert("conditionMet", self.condition_met);
+        insert_optional(&mut nbt, "CustomName", self.custom_name.clone());
+        nbt.insert("LastExecution", self.last_execution);
+        nbt.insert("LastOutput", self.last_output.as_str());
+        nbt.insert("powered", self.powered);
+        nbt.insert("SuccessCount", self.success_count);
+        nbt.insert("TrackOutput", self.track_output);
+        nbt.insert("UpdateLastExecution", self.update_last_execution);
+
+        nbt
+    }
+}
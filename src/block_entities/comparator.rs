@@ -1,4 +1,8 @@
-use crate::{error::SculkParseError, traits::FromCompoundNbt};
+use crate::{
+    error::SculkParseError,
+    traits::{FromCompoundNbt, ToCompoundNbt},
+};
+use simdnbt::owned;
 
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -23,3 +27,13 @@ impl FromCompoundNbt for Comparator {
         })
     }
 }
+
+impl ToCompoundNbt for Comparator {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        nbt.insert("OutputSignal", self.output_signal);
+
+        nbt
+    }
+}
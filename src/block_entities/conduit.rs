@@ -1,4 +1,9 @@
-use crate::{error::SculkParseError, traits::FromCompoundNbt, uuid::Uuid};
+use crate::{
+    error::SculkParseError,
+    traits::{FromCompoundNbt, ToCompoundNbt},
+    uuid::Uuid,
+};
+use simdnbt::owned;
 
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -22,3 +27,13 @@ impl FromCompoundNbt for Conduit {
         })
     }
 }
+
+impl ToCompoundNbt for Conduit {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        nbt.insert("target", self.target);
+
+        nbt
+    }
+}
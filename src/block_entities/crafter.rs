@@ -1,9 +1,13 @@
 use crate::{
     error::SculkParseError,
     item::Item,
-    traits::FromCompoundNbt,
-    util::{get_bool, get_int_array, get_loot_table_data, get_optional_lock, get_t_compound_vec},
+    traits::{FromCompoundNbt, ToCompoundNbt},
+    util::{
+        compound_list, get_bool, get_int_array, get_loot_table_data, get_optional_lock,
+        get_t_compound_vec, insert_loot_table_data, insert_optional, int_list,
+    },
 };
+use simdnbt::owned;
 
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -70,3 +74,18 @@ impl FromCompoundNbt for Crafter {
         })
     }
 }
+
+impl ToCompoundNbt for Crafter {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        nbt.insert("crafting_ticks_remaining", self.crafting_ticks_remaining);
+        nbt.insert("triggered", self.triggered);
+        nbt.insert("disabled_slots", int_list(&self.disabled_slots));
+        nbt.insert("Items", compound_list(&self.items));
+        insert_optional(&mut nbt, "Lock", self.lock.clone());
+        insert_loot_table_data(&mut nbt, &self.loot_table, self.loot_table_seed);
+
+        nbt
+    }
+}
@@ -1,4 +1,9 @@
-use crate::{item::ItemWithNoSlot, traits::FromCompoundNbt, util::get_loot_table_data};
+use crate::{
+    item::ItemWithNoSlot,
+    traits::{FromCompoundNbt, ToCompoundNbt},
+    util::{get_loot_table_data, insert_loot_table_data, string_list},
+};
+use simdnbt::owned;
 
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -31,10 +36,8 @@ impl FromCompoundNbt for DecoratedPot {
     {
         let sherds = if let Some(sherds_list) = nbt.list("sherds") {
             let mut sherds: Vec<String> = vec![];
-            for sherd in sherds_list.strings().into_iter() {
-                // maybe???
-                let str = (*sherd.first().unwrap()).to_string();
-                sherds.push(str);
+            for sherd in sherds_list.strings().unwrap_or_default() {
+                sherds.push(sherd.to_string());
             }
 
             sherds
@@ -58,3 +61,17 @@ impl FromCompoundNbt for DecoratedPot {
         })
     }
 }
+
+impl ToCompoundNbt for DecoratedPot {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        nbt.insert("sherds", string_list(&self.sherds));
+        if let Some(item) = &self.item {
+            nbt.insert("item", item.to_compound_nbt());
+        }
+        insert_loot_table_data(&mut nbt, &self.loot_table, self.loot_table_seed);
+
+        nbt
+    }
+}
@@ -1,4 +1,8 @@
-use crate::{traits::FromCompoundNbt, util::get_optional_name};
+use crate::{
+    traits::{FromCompoundNbt, ToCompoundNbt},
+    util::{get_optional_name, insert_optional},
+};
+use simdnbt::owned;
 
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -21,3 +25,13 @@ impl FromCompoundNbt for EnchantingTable {
         })
     }
 }
+
+impl ToCompoundNbt for EnchantingTable {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        insert_optional(&mut nbt, "CustomName", self.custom_name.clone());
+
+        nbt
+    }
+}
@@ -1,4 +1,9 @@
-use crate::{error::SculkParseError, traits::FromCompoundNbt, util::get_bool};
+use crate::{
+    error::SculkParseError,
+    traits::{FromCompoundNbt, ToCompoundNbt},
+    util::get_bool,
+};
+use simdnbt::owned;
 
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -80,3 +85,27 @@ impl FromCompoundNbt for EndGateway {
         })
     }
 }
+
+impl ToCompoundNbt for ExitPortal {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        nbt.insert("X", self.x);
+        nbt.insert("Y", self.y);
+        nbt.insert("Z", self.z);
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for EndGateway {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        nbt.insert("Age", self.age);
+        nbt.insert("ExactTeleport", self.exact_teleport);
+        nbt.insert("ExitPortal", self.exit_portal.to_compound_nbt());
+
+        nbt
+    }
+}
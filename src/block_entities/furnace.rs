@@ -1,9 +1,12 @@
 use crate::{
     error::SculkParseError,
     item::Item,
-    traits::FromCompoundNbt,
-    util::{get_optional_lock, get_optional_name, get_t_compound_vec},
+    traits::{FromCompoundNbt, ToCompoundNbt},
+    util::{
+        compound_list, get_optional_lock, get_optional_name, get_t_compound_vec, insert_optional,
+    },
 };
+use simdnbt::owned;
 use std::collections::HashMap;
 
 #[derive(Debug, Clone, PartialEq)]
@@ -96,3 +99,24 @@ impl FromCompoundNbt for Furnace {
         })
     }
 }
+
+impl ToCompoundNbt for Furnace {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        nbt.insert("BurnTime", self.burn_time);
+        nbt.insert("CookTime", self.cook_time);
+        nbt.insert("CookTimeTotal", self.cook_time_total);
+        insert_optional(&mut nbt, "CustomName", self.custom_name.clone());
+        nbt.insert("Items", compound_list(&self.items));
+        insert_optional(&mut nbt, "Lock", self.lock.clone());
+
+        let mut recipes_used = owned::NbtCompound::new();
+        for (recipe, count) in &self.recipes_used {
+            recipes_used.insert(recipe.as_str(), *count);
+        }
+        nbt.insert("RecipesUsed", recipes_used);
+
+        nbt
+    }
+}
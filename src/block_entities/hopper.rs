@@ -1,8 +1,12 @@
 use crate::{
     item::Item,
-    traits::FromCompoundNbt,
-    util::{get_loot_table_data, get_optional_lock, get_optional_name, get_t_compound_vec},
+    traits::{FromCompoundNbt, ToCompoundNbt},
+    util::{
+        compound_list, get_loot_table_data, get_optional_lock, get_optional_name,
+        get_t_compound_vec, insert_loot_table_data, insert_optional,
+    },
 };
+use simdnbt::owned;
 
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -63,3 +67,17 @@ impl FromCompoundNbt for Hopper {
         })
     }
 }
+
+impl ToCompoundNbt for Hopper {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        insert_optional(&mut nbt, "CustomName", self.custom_name.clone());
+        nbt.insert("Items", compound_list(&self.items));
+        insert_optional(&mut nbt, "Lock", self.lock.clone());
+        insert_loot_table_data(&mut nbt, &self.loot_table, self.loot_table_seed);
+        nbt.insert("TransferCooldown", self.transfer_cooldown);
+
+        nbt
+    }
+}
@@ -1,4 +1,9 @@
-use crate::{item::ItemWithNoSlot, traits::FromCompoundNbt};
+use crate::{
+    item::ItemWithNoSlot,
+    traits::{FromCompoundNbt, ToCompoundNbt},
+    util::insert_optional,
+};
+use simdnbt::owned;
 
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -30,3 +35,20 @@ impl FromCompoundNbt for Jukebox {
         })
     }
 }
+
+impl ToCompoundNbt for Jukebox {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        if let Some(record_item) = &self.record_item {
+            nbt.insert("RecordItem", record_item.to_compound_nbt());
+        }
+        insert_optional(
+            &mut nbt,
+            "ticks_since_song_started",
+            self.ticks_since_song_started,
+        );
+
+        nbt
+    }
+}
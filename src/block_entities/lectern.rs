@@ -1,4 +1,9 @@
-use crate::{item::ItemWithNoSlot, traits::FromCompoundNbt};
+use crate::{
+    item::ItemWithNoSlot,
+    traits::{FromCompoundNbt, ToCompoundNbt},
+    util::insert_optional,
+};
+use simdnbt::owned;
 
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -30,3 +35,16 @@ impl FromCompoundNbt for Lectern {
         })
     }
 }
+
+impl ToCompoundNbt for Lectern {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        if let Some(book) = &self.book {
+            nbt.insert("Book", book.to_compound_nbt());
+        }
+        insert_optional(&mut nbt, "Page", self.page);
+
+        nbt
+    }
+}
@@ -1,7 +1,10 @@
 use crate::{
-    entity::MaybeEntity, error::SculkParseError, traits::FromCompoundNbt, util::get_owned_string,
+    entity::MaybeEntity,
+    error::SculkParseError,
+    traits::{FromCompoundNbt, ToCompoundNbt},
+    util::{compound_list, get_owned_string, insert_optional},
 };
-use simdnbt::borrow::NbtCompound;
+use simdnbt::{borrow::NbtCompound, owned};
 
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -314,3 +317,96 @@ impl FromCompoundNbt for DropChances {
         })
     }
 }
+
+impl ToCompoundNbt for MobSpawner {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        nbt.insert("Delay", self.delay);
+        nbt.insert("MaxNearbyEntities", self.max_nearby_entities);
+        nbt.insert("MaxSpawnDelay", self.max_spawn_delay);
+        nbt.insert("MinSpawnDelay", self.min_spawn_delay);
+        nbt.insert("RequiredPlayerRange", self.required_player_range);
+        nbt.insert("SpawnCount", self.spawn_count);
+        nbt.insert("SpawnData", self.spawn_data.to_compound_nbt());
+        insert_optional(
+            &mut nbt,
+            "SpawnPotentials",
+            self.spawn_potentials.as_deref().map(compound_list),
+        );
+        nbt.insert("SpawnRange", self.spawn_range);
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for PotentialSpawn {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        nbt.insert("weight", self.weight);
+        nbt.insert("data", self.data.to_compound_nbt());
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for SpawnData {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        nbt.insert("entity", self.entity.to_compound_nbt());
+        if let Some(custom_spawn_rules) = &self.custom_spawn_rules {
+            nbt.insert("custom_spawn_rules", custom_spawn_rules.to_compound_nbt());
+        }
+        if let Some(equipment) = &self.equipment {
+            nbt.insert("equipment", equipment.to_compound_nbt());
+        }
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for SpawnRules {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        nbt.insert("block_light_limit", self.block_light_limit);
+        nbt.insert("sky_light_limit", self.sky_light_limit);
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for Equipment {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        nbt.insert("loot_table", self.loot_table.as_str());
+        match &self.slot_drop_chances {
+            Some(DropChanceType::All(chance)) => nbt.insert("slot_drop_chances", *chance),
+            Some(DropChanceType::Indiviual(chances)) => {
+                nbt.insert("slot_drop_chances", chances.to_compound_nbt())
+            }
+            None => {}
+        }
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for DropChances {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        insert_optional(&mut nbt, "feet", self.feet);
+        insert_optional(&mut nbt, "legs", self.legs);
+        insert_optional(&mut nbt, "chest", self.chest);
+        insert_optional(&mut nbt, "head", self.head);
+        insert_optional(&mut nbt, "body", self.body);
+        insert_optional(&mut nbt, "mainhand", self.mainhand);
+        insert_optional(&mut nbt, "offhand", self.offhand);
+
+        nbt
+    }
+}
@@ -1,5 +1,10 @@
-use crate::{error::SculkParseError, traits::FromCompoundNbt, util::get_owned_string};
+use crate::{
+    error::SculkParseError,
+    traits::{FromCompoundNbt, ToCompoundNbt},
+    util::get_owned_string,
+};
 use jukebox::Jukebox;
+use simdnbt::owned;
 
 pub mod variant;
 
@@ -118,7 +123,7 @@ pub enum BlockEntityKind {
     /// `minecraft:furnace`
     Furnace(furnace::Furnace),
 
-    /// `minecraft:<wood>_hanging_sign` or `minecraft:<wood>_wall_hanging_sign`
+    /// `minecraft:hanging_sign`, `minecraft:<wood>_hanging_sign` or `minecraft:<wood>_wall_hanging_sign`
     HangingSign(sign::Sign),
 
     /// `minecraft:hopper`
@@ -167,6 +172,9 @@ pub enum BlockEntityKind {
     /// `minecraft:soul_campfire`
     SoulCampfire(campfire::Campfire),
 
+    /// `minecraft:brushable_block`, the id vanilla uses for both suspicious blocks
+    BrushableBlock(suspicious_block::SuspiciousBlock),
+
     /// `minecraft:suspicious_gravel`
     SuspiciousGravel(suspicious_block::SuspiciousBlock),
 
@@ -184,6 +192,10 @@ pub enum BlockEntityKind {
 
     /// `DUMMY`
     Dummy,
+
+    /// A block entity sculk can't read, like a sign from before 1.20, holding every tag but the base ones.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    Unknown(owned::NbtCompound),
 }
 
 impl FromCompoundNbt for BlockEntityKind {
@@ -332,7 +344,8 @@ impl FromCompoundNbt for BlockEntityKind {
             }
             "minecraft:end_portal" => BlockEntityKind::EndPortal,
             "minecraft:furnace" => BlockEntityKind::Furnace(Furnace::from_compound_nbt(&nbt)?),
-            "minecraft:oak_hanging_sign"
+            "minecraft:hanging_sign"
+            | "minecraft:oak_hanging_sign"
             | "minecraft:spruce_hanging_sign"
             | "minecraft:birch_hanging_sign"
             | "minecraft:jungle_hanging_sign"
@@ -440,6 +453,9 @@ impl FromCompoundNbt for BlockEntityKind {
             "minecraft:soul_campfire" => {
                 BlockEntityKind::SoulCampfire(Campfire::from_compound_nbt(&nbt)?)
             }
+            "minecraft:brushable_block" => {
+                BlockEntityKind::BrushableBlock(SuspiciousBlock::from_compound_nbt(nbt)?)
+            }
             "minecraft:suspicious_gravel" => {
                 BlockEntityKind::SuspiciousGravel(SuspiciousBlock::from_compound_nbt(&nbt)?)
             }
@@ -460,3 +476,65 @@ impl FromCompoundNbt for BlockEntityKind {
         Ok(kind)
     }
 }
+
+impl ToCompoundNbt for BlockEntityKind {
+    /// Only writes the fields specific to the kind, the base fields (`id`, `x`, `y`, `z`, ...) are written by the block entity.
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        match self {
+            BlockEntityKind::Banners(banner) => banner.to_compound_nbt(),
+            BlockEntityKind::Barrel(barrel) => barrel.to_compound_nbt(),
+            BlockEntityKind::Beacon(beacon) => beacon.to_compound_nbt(),
+            BlockEntityKind::Beehive(beehive) => beehive.to_compound_nbt(),
+            BlockEntityKind::BlastFurnace(furnace)
+            | BlockEntityKind::Furnace(furnace)
+            | BlockEntityKind::Smoker(furnace) => furnace.to_compound_nbt(),
+            BlockEntityKind::BrewingStand(brewing_stand) => brewing_stand.to_compound_nbt(),
+            BlockEntityKind::CalibratedSculkSensor(sensor) => sensor.to_compound_nbt(),
+            BlockEntityKind::Campfire(campfire) | BlockEntityKind::SoulCampfire(campfire) => {
+                campfire.to_compound_nbt()
+            }
+            BlockEntityKind::ChiseledBookshelf(bookshelf) => bookshelf.to_compound_nbt(),
+            BlockEntityKind::Chest(chest) | BlockEntityKind::TrappedChest(chest) => {
+                chest.to_compound_nbt()
+            }
+            BlockEntityKind::Comparator(comparator) => comparator.to_compound_nbt(),
+            BlockEntityKind::CommandBlock(command_block) => command_block.to_compound_nbt(),
+            BlockEntityKind::Conduit(conduit) => conduit.to_compound_nbt(),
+            BlockEntityKind::Crafter(crafter) => crafter.to_compound_nbt(),
+            BlockEntityKind::DecoratedPot(decorated_pot) => decorated_pot.to_compound_nbt(),
+            BlockEntityKind::Dispenser(dispenser) => dispenser.to_compound_nbt(),
+            BlockEntityKind::Dropper(dropper) => dropper.to_compound_nbt(),
+            BlockEntityKind::EnchantingTable(enchanting_table) => {
+                enchanting_table.to_compound_nbt()
+            }
+            BlockEntityKind::EndGateway(end_gateway) => end_gateway.to_compound_nbt(),
+            BlockEntityKind::HangingSign(sign) | BlockEntityKind::Sign(sign) => {
+                sign.to_compound_nbt()
+            }
+            BlockEntityKind::Hopper(hopper) => hopper.to_compound_nbt(),
+            BlockEntityKind::Jigsaw(jigsaw) => jigsaw.to_compound_nbt(),
+            BlockEntityKind::Jukebox(jukebox) => jukebox.to_compound_nbt(),
+            BlockEntityKind::Lectern(lectern) => lectern.to_compound_nbt(),
+            BlockEntityKind::MobSpawner(mob_spawner) => mob_spawner.to_compound_nbt(),
+            BlockEntityKind::Piston(piston) => piston.to_compound_nbt(),
+            BlockEntityKind::SculkCatalyst(catalyst) => catalyst.to_compound_nbt(),
+            BlockEntityKind::SculkSensor(sensor) => sensor.to_compound_nbt(),
+            BlockEntityKind::SculkShrieker(shrieker) => shrieker.to_compound_nbt(),
+            BlockEntityKind::ShulkerBox(shulker_box) => shulker_box.to_compound_nbt(),
+            BlockEntityKind::Skull(skull) => skull.to_compound_nbt(),
+            BlockEntityKind::StructureBlock(structure_block) => structure_block.to_compound_nbt(),
+            BlockEntityKind::BrushableBlock(block)
+            | BlockEntityKind::SuspiciousGravel(block)
+            | BlockEntityKind::SuspiciousSand(block) => block.to_compound_nbt(),
+            BlockEntityKind::TrialSpawner(spawner) => spawner.to_compound_nbt(),
+            BlockEntityKind::Vault(vault) => vault.to_compound_nbt(),
+            BlockEntityKind::Bed
+            | BlockEntityKind::Bell
+            | BlockEntityKind::DaylightDetector
+            | BlockEntityKind::EnderChest
+            | BlockEntityKind::EndPortal
+            | BlockEntityKind::Dummy => owned::NbtCompound::new(),
+            BlockEntityKind::Unknown(nbt) => nbt.clone(),
+        }
+    }
+}
@@ -1,8 +1,9 @@
+use simdnbt::owned;
 use std::collections::HashMap;
 
 use crate::{
     error::SculkParseError,
-    traits::FromCompoundNbt,
+    traits::{FromCompoundNbt, ToCompoundNbt},
     util::{get_bool, get_owned_string},
 };
 
@@ -142,3 +143,34 @@ impl FromCompoundNbt for BlockState {
         Ok(BlockState { name, properties })
     }
 }
+
+impl ToCompoundNbt for Piston {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        nbt.insert("blockState", self.block_state.to_compound_nbt());
+        nbt.insert("extending", self.extending);
+        nbt.insert("facing", i32::from(self.facing.clone()));
+        nbt.insert("progress", self.progress);
+        nbt.insert("source", self.source);
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for BlockState {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        nbt.insert("name", self.name.as_str());
+        if let Some(properties) = &self.properties {
+            let mut props = owned::NbtCompound::new();
+            for (key, value) in properties {
+                props.insert(key.as_str(), value.as_str());
+            }
+            nbt.insert("properties", props);
+        }
+
+        nbt
+    }
+}
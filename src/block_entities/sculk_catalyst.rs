@@ -1,4 +1,9 @@
-use crate::{error::SculkParseError, traits::FromCompoundNbt, util::get_int_array};
+use crate::{
+    error::SculkParseError,
+    traits::{FromCompoundNbt, ToCompoundNbt},
+    util::{compound_list, get_int_array, int_list},
+};
+use simdnbt::owned;
 
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -100,3 +105,29 @@ impl FromCompoundNbt for Cursor {
         })
     }
 }
+
+impl ToCompoundNbt for SculkCatalyst {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        nbt.insert("cursors", compound_list(&self.cursors));
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for Cursor {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        nbt.insert("charge", self.charge);
+        nbt.insert("pos", int_list(&self.pos));
+        nbt.insert("decay_delay", self.decay_delay);
+        nbt.insert("update_delay", self.update_delay);
+        if !self.facings.is_empty() {
+            nbt.insert("facings", owned::NbtList::Compound(self.facings.clone()));
+        }
+
+        nbt
+    }
+}
@@ -1,4 +1,8 @@
-use crate::{error::SculkParseError, traits::FromCompoundNbt};
+use crate::{
+    error::SculkParseError,
+    traits::{FromCompoundNbt, ToCompoundNbt},
+};
+use simdnbt::owned;
 
 use super::calibrated_sculk_sensor::Listener;
 
@@ -23,3 +27,13 @@ impl FromCompoundNbt for SculkShrieker {
         Ok(SculkShrieker { listener })
     }
 }
+
+impl ToCompoundNbt for SculkShrieker {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        nbt.insert("listener", self.listener.to_compound_nbt());
+
+        nbt
+    }
+}
@@ -1,4 +1,10 @@
-use crate::{color::Color, error::SculkParseError, traits::FromCompoundNbt, util::get_bool};
+use crate::{
+    color::Color,
+    error::SculkParseError,
+    traits::{FromCompoundNbt, ToCompoundNbt},
+    util::{get_bool, string_list},
+};
+use simdnbt::owned;
 
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -29,6 +35,17 @@ pub struct SignText {
     pub messages: Vec<String>,
 }
 
+#[cfg(feature = "text")]
+impl SignText {
+    /// Parses every line of the sign as a text component.
+    pub fn message_components(&self) -> Vec<crate::text::TextComponent> {
+        self.messages
+            .iter()
+            .map(|message| crate::text::TextComponent::parse(message))
+            .collect()
+    }
+}
+
 impl FromCompoundNbt for Sign {
     fn from_compound_nbt(nbt: &simdnbt::borrow::NbtCompound) -> Result<Self, SculkParseError>
     where
@@ -74,9 +91,8 @@ impl FromCompoundNbt for SignText {
         let filtered_messages = if let Some(list) = nbt.list("filtered_messages") {
             let mut filtered_messages: Vec<String> = vec![];
 
-            for message in list.strings().into_iter() {
-                let str = (*message.first().unwrap()).to_string();
-                filtered_messages.push(str);
+            for message in list.strings().unwrap_or_default() {
+                filtered_messages.push(message.to_string());
             }
 
             Some(filtered_messages)
@@ -89,9 +105,8 @@ impl FromCompoundNbt for SignText {
             .ok_or(SculkParseError::MissingField("messages".into()))?;
         let mut messages: Vec<String> = vec![];
 
-        for message in messages_list.strings().into_iter() {
-            let str = (*message.first().unwrap()).to_string();
-            messages.push(str);
+        for message in messages_list.strings().unwrap_or_default() {
+            messages.push(message.to_string());
         }
 
         Ok(SignText {
@@ -102,3 +117,30 @@ impl FromCompoundNbt for SignText {
         })
     }
 }
+
+impl ToCompoundNbt for Sign {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        nbt.insert("is_waxed", self.is_waxed);
+        nbt.insert("front_text", self.front_text.to_compound_nbt());
+        nbt.insert("back_text", self.back_text.to_compound_nbt());
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for SignText {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        nbt.insert("has_glowing_text", self.has_glowing_text);
+        nbt.insert("color", self.color.to_str());
+        if let Some(filtered_messages) = &self.filtered_messages {
+            nbt.insert("filtered_messages", string_list(filtered_messages));
+        }
+        nbt.insert("messages", string_list(&self.messages));
+
+        nbt
+    }
+}
@@ -1,9 +1,10 @@
 use crate::{
     error::SculkParseError,
-    traits::FromCompoundNbt,
-    util::{get_owned_optional_string, get_owned_string},
+    traits::{FromCompoundNbt, ToCompoundNbt},
+    util::{compound_list, get_owned_optional_string, get_owned_string, insert_optional},
     uuid::Uuid,
 };
+use simdnbt::owned;
 
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -162,3 +163,57 @@ impl FromCompoundNbt for Property {
         })
     }
 }
+
+impl ToCompoundNbt for Skull {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        insert_optional(&mut nbt, "custom_name", self.custom_name.clone());
+        insert_optional(&mut nbt, "note_block_sound", self.note_block_sound.clone());
+        insert_optional(
+            &mut nbt,
+            "profile",
+            self.profile.as_ref().map(SkullProfile::to_nbt_tag),
+        );
+
+        nbt
+    }
+}
+
+impl SkullProfile {
+    /// Converts the profile into either a string or a compound tag.
+    pub fn to_nbt_tag(&self) -> owned::NbtTag {
+        match self {
+            SkullProfile::Name(name) => owned::NbtTag::String(name.as_str().into()),
+            SkullProfile::Profile(profile) => owned::NbtTag::Compound(profile.to_compound_nbt()),
+        }
+    }
+}
+
+impl ToCompoundNbt for Profile {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        insert_optional(&mut nbt, "name", self.name.clone());
+        insert_optional(&mut nbt, "id", self.id);
+        insert_optional(
+            &mut nbt,
+            "properties",
+            self.properties.as_deref().map(compound_list),
+        );
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for Property {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        nbt.insert("name", self.name.as_str());
+        nbt.insert("value", self.value.as_str());
+        insert_optional(&mut nbt, "signature", self.signature.clone());
+
+        nbt
+    }
+}
@@ -1,8 +1,9 @@
 use crate::{
     error::SculkParseError,
-    traits::FromCompoundNbt,
+    traits::{FromCompoundNbt, ToCompoundNbt},
     util::{get_bool, get_owned_string},
 };
+use simdnbt::owned;
 
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -252,3 +253,29 @@ impl FromCompoundNbt for StructureBlock {
         })
     }
 }
+
+impl ToCompoundNbt for StructureBlock {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        nbt.insert("author", self.author.as_str());
+        nbt.insert("ignoreEntities", self.ignore_entities);
+        nbt.insert("integrity", self.integrity);
+        nbt.insert("metadata", self.metadata.as_str());
+        nbt.insert("mirror", <&str>::from(self.mirror.clone()));
+        nbt.insert("mode", <&str>::from(self.mode.clone()));
+        nbt.insert("name", self.name.as_str());
+        nbt.insert("posX", self.pos_x);
+        nbt.insert("posY", self.pos_y);
+        nbt.insert("posZ", self.pos_z);
+        nbt.insert("powered", self.powered);
+        nbt.insert("rotation", <&str>::from(self.rotation.clone()));
+        nbt.insert("seed", self.seed);
+        nbt.insert("showboundingbox", self.show_bounding_box);
+        nbt.insert("sizeX", self.size_x);
+        nbt.insert("sizeY", self.size_y);
+        nbt.insert("sizeZ", self.size_z);
+
+        nbt
+    }
+}
@@ -1,4 +1,9 @@
-use crate::{item::Item, traits::FromCompoundNbt, util::get_loot_table_data};
+use crate::{
+    item::Item,
+    traits::{FromCompoundNbt, ToCompoundNbt},
+    util::{get_loot_table_data, insert_loot_table_data},
+};
+use simdnbt::owned;
 
 /// Both loot table tags are removed once the items have been generated.
 #[derive(Debug, Clone, PartialEq)]
@@ -40,3 +45,16 @@ impl FromCompoundNbt for SuspiciousBlock {
         })
     }
 }
+
+impl ToCompoundNbt for SuspiciousBlock {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        insert_loot_table_data(&mut nbt, &self.loot_table, self.loot_table_seed);
+        if let Some(item) = &self.item {
+            nbt.insert("item", item.to_compound_nbt());
+        }
+
+        nbt
+    }
+}
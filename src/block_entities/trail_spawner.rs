@@ -1,9 +1,10 @@
 use crate::{
     error::SculkParseError,
-    traits::FromCompoundNbt,
-    util::{get_owned_optional_string, get_owned_string},
+    traits::{FromCompoundNbt, ToCompoundNbt},
+    util::{compound_list, get_owned_optional_string, get_owned_string, insert_optional},
     uuid::Uuid,
 };
+use simdnbt::owned;
 
 use super::mob_spawner::{PotentialSpawn, SpawnData};
 
@@ -212,3 +213,85 @@ impl FromCompoundNbt for LootTable {
         Ok(LootTable { weight, data })
     }
 }
+
+impl ToCompoundNbt for TrailSpawner {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        nbt.insert("required_player_range", self.required_player_range);
+        nbt.insert("target_cooldown_length", self.target_cooldown_length);
+        if let Some(normal_config) = &self.normal_config {
+            nbt.insert("normal_config", normal_config.to_compound_nbt());
+        }
+        if let Some(ominous_config) = &self.ominous_config {
+            nbt.insert("ominous_config", ominous_config.to_compound_nbt());
+        }
+        nbt.insert(
+            "registered_players",
+            Uuid::to_nbt_list(&self.registered_players),
+        );
+        nbt.insert("current_mobs", Uuid::to_nbt_list(&self.current_mobs));
+        nbt.insert("cooldown_ends_at", self.cooldown_ends_at);
+        nbt.insert("next_mob_spawns_at", self.next_mob_spawns_at);
+        nbt.insert("total_mobs_spawned", self.total_mobs_spawned);
+        if let Some(spawn_data) = &self.spawn_data {
+            nbt.insert("spawn_data", spawn_data.to_compound_nbt());
+        }
+        insert_optional(
+            &mut nbt,
+            "ejecting_loot_table",
+            self.ejecting_loot_table.clone(),
+        );
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for TrailSpawnerConfig {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        insert_optional(&mut nbt, "spawn_range", self.spawn_range);
+        insert_optional(&mut nbt, "total_mobs", self.total_mobs);
+        insert_optional(&mut nbt, "simultaneous_mobs", self.simultaneous_mobs);
+        insert_optional(
+            &mut nbt,
+            "total_mobs_added_per_player",
+            self.total_mobs_added_per_player,
+        );
+        insert_optional(
+            &mut nbt,
+            "simultaneous_mobs_added_per_player",
+            self.simultaneous_mobs_added_per_player,
+        );
+        insert_optional(&mut nbt, "ticks_between_spawn", self.ticks_between_spawn);
+        insert_optional(
+            &mut nbt,
+            "spawn_potentials",
+            self.spawn_potentials.as_deref().map(compound_list),
+        );
+        insert_optional(
+            &mut nbt,
+            "loot_tables_to_eject",
+            self.loot_tables_to_eject.as_deref().map(compound_list),
+        );
+        insert_optional(
+            &mut nbt,
+            "items_to_drop_when_ominous",
+            self.items_to_drop_when_ominous.clone(),
+        );
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for LootTable {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        nbt.insert("weight", self.weight);
+        nbt.insert("data", self.data.as_str());
+
+        nbt
+    }
+}
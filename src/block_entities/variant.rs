@@ -44,12 +44,14 @@ pub enum BlockEntityVariant {
     StructureBlock,
     Smoker,
     SoulCampfire,
+    BrushableBlock,
     SuspiciousGravel,
     SuspiciousSand,
     TrappedChest,
     TrialSpawner,
     Vault,
     Dummy,
+    Unknown,
 }
 
 impl BlockEntityKind {
@@ -96,12 +98,14 @@ impl BlockEntityKind {
             BlockEntityKind::StructureBlock(_) => BlockEntityVariant::StructureBlock,
             BlockEntityKind::Smoker(_) => BlockEntityVariant::Smoker,
             BlockEntityKind::SoulCampfire(_) => BlockEntityVariant::SoulCampfire,
+            BlockEntityKind::BrushableBlock(_) => BlockEntityVariant::BrushableBlock,
             BlockEntityKind::SuspiciousGravel(_) => BlockEntityVariant::SuspiciousGravel,
             BlockEntityKind::SuspiciousSand(_) => BlockEntityVariant::SuspiciousSand,
             BlockEntityKind::TrappedChest(_) => BlockEntityVariant::TrappedChest,
             BlockEntityKind::TrialSpawner(_) => BlockEntityVariant::TrialSpawner,
             BlockEntityKind::Vault(_) => BlockEntityVariant::Vault,
             BlockEntityKind::Dummy => BlockEntityVariant::Dummy,
+            BlockEntityKind::Unknown(_) => BlockEntityVariant::Unknown,
         }
     }
 }
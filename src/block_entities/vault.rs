@@ -1,10 +1,11 @@
 use crate::{
     error::SculkParseError,
     item::{Item, ItemWithNoSlot},
-    traits::FromCompoundNbt,
-    util::{get_owned_optional_string, get_t_compound_vec},
+    traits::{FromCompoundNbt, ToCompoundNbt},
+    util::{compound_list, get_owned_optional_string, get_t_compound_vec, insert_optional},
     uuid::Uuid,
 };
+use simdnbt::owned;
 
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -173,3 +174,78 @@ impl FromCompoundNbt for VaultSharedData {
         })
     }
 }
+
+impl ToCompoundNbt for Vault {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        nbt.insert("config", self.config.to_compound_nbt());
+        nbt.insert("server_data", self.server_data.to_compound_nbt());
+        nbt.insert("shared_data", self.shared_data.to_compound_nbt());
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for VaultConfig {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        insert_optional(&mut nbt, "loot_table", self.loot_table.clone());
+        insert_optional(
+            &mut nbt,
+            "override_loot_table_to_display",
+            self.override_loot_table_to_display.clone(),
+        );
+        insert_optional(&mut nbt, "activation_range", self.activation_range);
+        insert_optional(&mut nbt, "deactivation_range", self.deactivation_range);
+        nbt.insert("key_item", self.key_item.to_compound_nbt());
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for VaultServerData {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        nbt.insert(
+            "rewarded_players",
+            Uuid::to_nbt_list(&self.rewarded_players),
+        );
+        insert_optional(
+            &mut nbt,
+            "state_updating_resumes_at",
+            self.state_updating_resumes_at,
+        );
+        nbt.insert("items_to_eject", compound_list(&self.items_to_eject));
+        insert_optional(
+            &mut nbt,
+            "total_ejections_needed",
+            self.total_ejections_needed,
+        );
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for VaultSharedData {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        if let Some(display_item) = &self.display_item {
+            nbt.insert("display_item", display_item.to_compound_nbt());
+        }
+        nbt.insert(
+            "connected_players",
+            Uuid::to_nbt_list(&self.connected_players),
+        );
+        insert_optional(
+            &mut nbt,
+            "connected_particles_range",
+            self.connected_particles_range,
+        );
+
+        nbt
+    }
+}
@@ -2,12 +2,18 @@ use crate::{
     block_entities::{variant::BlockEntityVariant, BlockEntityKind},
     components::Components,
     error::SculkParseError,
-    traits::{FromCompoundNbt, FromNbt},
-    util::{get_bool, get_optional_components, get_owned_string},
+    traits::{FromCompoundNbt, FromNbt, ToCompoundNbt},
+    util::{
+        get_bool, get_optional_components, get_owned_string, get_unknown_tags,
+        insert_optional_components,
+    },
 };
-use simdnbt::borrow::BaseNbt;
+use simdnbt::{borrow::BaseNbt, owned};
 use std::io::Cursor;
 
+/// The tags parsed into [`BlockEntityBase`], never kept in [`BlockEntityKind::Unknown`].
+const BASE_KEYS: [&str; 6] = ["components", "id", "keepPacked", "x", "y", "z"];
+
 /// The base fields of a block entity.
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -257,6 +263,20 @@ impl FromCompoundNbt for BlockEntity {
     }
 }
 
+impl BlockEntity {
+    /// Parses a block entity, keeping the tags of one sculk can't read in [`BlockEntityKind::Unknown`] instead of failing.
+    /// Only fails if the base fields are missing.
+    pub fn from_compound_nbt_or_unknown(
+        nbt: &simdnbt::borrow::NbtCompound,
+    ) -> Result<Self, SculkParseError> {
+        let base = BlockEntityBase::from_compound_nbt(nbt)?;
+        let kind = BlockEntityKind::from_compound_nbt(nbt)
+            .unwrap_or_else(|_| BlockEntityKind::Unknown(get_unknown_tags(nbt, &BASE_KEYS)));
+
+        Ok(Self { base, kind })
+    }
+}
+
 impl FromCompoundNbt for NoCoordinatesBlockEntity {
     fn from_compound_nbt(nbt: &simdnbt::borrow::NbtCompound) -> Result<Self, SculkParseError>
     where
@@ -269,6 +289,51 @@ impl FromCompoundNbt for NoCoordinatesBlockEntity {
     }
 }
 
+impl ToCompoundNbt for BlockEntityBase {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        insert_optional_components(&mut nbt, &self.components);
+        nbt.insert("id", self.id.as_str());
+        nbt.insert("keepPacked", self.keep_packed);
+        nbt.insert("x", self.x);
+        nbt.insert("y", self.y);
+        nbt.insert("z", self.z);
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for NoCoordinatesBlockEntityBase {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        insert_optional_components(&mut nbt, &self.components);
+        nbt.insert("id", self.id.as_str());
+        nbt.insert("keepPacked", self.keep_packed);
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for BlockEntity {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = self.kind.to_compound_nbt();
+        nbt.extend(self.base.to_compound_nbt());
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for NoCoordinatesBlockEntity {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = self.kind.to_compound_nbt();
+        nbt.extend(self.base.to_compound_nbt());
+
+        nbt
+    }
+}
+
 // It got its own silly implementation :3
 impl<'a> LazyBlockEntity<'a> {
     fn from_nbt(nbt: simdnbt::borrow::Nbt, bytes: &'a [u8]) -> Result<Self, SculkParseError>
@@ -387,3 +452,36 @@ fn test() {
     let _ = BlockEntity::from_bytes(bytes.as_slice()).unwrap();
     println!("LazyBlockEntity: {:?}", instant.elapsed());
 }
+
+#[cfg(test)]
+#[test]
+fn block_entity_round_trip() {
+    use simdnbt::owned::{NbtList, NbtTag};
+
+    for path in [
+        "test_data/chest.nbt",
+        "test_data/chest_banner.nbt",
+        "test_data/chest_tool.nbt",
+    ] {
+        let bytes = crate::util::read_test_data(path);
+        let block_entity = BlockEntity::from_bytes(&bytes).unwrap();
+
+        // These were written by hand with an int `keepPacked` and the `Count` of items from before 1.20.5,
+        // which are written back as a byte and `count` like the game does.
+        let mut original = simdnbt::owned::read(&mut std::io::Cursor::new(&bytes[..]))
+            .unwrap()
+            .unwrap()
+            .into_inner();
+        original.remove("keepPacked");
+        original.insert("keepPacked", false);
+        if let Some(NbtList::Compound(items)) = original.list_mut("Items") {
+            for item in items {
+                if let Some(NbtTag::Int(count)) = item.remove("Count") {
+                    item.insert("count", count);
+                }
+            }
+        }
+
+        crate::util::assert_same_nbt(&original, &block_entity.to_compound_nbt());
+    }
+}
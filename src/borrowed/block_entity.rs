@@ -0,0 +1,101 @@
+use super::get_borrowed_string;
+use crate::{
+    block_entities::BlockEntityKind,
+    block_entity::{self, BlockEntityBase},
+    components::Components,
+    error::SculkParseError,
+    traits::{FromBorrowedCompoundNbt, FromCompoundNbt},
+    util::{get_bool, get_optional_components},
+    version::{self, DataVersion},
+};
+use simdnbt::borrow::NbtCompound;
+use std::borrow::Cow;
+
+/// Borrowed variant of [`block_entity::BlockEntity`].
+/// Only the base fields are parsed, the [`BlockEntityKind`] and components are parsed when accessed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlockEntity<'a> {
+    /// ID of block entity.
+    /// `id`
+    pub id: Cow<'a, str>,
+
+    /// If true, this is an invalid block entity, and this block is not immediately placed when a loaded chunk is loaded.
+    /// `keepPacked`
+    pub keep_packed: bool,
+
+    /// X coordinate of the block entity.
+    /// `x`
+    pub x: i32,
+
+    /// Y coordinate of the block entity.
+    /// `y`
+    pub y: i32,
+
+    /// Z coordinate of the block entity.
+    /// `z`
+    pub z: i32,
+
+    /// The whole block entity compound.
+    nbt: NbtCompound<'a, 'a>,
+
+    /// The data version the block entity was read with, set again when its kind and components are parsed.
+    version: Option<DataVersion>,
+}
+
+impl<'a> FromBorrowedCompoundNbt<'a> for BlockEntity<'a> {
+    fn from_borrowed_compound_nbt(nbt: &NbtCompound<'a, 'a>) -> Result<Self, SculkParseError> {
+        let x = nbt
+            .int("x")
+            .ok_or(SculkParseError::MissingField("x".into()))?;
+        let y = nbt
+            .int("y")
+            .ok_or(SculkParseError::MissingField("y".into()))?;
+        let z = nbt
+            .int("z")
+            .ok_or(SculkParseError::MissingField("z".into()))?;
+
+        Ok(BlockEntity {
+            id: get_borrowed_string(nbt, "id")?,
+            keep_packed: get_bool(nbt, "keepPacked"),
+            x,
+            y,
+            z,
+            nbt: *nbt,
+            version: version::current(),
+        })
+    }
+}
+
+impl<'a> BlockEntity<'a> {
+    /// The whole block entity compound.
+    pub fn nbt(&self) -> &NbtCompound<'a, 'a> {
+        &self.nbt
+    }
+
+    /// Parses the data specific to this block entity.
+    pub fn kind(&self) -> Result<BlockEntityKind, SculkParseError> {
+        version::with_version(self.version, || {
+            BlockEntityKind::from_compound_nbt(&self.nbt)
+        })
+    }
+
+    /// Parses the components of the block entity.
+    pub fn components(&self) -> Result<Option<Components>, SculkParseError> {
+        version::with_version(self.version, || get_optional_components(&self.nbt))
+    }
+
+    /// Converts the block entity into an owned [`block_entity::BlockEntity`], parsing its kind and components.
+    pub fn into_owned(self) -> Result<block_entity::BlockEntity, SculkParseError> {
+        let kind = self.kind()?;
+        let base = BlockEntityBase {
+            components: self.components()?,
+            id: self.id.into_owned(),
+            keep_packed: self.keep_packed,
+            x: self.x,
+            y: self.y,
+            z: self.z,
+        };
+
+        Ok(block_entity::BlockEntity { base, kind })
+    }
+}
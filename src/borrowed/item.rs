@@ -0,0 +1,143 @@
+use super::get_borrowed_string;
+use crate::{
+    components::Components,
+    error::SculkParseError,
+    item,
+    traits::FromBorrowedCompoundNbt,
+    util::{get_item_count, get_optional_item_components},
+    version::{self, DataVersion},
+};
+use simdnbt::borrow::NbtCompound;
+use std::borrow::Cow;
+
+/// Borrowed variant of [`item::Item`].
+/// The components are only parsed when accessed.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Item<'a> {
+    /// The inventory slot the item is in.
+    /// `Slot`
+    pub slot: i8,
+
+    /// The resource location of the item.
+    /// `id`
+    pub id: Cow<'a, str>,
+
+    /// Number of items stacked in this inventory slot.
+    /// `count`
+    pub count: i32,
+
+    /// The whole item compound, the components are parsed from it when accessed.
+    nbt: NbtCompound<'a, 'a>,
+
+    /// The data version the item was read with, set again when its components are parsed.
+    version: Option<DataVersion>,
+}
+
+/// Borrowed variant of [`item::ItemWithNoSlot`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ItemWithNoSlot<'a> {
+    /// The resource location of the item.
+    /// `id`
+    pub id: Cow<'a, str>,
+
+    /// Number of items stacked in this inventory slot.
+    /// `count`
+    pub count: i32,
+
+    /// The whole item compound, the components are parsed from it when accessed.
+    nbt: NbtCompound<'a, 'a>,
+
+    /// The data version the item was read with, set again when its components are parsed.
+    version: Option<DataVersion>,
+}
+
+impl<'a> FromBorrowedCompoundNbt<'a> for Item<'a> {
+    fn from_borrowed_compound_nbt(nbt: &NbtCompound<'a, 'a>) -> Result<Self, SculkParseError> {
+        let slot = nbt
+            .byte("Slot")
+            .ok_or(SculkParseError::MissingField("Slot".into()))?;
+        let item = ItemWithNoSlot::from_borrowed_compound_nbt(nbt)?;
+
+        Ok(Item {
+            slot,
+            id: item.id,
+            count: item.count,
+            nbt: item.nbt,
+            version: item.version,
+        })
+    }
+}
+
+impl<'a> FromBorrowedCompoundNbt<'a> for ItemWithNoSlot<'a> {
+    fn from_borrowed_compound_nbt(nbt: &NbtCompound<'a, 'a>) -> Result<Self, SculkParseError> {
+        let count = get_item_count(nbt);
+
+        Ok(ItemWithNoSlot {
+            id: get_borrowed_string(nbt, "id")?,
+            count,
+            nbt: *nbt,
+            version: version::current(),
+        })
+    }
+}
+
+impl Item<'_> {
+    /// Parses the components of the item.
+    pub fn components(&self) -> Result<Option<Components>, SculkParseError> {
+        version::with_version(self.version, || get_optional_item_components(&self.nbt))
+    }
+
+    /// Converts the item into an owned [`item::Item`], parsing its components.
+    pub fn into_owned(self) -> Result<item::Item, SculkParseError> {
+        Ok(item::Item {
+            slot: self.slot,
+            components: self.components()?,
+            id: self.id.into_owned(),
+            count: self.count,
+        })
+    }
+}
+
+impl ItemWithNoSlot<'_> {
+    /// Parses the components of the item.
+    pub fn components(&self) -> Result<Option<Components>, SculkParseError> {
+        version::with_version(self.version, || get_optional_item_components(&self.nbt))
+    }
+
+    /// Converts the item into an owned [`item::ItemWithNoSlot`], parsing its components.
+    pub fn into_owned(self) -> Result<item::ItemWithNoSlot, SculkParseError> {
+        Ok(item::ItemWithNoSlot {
+            components: self.components()?,
+            id: self.id.into_owned(),
+            count: self.count,
+        })
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn borrowed_item_test() {
+    use super::get_borrowed_vec;
+    use crate::traits::FromCompoundNbt;
+
+    let bytes = std::fs::read("test_data/chest_tool.nbt").unwrap();
+    let base = simdnbt::borrow::read(&mut std::io::Cursor::new(bytes.as_slice()))
+        .unwrap()
+        .unwrap();
+    let nbt = base.as_compound();
+
+    let items: Vec<Item> = get_borrowed_vec(&nbt, "Items").unwrap();
+    let owned =
+        crate::util::get_t_compound_vec(&nbt, "Items", item::Item::from_compound_nbt).unwrap();
+
+    assert!(!items.is_empty());
+    assert!(items.iter().all(|item| matches!(item.id, Cow::Borrowed(_))));
+    assert_eq!(
+        items
+            .into_iter()
+            .map(Item::into_owned)
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap(),
+        owned
+    );
+}
@@ -0,0 +1,286 @@
+//! Borrowed variants of the most allocation heavy data structures.
+//!
+//! The types in this module keep their strings as [`Cow<'a, str>`](std::borrow::Cow) slices into the
+//! Nbt input buffer instead of copying them, which makes scanning a lot of chunks noticeably faster.
+//! Data that is rarely needed, like the [`BlockEntityKind`](crate::block_entities::BlockEntityKind) of a block entity
+//! or the components of an item, is kept as a borrowed Nbt compound and only parsed when asked for.
+//! Every type has an `into_owned()` method that converts it into its owned counterpart.
+//!
+//! The borrowed data lives as long as the parsed [`BaseNbt`], so it has to be kept around by the caller.
+//!
+//! ```no_run
+//! use sculk::borrowed;
+//!
+//! let bytes = std::fs::read("chunk.nbt").unwrap();
+//! let nbt = simdnbt::borrow::read(&mut std::io::Cursor::new(bytes.as_slice()))
+//!     .unwrap()
+//!     .unwrap();
+//!
+//! let chunk = borrowed::Chunk::from_base_nbt(&nbt).unwrap();
+//! let owned: sculk::chunk::Chunk = chunk.into_owned().unwrap();
+//! ```
+
+use crate::{
+    chunk::{self, status::ChunkStatus},
+    error::SculkParseError,
+    traits::FromBorrowedCompoundNbt,
+    version::{self, DataVersion},
+};
+use simdnbt::{
+    borrow::{BaseNbt, NbtCompound},
+    Mutf8Str,
+};
+use std::borrow::Cow;
+
+mod block_entity;
+mod item;
+mod section;
+
+pub use block_entity::*;
+pub use item::*;
+pub use section::*;
+
+/// Borrowed variant of [`chunk::Chunk`].
+/// Only the sections and block entities are borrowed, the remaining fields are parsed by [`Chunk::into_owned`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Chunk<'a> {
+    /// Version of the chunk NBT structure.
+    /// `DataVersion`
+    pub data_version: i32,
+
+    /// X position of the chunk in absolute chunks.
+    /// `xPos`
+    pub x_pos: i32,
+    /// Z position of the chunk in absolute chunks.
+    /// `zPos`
+    pub z_pos: i32,
+    /// Lowest Y section position in the chunk.
+    /// `yPos`
+    pub y_pos: i32,
+
+    /// Defines the world generation status of this chunk.
+    /// `Status`
+    pub status: ChunkStatus,
+
+    /// Tick when the chunk was last saved.
+    /// `LastUpdate`
+    pub last_update: i64,
+
+    /// The cumulative number of ticks players have been in this chunk.
+    /// `InhabitedTime`
+    pub inhabited_time: i64,
+
+    /// The sections of the chunk.
+    /// `sections`
+    pub sections: Vec<ChunkSection<'a>>,
+
+    /// The block entities of the chunk.
+    /// `block_entities`
+    pub block_entities: Vec<BlockEntity<'a>>,
+
+    /// The whole chunk compound, used for the fields that are not borrowed.
+    nbt: NbtCompound<'a, 'a>,
+}
+
+impl<'a> FromBorrowedCompoundNbt<'a> for Chunk<'a> {
+    fn from_borrowed_compound_nbt(nbt: &NbtCompound<'a, 'a>) -> Result<Self, SculkParseError> {
+        let data_version = nbt
+            .int("DataVersion")
+            .ok_or(SculkParseError::MissingField("DataVersion".into()))?;
+
+        let x_pos = nbt
+            .int("xPos")
+            .ok_or(SculkParseError::MissingField("xPos".into()))?;
+        let z_pos = nbt
+            .int("zPos")
+            .ok_or(SculkParseError::MissingField("zPos".into()))?;
+        let y_pos = nbt
+            .int("yPos")
+            .ok_or(SculkParseError::MissingField("yPos".into()))?;
+
+        let status = nbt
+            .string("Status")
+            .map(|s| ChunkStatus::from(s.to_str().as_ref()))
+            .ok_or(SculkParseError::MissingField("Status".into()))?;
+
+        let last_update = nbt
+            .long("LastUpdate")
+            .ok_or(SculkParseError::MissingField("LastUpdate".into()))?;
+        let inhabited_time = nbt
+            .long("InhabitedTime")
+            .ok_or(SculkParseError::MissingField("InhabitedTime".into()))?;
+
+        // The block entities keep this version for the components and kinds they parse later.
+        let (sections, block_entities) =
+            version::with_version(Some(DataVersion(data_version)), || {
+                Ok::<_, SculkParseError>((
+                    get_borrowed_vec(nbt, "sections")?,
+                    get_borrowed_vec(nbt, "block_entities")?,
+                ))
+            })?;
+
+        Ok(Chunk {
+            data_version,
+            x_pos,
+            z_pos,
+            y_pos,
+            status,
+            last_update,
+            inhabited_time,
+            sections,
+            block_entities,
+            nbt: *nbt,
+        })
+    }
+}
+
+impl<'a> Chunk<'a> {
+    /// Parses a chunk that borrows from the given Nbt.
+    pub fn from_base_nbt(nbt: &'a BaseNbt<'a>) -> Result<Self, SculkParseError> {
+        Chunk::from_borrowed_compound_nbt(&nbt.as_compound())
+    }
+
+    /// The whole chunk compound, for reading fields that are not part of the borrowed chunk.
+    pub fn nbt(&self) -> &NbtCompound<'a, 'a> {
+        &self.nbt
+    }
+
+    /// Returns the section containing the given block Y coordinate.
+    pub fn section_at(&self, y: i32) -> Option<&ChunkSection<'a>> {
+        let section_y = y.div_euclid(16);
+
+        match usize::try_from(section_y - self.y_pos)
+            .ok()
+            .and_then(|i| self.sections.get(i))
+        {
+            Some(section) if section.y as i32 == section_y => Some(section),
+            _ => self.sections.iter().find(|s| s.y as i32 == section_y),
+        }
+    }
+
+    /// Returns the block state at the given world position.
+    /// Only the lowest 4 bits of X and Z are used, so the position is not checked to be inside this chunk.
+    pub fn block_at(&self, x: i32, y: i32, z: i32) -> Option<&Palette<'a>> {
+        self.section_at(y)?.block_at(
+            x.rem_euclid(16) as usize,
+            y.rem_euclid(16) as usize,
+            z.rem_euclid(16) as usize,
+        )
+    }
+
+    /// Converts the chunk into an owned [`chunk::Chunk`], parsing the fields that were not borrowed.
+    pub fn into_owned(self) -> Result<chunk::Chunk, SculkParseError> {
+        let sections = self
+            .sections
+            .into_iter()
+            .map(ChunkSection::into_owned)
+            .collect();
+        let block_entities = self
+            .block_entities
+            .into_iter()
+            .map(BlockEntity::into_owned)
+            .collect::<Result<Vec<_>, _>>()?;
+
+        version::with_version(Some(DataVersion(self.data_version)), || {
+            chunk::Chunk::from_parts(&self.nbt, sections, block_entities)
+        })
+    }
+}
+
+/// Borrowed counterpart of `util::get_t_compound_vec`.
+pub(crate) fn get_borrowed_vec<'a, T: FromBorrowedCompoundNbt<'a>>(
+    nbt: &NbtCompound<'a, 'a>,
+    key: &'static str,
+) -> Result<Vec<T>, SculkParseError> {
+    let list = match nbt.list(key) {
+        Some(list) => list,
+        None => return Ok(vec![]),
+    };
+
+    if list.empty() {
+        return Ok(vec![]);
+    }
+
+    list.compounds()
+        .ok_or(SculkParseError::InvalidField(key.into()))?
+        .into_iter()
+        .map(|nbt| T::from_borrowed_compound_nbt(&nbt))
+        .collect()
+}
+
+/// Gets a string that borrows from the input buffer.
+pub(crate) fn get_borrowed_string<'a>(
+    nbt: &NbtCompound<'a, 'a>,
+    key: &'static str,
+) -> Result<Cow<'a, str>, SculkParseError> {
+    nbt.string(key)
+        .map(Mutf8Str::to_str)
+        .ok_or(SculkParseError::InvalidField(key.into()))
+}
+
+#[cfg(test)]
+#[test]
+fn borrowed_chunk_test() {
+    use crate::traits::FromCompoundNbt;
+    use simdnbt::owned;
+
+    let chest = std::fs::read("test_data/chest.nbt").unwrap();
+    let chest = simdnbt::owned::read(&mut std::io::Cursor::new(chest.as_slice()))
+        .unwrap()
+        .unwrap()
+        .into_inner();
+
+    let mut stone = owned::NbtCompound::new();
+    stone.insert("Name", "minecraft:stone");
+    let mut air = owned::NbtCompound::new();
+    air.insert("Name", "minecraft:air");
+    let mut block_states = owned::NbtCompound::new();
+    block_states.insert(
+        "palette",
+        owned::NbtList::Compound(vec![air.clone(), stone.clone()]),
+    );
+    block_states.insert("data", owned::NbtTag::LongArray(vec![0x10; 256]));
+    let mut section = owned::NbtCompound::new();
+    section.insert("Y", -4i8);
+    section.insert("block_states", block_states);
+
+    let mut structures = owned::NbtCompound::new();
+    structures.insert("References", owned::NbtCompound::new());
+    structures.insert("starts", owned::NbtCompound::new());
+
+    let mut nbt = owned::NbtCompound::new();
+    nbt.insert("DataVersion", 3953);
+    nbt.insert("xPos", 2);
+    nbt.insert("zPos", -1);
+    nbt.insert("yPos", -4);
+    nbt.insert("Status", "minecraft:full");
+    nbt.insert("LastUpdate", 100i64);
+    nbt.insert("InhabitedTime", 20i64);
+    nbt.insert("sections", owned::NbtList::Compound(vec![section]));
+    nbt.insert("block_entities", owned::NbtList::Compound(vec![chest]));
+    nbt.insert("Heightmaps", owned::NbtCompound::new());
+    nbt.insert("structures", structures);
+
+    let bytes = crate::util::write_nbt(&nbt);
+    let base = simdnbt::borrow::read(&mut std::io::Cursor::new(bytes.as_slice()))
+        .unwrap()
+        .unwrap();
+
+    let chunk = Chunk::from_base_nbt(&base).unwrap();
+    assert_eq!(chunk.x_pos, 2);
+    assert!(matches!(
+        chunk.block_at(32, -64, -16).unwrap().name,
+        Cow::Borrowed("minecraft:air")
+    ));
+    assert_eq!(
+        chunk.block_at(33, -64, -16).unwrap().name,
+        "minecraft:stone"
+    );
+    assert!(matches!(chunk.block_entities[0].id, Cow::Borrowed(_)));
+
+    let owned = chunk.into_owned().unwrap();
+    assert_eq!(
+        owned,
+        chunk::Chunk::from_compound_nbt(&base.as_compound()).unwrap()
+    );
+}
@@ -0,0 +1,233 @@
+use super::{get_borrowed_string, get_borrowed_vec};
+use crate::{
+    chunk::section::{self, bits_for, unpack},
+    error::SculkParseError,
+    kv::KVPair,
+    traits::FromBorrowedCompoundNbt,
+};
+use simdnbt::borrow::NbtCompound;
+use std::borrow::Cow;
+
+/// Borrowed variant of [`section::ChunkSection`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChunkSection<'a> {
+    /// The Y position of this section.
+    /// `Y`
+    pub y: i8,
+
+    pub block_states: Option<BlockStates<'a>>,
+
+    pub biomes: Option<Biomes<'a>>,
+
+    pub block_light: Option<&'a [u8]>,
+
+    pub sky_light: Option<&'a [u8]>,
+}
+
+/// Borrowed variant of [`section::BlockStates`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct BlockStates<'a> {
+    /// Set of different block states used in this particular section.
+    pub palette: Vec<Palette<'a>>,
+
+    /// A packed array of 4096 indices pointing to the palette.
+    pub data: Option<Vec<i64>>,
+}
+
+/// Borrowed variant of [`section::Biomes`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Biomes<'a> {
+    /// Set of different biomes used in this particular section.
+    pub palette: Vec<Cow<'a, str>>,
+
+    /// A packed array of 64 indices pointing to the palette.
+    pub data: Option<Vec<i64>>,
+}
+
+/// Borrowed variant of [`section::Palette`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct Palette<'a> {
+    /// Block resource location.
+    /// `Name`
+    pub name: Cow<'a, str>,
+
+    /// List of block state properties as name and value pairs.
+    /// `Properties`
+    pub properties: Vec<(Cow<'a, str>, Cow<'a, str>)>,
+}
+
+impl<'a> ChunkSection<'a> {
+    /// Returns the block state at the given position relative to the section.
+    pub fn block_at(&self, x: usize, y: usize, z: usize) -> Option<&Palette<'a>> {
+        self.block_states.as_ref()?.get(x, y, z)
+    }
+
+    /// Returns the biome at the given block position relative to the section.
+    pub fn biome_at(&self, x: usize, y: usize, z: usize) -> Option<&str> {
+        self.biomes.as_ref()?.get(x / 4, y / 4, z / 4)
+    }
+
+    /// Converts the section into an owned [`section::ChunkSection`].
+    pub fn into_owned(self) -> section::ChunkSection {
+        section::ChunkSection {
+            y: self.y,
+            block_states: self.block_states.map(BlockStates::into_owned),
+            biomes: self.biomes.map(Biomes::into_owned),
+            block_light: self.block_light.map(<[u8]>::to_vec),
+            sky_light: self.sky_light.map(<[u8]>::to_vec),
+        }
+    }
+}
+
+impl<'a> BlockStates<'a> {
+    /// Returns the block state at the given position relative to the section.
+    pub fn get(&self, x: usize, y: usize, z: usize) -> Option<&Palette<'a>> {
+        if x >= 16 || y >= 16 || z >= 16 {
+            return None;
+        }
+
+        let index = match &self.data {
+            Some(data) if self.palette.len() > 1 => unpack(
+                data,
+                bits_for(self.palette.len()).max(4),
+                (y * 16 + z) * 16 + x,
+            ),
+            _ => 0,
+        };
+
+        self.palette.get(index)
+    }
+
+    /// Converts the block states into owned [`section::BlockStates`].
+    pub fn into_owned(self) -> section::BlockStates {
+        section::BlockStates {
+            palette: self.palette.into_iter().map(Palette::into_owned).collect(),
+            data: self.data,
+        }
+    }
+}
+
+impl<'a> Biomes<'a> {
+    /// Returns the biome at the given biome cell, each axis going from 0 to 3.
+    pub fn get(&self, x: usize, y: usize, z: usize) -> Option<&str> {
+        if x >= 4 || y >= 4 || z >= 4 {
+            return None;
+        }
+
+        let index = match &self.data {
+            Some(data) if self.palette.len() > 1 => {
+                unpack(data, bits_for(self.palette.len()), (y * 4 + z) * 4 + x)
+            }
+            _ => 0,
+        };
+
+        self.palette.get(index).map(Cow::as_ref)
+    }
+
+    /// Converts the biomes into owned [`section::Biomes`].
+    pub fn into_owned(self) -> section::Biomes {
+        section::Biomes {
+            palette: self
+                .palette
+                .into_iter()
+                .map(|name| section::PaletteNoProps {
+                    name: name.into_owned(),
+                })
+                .collect(),
+            data: self.data,
+        }
+    }
+}
+
+impl Palette<'_> {
+    /// Returns the value of the given block state property.
+    pub fn property(&self, name: &str) -> Option<&str> {
+        self.properties
+            .iter()
+            .find(|(key, _)| key == name)
+            .map(|(_, value)| value.as_ref())
+    }
+
+    /// Converts the palette entry into an owned [`section::Palette`].
+    pub fn into_owned(self) -> section::Palette {
+        section::Palette {
+            name: self.name.into_owned(),
+            properties: KVPair::new(
+                self.properties
+                    .into_iter()
+                    .map(|(key, value)| (key.into_owned(), value.into_owned()))
+                    .collect(),
+            ),
+        }
+    }
+}
+
+impl<'a> FromBorrowedCompoundNbt<'a> for ChunkSection<'a> {
+    fn from_borrowed_compound_nbt(nbt: &NbtCompound<'a, 'a>) -> Result<Self, SculkParseError> {
+        let y = nbt
+            .byte("Y")
+            .ok_or(SculkParseError::MissingField("Y".into()))?;
+
+        let block_states = nbt
+            .compound("block_states")
+            .map(|nbt| BlockStates::from_borrowed_compound_nbt(&nbt))
+            .transpose()?;
+        let biomes = nbt
+            .compound("biomes")
+            .map(|nbt| Biomes::from_borrowed_compound_nbt(&nbt))
+            .transpose()?;
+
+        Ok(ChunkSection {
+            y,
+            block_states,
+            biomes,
+            block_light: nbt.byte_array("block_light"),
+            sky_light: nbt.byte_array("sky_light"),
+        })
+    }
+}
+
+impl<'a> FromBorrowedCompoundNbt<'a> for BlockStates<'a> {
+    fn from_borrowed_compound_nbt(nbt: &NbtCompound<'a, 'a>) -> Result<Self, SculkParseError> {
+        Ok(BlockStates {
+            palette: get_borrowed_vec(nbt, "palette")?,
+            data: nbt.long_array("data"),
+        })
+    }
+}
+
+impl<'a> FromBorrowedCompoundNbt<'a> for Biomes<'a> {
+    fn from_borrowed_compound_nbt(nbt: &NbtCompound<'a, 'a>) -> Result<Self, SculkParseError> {
+        let palette = match nbt.list("palette") {
+            Some(palette) => palette
+                .strings()
+                .ok_or(SculkParseError::InvalidField("palette".into()))?
+                .iter()
+                .map(|s| s.to_str())
+                .collect(),
+            None => Vec::new(),
+        };
+
+        Ok(Biomes {
+            palette,
+            data: nbt.long_array("data"),
+        })
+    }
+}
+
+impl<'a> FromBorrowedCompoundNbt<'a> for Palette<'a> {
+    fn from_borrowed_compound_nbt(nbt: &NbtCompound<'a, 'a>) -> Result<Self, SculkParseError> {
+        let properties = match nbt.compound("Properties") {
+            Some(nbt) => nbt
+                .iter()
+                .filter_map(|(key, value)| Some((key.to_str(), value.string()?.to_str())))
+                .collect(),
+            None => Vec::new(),
+        };
+
+        Ok(Palette {
+            name: get_borrowed_string(nbt, "Name")?,
+            properties,
+        })
+    }
+}
@@ -0,0 +1,125 @@
+use std::io::Cursor;
+
+use crate::{
+    entity::Entity,
+    error::SculkParseError,
+    traits::{FromCompoundNbt, ToCompoundNbt},
+    util::{compound_list, get_t_compound_vec},
+    version::{self, Versioned},
+};
+use simdnbt::owned;
+
+/// The entities of a chunk, stored in the `entities/r.X.Z.mca` region files since 1.17.  
+/// [Minecraft Wiki](https://minecraft.wiki/w/Entity_format#Entity_Format)
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EntityChunk {
+    /// Version of the entity chunk NBT structure.  
+    /// `DataVersion`
+    pub data_version: i32,
+
+    /// The X and Z position of the chunk (in absolute chunks from world x, z origin, not relative to the region).  
+    /// `Position`
+    pub position: [i32; 2],
+
+    /// Each Compound in this list is an entity in the chunk.  
+    /// `Entities`
+    pub entities: Vec<Entity>,
+}
+
+impl FromCompoundNbt for EntityChunk {
+    fn from_compound_nbt(nbt: &simdnbt::borrow::NbtCompound) -> Result<Self, SculkParseError>
+    where
+        Self: Sized,
+    {
+        version::with_version(EntityChunk::version_of(nbt), || EntityChunk::parse(nbt))
+    }
+}
+
+impl EntityChunk {
+    /// Parses the entity chunk, with the data version of the file already set for nested parsers.
+    fn parse(nbt: &simdnbt::borrow::NbtCompound) -> Result<Self, SculkParseError> {
+        let data_version = nbt
+            .int("DataVersion")
+            .ok_or(SculkParseError::MissingField("DataVersion".into()))?;
+
+        let position = nbt
+            .int_array("Position")
+            .ok_or(SculkParseError::MissingField("Position".into()))?;
+        let position: [i32; 2] = position
+            .as_slice()
+            .try_into()
+            .map_err(|_| SculkParseError::InvalidField("Position".into()))?;
+
+        let entities = get_t_compound_vec(nbt, "Entities", Entity::from_compound_nbt)?;
+
+        Ok(EntityChunk {
+            data_version,
+            position,
+            entities,
+        })
+    }
+}
+
+impl ToCompoundNbt for EntityChunk {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        version::with_version(Some(self.version()), || self.write())
+    }
+}
+
+impl EntityChunk {
+    /// Writes the entity chunk, with its data version set for nested writers that depend on it.
+    fn write(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        nbt.insert("Entities", compound_list(&self.entities));
+        nbt.insert("Position", owned::NbtTag::IntArray(self.position.to_vec()));
+        nbt.insert("DataVersion", self.data_version);
+
+        nbt
+    }
+}
+
+impl EntityChunk {
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, SculkParseError> {
+        let nbt = simdnbt::borrow::read(&mut Cursor::new(bytes))?;
+
+        let nbt = match nbt {
+            simdnbt::borrow::Nbt::Some(nbt) => nbt,
+            simdnbt::borrow::Nbt::None => return Err(SculkParseError::NoNbt),
+        };
+        let compound = nbt.as_compound();
+
+        EntityChunk::from_compound_nbt(&compound)
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn entity_chunk_region() {
+    use crate::region::{CompressionType, Region};
+
+    let mut pig = owned::NbtCompound::new();
+    pig.insert("id", "minecraft:pig");
+    pig.insert("Health", 10f32);
+    pig.insert("Pos", owned::NbtList::Double(vec![-8.5, 64.0, 40.5]));
+    pig.insert("UUID", owned::NbtTag::IntArray(vec![1, 2, 3, 4]));
+
+    let mut nbt = owned::NbtCompound::new();
+    nbt.insert("Entities", owned::NbtList::Compound(vec![pig]));
+    nbt.insert("Position", owned::NbtTag::IntArray(vec![-1, 2]));
+    nbt.insert("DataVersion", 3953);
+
+    let mut region = Region::new(-1, 0);
+    region
+        .set_chunk_bytes(31, 2, &crate::util::write_nbt(&nbt), CompressionType::Zlib)
+        .unwrap();
+
+    let chunk = region.get_entity_chunk_at(-1, 2).unwrap().unwrap();
+    assert_eq!(chunk.position, [-1, 2]);
+    assert_eq!(chunk.entities.len(), 1);
+    assert_eq!(chunk.entities[0].id, "minecraft:pig");
+    assert_eq!(chunk.entities[0].pos, Some([-8.5, 64.0, 40.5]));
+
+    assert_eq!(EntityChunk::from_bytes(&chunk.to_bytes()).unwrap(), chunk);
+}
@@ -0,0 +1,453 @@
+//! Decoding and recomputing the heightmaps of a chunk.
+
+use super::{
+    section::{bits_for, unpack, ChunkSection, Palette},
+    HeightMaps,
+};
+
+/// A 16x16 grid of world Y values, indexed as `[z][x]`.
+pub type HeightGrid = [[i32; 16]; 16];
+
+/// The types of heightmaps stored in a chunk.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum HeightMapType {
+    /// The highest block that blocks motion or contains a fluid.
+    /// `MOTION_BLOCKING`
+    MotionBlocking,
+
+    /// The highest block that blocks motion or contains a fluid, ignoring leaves.
+    /// `MOTION_BLOCKING_NO_LEAVES`
+    MotionBlockingNoLeaves,
+
+    /// The highest block that blocks motion.
+    /// `OCEAN_FLOOR`
+    OceanFloor,
+
+    /// The highest block that blocks motion, used during world generation.
+    /// `OCEAN_FLOOR_WG`
+    OceanFloorWg,
+
+    /// The highest block that isn't air.
+    /// `WORLD_SURFACE`
+    WorldSurface,
+
+    /// The highest block that isn't air, used during world generation.
+    /// `WORLD_SURFACE_WG`
+    WorldSurfaceWg,
+}
+
+impl HeightMapType {
+    /// Every heightmap type.
+    pub const ALL: [HeightMapType; 6] = [
+        HeightMapType::MotionBlocking,
+        HeightMapType::MotionBlockingNoLeaves,
+        HeightMapType::OceanFloor,
+        HeightMapType::OceanFloorWg,
+        HeightMapType::WorldSurface,
+        HeightMapType::WorldSurfaceWg,
+    ];
+
+    /// The key of the heightmap in the `Heightmaps` compound.
+    pub fn key(&self) -> &'static str {
+        match self {
+            HeightMapType::MotionBlocking => "MOTION_BLOCKING",
+            HeightMapType::MotionBlockingNoLeaves => "MOTION_BLOCKING_NO_LEAVES",
+            HeightMapType::OceanFloor => "OCEAN_FLOOR",
+            HeightMapType::OceanFloorWg => "OCEAN_FLOOR_WG",
+            HeightMapType::WorldSurface => "WORLD_SURFACE",
+            HeightMapType::WorldSurfaceWg => "WORLD_SURFACE_WG",
+        }
+    }
+
+    /// Whether the block counts towards this heightmap, using the vanilla rules.
+    pub fn is_opaque(&self, block: &Palette) -> bool {
+        match self {
+            HeightMapType::MotionBlocking => blocks_motion(block) || has_fluid(block),
+            HeightMapType::MotionBlockingNoLeaves => {
+                (blocks_motion(block) || has_fluid(block)) && !block.name.ends_with("_leaves")
+            }
+            HeightMapType::OceanFloor | HeightMapType::OceanFloorWg => blocks_motion(block),
+            HeightMapType::WorldSurface | HeightMapType::WorldSurfaceWg => !is_air(block),
+        }
+    }
+}
+
+impl HeightMaps {
+    /// Returns the packed values of the given heightmap.
+    pub fn get(&self, r#type: HeightMapType) -> &Vec<i64> {
+        match r#type {
+            HeightMapType::MotionBlocking => &self.motion_blocking,
+            HeightMapType::MotionBlockingNoLeaves => &self.motion_blocking_no_leaves,
+            HeightMapType::OceanFloor => &self.ocean_floor,
+            HeightMapType::OceanFloorWg => &self.ocean_floor_wg,
+            HeightMapType::WorldSurface => &self.world_surface,
+            HeightMapType::WorldSurfaceWg => &self.world_surface_wg,
+        }
+    }
+
+    /// Returns the packed values of the given heightmap, mutably.
+    pub fn get_mut(&mut self, r#type: HeightMapType) -> &mut Vec<i64> {
+        match r#type {
+            HeightMapType::MotionBlocking => &mut self.motion_blocking,
+            HeightMapType::MotionBlockingNoLeaves => &mut self.motion_blocking_no_leaves,
+            HeightMapType::OceanFloor => &mut self.ocean_floor,
+            HeightMapType::OceanFloorWg => &mut self.ocean_floor_wg,
+            HeightMapType::WorldSurface => &mut self.world_surface,
+            HeightMapType::WorldSurfaceWg => &mut self.world_surface_wg,
+        }
+    }
+
+    /// Decodes a heightmap into world Y values, `y_pos` being the lowest section of the chunk.
+    /// Each value is the Y coordinate right above the highest matching block, or the bottom of the world if there is none.
+    /// `None` if the heightmap isn't stored.
+    pub fn decode(&self, r#type: HeightMapType, y_pos: i32) -> Option<HeightGrid> {
+        let data = self.get(r#type);
+        if data.is_empty() {
+            return None;
+        }
+
+        // 9 bits for a 384 block tall world, but custom dimension heights use a different amount
+        let bits = 64 / 256usize.div_ceil(data.len());
+        let min_y = y_pos * 16;
+
+        let mut grid = [[min_y; 16]; 16];
+        for (i, height) in grid.iter_mut().flatten().enumerate() {
+            *height += unpack(data, bits, i) as i32;
+        }
+
+        Some(grid)
+    }
+
+    /// Encodes world Y values into a heightmap, `height` being the total height of the world in blocks.
+    pub fn encode(&mut self, r#type: HeightMapType, grid: &HeightGrid, y_pos: i32, height: usize) {
+        let min_y = y_pos * 16;
+        let bits = bits_for(height + 1);
+
+        let values: Vec<usize> = grid
+            .iter()
+            .flatten()
+            .map(|y| (y - min_y).clamp(0, height as i32) as usize)
+            .collect();
+
+        *self.get_mut(r#type) = super::section::pack(&values, bits);
+    }
+}
+
+/// The height in blocks from the bottom of the chunk to the top of its highest section.
+/// Sections without block data count, but the sections that only store light, one below and one above the world, don't.
+pub(crate) fn world_height(sections: &[ChunkSection], y_pos: i32) -> usize {
+    let top = sections
+        .iter()
+        .filter(|section| !is_light_only(section))
+        .map(|section| section.y as i32 + 1)
+        .max()
+        .unwrap_or(y_pos + 1);
+
+    (top - y_pos).max(1) as usize * 16
+}
+
+/// Whether the section only stores light, like the ones saved right below and above the world.
+fn is_light_only(section: &ChunkSection) -> bool {
+    section.block_states.is_none()
+        && section.biomes.is_none()
+        && (section.block_light.is_some() || section.sky_light.is_some())
+}
+
+/// Computes the heightmaps of the given types from the block data of the sections.
+pub(crate) fn compute(
+    sections: &[ChunkSection],
+    y_pos: i32,
+    types: &[HeightMapType],
+) -> Vec<HeightGrid> {
+    let min_y = y_pos * 16;
+    let mut grids = vec![[[min_y; 16]; 16]; types.len()];
+    let mut found = vec![[[false; 16]; 16]; types.len()];
+    let mut remaining = types.len() * 256;
+
+    let mut sections: Vec<&ChunkSection> = sections.iter().collect();
+    sections.sort_by_key(|section| std::cmp::Reverse(section.y));
+
+    for section in sections {
+        let Some(block_states) = &section.block_states else {
+            continue;
+        };
+
+        let opaque: Vec<Vec<bool>> = block_states
+            .palette
+            .iter()
+            .map(|block| types.iter().map(|t| t.is_opaque(block)).collect())
+            .collect();
+        if opaque.iter().all(|o| o.iter().all(|o| !o)) {
+            continue;
+        }
+
+        let bits = block_states.bits_per_block();
+        for y in (0..16).rev() {
+            for z in 0..16 {
+                for x in 0..16 {
+                    let index = match &block_states.data {
+                        Some(data) => unpack(data, bits, (y * 16 + z) * 16 + x),
+                        None => 0,
+                    };
+                    let Some(opaque) = opaque.get(index) else {
+                        continue;
+                    };
+
+                    for (i, opaque) in opaque.iter().enumerate() {
+                        if *opaque && !found[i][z][x] {
+                            found[i][z][x] = true;
+                            grids[i][z][x] = section.y as i32 * 16 + y as i32 + 1;
+                            remaining -= 1;
+                        }
+                    }
+                }
+            }
+
+            if remaining == 0 {
+                return grids;
+            }
+        }
+    }
+
+    grids
+}
+
+fn is_air(block: &Palette) -> bool {
+    matches!(
+        block.name.as_str(),
+        "minecraft:air" | "minecraft:cave_air" | "minecraft:void_air"
+    )
+}
+
+/// Whether the block contains water or lava, either as a fluid block, waterlogged or a plant that is always underwater.
+fn has_fluid(block: &Palette) -> bool {
+    matches!(
+        block.name.as_str(),
+        "minecraft:water"
+            | "minecraft:lava"
+            | "minecraft:bubble_column"
+            | "minecraft:kelp"
+            | "minecraft:kelp_plant"
+            | "minecraft:seagrass"
+            | "minecraft:tall_seagrass"
+    ) || block
+        .properties
+        .inner()
+        .get("waterlogged")
+        .is_some_and(|waterlogged| waterlogged == "true")
+}
+
+/// Whether the block has a collision box that blocks motion.
+/// This is decided by block name, as there is no block registry, so modded blocks count as blocking motion.
+fn blocks_motion(block: &Palette) -> bool {
+    let waterloggable = block.properties.inner().contains_key("waterlogged");
+    if is_air(block) || (has_fluid(block) && !waterloggable) {
+        return false;
+    }
+
+    let name = block.name.strip_prefix("minecraft:").unwrap_or(&block.name);
+
+    const NON_SOLID: &[&str] = &[
+        "short_grass",
+        "grass",
+        "tall_grass",
+        "fern",
+        "large_fern",
+        "dead_bush",
+        "vine",
+        "glow_lichen",
+        "sculk_vein",
+        "redstone_wire",
+        "lever",
+        "tripwire",
+        "tripwire_hook",
+        "cobweb",
+        "powder_snow",
+        "fire",
+        "soul_fire",
+        "nether_portal",
+        "end_portal",
+        "end_gateway",
+        "light",
+        "structure_void",
+        "dandelion",
+        "poppy",
+        "blue_orchid",
+        "allium",
+        "azure_bluet",
+        "red_tulip",
+        "orange_tulip",
+        "white_tulip",
+        "pink_tulip",
+        "oxeye_daisy",
+        "cornflower",
+        "lily_of_the_valley",
+        "wither_rose",
+        "torchflower",
+        "sunflower",
+        "lilac",
+        "rose_bush",
+        "peony",
+        "pitcher_plant",
+        "brown_mushroom",
+        "red_mushroom",
+        "wheat",
+        "carrots",
+        "potatoes",
+        "beetroots",
+        "melon_stem",
+        "pumpkin_stem",
+        "sweet_berry_bush",
+        "nether_wart",
+        "sugar_cane",
+        "bamboo_sapling",
+        "cave_vines",
+        "cave_vines_plant",
+        "weeping_vines",
+        "weeping_vines_plant",
+        "twisting_vines",
+        "twisting_vines_plant",
+        "hanging_roots",
+        "pale_hanging_moss",
+        "spore_blossom",
+        "small_dripleaf",
+        "big_dripleaf_stem",
+        "mangrove_propagule",
+        "pink_petals",
+        "nether_sprouts",
+        "crimson_roots",
+        "warped_roots",
+        "frogspawn",
+    ];
+
+    const NON_SOLID_SUFFIXES: &[&str] = &[
+        "torch",
+        "_sign",
+        "_banner",
+        "rail",
+        "_button",
+        "_pressure_plate",
+        "_sapling",
+        "_fungus",
+        "_coral",
+        "_coral_fan",
+    ];
+
+    !(NON_SOLID.contains(&name)
+        || NON_SOLID_SUFFIXES
+            .iter()
+            .any(|suffix| name.ends_with(suffix))
+        || name.starts_with("attached_")
+        || name.ends_with("_wall_fan"))
+}
+
+#[cfg(test)]
+#[test]
+fn height_map_test() {
+    use crate::kv::KVPair;
+
+    let block = |name: &str| Palette {
+        name: name.into(),
+        properties: KVPair::new(Default::default()),
+    };
+
+    // 24 empty sections from y -64 to 319
+    let mut sections: Vec<ChunkSection> = (-4..20)
+        .map(|y| ChunkSection {
+            y,
+            block_states: None,
+            biomes: None,
+            block_light: None,
+            sky_light: None,
+        })
+        .collect();
+
+    for x in 0..16 {
+        for z in 0..16 {
+            sections[8].set_block(x, 0, z, block("minecraft:stone"));
+        }
+    }
+    sections[8].set_block(0, 1, 0, block("minecraft:water"));
+    sections[8].set_block(0, 2, 0, block("minecraft:water"));
+    sections[8].set_block(1, 1, 0, block("minecraft:oak_leaves"));
+    sections[8].set_block(2, 1, 0, block("minecraft:short_grass"));
+    sections[8].set_block(3, 15, 0, block("minecraft:stone"));
+    sections[9].set_block(3, 0, 0, block("minecraft:torch"));
+
+    let types = HeightMapType::ALL;
+    let grids = compute(&sections, -4, &types);
+    let [motion_blocking, no_leaves, ocean_floor, _, world_surface, _] = grids.as_slice() else {
+        panic!("expected 6 heightmaps");
+    };
+
+    // stone at y 64
+    assert_eq!(ocean_floor[5][5], 65);
+    assert_eq!(world_surface[5][5], 65);
+    // water up to y 66
+    assert_eq!(motion_blocking[0][0], 67);
+    assert_eq!(ocean_floor[0][0], 65);
+    // leaves at y 65
+    assert_eq!(motion_blocking[0][1], 66);
+    assert_eq!(no_leaves[0][1], 65);
+    // grass at y 65
+    assert_eq!(motion_blocking[0][2], 65);
+    assert_eq!(world_surface[0][2], 66);
+    // stone at y 79, torch at y 80
+    assert_eq!(motion_blocking[0][3], 80);
+    assert_eq!(world_surface[0][3], 81);
+
+    let mut height_maps = HeightMaps {
+        motion_blocking: vec![],
+        motion_blocking_no_leaves: vec![],
+        ocean_floor: vec![],
+        ocean_floor_wg: vec![],
+        world_surface: vec![],
+        world_surface_wg: vec![],
+    };
+    height_maps.encode(HeightMapType::MotionBlocking, motion_blocking, -4, 384);
+    assert_eq!(height_maps.motion_blocking.len(), 37);
+    assert_eq!(
+        height_maps.decode(HeightMapType::MotionBlocking, -4),
+        Some(*motion_blocking)
+    );
+    assert_eq!(height_maps.decode(HeightMapType::OceanFloor, -4), None);
+
+    // the light only sections above and below the world aren't part of its height
+    let light_only = |y| ChunkSection {
+        y,
+        block_states: None,
+        biomes: None,
+        block_light: None,
+        sky_light: Some(vec![0; 2048]),
+    };
+    let mut tall: Vec<ChunkSection> = (-4..26)
+        .map(|y| {
+            let mut section = light_only(y);
+            section.set_block(0, 0, 0, block("minecraft:air"));
+            section
+        })
+        .collect();
+    tall.insert(0, light_only(-5));
+    tall.push(light_only(26));
+    assert_eq!(world_height(&tall, -4), 480);
+    assert_eq!(bits_for(world_height(&tall, -4) + 1), 9);
+    // sections without any block data are still part of the world
+    assert_eq!(world_height(&sections, -4), 384);
+    assert_eq!(bits_for(world_height(&sections, -4) + 1), 9);
+
+    for name in [
+        "minecraft:mangrove_propagule",
+        "minecraft:powder_snow",
+        "minecraft:end_gateway",
+        "minecraft:small_dripleaf",
+        "minecraft:big_dripleaf_stem",
+        "minecraft:pale_hanging_moss",
+    ] {
+        assert!(!blocks_motion(&block(name)), "{name}");
+    }
+    assert!(blocks_motion(&block("minecraft:big_dripleaf")));
+
+    let empty = compute(&sections[..8], -4, &[HeightMapType::WorldSurface]);
+    assert_eq!(empty[0], [[-64; 16]; 16]);
+}
@@ -0,0 +1,406 @@
+use std::{io::Cursor, ops::Range};
+
+use simdnbt::{
+    borrow::{read_compound, NbtCompound},
+    Mutf8Str,
+};
+
+use crate::{
+    block_entity::BlockEntity,
+    error::SculkParseError,
+    traits::FromCompoundNbt,
+    version::{self, DataVersion},
+};
+
+use super::{
+    section::ChunkSection, status::ChunkStatus, structure::Structures, tile_tick::TileTick, Chunk,
+};
+
+/// The same max depth simdnbt uses.
+const MAX_DEPTH: usize = 512;
+
+const END_ID: u8 = 0;
+const STRING_ID: u8 = 8;
+const LIST_ID: u8 = 9;
+const COMPOUND_ID: u8 = 10;
+
+/// A chunk that only parses its header fields.
+/// The sections, block entities, ticks and structures are kept as byte ranges into the chunk bytes,
+/// and are only decoded when their method is called, straight from the borrowed bytes.
+///
+/// This is useful for filtering chunks on their position, status or inhabited time,
+/// since the rest of the chunk doesn't have to be parsed at all.
+#[derive(Debug, Clone, PartialEq)]
+pub struct LazyChunk<'a> {
+    /// Version of the chunk NBT structure.
+    /// `DataVersion`
+    pub data_version: i32,
+
+    /// X position of the chunk (in absolute chunks from world x, z origin, not relative to the region).
+    /// `xPos`
+    pub x_pos: i32,
+    /// Z position of the chunk (in absolute chunks from world x, z origin, not relative to the region).
+    /// `zPos`
+    pub z_pos: i32,
+    /// Lowest Y section position in the chunk (e.g. -4 in 1.18).
+    /// `yPos`
+    pub y_pos: i32,
+
+    /// Defines the world generation status of this chunk.
+    /// `Status`
+    pub status: ChunkStatus,
+
+    /// Tick when the chunk was last saved.
+    /// `LastUpdate`
+    pub last_update: i64,
+
+    /// The cumulative number of ticks players have been in this chunk.
+    /// `InhabitedTime`
+    pub inhabited_time: i64,
+
+    /// The uncompressed chunk Nbt.
+    bytes: &'a [u8],
+
+    /// Payload ranges of the deferred fields.
+    sections: Option<Range<usize>>,
+    block_entities: Option<Range<usize>>,
+    fluid_ticks: Option<Range<usize>>,
+    block_ticks: Option<Range<usize>>,
+    structures: Option<Range<usize>>,
+}
+
+impl<'a> LazyChunk<'a> {
+    /// Reads the header fields of the uncompressed chunk Nbt and the byte ranges of the deferred fields.
+    pub fn from_bytes(bytes: &'a [u8]) -> Result<Self, SculkParseError> {
+        let mut reader = Reader { bytes, pos: 0 };
+
+        match reader.u8()? {
+            COMPOUND_ID => {}
+            END_ID => return Err(SculkParseError::NoNbt),
+            id => return Err(simdnbt::Error::InvalidRootType(id).into()),
+        }
+        reader.string()?;
+
+        let mut data_version = None;
+        let mut x_pos = None;
+        let mut z_pos = None;
+        let mut y_pos = None;
+        let mut status = None;
+        let mut last_update = None;
+        let mut inhabited_time = None;
+
+        let mut sections = None;
+        let mut block_entities = None;
+        let mut fluid_ticks = None;
+        let mut block_ticks = None;
+        let mut structures = None;
+
+        loop {
+            let id = reader.u8()?;
+            if id == END_ID {
+                break;
+            }
+            let name = reader.string()?;
+
+            let start = reader.pos;
+            match (name.as_bytes(), id) {
+                (b"DataVersion", 3) => data_version = Some(reader.i32()?),
+                (b"xPos", 3) => x_pos = Some(reader.i32()?),
+                (b"zPos", 3) => z_pos = Some(reader.i32()?),
+                (b"yPos", 3) => y_pos = Some(reader.i32()?),
+                (b"LastUpdate", 4) => last_update = Some(reader.i64()?),
+                (b"InhabitedTime", 4) => inhabited_time = Some(reader.i64()?),
+                (b"Status", STRING_ID) => {
+                    status = Some(ChunkStatus::from(reader.string()?.to_str().as_ref()))
+                }
+                (key, id) => {
+                    reader.skip(id, 0)?;
+
+                    let range = Some(start..reader.pos);
+                    match (key, id) {
+                        (b"sections", LIST_ID) => sections = range,
+                        (b"block_entities", LIST_ID) => block_entities = range,
+                        (b"fluid_ticks", LIST_ID) => fluid_ticks = range,
+                        (b"block_ticks", LIST_ID) => block_ticks = range,
+                        (b"structures", COMPOUND_ID) => structures = range,
+                        _ => {}
+                    }
+                }
+            }
+        }
+
+        Ok(LazyChunk {
+            data_version: data_version
+                .ok_or(SculkParseError::MissingField("DataVersion".into()))?,
+            x_pos: x_pos.ok_or(SculkParseError::MissingField("xPos".into()))?,
+            z_pos: z_pos.ok_or(SculkParseError::MissingField("zPos".into()))?,
+            y_pos: y_pos.ok_or(SculkParseError::MissingField("yPos".into()))?,
+            status: status.ok_or(SculkParseError::MissingField("Status".into()))?,
+            last_update: last_update.ok_or(SculkParseError::MissingField("LastUpdate".into()))?,
+            inhabited_time: inhabited_time
+                .ok_or(SculkParseError::MissingField("InhabitedTime".into()))?,
+            bytes,
+            sections,
+            block_entities,
+            fluid_ticks,
+            block_ticks,
+            structures,
+        })
+    }
+
+    /// Decodes the sections of the chunk.
+    /// `sections`
+    pub fn sections(&self) -> Result<Vec<ChunkSection>, SculkParseError> {
+        self.compound_list(&self.sections, "sections", ChunkSection::from_compound_nbt)
+    }
+
+    /// Decodes the block entities of the chunk.
+    /// `block_entities`
+    pub fn block_entities(&self) -> Result<Vec<BlockEntity>, SculkParseError> {
+        self.compound_list(
+            &self.block_entities,
+            "block_entities",
+            BlockEntity::from_compound_nbt,
+        )
+    }
+
+    /// Decodes the fluid ticks of the chunk.
+    /// `fluid_ticks`
+    pub fn fluid_ticks(&self) -> Result<Vec<TileTick>, SculkParseError> {
+        self.compound_list(
+            &self.fluid_ticks,
+            "fluid_ticks",
+            TileTick::from_compound_nbt,
+        )
+    }
+
+    /// Decodes the block ticks of the chunk.
+    /// `block_ticks`
+    pub fn block_ticks(&self) -> Result<Vec<TileTick>, SculkParseError> {
+        self.compound_list(
+            &self.block_ticks,
+            "block_ticks",
+            TileTick::from_compound_nbt,
+        )
+    }
+
+    /// Decodes the structure data of the chunk.
+    /// `structures`
+    pub fn structures(&self) -> Result<Structures, SculkParseError> {
+        let range = self
+            .structures
+            .clone()
+            .ok_or(SculkParseError::MissingField("structures".into()))?;
+
+        let nbt = read_compound(&mut Cursor::new(&self.bytes[range]))?;
+        Structures::from_compound_nbt(&NbtCompound::from(&nbt))
+    }
+
+    /// Parses the whole chunk.
+    pub fn to_chunk(&self) -> Result<Chunk, SculkParseError> {
+        Chunk::from_bytes(self.bytes)
+    }
+
+    /// The uncompressed chunk Nbt this chunk was read from.
+    pub fn bytes(&self) -> &'a [u8] {
+        self.bytes
+    }
+
+    /// Reads every compound of a list payload directly from the chunk bytes.
+    fn compound_list<T>(
+        &self,
+        range: &Option<Range<usize>>,
+        key: &'static str,
+        nbt_conversion: fn(nbt: &NbtCompound) -> Result<T, SculkParseError>,
+    ) -> Result<Vec<T>, SculkParseError> {
+        let Some(range) = range.clone() else {
+            return Ok(vec![]);
+        };
+
+        let mut reader = Reader {
+            bytes: &self.bytes[range],
+            pos: 0,
+        };
+        let id = reader.u8()?;
+        let len = reader.i32()?;
+
+        if id == END_ID || len <= 0 {
+            return Ok(vec![]);
+        }
+        if id != COMPOUND_ID {
+            return Err(SculkParseError::InvalidField(key.into()));
+        }
+
+        let mut cursor = Cursor::new(&reader.bytes[reader.pos..]);
+        // Every compound is at least one byte, so a bogus length can't over allocate.
+        let mut vec = Vec::with_capacity((len as usize).min(cursor.get_ref().len()));
+
+        version::with_version(Some(DataVersion(self.data_version)), || {
+            for _ in 0..len {
+                let nbt = read_compound(&mut cursor)?;
+                vec.push(nbt_conversion(&NbtCompound::from(&nbt))?);
+            }
+
+            Ok(vec)
+        })
+    }
+}
+
+/// A minimal Nbt reader that can skip over tags without parsing them.
+struct Reader<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Reader<'a> {
+    fn take(&mut self, len: usize) -> Result<&'a [u8], SculkParseError> {
+        let slice = self
+            .pos
+            .checked_add(len)
+            .and_then(|end| self.bytes.get(self.pos..end))
+            .ok_or(simdnbt::Error::UnexpectedEof)?;
+
+        self.pos += len;
+        Ok(slice)
+    }
+
+    fn u8(&mut self) -> Result<u8, SculkParseError> {
+        Ok(self.take(1)?[0])
+    }
+
+    fn u16(&mut self) -> Result<u16, SculkParseError> {
+        Ok(u16::from_be_bytes(self.take(2)?.try_into().unwrap()))
+    }
+
+    fn i32(&mut self) -> Result<i32, SculkParseError> {
+        Ok(i32::from_be_bytes(self.take(4)?.try_into().unwrap()))
+    }
+
+    fn i64(&mut self) -> Result<i64, SculkParseError> {
+        Ok(i64::from_be_bytes(self.take(8)?.try_into().unwrap()))
+    }
+
+    fn string(&mut self) -> Result<&'a Mutf8Str, SculkParseError> {
+        let len = self.u16()? as usize;
+        Ok(Mutf8Str::from_slice(self.take(len)?))
+    }
+
+    /// Reads an array or list length, negative lengths are treated as empty.
+    fn len(&mut self) -> Result<usize, SculkParseError> {
+        Ok(self.i32()?.max(0) as usize)
+    }
+
+    /// Skips the payload of a tag with the given id.
+    fn skip(&mut self, id: u8, depth: usize) -> Result<(), SculkParseError> {
+        if depth > MAX_DEPTH {
+            return Err(simdnbt::Error::MaxDepthExceeded.into());
+        }
+
+        match id {
+            1 => self.take(1).map(drop),
+            2 => self.take(2).map(drop),
+            3 | 5 => self.take(4).map(drop),
+            4 | 6 => self.take(8).map(drop),
+            7 => {
+                let len = self.len()?;
+                self.take(len).map(drop)
+            }
+            STRING_ID => self.string().map(drop),
+            LIST_ID => {
+                let id = self.u8()?;
+                let len = self.len()?;
+
+                match fixed_size(id) {
+                    Some(size) => self.take(len.saturating_mul(size)).map(drop),
+                    None => (0..len).try_for_each(|_| self.skip(id, depth + 1)),
+                }
+            }
+            COMPOUND_ID => loop {
+                let id = self.u8()?;
+                if id == END_ID {
+                    return Ok(());
+                }
+                self.string()?;
+                self.skip(id, depth + 1)?;
+            },
+            11 => {
+                let len = self.len()?;
+                self.take(len.saturating_mul(4)).map(drop)
+            }
+            12 => {
+                let len = self.len()?;
+                self.take(len.saturating_mul(8)).map(drop)
+            }
+            id => Err(simdnbt::Error::UnknownTagId(id).into()),
+        }
+    }
+}
+
+/// The payload size of tags that always have the same size.
+fn fixed_size(id: u8) -> Option<usize> {
+    match id {
+        END_ID => Some(0),
+        1 => Some(1),
+        2 => Some(2),
+        3 | 5 => Some(4),
+        4 | 6 => Some(8),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn lazy_chunk_test() {
+    use crate::traits::ToCompoundNbt;
+    use simdnbt::owned;
+
+    let chest = std::fs::read("test_data/chest.nbt").unwrap();
+    let chest = owned::read(&mut Cursor::new(chest.as_slice()))
+        .unwrap()
+        .unwrap()
+        .into_inner();
+
+    let mut stone = owned::NbtCompound::new();
+    stone.insert("Name", "minecraft:stone");
+    let mut block_states = owned::NbtCompound::new();
+    block_states.insert("palette", owned::NbtList::Compound(vec![stone]));
+    let mut section = owned::NbtCompound::new();
+    section.insert("Y", -4i8);
+    section.insert("block_states", block_states);
+    section.insert("sky_light", owned::NbtTag::ByteArray(vec![15; 2048]));
+
+    let mut structures = owned::NbtCompound::new();
+    structures.insert("References", owned::NbtCompound::new());
+    structures.insert("starts", owned::NbtCompound::new());
+
+    let mut nbt = owned::NbtCompound::new();
+    nbt.insert("DataVersion", 3953);
+    nbt.insert("xPos", 2);
+    nbt.insert("zPos", -1);
+    nbt.insert("yPos", -4);
+    nbt.insert("Status", "minecraft:full");
+    nbt.insert("LastUpdate", 100i64);
+    nbt.insert("InhabitedTime", 20i64);
+    nbt.insert("sections", owned::NbtList::Compound(vec![section]));
+    nbt.insert("block_entities", owned::NbtList::Compound(vec![chest]));
+    nbt.insert("fluid_ticks", owned::NbtList::Empty);
+    nbt.insert("block_ticks", owned::NbtList::Empty);
+    nbt.insert("Heightmaps", owned::NbtCompound::new());
+    nbt.insert("structures", structures);
+
+    let bytes = crate::util::write_nbt(&nbt);
+    let lazy = LazyChunk::from_bytes(&bytes).unwrap();
+    let chunk = lazy.to_chunk().unwrap();
+
+    assert_eq!((lazy.x_pos, lazy.z_pos, lazy.inhabited_time), (2, -1, 20));
+    assert_eq!(lazy.status, chunk.status);
+    assert_eq!(lazy.sections().unwrap(), chunk.sections);
+    assert_eq!(lazy.block_entities().unwrap(), chunk.block_entities);
+    assert!(lazy.fluid_ticks().unwrap().is_empty());
+    assert_eq!(lazy.structures().unwrap(), chunk.structures);
+
+    // A chunk missing a header field is rejected.
+    nbt.remove("Status");
+    assert!(LazyChunk::from_bytes(&chunk.to_bytes()[..10]).is_err());
+    assert!(LazyChunk::from_bytes(&crate::util::write_nbt(&nbt)).is_err());
+}
@@ -0,0 +1,943 @@
+//! Chunks from before 21w43a (1.18) removed the `Level` wrapper.
+//!
+//! Those chunks are 256 blocks tall, use capitalized keys like `Sections`, `TileEntities` and `TileTicks`,
+//! store biomes as an array of numeric ids, and before 1.16 pack their indices across longs.
+//! Before 1.13 sections store numeric block ids in `Blocks`, `Add` and `Data` instead of a palette, those are translated
+//! with the [flattening table](super::flattening) and the heightmaps are computed from the blocks.
+//! Everything is normalized into the current [`Chunk`] model, so the rest of the crate doesn't have to care.
+//!
+//! Block entities sculk can't read, like signs from before 1.20, are kept as [`BlockEntityKind::Unknown`](crate::block_entities::BlockEntityKind::Unknown).
+//! Before 1.13 the color of beds and banners, the type of skulls, the plant in flower pots and the note of note blocks
+//! were stored in block entities, those are moved into the block states like the flattening did.
+
+use super::{
+    flattening::flatten,
+    height_map::{self, HeightMapType},
+    section::{bits_for, pack, Biomes, BlockStates, ChunkSection, Palette, PaletteNoProps},
+    status::ChunkStatus,
+    structure::{Structure, Structures},
+    tile_tick::TileTick,
+    CarvingMasks, Chunk, HeightMaps,
+};
+use crate::{
+    block_entity::BlockEntity,
+    color::Color,
+    entity::Entity,
+    error::SculkParseError,
+    kv::KVPair,
+    traits::FromCompoundNbt,
+    util::{get_t_compound_vec, write_nbt},
+    version::DataVersion,
+};
+use simdnbt::borrow::{self, NbtCompound};
+use std::collections::HashMap;
+
+/// Height of the world before 1.18, in blocks.
+const LEGACY_HEIGHT: usize = 256;
+
+/// Numeric biome ids used before 1.18, with the name they have since 1.18.
+/// Biomes that were merged into another one in 1.18 get the name of the biome they were merged into.
+pub const LEGACY_BIOMES: &[(i32, &str)] = &[
+    (0, "minecraft:ocean"),
+    (1, "minecraft:plains"),
+    (2, "minecraft:desert"),
+    (3, "minecraft:windswept_hills"),
+    (4, "minecraft:forest"),
+    (5, "minecraft:taiga"),
+    (6, "minecraft:swamp"),
+    (7, "minecraft:river"),
+    (8, "minecraft:nether_wastes"),
+    (9, "minecraft:the_end"),
+    (10, "minecraft:frozen_ocean"),
+    (11, "minecraft:frozen_river"),
+    (12, "minecraft:snowy_plains"),
+    (13, "minecraft:snowy_plains"),
+    (14, "minecraft:mushroom_fields"),
+    (15, "minecraft:mushroom_fields"),
+    (16, "minecraft:beach"),
+    (17, "minecraft:desert"),
+    (18, "minecraft:forest"),
+    (19, "minecraft:taiga"),
+    (20, "minecraft:windswept_hills"),
+    (21, "minecraft:jungle"),
+    (22, "minecraft:jungle"),
+    (23, "minecraft:sparse_jungle"),
+    (24, "minecraft:deep_ocean"),
+    (25, "minecraft:stony_shore"),
+    (26, "minecraft:snowy_beach"),
+    (27, "minecraft:birch_forest"),
+    (28, "minecraft:birch_forest"),
+    (29, "minecraft:dark_forest"),
+    (30, "minecraft:snowy_taiga"),
+    (31, "minecraft:snowy_taiga"),
+    (32, "minecraft:old_growth_pine_taiga"),
+    (33, "minecraft:old_growth_pine_taiga"),
+    (34, "minecraft:windswept_forest"),
+    (35, "minecraft:savanna"),
+    (36, "minecraft:savanna_plateau"),
+    (37, "minecraft:badlands"),
+    (38, "minecraft:wooded_badlands"),
+    (39, "minecraft:badlands"),
+    (40, "minecraft:small_end_islands"),
+    (41, "minecraft:end_midlands"),
+    (42, "minecraft:end_highlands"),
+    (43, "minecraft:end_barrens"),
+    (44, "minecraft:warm_ocean"),
+    (45, "minecraft:lukewarm_ocean"),
+    (46, "minecraft:cold_ocean"),
+    (47, "minecraft:deep_lukewarm_ocean"),
+    (48, "minecraft:deep_lukewarm_ocean"),
+    (49, "minecraft:deep_cold_ocean"),
+    (50, "minecraft:deep_frozen_ocean"),
+    (127, "minecraft:the_void"),
+    (129, "minecraft:sunflower_plains"),
+    (130, "minecraft:desert"),
+    (131, "minecraft:windswept_gravelly_hills"),
+    (132, "minecraft:flower_forest"),
+    (133, "minecraft:taiga"),
+    (134, "minecraft:swamp"),
+    (140, "minecraft:ice_spikes"),
+    (149, "minecraft:jungle"),
+    (151, "minecraft:sparse_jungle"),
+    (155, "minecraft:old_growth_birch_forest"),
+    (156, "minecraft:old_growth_birch_forest"),
+    (157, "minecraft:dark_forest"),
+    (158, "minecraft:snowy_taiga"),
+    (160, "minecraft:old_growth_spruce_taiga"),
+    (161, "minecraft:old_growth_spruce_taiga"),
+    (162, "minecraft:windswept_gravelly_hills"),
+    (163, "minecraft:windswept_savanna"),
+    (164, "minecraft:windswept_savanna"),
+    (165, "minecraft:eroded_badlands"),
+    (166, "minecraft:wooded_badlands"),
+    (167, "minecraft:badlands"),
+    (168, "minecraft:bamboo_jungle"),
+    (169, "minecraft:bamboo_jungle"),
+    (170, "minecraft:soul_sand_valley"),
+    (171, "minecraft:crimson_forest"),
+    (172, "minecraft:warped_forest"),
+    (173, "minecraft:basalt_deltas"),
+    (174, "minecraft:dripstone_caves"),
+    (175, "minecraft:lush_caves"),
+];
+
+/// The name of a numeric biome id used before 1.18, `minecraft:plains` for unknown ids like the game does.
+pub fn legacy_biome_name(id: i32) -> &'static str {
+    LEGACY_BIOMES
+        .binary_search_by_key(&id, |(legacy, _)| *legacy)
+        .map(|i| LEGACY_BIOMES[i].1)
+        .unwrap_or("minecraft:plains")
+}
+
+impl Chunk {
+    /// Parses a chunk from before 1.18, where everything is stored in the `Level` compound.
+    pub(crate) fn from_legacy_compound_nbt(nbt: &NbtCompound) -> Result<Self, SculkParseError> {
+        // Chunks from before 1.9 don't have a data version.
+        let version = DataVersion::from_nbt(nbt);
+        let source_data_version = version.map_or(0, |version| version.0);
+        let level = nbt
+            .compound("Level")
+            .ok_or(SculkParseError::MissingField("Level".into()))?;
+
+        let x_pos = level
+            .int("xPos")
+            .ok_or(SculkParseError::MissingField("xPos".into()))?;
+        let z_pos = level
+            .int("zPos")
+            .ok_or(SculkParseError::MissingField("zPos".into()))?;
+
+        let status = match level.string("Status") {
+            Some(status) => legacy_status(status.to_str().as_ref()),
+            // Before 1.13 only the last two generation steps were stored.
+            None => match (
+                level.byte("TerrainPopulated").unwrap_or(1) != 0,
+                level.byte("LightPopulated").unwrap_or(1) != 0,
+            ) {
+                (true, true) => ChunkStatus::Spawn,
+                (true, false) => ChunkStatus::Features,
+                (false, _) => ChunkStatus::Carvers,
+            },
+        };
+
+        let last_update = level.long("LastUpdate").unwrap_or_default();
+        let inhabited_time = level.long("InhabitedTime").unwrap_or_default();
+
+        // Biomes are bytes before 1.13
+        let biomes = level.int_array("Biomes").or_else(|| {
+            level
+                .byte_array("Biomes")
+                .map(|biomes| biomes.iter().map(|b| *b as i32).collect())
+        });
+        let mut sections = vec![];
+        let mut numeric = false;
+        if let Some(list) = level.list("Sections").filter(|list| !list.empty()) {
+            for nbt in list
+                .compounds()
+                .ok_or(SculkParseError::InvalidField("Sections".into()))?
+            {
+                numeric |= is_numeric(version, &nbt);
+                sections.push(legacy_section(&nbt, version, biomes.as_deref())?);
+            }
+        }
+        sections.sort_by_key(|section| section.y);
+
+        let mut block_entities = vec![];
+        if let Some(list) = level.list("TileEntities").filter(|list| !list.empty()) {
+            for nbt in list
+                .compounds()
+                .ok_or(SculkParseError::InvalidField("TileEntities".into()))?
+            {
+                if numeric && !flatten_block_entity(&mut sections, &nbt) {
+                    continue;
+                }
+                block_entities.push(legacy_block_entity(&nbt)?);
+            }
+        }
+        if numeric {
+            for block_states in sections.iter_mut().filter_map(|s| s.block_states.as_mut()) {
+                block_states.compact();
+            }
+        }
+
+        let entities = match get_t_compound_vec(&level, "Entities", Entity::from_compound_nbt) {
+            Ok(entities) => Some(entities),
+            Err(SculkParseError::MissingField(_)) => None,
+            Err(e) => return Err(e),
+        };
+
+        let block_ticks = get_t_compound_vec(&level, "TileTicks", TileTick::from_compound_nbt)?;
+        let fluid_ticks = get_t_compound_vec(&level, "LiquidTicks", TileTick::from_compound_nbt)?;
+
+        let mut height_maps = HeightMaps {
+            motion_blocking: vec![],
+            motion_blocking_no_leaves: vec![],
+            ocean_floor: vec![],
+            ocean_floor_wg: vec![],
+            world_surface: vec![],
+            world_surface_wg: vec![],
+        };
+        match level.compound("Heightmaps") {
+            Some(nbt) => {
+                height_maps = HeightMaps::from_compound_nbt(&nbt)?;
+
+                // 1.13 to 1.15 heightmaps span across longs as well
+                let bits = bits_for(LEGACY_HEIGHT + 1);
+                for r#type in HeightMapType::ALL {
+                    let data = height_maps.get_mut(r#type);
+                    if !data.is_empty() {
+                        *data = unspan(data, bits, 256, version);
+                    }
+                }
+            }
+            // Before 1.13 there's only the `HeightMap` used for lighting, so compute the current ones from the blocks.
+            None => {
+                let types = [
+                    HeightMapType::MotionBlocking,
+                    HeightMapType::MotionBlockingNoLeaves,
+                    HeightMapType::OceanFloor,
+                    HeightMapType::WorldSurface,
+                ];
+                let grids = height_map::compute(&sections, 0, &types);
+                for (r#type, grid) in types.into_iter().zip(grids) {
+                    height_maps.encode(r#type, &grid, 0, LEGACY_HEIGHT);
+                }
+            }
+        }
+
+        let carving_masks = match level.compound("CarvingMasks") {
+            Some(nbt) => Some(CarvingMasks::from_compound_nbt(&nbt)?),
+            None => None,
+        };
+
+        let lights = match level.list("Lights").and_then(|list| list.lists()) {
+            Some(lists) => lists
+                .into_iter()
+                .map(|list| list.shorts().unwrap_or_default())
+                .collect(),
+            None => Vec::new(),
+        };
+
+        let structures = match level.compound("Structures") {
+            Some(nbt) => legacy_structures(&nbt)?,
+            None => Structures {
+                references: KVPair::new(Default::default()),
+                starts: KVPair::new(Default::default()),
+            },
+        };
+
+        Ok(Chunk {
+            data_version: DataVersion::LATEST_SUPPORTED.0,
+            source_data_version: Some(source_data_version),
+            x_pos,
+            z_pos,
+            y_pos: 0,
+            status,
+            last_update,
+            sections,
+            block_entities,
+            carving_masks,
+            height_maps,
+            lights,
+            entities,
+            fluid_ticks,
+            block_ticks,
+            inhabited_time,
+            blending_data: None,
+            post_processing: None,
+            structures,
+            unknown_tags: Default::default(),
+        })
+    }
+}
+
+/// Statuses were stored without a namespace, and 1.13 used its own set of them.
+fn legacy_status(status: &str) -> ChunkStatus {
+    match status {
+        "base" => ChunkStatus::Surface,
+        "carved" | "liquid_carved" => ChunkStatus::Carvers,
+        "decorated" => ChunkStatus::Features,
+        "lighted" => ChunkStatus::Light,
+        "mobs_spawned" => ChunkStatus::Spawn,
+        "finalized" | "fullchunk" | "postprocessed" => ChunkStatus::Full,
+        status if status.contains(':') => ChunkStatus::from(status),
+        status => ChunkStatus::from(format!("minecraft:{status}").as_str()),
+    }
+}
+
+/// `Y`, `Palette`, `BlockStates`, `BlockLight` and `SkyLight`, with the biomes of the chunk.
+/// Before 1.13 `Blocks`, `Add` and `Data` instead of `Palette` and `BlockStates`.
+fn legacy_section(
+    nbt: &NbtCompound,
+    version: Option<DataVersion>,
+    biomes: Option<&[i32]>,
+) -> Result<ChunkSection, SculkParseError> {
+    let y = nbt
+        .byte("Y")
+        .ok_or(SculkParseError::MissingField("Y".into()))?;
+
+    let block_states = match nbt.list("Palette") {
+        _ if is_numeric(version, nbt) => Some(numeric_block_states(nbt)),
+        Some(_) => {
+            let palette = get_t_compound_vec(nbt, "Palette", Palette::from_compound_nbt)?;
+            let bits = bits_for(palette.len()).max(4);
+            let data = match nbt.long_array("BlockStates") {
+                Some(data) if palette.len() > 1 => {
+                    Some(unspan(&data, bits, BlockStates::LEN, version))
+                }
+                _ => None,
+            };
+
+            Some(BlockStates { palette, data })
+        }
+        None => None,
+    };
+
+    let biomes = match biomes {
+        Some(biomes) if (0..16).contains(&y) => Some(legacy_biomes(biomes, y as usize)),
+        _ => None,
+    };
+
+    Ok(ChunkSection {
+        y,
+        block_states,
+        biomes,
+        block_light: nbt.byte_array("BlockLight").map(|x| x.to_vec()),
+        sky_light: nbt.byte_array("SkyLight").map(|x| x.to_vec()),
+    })
+}
+
+/// true if a section stores numeric block ids, which it does before the flattening in 17w47a.
+/// Without a data version the section is numeric if it has `Blocks` but no `Palette`.
+fn is_numeric(version: Option<DataVersion>, nbt: &NbtCompound) -> bool {
+    match version {
+        Some(version) => version < DataVersion::FLATTENING,
+        None => nbt.list("Palette").is_none() && nbt.byte_array("Blocks").is_some(),
+    }
+}
+
+/// Translates the numeric ids of a section from before 1.13 into a palette.
+/// `Blocks` has the lowest 8 bits of every id, `Add` the optional highest 4 bits and `Data` the 4 bit data values,
+/// all ordered by Y, Z then X.
+fn numeric_block_states(nbt: &NbtCompound) -> BlockStates {
+    let blocks = nbt.byte_array("Blocks").unwrap_or_default();
+    let add = nbt.byte_array("Add");
+    let data = nbt.byte_array("Data");
+
+    // even indices are in the low half of the byte
+    let nibble = |array: &[u8], i: usize| match array.get(i / 2) {
+        Some(byte) => (byte >> ((i & 1) * 4)) & 0x0f,
+        None => 0,
+    };
+
+    let mut palette: Vec<Palette> = vec![];
+    let mut known: HashMap<(u16, u8), usize> = HashMap::new();
+    let indices: Vec<usize> = (0..BlockStates::LEN)
+        .map(|i| {
+            let id = blocks.get(i).copied().unwrap_or(0) as u16
+                | (add.map_or(0, |add| nibble(add, i)) as u16) << 8;
+            let data = data.map_or(0, |data| nibble(data, i));
+
+            *known.entry((id, data)).or_insert_with(|| {
+                let block = flatten(id, data);
+                match palette.iter().position(|entry| *entry == block) {
+                    Some(i) => i,
+                    None => {
+                        palette.push(block);
+                        palette.len() - 1
+                    }
+                }
+            })
+        })
+        .collect();
+
+    let data = match palette.len() {
+        1 => None,
+        len => Some(pack(&indices, bits_for(len).max(4))),
+    };
+
+    BlockStates { palette, data }
+}
+
+/// Biomes of a section from the `Biomes` int array of the chunk.
+/// Since 1.15 it has 64 entries per section, 4x4x4 cells ordered by Y, Z then X.
+/// Before that it has 256 entries, one per column ordered by Z then X, the middle column of every cell is used.
+pub(crate) fn legacy_biomes(biomes: &[i32], section_y: usize) -> Biomes {
+    let ids: Vec<i32> = if biomes.len() == 256 {
+        (0..Biomes::LEN)
+            .map(|i| biomes[((i / 4) % 4 * 4 + 2) * 16 + (i % 4) * 4 + 2])
+            .collect()
+    } else {
+        (0..Biomes::LEN)
+            .map(|i| {
+                biomes
+                    .get(section_y * Biomes::LEN + i)
+                    .copied()
+                    .unwrap_or(1)
+            })
+            .collect()
+    };
+
+    let mut palette: Vec<PaletteNoProps> = vec![];
+    let indices: Vec<usize> = ids
+        .into_iter()
+        .map(|id| {
+            let name = legacy_biome_name(id);
+            match palette.iter().position(|biome| biome.name == name) {
+                Some(i) => i,
+                None => {
+                    palette.push(PaletteNoProps { name: name.into() });
+                    palette.len() - 1
+                }
+            }
+        })
+        .collect();
+
+    let data = match palette.len() {
+        1 => None,
+        len => Some(pack(&indices, bits_for(len))),
+    };
+
+    Biomes { palette, data }
+}
+
+/// Repacks an array where values span across two longs (before 20w17a) into the current format.
+/// Without a data version the length tells the formats apart, they only have the same length when `bits` divides 64
+/// and then they are identical. Arrays that are already in the current format are returned as is.
+pub(crate) fn unspan(
+    data: &[i64],
+    bits: usize,
+    len: usize,
+    version: Option<DataVersion>,
+) -> Vec<i64> {
+    if bits == 0 {
+        return data.to_vec();
+    }
+    let spanning = match version {
+        Some(version) => version < DataVersion::NON_SPANNING_ARRAYS,
+        None => data.len() != len.div_ceil(64 / bits),
+    };
+    if !spanning {
+        return data.to_vec();
+    }
+
+    let mask = (1u64 << bits) - 1;
+    let indices: Vec<usize> = (0..len)
+        .map(|i| {
+            let bit = i * bits;
+            let (long, offset) = (bit / 64, bit % 64);
+
+            let low = data.get(long).map_or(0, |l| *l as u64) >> offset;
+            let value = if offset + bits > 64 {
+                let high = data.get(long + 1).map_or(0, |l| *l as u64) << (64 - offset);
+                low | high
+            } else {
+                low
+            };
+
+            (value & mask) as usize
+        })
+        .collect();
+
+    pack(&indices, bits)
+}
+
+/// Parses a block entity, giving ids from before 1.11 like `Chest` their namespaced id first.
+/// Block entities that can't be read are kept as [`BlockEntityKind::Unknown`](crate::block_entities::BlockEntityKind::Unknown).
+fn legacy_block_entity(nbt: &NbtCompound) -> Result<BlockEntity, SculkParseError> {
+    let id = nbt
+        .string("id")
+        .ok_or(SculkParseError::MissingField("id".into()))?
+        .to_str();
+
+    let id = match id.as_ref() {
+        "Airportal" => "minecraft:end_portal",
+        "Banner" => "minecraft:banner",
+        "Beacon" => "minecraft:beacon",
+        "Cauldron" => "minecraft:brewing_stand",
+        "Chest" => "minecraft:chest",
+        "Comparator" => "minecraft:comparator",
+        "Control" => "minecraft:command_block",
+        "DLDetector" => "minecraft:daylight_detector",
+        "Dropper" => "minecraft:dropper",
+        "EnchantTable" => "minecraft:enchanting_table",
+        "EndGateway" => "minecraft:end_gateway",
+        "EnderChest" => "minecraft:ender_chest",
+        "Furnace" => "minecraft:furnace",
+        "Hopper" => "minecraft:hopper",
+        "MobSpawner" => "minecraft:mob_spawner",
+        "Piston" => "minecraft:piston",
+        "RecordPlayer" => "minecraft:jukebox",
+        "Sign" => "minecraft:sign",
+        "Skull" => "minecraft:skull",
+        "Structure" => "minecraft:structure_block",
+        "Trap" => "minecraft:dispenser",
+        _ => return BlockEntity::from_compound_nbt_or_unknown(nbt),
+    };
+
+    let mut owned = nbt.to_owned();
+    owned.remove("id");
+    owned.insert("id", id);
+    let bytes = write_nbt(&owned);
+    let base = match borrow::read(&mut std::io::Cursor::new(bytes.as_slice()))? {
+        borrow::Nbt::Some(base) => base,
+        borrow::Nbt::None => return Err(SculkParseError::NoNbt),
+    };
+
+    BlockEntity::from_compound_nbt_or_unknown(&base.as_compound())
+}
+
+/// Moves the data a block entity from before 1.13 held about its block into the flattened block state.
+/// Returns `false` for flower pots and note blocks, which stopped being block entities.
+fn flatten_block_entity(sections: &mut [ChunkSection], nbt: &NbtCompound) -> bool {
+    let (Some(id), Some(x), Some(y), Some(z)) =
+        (nbt.string("id"), nbt.int("x"), nbt.int("y"), nbt.int("z"))
+    else {
+        return true;
+    };
+    let Some(section) = sections
+        .iter_mut()
+        .find(|section| section.y as i32 == y >> 4)
+    else {
+        return true;
+    };
+    let (x, y, z) = (
+        x.rem_euclid(16) as usize,
+        y.rem_euclid(16) as usize,
+        z.rem_euclid(16) as usize,
+    );
+    let Some(mut block) = section.block_at(x, y, z).cloned() else {
+        return true;
+    };
+
+    let id = id.to_str();
+    let keep = match id.as_ref() {
+        "FlowerPot" | "minecraft:flower_pot" if block.name == "minecraft:flower_pot" => {
+            block.name = potted_plant(nbt).into();
+            false
+        }
+        "Music" | "minecraft:noteblock" if block.name == "minecraft:note_block" => {
+            let note = nbt.byte("note").unwrap_or(0).clamp(0, 24);
+            let powered = nbt.byte("powered").unwrap_or(0) != 0;
+            block.properties.insert("note".into(), note.to_string());
+            block
+                .properties
+                .insert("powered".into(), powered.to_string());
+            false
+        }
+        "minecraft:bed" if block.name == "minecraft:red_bed" => {
+            let color = Color::from_id(nbt.int("color").unwrap_or(14) as u8).unwrap_or(Color::Red);
+            block.name = format!("minecraft:{}_bed", color.to_str());
+            true
+        }
+        "Banner" | "minecraft:banner"
+            if block.name == "minecraft:white_banner"
+                || block.name == "minecraft:white_wall_banner" =>
+        {
+            // `Base` is the dye damage value, which counts the colors backwards.
+            let color = Color::from_id(15 - nbt.int("Base").unwrap_or(15).clamp(0, 15) as u8)
+                .unwrap_or(Color::White);
+            block.name = block.name.replacen("white", color.to_str(), 1);
+            true
+        }
+        "Skull" | "minecraft:skull"
+            if block.name == "minecraft:skeleton_skull"
+                || block.name == "minecraft:skeleton_wall_skull" =>
+        {
+            let (skull, wall_skull) = match nbt.byte("SkullType").unwrap_or(0) {
+                1 => (
+                    "minecraft:wither_skeleton_skull",
+                    "minecraft:wither_skeleton_wall_skull",
+                ),
+                2 => ("minecraft:zombie_head", "minecraft:zombie_wall_head"),
+                3 => ("minecraft:player_head", "minecraft:player_wall_head"),
+                4 => ("minecraft:creeper_head", "minecraft:creeper_wall_head"),
+                5 => ("minecraft:dragon_head", "minecraft:dragon_wall_head"),
+                _ => ("minecraft:skeleton_skull", "minecraft:skeleton_wall_skull"),
+            };
+
+            if block.name == "minecraft:skeleton_wall_skull" {
+                block.name = wall_skull.into();
+            } else {
+                block.name = skull.into();
+                let rotation = nbt.byte("Rot").unwrap_or(0).rem_euclid(16);
+                block
+                    .properties
+                    .insert("rotation".into(), rotation.to_string());
+            }
+            true
+        }
+        _ => return true,
+    };
+
+    section.set_block(x, y, z, block);
+    keep
+}
+
+/// The potted block a flower pot from before 1.13 becomes, from its `Item` and `Data`.
+/// `Item` is a numeric id before 1.8.
+fn potted_plant(nbt: &NbtCompound) -> &'static str {
+    let item = match (nbt.string("Item"), nbt.int("Item")) {
+        (Some(item), _) => item.to_str().into_owned(),
+        (None, Some(id)) => match id {
+            6 => "minecraft:sapling",
+            31 => "minecraft:tallgrass",
+            32 => "minecraft:deadbush",
+            37 => "minecraft:yellow_flower",
+            38 => "minecraft:red_flower",
+            39 => "minecraft:brown_mushroom",
+            40 => "minecraft:red_mushroom",
+            81 => "minecraft:cactus",
+            _ => "minecraft:air",
+        }
+        .into(),
+        (None, None) => "minecraft:air".into(),
+    };
+
+    match (item.as_str(), nbt.int("Data").unwrap_or(0)) {
+        ("minecraft:sapling", 1) => "minecraft:potted_spruce_sapling",
+        ("minecraft:sapling", 2) => "minecraft:potted_birch_sapling",
+        ("minecraft:sapling", 3) => "minecraft:potted_jungle_sapling",
+        ("minecraft:sapling", 4) => "minecraft:potted_acacia_sapling",
+        ("minecraft:sapling", 5) => "minecraft:potted_dark_oak_sapling",
+        ("minecraft:sapling", _) => "minecraft:potted_oak_sapling",
+        ("minecraft:tallgrass", 2) => "minecraft:potted_fern",
+        ("minecraft:deadbush", _) => "minecraft:potted_dead_bush",
+        ("minecraft:yellow_flower", _) => "minecraft:potted_dandelion",
+        ("minecraft:red_flower", 1) => "minecraft:potted_blue_orchid",
+        ("minecraft:red_flower", 2) => "minecraft:potted_allium",
+        ("minecraft:red_flower", 3) => "minecraft:potted_azure_bluet",
+        ("minecraft:red_flower", 4) => "minecraft:potted_red_tulip",
+        ("minecraft:red_flower", 5) => "minecraft:potted_orange_tulip",
+        ("minecraft:red_flower", 6) => "minecraft:potted_white_tulip",
+        ("minecraft:red_flower", 7) => "minecraft:potted_pink_tulip",
+        ("minecraft:red_flower", 8) => "minecraft:potted_oxeye_daisy",
+        ("minecraft:red_flower", _) => "minecraft:potted_poppy",
+        ("minecraft:brown_mushroom", _) => "minecraft:potted_brown_mushroom",
+        ("minecraft:red_mushroom", _) => "minecraft:potted_red_mushroom",
+        ("minecraft:cactus", _) => "minecraft:potted_cactus",
+        _ => "minecraft:flower_pot",
+    }
+}
+
+/// `References` and `Starts`, the starts are keyed by their old names like `Village`.
+fn legacy_structures(nbt: &NbtCompound) -> Result<Structures, SculkParseError> {
+    let references = match nbt.compound("References") {
+        Some(nbt) => KVPair::<Vec<i64>>::from_compound_nbt(&nbt)?,
+        None => KVPair::new(Default::default()),
+    };
+    let starts = match nbt.compound("Starts") {
+        Some(nbt) => KVPair::<Structure>::from_compound_nbt(&nbt)?,
+        None => KVPair::new(Default::default()),
+    };
+
+    Ok(Structures { references, starts })
+}
+
+#[cfg(test)]
+#[test]
+fn legacy_chunk_test() {
+    use crate::traits::ToCompoundNbt;
+    use simdnbt::owned;
+
+    // 17 blocks need 5 bits, which spans across longs before 1.16
+    let names: Vec<String> = (0..17).map(|i| format!("minecraft:block_{i}")).collect();
+    let mut bits = vec![0u64; 4096 * 5 / 64];
+    for i in 0..4096usize {
+        let value = (i % 17) as u64;
+        let (long, offset) = (i * 5 / 64, i * 5 % 64);
+        bits[long] |= value << offset;
+        if offset + 5 > 64 {
+            bits[long + 1] |= value >> (64 - offset);
+        }
+    }
+
+    let mut section = owned::NbtCompound::new();
+    section.insert("Y", 1i8);
+    section.insert(
+        "Palette",
+        owned::NbtList::Compound(
+            names
+                .iter()
+                .map(|name| {
+                    let mut nbt = owned::NbtCompound::new();
+                    nbt.insert("Name", name.as_str());
+                    nbt
+                })
+                .collect(),
+        ),
+    );
+    section.insert(
+        "BlockStates",
+        owned::NbtTag::LongArray(bits.iter().map(|l| *l as i64).collect()),
+    );
+
+    let mut tick = owned::NbtCompound::new();
+    tick.insert("i", "minecraft:water");
+    for (key, value) in [("p", 0), ("t", 5), ("x", 1), ("y", 20), ("z", 2)] {
+        tick.insert(key, value);
+    }
+
+    // forest everywhere except a river in the first cell column
+    let mut biomes = vec![4; 1024];
+    for y in 0..64 {
+        biomes[y * 16] = 7;
+    }
+
+    let mut level = owned::NbtCompound::new();
+    level.insert("xPos", 3);
+    level.insert("zPos", -2);
+    level.insert("Status", "full");
+    level.insert("LastUpdate", 100i64);
+    level.insert("InhabitedTime", 20i64);
+    level.insert("Sections", owned::NbtList::Compound(vec![section]));
+    level.insert("Biomes", owned::NbtTag::IntArray(biomes));
+    level.insert("LiquidTicks", owned::NbtList::Compound(vec![tick]));
+
+    let mut nbt = owned::NbtCompound::new();
+    nbt.insert("DataVersion", 2230);
+    nbt.insert("Level", level);
+
+    let chunk = Chunk::from_bytes(&crate::util::write_nbt(&nbt)).unwrap();
+
+    assert_eq!((chunk.x_pos, chunk.z_pos, chunk.y_pos), (3, -2, 0));
+    assert_eq!(chunk.status, ChunkStatus::Full);
+    assert_eq!(chunk.fluid_ticks[0].i, "minecraft:water");
+    assert!(chunk.block_ticks.is_empty());
+
+    let section = chunk.section_at(16).unwrap();
+    assert_eq!(
+        section
+            .block_states
+            .as_ref()
+            .unwrap()
+            .data
+            .as_ref()
+            .unwrap()
+            .len(),
+        342
+    );
+    for (i, ([x, y, z], block)) in section.blocks().enumerate() {
+        assert_eq!(block.name, names[i % 17], "{x} {y} {z}");
+    }
+    assert_eq!(chunk.block_at(5, 31, 2).unwrap().name, names[3877 % 17]);
+
+    assert_eq!(chunk.biome_at(0, 20, 0).unwrap().name, "minecraft:river");
+    assert_eq!(chunk.biome_at(4, 20, 0).unwrap().name, "minecraft:forest");
+
+    // written with the version of its new format, so it isn't converted again when read back
+    assert_eq!(chunk.data_version, DataVersion::LATEST_SUPPORTED.0);
+    assert_eq!(chunk.source_data_version, Some(2230));
+    let written = Chunk::from_bytes(&chunk.to_bytes()).unwrap();
+    assert_eq!(written.source_data_version, None);
+    assert_eq!(
+        Chunk {
+            source_data_version: Some(2230),
+            ..written
+        },
+        chunk
+    );
+}
+
+#[cfg(test)]
+#[test]
+fn numeric_chunk_test() {
+    use simdnbt::owned;
+
+    // stone everywhere, with a layer of oak stairs facing south at y 3 and an id above 255 at 0, 0, 0
+    let mut blocks = vec![1u8; 4096];
+    let mut data = vec![0u8; 2048];
+    let mut add = vec![0u8; 2048];
+    for i in 3 * 256..4 * 256 {
+        blocks[i] = 53;
+        data[i / 2] |= 2 << ((i & 1) * 4);
+    }
+    add[0] = 1;
+
+    // blocks whose state depends on their block entity at y 5
+    let mut tile_entities = vec![];
+    for (x, id, block_data, tags) in [
+        (
+            1,
+            26,
+            0,
+            vec![
+                ("id", owned::NbtTag::String("minecraft:bed".into())),
+                ("color", owned::NbtTag::Int(11)),
+            ],
+        ),
+        (
+            2,
+            140,
+            0,
+            vec![
+                ("id", owned::NbtTag::String("FlowerPot".into())),
+                ("Item", owned::NbtTag::String("minecraft:red_flower".into())),
+                ("Data", owned::NbtTag::Int(2)),
+            ],
+        ),
+        (
+            3,
+            144,
+            1,
+            vec![
+                ("id", owned::NbtTag::String("Skull".into())),
+                ("SkullType", owned::NbtTag::Byte(4)),
+                ("Rot", owned::NbtTag::Byte(6)),
+            ],
+        ),
+        (
+            4,
+            25,
+            0,
+            vec![
+                ("id", owned::NbtTag::String("Music".into())),
+                ("note", owned::NbtTag::Byte(7)),
+            ],
+        ),
+        (
+            5,
+            176,
+            0,
+            vec![
+                ("id", owned::NbtTag::String("Banner".into())),
+                ("Base", owned::NbtTag::Int(4)),
+            ],
+        ),
+    ] {
+        let i = 5 * 256 + x as usize;
+        blocks[i] = id;
+        data[i / 2] |= block_data << ((i & 1) * 4);
+
+        let mut nbt = owned::NbtCompound::new();
+        for (key, value) in tags {
+            nbt.insert(key, value);
+        }
+        for (key, value) in [("x", x), ("y", 5), ("z", 0)] {
+            nbt.insert(key, value);
+        }
+        tile_entities.push(nbt);
+    }
+
+    let mut section = owned::NbtCompound::new();
+    section.insert("Y", 0i8);
+    section.insert("Blocks", owned::NbtTag::ByteArray(blocks));
+    section.insert("Data", owned::NbtTag::ByteArray(data));
+    section.insert("Add", owned::NbtTag::ByteArray(add));
+
+    let mut sign = owned::NbtCompound::new();
+    sign.insert("id", "Sign");
+    for (key, value) in [("x", 0), ("y", 4), ("z", 0)] {
+        sign.insert(key, value);
+    }
+
+    let mut level = owned::NbtCompound::new();
+    level.insert("xPos", 0);
+    level.insert("zPos", 0);
+    level.insert("TerrainPopulated", 1i8);
+    level.insert("LightPopulated", 0i8);
+    level.insert("Sections", owned::NbtList::Compound(vec![section]));
+    level.insert("Biomes", owned::NbtTag::ByteArray(vec![2; 256]));
+    tile_entities.push(sign);
+    level.insert("TileEntities", owned::NbtList::Compound(tile_entities));
+
+    let mut nbt = owned::NbtCompound::new();
+    nbt.insert("DataVersion", 1343);
+    nbt.insert("Level", level);
+
+    let chunk = Chunk::from_bytes(&crate::util::write_nbt(&nbt)).unwrap();
+
+    assert_eq!(chunk.status, ChunkStatus::Features);
+    assert_eq!(chunk.block_at(1, 0, 0).unwrap().name, "minecraft:stone");
+    // 256 + 1 isn't a block, so it becomes air
+    assert_eq!(chunk.block_at(0, 0, 0).unwrap().name, "minecraft:air");
+
+    let stairs = chunk.block_at(7, 3, 9).unwrap();
+    assert_eq!(stairs.name, "minecraft:oak_stairs");
+    assert_eq!(stairs.properties.get("facing").unwrap(), "south");
+
+    assert_eq!(chunk.biome_at(0, 0, 0).unwrap().name, "minecraft:desert");
+
+    assert_eq!(chunk.block_at(1, 5, 0).unwrap().name, "minecraft:blue_bed");
+    assert_eq!(
+        chunk.block_at(2, 5, 0).unwrap().name,
+        "minecraft:potted_allium"
+    );
+    let skull = chunk.block_at(3, 5, 0).unwrap();
+    assert_eq!(skull.name, "minecraft:creeper_head");
+    assert_eq!(skull.properties.get("rotation").unwrap(), "6");
+    let note_block = chunk.block_at(4, 5, 0).unwrap();
+    assert_eq!(note_block.properties.get("note").unwrap(), "7");
+    assert_eq!(
+        chunk.block_at(5, 5, 0).unwrap().name,
+        "minecraft:blue_banner"
+    );
+    assert!(chunk
+        .sections
+        .iter()
+        .flat_map(|section| &section.block_states.as_ref().unwrap().palette)
+        .all(|block| block.name != "minecraft:red_bed"));
+
+    // flower pots and note blocks aren't block entities anymore, and the sign can't be read but is kept
+    let ids: Vec<&str> = chunk
+        .block_entities
+        .iter()
+        .map(|block_entity| block_entity.base.id.as_str())
+        .collect();
+    assert_eq!(
+        ids,
+        [
+            "minecraft:bed",
+            "minecraft:skull",
+            "minecraft:banner",
+            "minecraft:sign"
+        ]
+    );
+    assert!(matches!(
+        chunk.block_entities[3].kind,
+        crate::block_entities::BlockEntityKind::Unknown(_)
+    ));
+    assert_eq!(
+        chunk.height_map(HeightMapType::WorldSurface).unwrap()[5][5],
+        16
+    );
+}
@@ -1,8 +1,11 @@
 use std::io::Cursor;
 
 use crate::{
-    block_entity::BlockEntity, error::SculkParseError, traits::FromCompoundNbt,
+    block_entity::BlockEntity,
+    error::SculkParseError,
+    traits::FromCompoundNbt,
     util::get_t_compound_vec,
+    version::{self, Versioned},
 };
 
 use super::{
@@ -66,6 +69,13 @@ impl FromCompoundNbt for MinimalChunk {
     where
         Self: Sized,
     {
+        version::with_version(MinimalChunk::version_of(nbt), || MinimalChunk::parse(nbt))
+    }
+}
+
+impl MinimalChunk {
+    /// Parses the chunk, with the data version of the file already set for nested parsers.
+    fn parse(nbt: &simdnbt::borrow::NbtCompound) -> Result<Self, SculkParseError> {
         let data_version = nbt
             .int("DataVersion")
             .ok_or(SculkParseError::MissingField("DataVersion".into()))?;
@@ -120,9 +130,7 @@ impl FromCompoundNbt for MinimalChunk {
             structures,
         })
     }
-}
 
-impl MinimalChunk {
     pub fn from_bytes(bytes: &[u8]) -> Result<Self, SculkParseError> {
         let nbt = simdnbt::borrow::read(&mut Cursor::new(bytes))?;
 
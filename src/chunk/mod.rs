@@ -1,19 +1,33 @@
 use crate::{
-    block_entity::BlockEntity, entity::Entity, error::SculkParseError, traits::FromCompoundNbt,
-    util::get_t_compound_vec,
+    block_entity::BlockEntity,
+    entity::Entity,
+    error::SculkParseError,
+    traits::{FromCompoundNbt, ToCompoundNbt},
+    util::{compound_list, get_t_compound_vec, get_unknown_tags, insert_unknown_tags},
+    version::{self, DataVersion, Versioned},
 };
-use section::ChunkSection;
+use height_map::{HeightGrid, HeightMapType};
+use section::{ChunkSection, Palette, PaletteNoProps};
+use simdnbt::owned;
 use status::ChunkStatus;
 use std::io::Cursor;
 use structure::Structures;
 use tile_tick::TileTick;
 
+mod entity_chunk;
+mod lazy_chunk;
+mod legacy;
 mod minimal_chunk;
 
+pub mod flattening;
+pub mod height_map;
 pub mod section;
 pub mod status;
 pub mod structure;
 pub mod tile_tick;
+pub use entity_chunk::*;
+pub use lazy_chunk::*;
+pub use legacy::{legacy_biome_name, LEGACY_BIOMES};
 pub use minimal_chunk::*;
 
 /// Represents a chunk in the world.  
@@ -25,6 +39,12 @@ pub struct Chunk {
     /// `DataVersion`
     pub data_version: i32,
 
+    /// The `DataVersion` the chunk was saved with, if it was converted from the format used before 1.18 (0 before 1.9).
+    /// [`Chunk::data_version`] is then [`DataVersion::LATEST_SUPPORTED`], the format it's written in, so the game doesn't upgrade it a second time.
+    /// A converted chunk keeps its height of 256 blocks, starting at `y_pos` 0. Not written.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub source_data_version: Option<i32>,
+
     /// X position of the chunk (in absolute chunks from world x, z origin, not relative to the region).  
     /// `xPos`
     pub x_pos: i32,
@@ -89,8 +109,33 @@ pub struct Chunk {
 
     /// Structure data in this chunk.
     pub structures: Structures,
+
+    /// Every tag that isn't parsed, like `isLightOn` or `UpgradeData`, written back as is.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub unknown_tags: owned::NbtCompound,
 }
 
+/// The tags parsed into [`Chunk`].
+const CHUNK_KEYS: [&str; 17] = [
+    "DataVersion",
+    "xPos",
+    "zPos",
+    "yPos",
+    "Status",
+    "LastUpdate",
+    "sections",
+    "block_entities",
+    "CarvingMasks",
+    "Heightmaps",
+    "Lights",
+    "Entities",
+    "fluid_ticks",
+    "block_ticks",
+    "InhabitedTime",
+    "blending_data",
+    "structures",
+];
+
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct BlendingData {
@@ -132,6 +177,34 @@ impl FromCompoundNbt for Chunk {
     where
         Self: Sized,
     {
+        let version = Chunk::version_of(nbt);
+        version::with_version(version, || {
+            // Chunks from before 21w43a (1.18) have everything wrapped in `Level`.
+            let legacy = match version {
+                Some(version) => version < DataVersion::CHUNKS_WITHOUT_LEVEL,
+                None => nbt.contains("Level"),
+            };
+            if legacy {
+                return Chunk::from_legacy_compound_nbt(nbt);
+            }
+
+            let sections = get_t_compound_vec(nbt, "sections", ChunkSection::from_compound_nbt)?;
+            let block_entities =
+                get_t_compound_vec(nbt, "block_entities", BlockEntity::from_compound_nbt)?;
+
+            Chunk::from_parts(nbt, sections, block_entities)
+        })
+    }
+}
+
+impl Chunk {
+    /// Parses every field except the sections and block entities, which are passed in already parsed.
+    /// Shared with [`crate::borrowed::Chunk::into_owned`].
+    pub(crate) fn from_parts(
+        nbt: &simdnbt::borrow::NbtCompound,
+        sections: Vec<ChunkSection>,
+        block_entities: Vec<BlockEntity>,
+    ) -> Result<Self, SculkParseError> {
         let data_version = nbt
             .int("DataVersion")
             .ok_or(SculkParseError::MissingField("DataVersion".into()))?;
@@ -155,10 +228,6 @@ impl FromCompoundNbt for Chunk {
             .long("LastUpdate")
             .ok_or(SculkParseError::MissingField("LastUpdate".into()))?;
 
-        let sections = get_t_compound_vec(&nbt, "sections", ChunkSection::from_compound_nbt)?;
-        let block_entities =
-            get_t_compound_vec(&nbt, "block_entities", BlockEntity::from_compound_nbt)?;
-
         let carving_masks = if let Some(nbt) = nbt.compound("CarvingMasks") {
             Some(CarvingMasks::from_compound_nbt(&nbt)?)
         } else {
@@ -233,6 +302,7 @@ impl FromCompoundNbt for Chunk {
 
         Ok(Chunk {
             data_version,
+            source_data_version: None,
             x_pos,
             z_pos,
             y_pos,
@@ -250,6 +320,7 @@ impl FromCompoundNbt for Chunk {
             blending_data,
             post_processing,
             structures,
+            unknown_tags: get_unknown_tags(nbt, &CHUNK_KEYS),
         })
     }
 }
@@ -331,4 +402,247 @@ impl Chunk {
 
         Chunk::from_compound_nbt(&compound)
     }
+
+    /// Returns the section containing the given block Y coordinate.
+    pub fn section_at(&self, y: i32) -> Option<&ChunkSection> {
+        let section_y = y.div_euclid(16);
+
+        // Sections are normally stored in order starting at `y_pos`, fall back to a search if not.
+        match usize::try_from(section_y - self.y_pos)
+            .ok()
+            .and_then(|i| self.sections.get(i))
+        {
+            Some(section) if section.y as i32 == section_y => Some(section),
+            _ => self.sections.iter().find(|s| s.y as i32 == section_y),
+        }
+    }
+
+    /// Returns the section containing the given block Y coordinate, mutably.
+    pub fn section_at_mut(&mut self, y: i32) -> Option<&mut ChunkSection> {
+        let section_y = y.div_euclid(16);
+
+        let index = usize::try_from(section_y - self.y_pos)
+            .ok()
+            .filter(|i| {
+                self.sections
+                    .get(*i)
+                    .is_some_and(|s| s.y as i32 == section_y)
+            })
+            .or_else(|| self.sections.iter().position(|s| s.y as i32 == section_y))?;
+
+        self.sections.get_mut(index)
+    }
+
+    /// Returns the block state at the given world position.
+    /// Only the lowest 4 bits of X and Z are used, so the position is not checked to be inside this chunk.
+    pub fn block_at(&self, x: i32, y: i32, z: i32) -> Option<&Palette> {
+        self.section_at(y)?.block_at(
+            x.rem_euclid(16) as usize,
+            y.rem_euclid(16) as usize,
+            z.rem_euclid(16) as usize,
+        )
+    }
+
+    /// Sets the block state at the given world position, the section is only repacked when its palette needs more bits.
+    /// Only the lowest 4 bits of X and Z are used, so the position is not checked to be inside this chunk.
+    ///
+    /// Returns `false` if the chunk has no section at that height.
+    pub fn set_block(&mut self, x: i32, y: i32, z: i32, block: Palette) -> bool {
+        let Some(section) = self.section_at_mut(y) else {
+            return false;
+        };
+
+        section.set_block(
+            x.rem_euclid(16) as usize,
+            y.rem_euclid(16) as usize,
+            z.rem_euclid(16) as usize,
+            block,
+        );
+        true
+    }
+
+    /// Returns the biome at the given world position, biomes are stored at a 4x4x4 resolution.
+    /// Only the lowest 4 bits of X and Z are used, so the position is not checked to be inside this chunk.
+    pub fn biome_at(&self, x: i32, y: i32, z: i32) -> Option<&PaletteNoProps> {
+        self.section_at(y)?.biome_at(
+            x.rem_euclid(16) as usize,
+            y.rem_euclid(16) as usize,
+            z.rem_euclid(16) as usize,
+        )
+    }
+
+    /// Decodes the given heightmap into world Y values, indexed as `[z][x]`.
+    /// `None` if the chunk doesn't store this heightmap.
+    pub fn height_map(&self, r#type: HeightMapType) -> Option<HeightGrid> {
+        self.height_maps.decode(r#type, self.y_pos)
+    }
+
+    /// Recomputes the heightmaps from the block data, needed after editing blocks.
+    /// `MOTION_BLOCKING`, `MOTION_BLOCKING_NO_LEAVES`, `OCEAN_FLOOR` and `WORLD_SURFACE` are always written,
+    /// the world generation heightmaps only if the chunk already has them.
+    pub fn recompute_height_maps(&mut self) {
+        let types: Vec<HeightMapType> = HeightMapType::ALL
+            .into_iter()
+            .filter(|t| match t {
+                HeightMapType::OceanFloorWg | HeightMapType::WorldSurfaceWg => {
+                    !self.height_maps.get(*t).is_empty()
+                }
+                _ => true,
+            })
+            .collect();
+
+        let grids = height_map::compute(&self.sections, self.y_pos, &types);
+        let height = height_map::world_height(&self.sections, self.y_pos);
+        for (r#type, grid) in types.into_iter().zip(grids) {
+            self.height_maps.encode(r#type, &grid, self.y_pos, height);
+        }
+    }
+
+    /// Iterates over every block in the chunk with its world position, from the lowest section up.
+    pub fn blocks(&self) -> impl Iterator<Item = ([i32; 3], &Palette)> {
+        let (x_offset, z_offset) = (self.x_pos * 16, self.z_pos * 16);
+
+        self.sections.iter().flat_map(move |section| {
+            let y_offset = section.y as i32 * 16;
+
+            section.blocks().map(move |([x, y, z], palette)| {
+                (
+                    [
+                        x_offset + x as i32,
+                        y_offset + y as i32,
+                        z_offset + z as i32,
+                    ],
+                    palette,
+                )
+            })
+        })
+    }
+}
+
+impl ToCompoundNbt for Chunk {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        version::with_version(Some(self.version()), || self.write())
+    }
+}
+
+impl Chunk {
+    /// Writes the chunk, with its data version set for nested writers that depend on it.
+    fn write(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        nbt.insert("DataVersion", self.data_version);
+        nbt.insert("xPos", self.x_pos);
+        nbt.insert("zPos", self.z_pos);
+        nbt.insert("yPos", self.y_pos);
+        nbt.insert("Status", String::from(self.status.clone()));
+        nbt.insert("LastUpdate", self.last_update);
+        nbt.insert("sections", compound_list(&self.sections));
+        nbt.insert("block_entities", compound_list(&self.block_entities));
+        if let Some(carving_masks) = &self.carving_masks {
+            nbt.insert("CarvingMasks", carving_masks.to_compound_nbt());
+        }
+        nbt.insert("Heightmaps", self.height_maps.to_compound_nbt());
+        if !self.lights.is_empty() {
+            let lights = self
+                .lights
+                .iter()
+                .map(|light| owned::NbtList::Short(light.clone()))
+                .collect();
+            nbt.insert("Lights", owned::NbtList::List(lights));
+        }
+        if let Some(entities) = &self.entities {
+            if !entities.is_empty() {
+                nbt.insert("Entities", compound_list(entities));
+            }
+        }
+        nbt.insert("fluid_ticks", compound_list(&self.fluid_ticks));
+        nbt.insert("block_ticks", compound_list(&self.block_ticks));
+        nbt.insert("InhabitedTime", self.inhabited_time);
+        if let Some(blending_data) = &self.blending_data {
+            nbt.insert("blending_data", blending_data.to_compound_nbt());
+        }
+        if let Some(post_processing) = &self.post_processing {
+            nbt.insert(
+                "PostProcessing",
+                owned::NbtList::Short(post_processing.clone()),
+            );
+        }
+        nbt.insert("structures", self.structures.to_compound_nbt());
+        insert_unknown_tags(&mut nbt, &self.unknown_tags);
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for BlendingData {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        nbt.insert("min_section", self.min_section);
+        nbt.insert("max_section", self.max_section);
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for HeightMaps {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        for r#type in HeightMapType::ALL {
+            let values = self.get(r#type);
+            if !values.is_empty() {
+                nbt.insert(r#type.key(), owned::NbtTag::LongArray(values.clone()));
+            }
+        }
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for CarvingMasks {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        let air = self.air.iter().map(|b| *b as u8).collect();
+        let liquid = self.liquid.iter().map(|b| *b as u8).collect();
+        nbt.insert("AIR", owned::NbtTag::ByteArray(air));
+        nbt.insert("LIQUID", owned::NbtTag::ByteArray(liquid));
+
+        nbt
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn chunk_unknown_tags_test() {
+    let mut structures = owned::NbtCompound::new();
+    structures.insert("References", owned::NbtCompound::new());
+    structures.insert("starts", owned::NbtCompound::new());
+
+    let mut nbt = owned::NbtCompound::new();
+    nbt.insert("DataVersion", DataVersion::V1_21_4.0);
+    nbt.insert("xPos", 1);
+    nbt.insert("zPos", 2);
+    nbt.insert("yPos", -4);
+    nbt.insert("Status", "minecraft:full");
+    nbt.insert("LastUpdate", 10i64);
+    nbt.insert("InhabitedTime", 0i64);
+    nbt.insert("sections", owned::NbtList::Empty);
+    nbt.insert("block_entities", owned::NbtList::Empty);
+    nbt.insert("fluid_ticks", owned::NbtList::Empty);
+    nbt.insert("block_ticks", owned::NbtList::Empty);
+    nbt.insert("Heightmaps", owned::NbtCompound::new());
+    nbt.insert("structures", structures);
+    nbt.insert("isLightOn", true);
+    nbt.insert("UpgradeData", owned::NbtCompound::new());
+
+    let chunk = Chunk::from_bytes(&crate::util::write_nbt(&nbt)).unwrap();
+    assert_eq!(chunk.unknown_tags.byte("isLightOn"), Some(1));
+    assert!(chunk.unknown_tags.get("xPos").is_none());
+
+    let written = chunk.to_compound_nbt();
+    assert_eq!(written.byte("isLightOn"), Some(1));
+    assert!(written.compound("UpgradeData").is_some());
+    assert_eq!(Chunk::from_bytes(&chunk.to_bytes()).unwrap(), chunk);
 }
@@ -1,9 +1,10 @@
 use crate::{
     error::SculkParseError,
     kv::KVPair,
-    traits::FromCompoundNbt,
-    util::{get_owned_string, get_t_compound_vec},
+    traits::{FromCompoundNbt, ToCompoundNbt},
+    util::{compound_list, get_owned_string, get_t_compound_vec, string_list},
 };
+use simdnbt::owned;
 
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -67,6 +68,327 @@ pub struct PaletteNoProps {
     pub name: String,
 }
 
+impl ChunkSection {
+    /// Returns the block state at the given position, relative to the section (0-15 on every axis).
+    /// `None` if the section has no block states or the position is out of bounds.
+    pub fn block_at(&self, x: usize, y: usize, z: usize) -> Option<&Palette> {
+        self.block_states.as_ref()?.get(x, y, z)
+    }
+
+    /// Returns the biome at the given block position, relative to the section (0-15 on every axis).
+    /// Biomes are stored at a 4x4x4 resolution, so every 4x4x4 cell of blocks share the same biome.
+    pub fn biome_at(&self, x: usize, y: usize, z: usize) -> Option<&PaletteNoProps> {
+        self.biomes.as_ref()?.get(x / 4, y / 4, z / 4)
+    }
+
+    /// Iterates over every block in the section, with its position relative to the section.
+    /// Ordered by Y, then Z, then X, the same order as they are stored in.
+    pub fn blocks(&self) -> impl Iterator<Item = ([usize; 3], &Palette)> {
+        self.block_states
+            .iter()
+            .flat_map(|block_states| block_states.iter())
+    }
+
+    /// Sets the block state at the given position, relative to the section (0-15 on every axis).
+    /// A section without block states is filled with air first.
+    ///
+    /// # Panics
+    /// If the position is out of bounds.
+    pub fn set_block(&mut self, x: usize, y: usize, z: usize, block: Palette) {
+        self.block_states
+            .get_or_insert_with(|| BlockStates {
+                palette: vec![Palette {
+                    name: "minecraft:air".into(),
+                    properties: KVPair::new(Default::default()),
+                }],
+                data: None,
+            })
+            .set(x, y, z, block);
+    }
+
+    /// Sets the biome at the given block position, relative to the section (0-15 on every axis).
+    /// This changes the whole 4x4x4 cell containing the block.
+    /// A section without biomes is filled with `minecraft:plains` first.
+    ///
+    /// # Panics
+    /// If the position is out of bounds.
+    pub fn set_biome(&mut self, x: usize, y: usize, z: usize, biome: PaletteNoProps) {
+        self.biomes
+            .get_or_insert_with(|| Biomes {
+                palette: vec![PaletteNoProps {
+                    name: "minecraft:plains".into(),
+                }],
+                data: None,
+            })
+            .set(x / 4, y / 4, z / 4, biome);
+    }
+}
+
+impl BlockStates {
+    /// Number of blocks in a section.
+    pub const LEN: usize = 4096;
+
+    /// Returns the block state at the given position, relative to the section (0-15 on every axis).
+    pub fn get(&self, x: usize, y: usize, z: usize) -> Option<&Palette> {
+        if x >= 16 || y >= 16 || z >= 16 {
+            return None;
+        }
+
+        let index = self.palette_index((y * 16 + z) * 16 + x, self.bits_per_block());
+        self.palette.get(index)
+    }
+
+    /// Iterates over every block state with its position relative to the section.
+    pub fn iter(&self) -> impl Iterator<Item = ([usize; 3], &Palette)> {
+        let bits = self.bits_per_block();
+
+        (0..Self::LEN).filter_map(move |i| {
+            let palette = self.palette.get(self.palette_index(i, bits))?;
+            Some(([i % 16, i / 256, (i / 16) % 16], palette))
+        })
+    }
+
+    /// Sets the block state at the given position, relative to the section (0-15 on every axis).
+    /// The block is added to the palette if needed, `data` is only repacked when the palette outgrows the current amount of bits.
+    /// Replaced blocks stay in the palette until then, the repack drops the ones no longer used, see [`BlockStates::compact`].
+    ///
+    /// # Panics
+    /// If the position is out of bounds.
+    pub fn set(&mut self, x: usize, y: usize, z: usize, block: Palette) {
+        assert!(
+            x < 16 && y < 16 && z < 16,
+            "block position out of bounds: {x}, {y}, {z}"
+        );
+
+        set_packed(
+            &mut self.palette,
+            &mut self.data,
+            Self::LEN,
+            4,
+            (y * 16 + z) * 16 + x,
+            block,
+        );
+    }
+
+    /// Removes the block states no longer used by any block from the palette, repacking `data`.
+    /// Call this once after a batch of [`BlockStates::set`] calls rather than after each one.
+    pub fn compact(&mut self) {
+        compact_packed(&mut self.palette, &mut self.data, Self::LEN, 4);
+    }
+
+    /// The amount of bits used per index in `data`, with a minimum of 4.
+    pub fn bits_per_block(&self) -> usize {
+        bits_for(self.palette.len()).max(4)
+    }
+
+    fn palette_index(&self, index: usize, bits: usize) -> usize {
+        match &self.data {
+            Some(data) => unpack(data, bits, index),
+            None => 0,
+        }
+    }
+}
+
+impl Biomes {
+    /// Number of biome cells in a section.
+    pub const LEN: usize = 64;
+
+    /// Returns the biome at the given cell, relative to the section (0-3 on every axis).
+    pub fn get(&self, x: usize, y: usize, z: usize) -> Option<&PaletteNoProps> {
+        if x >= 4 || y >= 4 || z >= 4 {
+            return None;
+        }
+
+        let index = match &self.data {
+            Some(data) => unpack(data, self.bits_per_biome(), (y * 4 + z) * 4 + x),
+            None => 0,
+        };
+        self.palette.get(index)
+    }
+
+    /// Sets the biome at the given cell, relative to the section (0-3 on every axis).
+    /// The biome is added to the palette if needed, `data` is only repacked when the palette outgrows the current amount of bits.
+    /// Replaced biomes stay in the palette until then, the repack drops the ones no longer used, see [`Biomes::compact`].
+    ///
+    /// # Panics
+    /// If the position is out of bounds.
+    pub fn set(&mut self, x: usize, y: usize, z: usize, biome: PaletteNoProps) {
+        assert!(
+            x < 4 && y < 4 && z < 4,
+            "biome position out of bounds: {x}, {y}, {z}"
+        );
+
+        set_packed(
+            &mut self.palette,
+            &mut self.data,
+            Self::LEN,
+            0,
+            (y * 4 + z) * 4 + x,
+            biome,
+        );
+    }
+
+    /// Removes the biomes no longer used by any cell from the palette, repacking `data`.
+    pub fn compact(&mut self) {
+        compact_packed(&mut self.palette, &mut self.data, Self::LEN, 0);
+    }
+
+    /// The amount of bits used per index in `data`, biomes have no minimum.
+    pub fn bits_per_biome(&self) -> usize {
+        bits_for(self.palette.len())
+    }
+}
+
+/// Sets `index` to `value` in a paletted container of `len` entries.  
+/// The index is written in place, `data` is only repacked when the palette needs more bits per entry.
+/// Entries that are no longer used are kept in the palette until that repack, which drops them first,
+/// so replacing blocks over and over doesn't keep growing the palette.
+fn set_packed<T: PartialEq>(
+    palette: &mut Vec<T>,
+    data: &mut Option<Vec<i64>>,
+    len: usize,
+    min_bits: usize,
+    index: usize,
+    value: T,
+) {
+    let bits = bits_for(palette.len()).max(min_bits);
+    let value = match palette.iter().position(|entry| *entry == value) {
+        Some(i) => i,
+        None => {
+            palette.push(value);
+            palette.len() - 1
+        }
+    };
+
+    let new_bits = bits_for(palette.len()).max(min_bits);
+    if palette.len() <= 1 {
+        return;
+    }
+
+    if let Some(data) = data {
+        if new_bits == bits && data.len() == len.div_ceil(64 / bits) {
+            write_packed(data, bits, index, value);
+            return;
+        }
+    }
+
+    let mut indices: Vec<usize> = match data {
+        Some(data) => (0..len).map(|i| unpack(data, bits, i)).collect(),
+        None => vec![0; len],
+    };
+    indices[index] = value;
+    if new_bits > bits {
+        drop_unused(palette, &mut indices);
+    }
+
+    if palette.len() <= 1 {
+        *data = None;
+        return;
+    }
+    *data = Some(pack(&indices, bits_for(palette.len()).max(min_bits)));
+}
+
+/// Removes the palette entries that aren't used by any index in a paletted container of `len` entries.  
+/// `data` is repacked with the new amount of bits, or removed if only one entry is left.
+fn compact_packed<T>(
+    palette: &mut Vec<T>,
+    data: &mut Option<Vec<i64>>,
+    len: usize,
+    min_bits: usize,
+) {
+    let bits = bits_for(palette.len()).max(min_bits);
+    let mut indices: Vec<usize> = match data {
+        Some(data) => (0..len).map(|i| unpack(data, bits, i)).collect(),
+        None => vec![0; len],
+    };
+    drop_unused(palette, &mut indices);
+
+    if palette.len() <= 1 {
+        *data = None;
+        return;
+    }
+
+    let bits = bits_for(palette.len()).max(min_bits);
+    *data = Some(pack(&indices, bits));
+}
+
+/// Removes the palette entries that aren't used by any of the indices, and remaps the indices to the new palette.
+fn drop_unused<T>(palette: &mut Vec<T>, indices: &mut [usize]) {
+    let mut used = vec![false; palette.len()];
+    for &i in indices.iter() {
+        if let Some(used) = used.get_mut(i) {
+            *used = true;
+        }
+    }
+
+    let mut remap = vec![0; palette.len()];
+    let mut next = 0;
+    for (i, used) in used.iter().enumerate() {
+        remap[i] = next;
+        if *used {
+            next += 1;
+        }
+    }
+
+    let mut i = 0;
+    palette.retain(|_| {
+        i += 1;
+        used[i - 1]
+    });
+
+    for i in indices.iter_mut() {
+        *i = remap.get(*i).copied().unwrap_or(0);
+    }
+}
+
+/// Overwrites the value at `index` in a packed array, values are not spread across multiple longs (1.16+).
+fn write_packed(data: &mut [i64], bits: usize, index: usize, value: usize) {
+    let per_long = 64 / bits;
+    let shift = (index % per_long) * bits;
+    let mask = ((1u64 << bits) - 1) << shift;
+
+    let long = &mut data[index / per_long];
+    *long = ((*long as u64 & !mask) | (value as u64) << shift) as i64;
+}
+
+/// Packs indices into longs with `bits` per value, values are not spread across multiple longs (1.16+).
+pub(crate) fn pack(indices: &[usize], bits: usize) -> Vec<i64> {
+    let per_long = 64 / bits;
+
+    indices
+        .chunks(per_long)
+        .map(|chunk| {
+            chunk.iter().enumerate().fold(0u64, |long, (i, index)| {
+                long | (*index as u64) << (i * bits)
+            }) as i64
+        })
+        .collect()
+}
+
+/// The minimum amount of bits needed to represent every index of a palette with `len` entries.
+pub(crate) fn bits_for(len: usize) -> usize {
+    if len <= 1 {
+        0
+    } else {
+        (usize::BITS - (len - 1).leading_zeros()) as usize
+    }
+}
+
+/// Reads the value at `index` from a packed array, values are not spread across multiple longs (1.16+).
+pub(crate) fn unpack(data: &[i64], bits: usize, index: usize) -> usize {
+    if bits == 0 {
+        return 0;
+    }
+
+    let per_long = 64 / bits;
+    let Some(long) = data.get(index / per_long) else {
+        return 0;
+    };
+    let shift = (index % per_long) * bits;
+
+    ((*long as u64 >> shift) & ((1u64 << bits) - 1)) as usize
+}
+
 impl FromCompoundNbt for ChunkSection {
     fn from_compound_nbt(nbt: &simdnbt::borrow::NbtCompound) -> Result<Self, SculkParseError>
     where
@@ -167,8 +489,236 @@ impl FromCompoundNbt for Palette {
         Self: Sized,
     {
         let name = get_owned_string(&nbt, "Name")?;
-        let properties = KVPair::<String>::from_compound_nbt(&nbt)?;
+        let properties = match nbt.compound("Properties") {
+            Some(nbt) => KVPair::<String>::from_compound_nbt(&nbt)?,
+            None => KVPair::new(Default::default()),
+        };
 
         Ok(Palette { name, properties })
     }
 }
+
+impl ToCompoundNbt for ChunkSection {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        nbt.insert("Y", self.y);
+        if let Some(block_states) = &self.block_states {
+            nbt.insert("block_states", block_states.to_compound_nbt());
+        }
+        if let Some(biomes) = &self.biomes {
+            nbt.insert("biomes", biomes.to_compound_nbt());
+        }
+        if let Some(block_light) = &self.block_light {
+            nbt.insert("block_light", owned::NbtTag::ByteArray(block_light.clone()));
+        }
+        if let Some(sky_light) = &self.sky_light {
+            nbt.insert("sky_light", owned::NbtTag::ByteArray(sky_light.clone()));
+        }
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for Biomes {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        let palette: Vec<String> = self.palette.iter().map(|p| p.name.clone()).collect();
+        nbt.insert("palette", string_list(&palette));
+        if let Some(data) = &self.data {
+            nbt.insert("data", owned::NbtTag::LongArray(data.clone()));
+        }
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for BlockStates {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        nbt.insert("palette", compound_list(&self.palette));
+        if let Some(data) = &self.data {
+            nbt.insert("data", owned::NbtTag::LongArray(data.clone()));
+        }
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for PaletteNoProps {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        nbt.insert("name", self.name.as_str());
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for Palette {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        nbt.insert("Name", self.name.as_str());
+        if !self.properties.is_empty() {
+            nbt.insert("Properties", self.properties.to_compound_nbt());
+        }
+
+        nbt
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn block_access() {
+    let block = |name: &str| Palette {
+        name: name.into(),
+        properties: KVPair::new(Default::default()),
+    };
+
+    // 4 bits per block, 16 blocks per long, the block at index i is `i % 3`
+    let data = (0..256)
+        .map(|long| {
+            (0..16).fold(0u64, |acc, i| {
+                acc | (((long * 16 + i) % 3) as u64) << (i * 4)
+            }) as i64
+        })
+        .collect();
+
+    let section = ChunkSection {
+        y: -1,
+        block_states: Some(BlockStates {
+            palette: vec![
+                block("minecraft:air"),
+                block("minecraft:stone"),
+                block("minecraft:dirt"),
+            ],
+            data: Some(data),
+        }),
+        biomes: Some(Biomes {
+            palette: vec![
+                PaletteNoProps {
+                    name: "minecraft:plains".into(),
+                },
+                PaletteNoProps {
+                    name: "minecraft:river".into(),
+                },
+            ],
+            // 1 bit per biome, only the cell at x 1, y 0, z 0 is a river
+            data: Some(vec![0b10]),
+        }),
+        block_light: None,
+        sky_light: None,
+    };
+
+    assert_eq!(section.block_at(0, 0, 0).unwrap().name, "minecraft:air");
+    assert_eq!(section.block_at(1, 0, 0).unwrap().name, "minecraft:stone");
+    // index (15 * 16 + 2) * 16 + 5 = 3877, 3877 % 3 = 1
+    assert_eq!(section.block_at(5, 15, 2).unwrap().name, "minecraft:stone");
+    assert_eq!(section.block_at(16, 0, 0), None);
+    assert_eq!(section.blocks().count(), 4096);
+    assert_eq!(
+        section.blocks().nth(17).unwrap(),
+        ([1, 0, 1], &block("minecraft:dirt"))
+    );
+
+    assert_eq!(section.biome_at(4, 0, 0).unwrap().name, "minecraft:river");
+    assert_eq!(section.biome_at(7, 3, 3).unwrap().name, "minecraft:river");
+    assert_eq!(section.biome_at(8, 0, 0).unwrap().name, "minecraft:plains");
+    assert_eq!(section.biome_at(4, 4, 0).unwrap().name, "minecraft:plains");
+
+    let single = BlockStates {
+        palette: vec![block("minecraft:stone")],
+        data: None,
+    };
+    assert_eq!(single.get(3, 3, 3).unwrap().name, "minecraft:stone");
+}
+
+#[cfg(test)]
+#[test]
+fn block_editing() {
+    let block = |name: &str| Palette {
+        name: name.into(),
+        properties: KVPair::new(Default::default()),
+    };
+
+    let mut section = ChunkSection {
+        y: 0,
+        block_states: None,
+        biomes: None,
+        block_light: None,
+        sky_light: None,
+    };
+
+    section.set_block(1, 2, 3, block("minecraft:stone"));
+    let block_states = section.block_states.as_ref().unwrap();
+    assert_eq!(block_states.palette.len(), 2);
+    assert_eq!(block_states.bits_per_block(), 4);
+    assert_eq!(block_states.data.as_ref().unwrap().len(), 256);
+    assert_eq!(section.block_at(1, 2, 3).unwrap().name, "minecraft:stone");
+    assert_eq!(section.block_at(0, 2, 3).unwrap().name, "minecraft:air");
+
+    // 17 different blocks need 5 bits, 12 per long
+    for i in 0..16 {
+        section.set_block(i, 0, 0, block(&format!("minecraft:block_{i}")));
+    }
+    let block_states = section.block_states.as_ref().unwrap();
+    assert_eq!(block_states.palette.len(), 18);
+    assert_eq!(block_states.bits_per_block(), 5);
+    assert_eq!(block_states.data.as_ref().unwrap().len(), 342);
+    assert_eq!(section.block_at(7, 0, 0).unwrap().name, "minecraft:block_7");
+    assert_eq!(section.block_at(1, 2, 3).unwrap().name, "minecraft:stone");
+
+    // overwritten blocks stay in the palette until it's compacted
+    for i in 0..16 {
+        section.set_block(i, 0, 0, block("minecraft:air"));
+    }
+    let block_states = section.block_states.as_ref().unwrap();
+    assert_eq!(block_states.palette.len(), 18);
+    assert_eq!(block_states.data.as_ref().unwrap().len(), 342);
+    assert_eq!(section.block_at(7, 0, 0).unwrap().name, "minecraft:air");
+
+    section.block_states.as_mut().unwrap().compact();
+    let block_states = section.block_states.as_ref().unwrap();
+    assert_eq!(block_states.palette.len(), 2);
+    assert_eq!(block_states.bits_per_block(), 4);
+    assert_eq!(section.block_at(1, 2, 3).unwrap().name, "minecraft:stone");
+
+    // replacing the same block over and over drops the unused entries instead of growing the palette
+    for i in 0..100 {
+        section.set_block(0, 0, 0, block(&format!("minecraft:block_{i}")));
+    }
+    let block_states = section.block_states.as_ref().unwrap();
+    assert!(block_states.palette.len() <= 16);
+    assert_eq!(block_states.bits_per_block(), 4);
+    assert_eq!(
+        section.block_at(0, 0, 0).unwrap().name,
+        "minecraft:block_99"
+    );
+    assert_eq!(section.block_at(1, 2, 3).unwrap().name, "minecraft:stone");
+    section.set_block(0, 0, 0, block("minecraft:air"));
+    section.block_states.as_mut().unwrap().compact();
+
+    // a single block drops the data
+    section.set_block(1, 2, 3, block("minecraft:air"));
+    section.block_states.as_mut().unwrap().compact();
+    let block_states = section.block_states.as_ref().unwrap();
+    assert_eq!(block_states.palette, vec![block("minecraft:air")]);
+    assert_eq!(block_states.data, None);
+
+    let biome = |name: &str| PaletteNoProps { name: name.into() };
+    section.set_biome(15, 15, 15, biome("minecraft:desert"));
+    let biomes = section.biomes.as_ref().unwrap();
+    assert_eq!(biomes.bits_per_biome(), 1);
+    assert_eq!(biomes.data.as_ref().unwrap().len(), 1);
+    assert_eq!(
+        section.biome_at(12, 12, 12).unwrap().name,
+        "minecraft:desert"
+    );
+    assert_eq!(
+        section.biome_at(11, 12, 12).unwrap().name,
+        "minecraft:plains"
+    );
+}
@@ -3,10 +3,10 @@
 use crate::{
     error::SculkParseError,
     kv::KVPair,
-    traits::FromCompoundNbt,
-    util::{get_owned_optional_string, get_owned_string},
+    traits::{FromCompoundNbt, ToCompoundNbt},
+    util::{compound_list, get_owned_optional_string, get_owned_string, insert_optional},
 };
-use simdnbt::borrow::NbtCompound;
+use simdnbt::{borrow::NbtCompound, owned};
 use std::collections::HashMap;
 
 /// Structure data in this chunk.
@@ -189,3 +189,73 @@ impl FromCompoundNbt for ProcessedChunk {
         Ok(ProcessedChunk { x, z })
     }
 }
+
+impl ToCompoundNbt for Structures {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        nbt.insert("References", self.references.to_compound_nbt());
+        nbt.insert("starts", self.starts.to_compound_nbt());
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for KVPair<Vec<i64>> {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        for (key, value) in self.iter() {
+            nbt.insert(key.as_str(), owned::NbtTag::LongArray(value.clone()));
+        }
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for KVPair<Structure> {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        for (key, value) in self.iter() {
+            nbt.insert(key.as_str(), value.to_compound_nbt());
+        }
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for Structure {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        insert_optional(
+            &mut nbt,
+            "BB",
+            self.bb.map(|bb| owned::NbtTag::IntArray(bb.to_vec())),
+        );
+        insert_optional(&mut nbt, "biome", self.biome.clone());
+        insert_optional(&mut nbt, "ChunkX", self.chunk_x);
+        insert_optional(&mut nbt, "ChunkZ", self.chunk_z);
+        nbt.insert("id", self.id.as_str());
+        insert_optional(
+            &mut nbt,
+            "processed",
+            self.processed.as_deref().map(compound_list),
+        );
+        insert_optional(&mut nbt, "Valid", self.valid);
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for ProcessedChunk {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        nbt.insert("X", self.x);
+        nbt.insert("Z", self.z);
+
+        nbt
+    }
+}
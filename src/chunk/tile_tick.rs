@@ -1,4 +1,9 @@
-use crate::{error::SculkParseError, traits::FromCompoundNbt, util::get_owned_string};
+use crate::{
+    error::SculkParseError,
+    traits::{FromCompoundNbt, ToCompoundNbt},
+    util::get_owned_string,
+};
+use simdnbt::owned;
 
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -48,3 +53,18 @@ impl FromCompoundNbt for TileTick {
         Ok(TileTick { i, p, t, x, y, z })
     }
 }
+
+impl ToCompoundNbt for TileTick {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        nbt.insert("i", self.i.as_str());
+        nbt.insert("p", self.p);
+        nbt.insert("t", self.t);
+        nbt.insert("x", self.x);
+        nbt.insert("y", self.y);
+        nbt.insert("z", self.z);
+
+        nbt
+    }
+}
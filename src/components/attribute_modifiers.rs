@@ -2,9 +2,10 @@
 
 use crate::{
     error::SculkParseError,
-    traits::FromCompoundNbt,
-    util::{get_bool, get_owned_string, get_t_list},
+    traits::{FromCompoundNbt, ToCompoundNbt},
+    util::{compound_list, get_bool, get_owned_string, get_t_list},
 };
+use simdnbt::owned::{NbtCompound, NbtTag};
 
 /// A compound of attribute modifiers.
 #[derive(Debug, Clone, PartialEq)]
@@ -169,7 +170,57 @@ impl FromCompoundNbt for Modifier {
     }
 }
 
+impl AttributeModifier {
+    /// Converts the component into its Nbt value, either a list or a compound.
+    pub fn to_nbt_tag(&self) -> NbtTag {
+        match self {
+            AttributeModifier::ModifierList(modifiers) => NbtTag::List(compound_list(modifiers)),
+            AttributeModifier::Compound(modifiers) => NbtTag::Compound(modifiers.to_compound_nbt()),
+        }
+    }
+}
+
+impl ToCompoundNbt for AttributeModifiers {
+    fn to_compound_nbt(&self) -> NbtCompound {
+        let mut nbt = NbtCompound::new();
+
+        nbt.insert("show_in_tooltip", self.show_in_tooltip);
+        nbt.insert("modifiers", compound_list(&self.modifiers));
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for Modifier {
+    fn to_compound_nbt(&self) -> NbtCompound {
+        let mut nbt = NbtCompound::new();
+
+        nbt.insert("type", self.r#type.as_str());
+        nbt.insert("slot", self.slot.to_str());
+        nbt.insert("id", self.id.as_str());
+        nbt.insert("amount", self.amount);
+        nbt.insert("operation", self.operation.to_str());
+
+        nbt
+    }
+}
+
 impl SlotType {
+    fn to_str(&self) -> &'static str {
+        match self {
+            SlotType::Any => "any",
+            SlotType::Hand => "hand",
+            SlotType::Armor => "armor",
+            SlotType::MainHand => "mainhand",
+            SlotType::OffHand => "offhand",
+            SlotType::Head => "head",
+            SlotType::Chest => "chest",
+            SlotType::Legs => "legs",
+            SlotType::Feet => "feet",
+            SlotType::Body => "body",
+        }
+    }
+
     fn from_str(value: &str) -> Result<Self, SculkParseError> {
         match value {
             "any" => Ok(SlotType::Any),
@@ -188,6 +239,14 @@ impl SlotType {
 }
 
 impl Operation {
+    fn to_str(&self) -> &'static str {
+        match self {
+            Operation::AddValue => "add_value",
+            Operation::AddMultipliedBase => "add_multiplied_base",
+            Operation::AddMultipliedTotal => "add_multiplied_total",
+        }
+    }
+
     fn from_str(value: &str) -> Result<Self, SculkParseError> {
         match value {
             "add_value" => Ok(Operation::AddValue),
@@ -1,8 +1,12 @@
 //! Banner patterns are used in banners to determine the pattern of the banner.
 
 use crate::{
-    color::Color, error::SculkParseError, traits::FromCompoundNbt, util::get_owned_string,
+    color::Color,
+    error::SculkParseError,
+    traits::{FromCompoundNbt, ToCompoundNbt},
+    util::get_owned_string,
 };
+use simdnbt::owned::NbtCompound;
 
 /// Represents a banner pattern.
 #[derive(Debug, Clone, PartialEq)]
@@ -129,6 +133,56 @@ impl From<&str> for ResourceName {
     }
 }
 
+impl ResourceName {
+    /// Returns the resource location of the pattern.
+    pub fn to_str(&self) -> &str {
+        match self {
+            Self::Base => "minecraft:base",
+            Self::StripeBottom => "minecraft:stripe_bottom",
+            Self::StripeTop => "minecraft:stripe_top",
+            Self::StripeLeft => "minecraft:stripe_left",
+            Self::StripeRight => "minecraft:stripe_right",
+            Self::StripeCenter => "minecraft:stripe_center",
+            Self::StripeMiddle => "minecraft:stripe_middle",
+            Self::StripeDownright => "minecraft:stripe_downright",
+            Self::StripeDownleft => "minecraft:stripe_downleft",
+            Self::SmallStripes => "minecraft:small_stripes",
+            Self::Cross => "minecraft:cross",
+            Self::StraightCross => "minecraft:straight_cross",
+            Self::DiagonalLeft => "minecraft:diagonal_left",
+            Self::DiagonalRight => "minecraft:diagonal_right",
+            Self::DiagonalUpLeft => "minecraft:diagonal_up_left",
+            Self::DiagonalUpRight => "minecraft:diagonal_up_right",
+            Self::HalfVertical => "minecraft:half_vertical",
+            Self::HalfVerticalRight => "minecraft:half_vertical_right",
+            Self::HalfHorizontal => "minecraft:half_horizontal",
+            Self::HalfHorizontalBottom => "minecraft:half_horizontal_bottom",
+            Self::SquareBottomLeft => "minecraft:square_bottom_left",
+            Self::SquareBottomRight => "minecraft:square_bottom_right",
+            Self::SquareTopLeft => "minecraft:square_top_left",
+            Self::SquareTopRight => "minecraft:square_top_right",
+            Self::TrianglesBottom => "minecraft:triangles_bottom",
+            Self::TrianglesTop => "minecraft:triangles_top",
+            Self::Circle => "minecraft:circle",
+            Self::Rhombus => "minecraft:rhombus",
+            Self::Border => "minecraft:border",
+            Self::CurlyBorder => "minecraft:curly_border",
+            Self::Bricks => "minecraft:bricks",
+            Self::Gradient => "minecraft:gradient",
+            Self::GradientUp => "minecraft:gradient_up",
+            Self::Creeper => "minecraft:creeper",
+            Self::Skull => "minecraft:skull",
+            Self::Flower => "minecraft:flower",
+            Self::Mojang => "minecraft:mojang",
+            Self::Globe => "minecraft:globe",
+            Self::Piglin => "minecraft:piglin",
+            Self::Flow => "minecraft:flow",
+            Self::Guster => "minecraft:guster",
+            Self::Unknown(value) => value.as_str(),
+        }
+    }
+}
+
 impl FromCompoundNbt for BannerPattern {
     fn from_compound_nbt(
         nbt: &simdnbt::borrow::NbtCompound,
@@ -146,10 +200,10 @@ impl FromCompoundNbt for BannerPattern {
             let resource = ResourceName::from(id.to_str().as_ref());
 
             Pattern::ID(resource)
-        } else if let Some(_) = nbt.compound("pattern") {
+        } else if let Some(pattern) = nbt.compound("pattern") {
             // Inlined pattern
-            let asset_id = get_owned_string(&nbt, "asset_id")?;
-            let translation_key = get_owned_string(&nbt, "translation_key")?;
+            let asset_id = get_owned_string(&pattern, "asset_id")?;
+            let translation_key = get_owned_string(&pattern, "translation_key")?;
 
             Pattern::Pattern {
                 asset_id,
@@ -162,3 +216,27 @@ impl FromCompoundNbt for BannerPattern {
         Ok(BannerPattern { color, pattern })
     }
 }
+
+impl ToCompoundNbt for BannerPattern {
+    fn to_compound_nbt(&self) -> NbtCompound {
+        let mut nbt = NbtCompound::new();
+
+        nbt.insert("color", self.color.to_str());
+
+        match &self.pattern {
+            Pattern::ID(resource) => nbt.insert("pattern", resource.to_str()),
+            Pattern::Pattern {
+                asset_id,
+                translation_key,
+            } => {
+                let mut pattern = NbtCompound::new();
+                pattern.insert("asset_id", asset_id.as_str());
+                pattern.insert("translation_key", translation_key.as_str());
+
+                nbt.insert("pattern", pattern);
+            }
+        }
+
+        nbt
+    }
+}
@@ -1,6 +1,7 @@
 //! Base color of the banner applied on a shield.
 
 use crate::{traits::FromCompoundNbt, util::get_owned_string};
+use simdnbt::owned::NbtTag;
 
 /// The base dye color of the banner applied on a shield.
 #[derive(Debug, Clone, PartialEq)]
@@ -19,3 +20,10 @@ impl FromCompoundNbt for BaseColor {
         Ok(BaseColor(base_color))
     }
 }
+
+impl BaseColor {
+    /// Converts the component into its Nbt value.
+    pub fn to_nbt_tag(&self) -> NbtTag {
+        NbtTag::String(self.0.as_str().into())
+    }
+}
@@ -1,6 +1,11 @@
 //! Bee component for entities in a hive.
 
-use crate::{entity::Entity, error::SculkParseError, traits::FromCompoundNbt};
+use crate::{
+    entity::Entity,
+    error::SculkParseError,
+    traits::{FromCompoundNbt, ToCompoundNbt},
+};
+use simdnbt::owned::NbtCompound;
 
 /// A bee entity in a hive.
 #[derive(Debug, Clone, PartialEq)]
@@ -42,3 +47,15 @@ impl FromCompoundNbt for Bee {
         })
     }
 }
+
+impl ToCompoundNbt for Bee {
+    fn to_compound_nbt(&self) -> NbtCompound {
+        let mut nbt = NbtCompound::new();
+
+        nbt.insert("entity_data", self.entity_data.to_compound_nbt());
+        nbt.insert("min_ticks_in_hive", self.min_ticks_in_hive);
+        nbt.insert("ticks_in_hive", self.ticks_in_hive);
+
+        nbt
+    }
+}
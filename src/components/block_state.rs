@@ -1,6 +1,9 @@
 //! Block state component.
 
-use crate::{kv::KVPair, traits::FromCompoundNbt};
+use crate::{
+    kv::KVPair,
+    traits::{FromCompoundNbt, ToCompoundNbt},
+};
 
 /// Represents multiple key-value pairs of block states.
 #[derive(Debug, Clone, PartialEq)]
@@ -17,3 +20,9 @@ impl FromCompoundNbt for BlockState {
         Ok(BlockState(KVPair::from_compound_nbt(&nbt)?))
     }
 }
+
+impl ToCompoundNbt for BlockState {
+    fn to_compound_nbt(&self) -> simdnbt::owned::NbtCompound {
+        self.0.to_compound_nbt()
+    }
+}
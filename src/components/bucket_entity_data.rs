@@ -1,6 +1,9 @@
 //! Bucket entity data component.
 
-use crate::traits::FromCompoundNbt;
+use crate::{
+    traits::{FromCompoundNbt, ToCompoundNbt},
+    util::insert_optional,
+};
 use simdnbt::borrow::NbtCompound;
 
 /// Represents the bucket entity data component.  
@@ -67,7 +70,7 @@ impl FromCompoundNbt for BucketEntityData {
         Self: Sized,
     {
         fn bool(nbt: &NbtCompound, key: &'static str) -> Option<bool> {
-            nbt.int(key).map(|b| b != 0)
+            nbt.byte(key).map(|b| b != 0)
         }
 
         let no_ai = bool(&nbt, "NoAI");
@@ -95,3 +98,22 @@ impl FromCompoundNbt for BucketEntityData {
         })
     }
 }
+
+impl ToCompoundNbt for BucketEntityData {
+    fn to_compound_nbt(&self) -> simdnbt::owned::NbtCompound {
+        let mut nbt = simdnbt::owned::NbtCompound::new();
+
+        insert_optional(&mut nbt, "NoAI", self.no_ai);
+        insert_optional(&mut nbt, "Silent", self.silent);
+        insert_optional(&mut nbt, "NoGravity", self.no_gravity);
+        insert_optional(&mut nbt, "Glowing", self.glowing);
+        insert_optional(&mut nbt, "Invulnerable", self.invulnerable);
+        insert_optional(&mut nbt, "Health", self.health);
+        insert_optional(&mut nbt, "Age", self.age);
+        insert_optional(&mut nbt, "Variant", self.variant);
+        insert_optional(&mut nbt, "HuntingCooldown", self.hunting_cooldown);
+        insert_optional(&mut nbt, "BucketVariantTag", self.bucket_variant_tag);
+
+        nbt
+    }
+}
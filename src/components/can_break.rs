@@ -2,8 +2,12 @@
 
 use super::block_state::BlockState;
 use crate::{
-    block_entity::BlockEntity, error::SculkParseError, traits::FromCompoundNbt, util::get_t_list,
+    block_entity::BlockEntity,
+    error::SculkParseError,
+    traits::{FromCompoundNbt, ToCompoundNbt},
+    util::{compound_list, get_t_list, string_list},
 };
+use simdnbt::owned::NbtCompound;
 
 /// If an item can break blocks.
 #[derive(Debug, Clone, PartialEq)]
@@ -158,3 +162,61 @@ impl FromCompoundNbt for Blocks {
         };
     }
 }
+
+impl ToCompoundNbt for CanBreak {
+    fn to_compound_nbt(&self) -> NbtCompound {
+        let mut nbt = NbtCompound::new();
+
+        match self {
+            CanBreak::List {
+                predicates,
+                show_in_tooltip,
+            } => {
+                nbt.insert("predicates", compound_list(predicates));
+                nbt.insert("show_in_tooltip", *show_in_tooltip);
+            }
+            CanBreak::Single {
+                blocks,
+                nbt: block_entity,
+                state,
+                show_in_tooltip,
+            } => {
+                blocks.insert_into(&mut nbt);
+                if let Some(block_entity) = block_entity {
+                    nbt.insert("nbt", block_entity.to_compound_nbt());
+                }
+                if let Some(state) = state {
+                    nbt.insert("state", state.to_compound_nbt());
+                }
+                nbt.insert("show_in_tooltip", *show_in_tooltip);
+            }
+        }
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for Predicate {
+    fn to_compound_nbt(&self) -> NbtCompound {
+        let mut nbt = NbtCompound::new();
+
+        self.blocks.insert_into(&mut nbt);
+        if let Some(block_entity) = &self.nbt {
+            nbt.insert("nbt", block_entity.to_compound_nbt());
+        }
+        if let Some(state) = &self.state {
+            nbt.insert("state", state.to_compound_nbt());
+        }
+
+        nbt
+    }
+}
+
+impl Blocks {
+    fn insert_into(&self, nbt: &mut NbtCompound) {
+        match self {
+            Blocks::Block(block) => nbt.insert("blocks", block.as_str()),
+            Blocks::Blocks(blocks) => nbt.insert("blocks", string_list(blocks)),
+        }
+    }
+}
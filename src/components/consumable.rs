@@ -0,0 +1,196 @@
+//! Consumable component. Since 1.21.2 this makes an item eatable or drinkable, instead of the `food` component.
+
+use crate::{
+    error::SculkParseError,
+    traits::{FromCompoundNbt, ToCompoundNbt},
+    util::{compound_list, get_owned_string, get_t_compound_vec, insert_optional},
+};
+use simdnbt::owned::NbtCompound;
+
+use super::{food::EffectDetails, id_set::IdSet, instrument::SoundEvent};
+
+#[cfg(feature = "serde")]
+fn default_consume_seconds() -> f32 {
+    1.6
+}
+
+#[cfg(feature = "serde")]
+fn default_animation() -> String {
+    "eat".into()
+}
+
+#[cfg(feature = "serde")]
+fn default_probability() -> f32 {
+    1.0
+}
+
+/// The consumable component.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Consumable {
+    /// The number of seconds it takes to consume the item. Defaults to 1.6.
+    #[cfg_attr(feature = "serde", serde(default = "default_consume_seconds"))]
+    pub consume_seconds: f32,
+
+    /// The animation used while consuming, like `eat`, `drink` or `none`. Defaults to `eat`.
+    #[cfg_attr(feature = "serde", serde(default = "default_animation"))]
+    pub animation: String,
+
+    /// The sound played while consuming. Optional, defaults to `entity.generic.eat`.
+    pub sound: Option<SoundEvent>,
+
+    /// If particles of the item are shown while consuming. Defaults to true.
+    #[cfg_attr(feature = "serde", serde(default = "crate::util::default_true"))]
+    pub has_consume_particles: bool,
+
+    /// The effects applied when the item has been consumed.
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub on_consume_effects: Vec<ConsumeEffect>,
+}
+
+/// An effect applied when an item has been consumed.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ConsumeEffect {
+    /// Applies status effects with a chance.
+    /// `apply_effects`
+    ApplyEffects {
+        /// The effects to apply.
+        effects: Vec<EffectDetails>,
+
+        /// The chance for the effects to be applied, between 0 and 1. Defaults to 1.
+        #[cfg_attr(feature = "serde", serde(default = "default_probability"))]
+        probability: f32,
+    },
+
+    /// Removes status effects.
+    /// `remove_effects`
+    RemoveEffects(IdSet),
+
+    /// Removes every status effect.
+    /// `clear_all_effects`
+    ClearAllEffects,
+
+    /// Teleports the consumer like a chorus fruit.
+    /// `teleport_randomly`
+    TeleportRandomly(Option<f32>),
+
+    /// Plays a sound.
+    /// `play_sound`
+    PlaySound(SoundEvent),
+
+    /// An effect type sculk doesn't know.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    Unknown(NbtCompound),
+}
+
+impl FromCompoundNbt for Consumable {
+    fn from_compound_nbt(nbt: &simdnbt::borrow::NbtCompound) -> Result<Self, SculkParseError>
+    where
+        Self: Sized,
+    {
+        let consume_seconds = nbt.float("consume_seconds").unwrap_or(1.6);
+        let animation = nbt
+            .string("animation")
+            .map(|animation| animation.to_string())
+            .unwrap_or("eat".into());
+        let sound = SoundEvent::from_key(nbt, "sound")?;
+        let has_consume_particles = nbt
+            .byte("has_consume_particles")
+            .map(|b| b != 0)
+            .unwrap_or(true);
+        let on_consume_effects =
+            get_t_compound_vec(nbt, "on_consume_effects", ConsumeEffect::from_compound_nbt)?;
+
+        Ok(Consumable {
+            consume_seconds,
+            animation,
+            sound,
+            has_consume_particles,
+            on_consume_effects,
+        })
+    }
+}
+
+impl FromCompoundNbt for ConsumeEffect {
+    fn from_compound_nbt(nbt: &simdnbt::borrow::NbtCompound) -> Result<Self, SculkParseError>
+    where
+        Self: Sized,
+    {
+        let r#type = get_owned_string(nbt, "type")?;
+
+        match r#type.trim_start_matches("minecraft:") {
+            "apply_effects" => Ok(ConsumeEffect::ApplyEffects {
+                effects: get_t_compound_vec(nbt, "effects", EffectDetails::from_compound_nbt)?,
+                probability: nbt.float("probability").unwrap_or(1.0),
+            }),
+            "remove_effects" => {
+                let effects = nbt
+                    .get("effects")
+                    .ok_or(SculkParseError::MissingField("effects".into()))?;
+                Ok(ConsumeEffect::RemoveEffects(IdSet::from_nbt_tag(
+                    &effects, "effects",
+                )?))
+            }
+            "clear_all_effects" => Ok(ConsumeEffect::ClearAllEffects),
+            "teleport_randomly" => Ok(ConsumeEffect::TeleportRandomly(nbt.float("diameter"))),
+            "play_sound" => Ok(ConsumeEffect::PlaySound(
+                SoundEvent::from_key(nbt, "sound")?
+                    .ok_or(SculkParseError::MissingField("sound".into()))?,
+            )),
+            _ => Ok(ConsumeEffect::Unknown(nbt.to_owned())),
+        }
+    }
+}
+
+impl ToCompoundNbt for Consumable {
+    fn to_compound_nbt(&self) -> NbtCompound {
+        let mut nbt = NbtCompound::new();
+
+        nbt.insert("consume_seconds", self.consume_seconds);
+        nbt.insert("animation", self.animation.as_str());
+        if let Some(sound) = &self.sound {
+            nbt.insert("sound", sound.to_nbt_tag());
+        }
+        nbt.insert("has_consume_particles", self.has_consume_particles);
+        nbt.insert(
+            "on_consume_effects",
+            compound_list(&self.on_consume_effects),
+        );
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for ConsumeEffect {
+    fn to_compound_nbt(&self) -> NbtCompound {
+        let mut nbt = NbtCompound::new();
+
+        match self {
+            ConsumeEffect::ApplyEffects {
+                effects,
+                probability,
+            } => {
+                nbt.insert("type", "minecraft:apply_effects");
+                nbt.insert("effects", compound_list(effects));
+                nbt.insert("probability", *probability);
+            }
+            ConsumeEffect::RemoveEffects(effects) => {
+                nbt.insert("type", "minecraft:remove_effects");
+                nbt.insert("effects", effects.to_nbt_tag());
+            }
+            ConsumeEffect::ClearAllEffects => nbt.insert("type", "minecraft:clear_all_effects"),
+            ConsumeEffect::TeleportRandomly(diameter) => {
+                nbt.insert("type", "minecraft:teleport_randomly");
+                insert_optional(&mut nbt, "diameter", *diameter);
+            }
+            ConsumeEffect::PlaySound(sound) => {
+                nbt.insert("type", "minecraft:play_sound");
+                nbt.insert("sound", sound.to_nbt_tag());
+            }
+            ConsumeEffect::Unknown(unknown) => return unknown.clone(),
+        }
+
+        nbt
+    }
+}
@@ -1,6 +1,9 @@
 //! Contains the `Container` component.
 
-use crate::{item::ItemWithNoSlot, traits::FromCompoundNbt};
+use crate::{
+    item::ItemWithNoSlot,
+    traits::{FromCompoundNbt, ToCompoundNbt},
+};
 
 /// The items contained in this container.
 #[derive(Debug, Clone, PartialEq)]
@@ -32,3 +35,14 @@ impl FromCompoundNbt for Container {
         Ok(Container { item, slot })
     }
 }
+
+impl ToCompoundNbt for Container {
+    fn to_compound_nbt(&self) -> simdnbt::owned::NbtCompound {
+        let mut nbt = simdnbt::owned::NbtCompound::new();
+
+        nbt.insert("item", self.item.to_compound_nbt());
+        nbt.insert("slot", self.slot);
+
+        nbt
+    }
+}
@@ -1,6 +1,9 @@
 //! Contains the `ContainerLoot` component.
 
-use crate::{traits::FromCompoundNbt, util::get_owned_string};
+use crate::{
+    traits::{FromCompoundNbt, ToCompoundNbt},
+    util::{get_owned_string, insert_optional},
+};
 
 /// Represents the loot table of a container.
 #[derive(Debug, Clone, PartialEq)]
@@ -27,3 +30,14 @@ impl FromCompoundNbt for ContainerLoot {
         Ok(ContainerLoot { loot_table, seed })
     }
 }
+
+impl ToCompoundNbt for ContainerLoot {
+    fn to_compound_nbt(&self) -> simdnbt::owned::NbtCompound {
+        let mut nbt = simdnbt::owned::NbtCompound::new();
+
+        nbt.insert("loot_table", self.loot_table.as_str());
+        insert_optional(&mut nbt, "seed", self.seed);
+
+        nbt
+    }
+}
@@ -1,6 +1,11 @@
 //! Custom data component.
 
-use crate::{error::SculkParseError, kv::KVPair, traits::FromCompoundNbt};
+use crate::{
+    error::SculkParseError,
+    kv::KVPair,
+    traits::{FromCompoundNbt, ToCompoundNbt},
+};
+use simdnbt::owned::{NbtCompound, NbtTag};
 
 /// Custom data component.
 #[derive(Debug, Clone, PartialEq)]
@@ -32,3 +37,32 @@ impl FromCompoundNbt for CustomData {
         }
     }
 }
+
+impl CustomData {
+    /// Converts the component into its Nbt value, either a string or a compound.
+    pub fn to_nbt_tag(&self) -> NbtTag {
+        match self {
+            CustomData::Snbt(snbt) => NbtTag::String(snbt.as_str().into()),
+            CustomData::KeyValues(map) => NbtTag::Compound(map.to_compound_nbt()),
+        }
+    }
+
+    /// Returns the custom data as a compound, parsing it if it's stored as SNBT.
+    pub fn resolve(&self) -> Result<NbtCompound, SculkParseError> {
+        match self {
+            CustomData::Snbt(snbt) => crate::snbt::parse(snbt),
+            CustomData::KeyValues(map) => Ok(map.to_compound_nbt()),
+        }
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn resolve_snbt() {
+    let data = CustomData::Snbt("{points: 10, owner: \"Steve\"}".into());
+    let nbt = data.resolve().unwrap();
+
+    assert_eq!(nbt.int("points"), Some(10));
+    assert_eq!(nbt.string("owner").unwrap().to_str(), "Steve");
+    assert!(CustomData::Snbt("{points:".into()).resolve().is_err());
+}
@@ -0,0 +1,121 @@
+//! Custom model data component.
+
+use crate::{
+    error::SculkParseError,
+    util::string_list,
+    version::{self, DataVersion},
+};
+use simdnbt::{
+    borrow::{NbtCompound, NbtTag},
+    owned,
+};
+
+/// Values used by item model definitions to pick a model.
+/// `minecraft:custom_model_data`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CustomModelData {
+    /// Before 1.21.4, a single integer used by the overrides of item models.
+    Legacy(i32),
+
+    /// Since 1.21.4, lists of values read by the `custom_model_data` properties of item model definitions.
+    Lists {
+        /// `floats`
+        floats: Vec<f32>,
+
+        /// `flags`
+        flags: Vec<bool>,
+
+        /// `strings`
+        strings: Vec<String>,
+
+        /// RGB colors.
+        /// `colors`
+        colors: Vec<i32>,
+    },
+}
+
+impl CustomModelData {
+    /// Parses the component from its value, an int before 1.21.4 and a compound since.
+    pub fn from_nbt_tag(tag: &NbtTag) -> Result<Self, SculkParseError> {
+        let legacy = match version::current() {
+            Some(version) => version < DataVersion::V1_21_4,
+            None => tag.int().is_some(),
+        };
+        if legacy {
+            return tag
+                .int()
+                .map(CustomModelData::Legacy)
+                .ok_or(SculkParseError::InvalidField(
+                    "minecraft:custom_model_data".into(),
+                ));
+        }
+
+        let nbt: NbtCompound = tag.compound().ok_or(SculkParseError::InvalidField(
+            "minecraft:custom_model_data".into(),
+        ))?;
+
+        Ok(CustomModelData::Lists {
+            floats: nbt
+                .list("floats")
+                .and_then(|list| list.floats())
+                .unwrap_or_default(),
+            flags: nbt
+                .list("flags")
+                .and_then(|list| list.bytes().map(|b| b.iter().map(|b| *b != 0).collect()))
+                .unwrap_or_default(),
+            strings: nbt
+                .list("strings")
+                .and_then(|list| {
+                    list.strings()
+                        .map(|s| s.iter().map(|s| s.to_string()).collect())
+                })
+                .unwrap_or_default(),
+            colors: nbt
+                .list("colors")
+                .and_then(|list| list.ints())
+                .unwrap_or_default(),
+        })
+    }
+
+    /// The floats of the component. The legacy integer is upgraded to a single float, like the game does.
+    pub fn floats(&self) -> Vec<f32> {
+        match self {
+            CustomModelData::Legacy(value) => vec![*value as f32],
+            CustomModelData::Lists { floats, .. } => floats.clone(),
+        }
+    }
+
+    /// Converts the component into its Nbt value, in the same format it was read from.
+    pub fn to_nbt_tag(&self) -> owned::NbtTag {
+        match self {
+            CustomModelData::Legacy(value) => owned::NbtTag::Int(*value),
+            CustomModelData::Lists {
+                floats,
+                flags,
+                strings,
+                colors,
+            } => {
+                let mut nbt = owned::NbtCompound::new();
+
+                if !floats.is_empty() {
+                    nbt.insert("floats", owned::NbtList::Float(floats.clone()));
+                }
+                if !flags.is_empty() {
+                    nbt.insert(
+                        "flags",
+                        owned::NbtList::Byte(flags.iter().map(|f| *f as i8).collect()),
+                    );
+                }
+                if !strings.is_empty() {
+                    nbt.insert("strings", string_list(strings));
+                }
+                if !colors.is_empty() {
+                    nbt.insert("colors", owned::NbtList::Int(colors.clone()));
+                }
+
+                owned::NbtTag::Compound(nbt)
+            }
+        }
+    }
+}
@@ -1,6 +1,7 @@
 //! Dyed color component.
 
 use crate::{color::RGB, error::SculkParseError, traits::FromCompoundNbt};
+use simdnbt::owned::{NbtCompound, NbtTag};
 
 /// A dyed color component.  
 /// Used on stuff like leather armor.  
@@ -48,3 +49,22 @@ impl FromCompoundNbt for DyedColor {
         }
     }
 }
+
+impl DyedColor {
+    /// Converts the component into its Nbt value, either an int or a compound.
+    pub fn to_nbt_tag(&self) -> NbtTag {
+        match self {
+            DyedColor::Int(rgb) => NbtTag::Int(rgb.clone().into()),
+            DyedColor::Compound {
+                rgb,
+                show_in_tooltip,
+            } => {
+                let mut nbt = NbtCompound::new();
+                nbt.insert("rgb", i32::from(rgb.clone()));
+                nbt.insert("show_in_tooltip", *show_in_tooltip);
+
+                NbtTag::Compound(nbt)
+            }
+        }
+    }
+}
@@ -1,6 +1,9 @@
 //! Enchantments on an item.
 
-use crate::{kv::KVPair, traits::FromCompoundNbt};
+use crate::{
+    kv::KVPair,
+    traits::{FromCompoundNbt, ToCompoundNbt},
+};
 
 /// Enchantments on an item.
 #[derive(Debug, Clone, PartialEq)]
@@ -43,3 +46,14 @@ impl FromCompoundNbt for Enchantments {
         };
     }
 }
+
+impl ToCompoundNbt for Enchantments {
+    fn to_compound_nbt(&self) -> simdnbt::owned::NbtCompound {
+        let mut nbt = simdnbt::owned::NbtCompound::new();
+
+        nbt.insert("levels", self.levels.to_compound_nbt());
+        nbt.insert("show_in_tooltip", self.show_in_tooltip);
+
+        nbt
+    }
+}
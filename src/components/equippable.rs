@@ -0,0 +1,107 @@
+//! Equippable component. Since 1.21.2 this makes an item wearable in an equipment slot.
+
+use crate::{
+    error::SculkParseError,
+    traits::{FromCompoundNbt, ToCompoundNbt},
+    util::{get_owned_optional_string, get_owned_string, insert_optional},
+    version::{self, DataVersion},
+};
+use simdnbt::owned::NbtCompound;
+
+use super::{id_set::IdSet, instrument::SoundEvent};
+
+/// The equippable component.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Equippable {
+    /// The slot the item can be equipped in, like `head`, `chest` or `body`.
+    pub slot: String,
+
+    /// The sound played when the item is equipped. Optional, defaults to `item.armor.equip_generic`.
+    pub equip_sound: Option<SoundEvent>,
+
+    /// The equipment model rendered while the item is worn. Optional.
+    /// `asset_id`, `model` before 1.21.4
+    pub asset_id: Option<String>,
+
+    /// The overlay texture shown on the screen while the item is worn, like the carved pumpkin blur. Optional.
+    pub camera_overlay: Option<String>,
+
+    /// The entities that can wear the item. Optional, if not set every entity can.
+    pub allowed_entities: Option<IdSet>,
+
+    /// If a dispenser can equip the item on an entity. Defaults to true.
+    #[cfg_attr(feature = "serde", serde(default = "crate::util::default_true"))]
+    pub dispensable: bool,
+
+    /// If the item can be swapped with the worn item by using it. Defaults to true.
+    #[cfg_attr(feature = "serde", serde(default = "crate::util::default_true"))]
+    pub swappable: bool,
+
+    /// If the item loses durability when the wearer is hurt. Defaults to true.
+    #[cfg_attr(feature = "serde", serde(default = "crate::util::default_true"))]
+    pub damage_on_hurt: bool,
+}
+
+impl FromCompoundNbt for Equippable {
+    fn from_compound_nbt(nbt: &simdnbt::borrow::NbtCompound) -> Result<Self, SculkParseError>
+    where
+        Self: Sized,
+    {
+        let slot = get_owned_string(nbt, "slot")?;
+        let equip_sound = SoundEvent::from_key(nbt, "equip_sound")?;
+
+        // `model` was renamed to `asset_id` in 1.21.4
+        let asset_id = match version::current() {
+            Some(version) if version < DataVersion::V1_21_4 => {
+                get_owned_optional_string(nbt, "model")
+            }
+            Some(_) => get_owned_optional_string(nbt, "asset_id"),
+            None => get_owned_optional_string(nbt, "asset_id")
+                .or_else(|| get_owned_optional_string(nbt, "model")),
+        };
+
+        let camera_overlay = get_owned_optional_string(nbt, "camera_overlay");
+        let allowed_entities = match nbt.get("allowed_entities") {
+            Some(tag) => Some(IdSet::from_nbt_tag(&tag, "allowed_entities")?),
+            None => None,
+        };
+
+        Ok(Equippable {
+            slot,
+            equip_sound,
+            asset_id,
+            camera_overlay,
+            allowed_entities,
+            dispensable: nbt.byte("dispensable").map(|b| b != 0).unwrap_or(true),
+            swappable: nbt.byte("swappable").map(|b| b != 0).unwrap_or(true),
+            damage_on_hurt: nbt.byte("damage_on_hurt").map(|b| b != 0).unwrap_or(true),
+        })
+    }
+}
+
+/// Writes the model in `model` before 1.21.4, and in `asset_id` since or without a data version.
+impl ToCompoundNbt for Equippable {
+    fn to_compound_nbt(&self) -> NbtCompound {
+        let mut nbt = NbtCompound::new();
+
+        nbt.insert("slot", self.slot.as_str());
+        if let Some(sound) = &self.equip_sound {
+            nbt.insert("equip_sound", sound.to_nbt_tag());
+        }
+        let asset_id_key = match version::current() {
+            Some(version) if version < DataVersion::V1_21_4 => "model",
+            _ => "asset_id",
+        };
+        insert_optional(&mut nbt, asset_id_key, self.asset_id.clone());
+        insert_optional(&mut nbt, "camera_overlay", self.camera_overlay.clone());
+        if let Some(entities) = &self.allowed_entities {
+            nbt.insert("allowed_entities", entities.to_nbt_tag());
+        }
+        nbt.insert("dispensable", self.dispensable);
+        nbt.insert("swappable", self.swappable);
+        nbt.insert("damage_on_hurt", self.damage_on_hurt);
+
+        nbt
+    }
+}
@@ -1,6 +1,11 @@
 //! Firework explosion component.
 
-use crate::{error::SculkParseError, traits::FromCompoundNbt, util::get_bool};
+use crate::{
+    error::SculkParseError,
+    traits::{FromCompoundNbt, ToCompoundNbt},
+    util::get_bool,
+};
+use simdnbt::owned::{NbtCompound, NbtTag};
 
 /// The explosion of a firework.
 #[derive(Debug, Clone, PartialEq)]
@@ -65,6 +70,16 @@ impl FromCompoundNbt for FireworkExplosion {
 }
 
 impl FireworkShape {
+    fn to_str(&self) -> &'static str {
+        match self {
+            FireworkShape::SmallBall => "small_ball",
+            FireworkShape::LargeBall => "large_ball",
+            FireworkShape::Star => "star",
+            FireworkShape::Creeper => "creeper",
+            FireworkShape::Burst => "burst",
+        }
+    }
+
     fn from_str(value: &str) -> Result<Self, SculkParseError> {
         match value {
             "small_ball" => Ok(FireworkShape::SmallBall),
@@ -76,3 +91,17 @@ impl FireworkShape {
         }
     }
 }
+
+impl ToCompoundNbt for FireworkExplosion {
+    fn to_compound_nbt(&self) -> NbtCompound {
+        let mut nbt = NbtCompound::new();
+
+        nbt.insert("shape", self.shape.to_str());
+        nbt.insert("colors", NbtTag::IntArray(self.colors.clone()));
+        nbt.insert("fade_colors", NbtTag::IntArray(self.fade_colors.clone()));
+        nbt.insert("has_trail", self.has_trail);
+        nbt.insert("has_twinkle", self.has_twinkle);
+
+        nbt
+    }
+}
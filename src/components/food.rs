@@ -2,9 +2,13 @@
 
 use crate::{
     error::SculkParseError,
-    traits::FromCompoundNbt,
-    util::{get_optional_components, get_owned_string, get_t_compound_vec},
+    traits::{FromCompoundNbt, ToCompoundNbt},
+    util::{
+        compound_list, get_optional_components, get_owned_string, get_t_compound_vec,
+        insert_optional, insert_optional_components,
+    },
 };
+use simdnbt::owned::NbtCompound;
 
 use super::Components;
 
@@ -180,3 +184,57 @@ impl FromCompoundNbt for EffectDetails {
         })
     }
 }
+
+impl ToCompoundNbt for Food {
+    fn to_compound_nbt(&self) -> NbtCompound {
+        let mut nbt = NbtCompound::new();
+
+        nbt.insert("nutrition", self.nutrition);
+        nbt.insert("saturation", self.saturation);
+        nbt.insert("can_always_eat", self.can_always_eat);
+        nbt.insert("eat_seconds", self.eat_seconds);
+        if let Some(item) = &self.using_converts_to {
+            nbt.insert("using_converts_to", item.to_compound_nbt());
+        }
+        nbt.insert("effects", compound_list(&self.effects));
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for FoodConvertedItem {
+    fn to_compound_nbt(&self) -> NbtCompound {
+        let mut nbt = NbtCompound::new();
+
+        nbt.insert("id", self.id.as_str());
+        insert_optional_components(&mut nbt, &self.components);
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for Effect {
+    fn to_compound_nbt(&self) -> NbtCompound {
+        let mut nbt = NbtCompound::new();
+
+        nbt.insert("effect", self.effect.to_compound_nbt());
+        nbt.insert("probability", self.probability);
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for EffectDetails {
+    fn to_compound_nbt(&self) -> NbtCompound {
+        let mut nbt = NbtCompound::new();
+
+        nbt.insert("id", self.id.as_str());
+        insert_optional(&mut nbt, "amplifier", self.amplifier);
+        insert_optional(&mut nbt, "duration", self.duration);
+        insert_optional(&mut nbt, "ambient", self.ambient);
+        insert_optional(&mut nbt, "show_particles", self.show_particles);
+        insert_optional(&mut nbt, "show_icon", self.show_icon);
+
+        nbt
+    }
+}
@@ -0,0 +1,46 @@
+//! A set of ids, used by components that match items, entities, effects or damage types.
+
+use crate::error::SculkParseError;
+use simdnbt::{borrow::NbtTag, owned};
+
+/// One id, a tag prefixed with `#`, or a list of ids.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(untagged))]
+pub enum IdSet {
+    /// A single id or a tag like `#minecraft:skeletons`.
+    Single(String),
+    /// A list of ids.
+    Multiple(Vec<String>),
+}
+
+impl IdSet {
+    /// Parses the set from its value, a string or a list of strings.
+    pub fn from_nbt_tag(tag: &NbtTag, key: &'static str) -> Result<Self, SculkParseError> {
+        if let Some(id) = tag.string() {
+            return Ok(IdSet::Single(id.to_string()));
+        }
+
+        let list = tag
+            .list()
+            .ok_or(SculkParseError::InvalidField(key.into()))?;
+        if list.empty() {
+            return Ok(IdSet::Multiple(vec![]));
+        }
+        let ids = list
+            .strings()
+            .ok_or(SculkParseError::InvalidField(key.into()))?;
+
+        Ok(IdSet::Multiple(
+            ids.iter().map(|id| id.to_string()).collect(),
+        ))
+    }
+
+    /// Converts the set into its Nbt value, in the same format it was read from.
+    pub fn to_nbt_tag(&self) -> owned::NbtTag {
+        match self {
+            IdSet::Single(id) => owned::NbtTag::String(id.as_str().into()),
+            IdSet::Multiple(ids) => owned::NbtTag::List(crate::util::string_list(ids)),
+        }
+    }
+}
@@ -1,6 +1,11 @@
 //! Instrument component.
 
-use crate::{error::SculkParseError, traits::FromCompoundNbt, util::get_owned_string};
+use crate::{
+    error::SculkParseError,
+    traits::{FromCompoundNbt, ToCompoundNbt},
+    util::{get_owned_string, insert_optional},
+};
+use simdnbt::owned::{NbtCompound, NbtTag};
 
 /// (referenced by ID or inlined)
 #[derive(Debug, Clone, PartialEq)]
@@ -120,3 +125,67 @@ impl FromCompoundNbt for SoundEventData {
         Ok(SoundEventData { sound_id, range })
     }
 }
+
+impl SoundEvent {
+    /// Reads the sound event stored under `key`, `None` if there is none.
+    pub(crate) fn from_key(
+        nbt: &simdnbt::borrow::NbtCompound,
+        key: &'static str,
+    ) -> Result<Option<Self>, SculkParseError> {
+        if let Some(id) = nbt.string(key) {
+            Ok(Some(SoundEvent::ID(id.to_string())))
+        } else if let Some(compound) = nbt.compound(key) {
+            Ok(Some(SoundEvent::Inline(SoundEventData::from_compound_nbt(
+                &compound,
+            )?)))
+        } else if nbt.contains(key) {
+            Err(SculkParseError::InvalidField(key.into()))
+        } else {
+            Ok(None)
+        }
+    }
+
+    /// Converts the sound event into its Nbt value, either a string or a compound.
+    pub fn to_nbt_tag(&self) -> NbtTag {
+        match self {
+            SoundEvent::ID(id) => NbtTag::String(id.as_str().into()),
+            SoundEvent::Inline(data) => NbtTag::Compound(data.to_compound_nbt()),
+        }
+    }
+}
+
+impl Instrument {
+    /// Converts the component into its Nbt value, either a string or a compound.
+    pub fn to_nbt_tag(&self) -> NbtTag {
+        match self {
+            Instrument::ID(id) => NbtTag::String(id.as_str().into()),
+            Instrument::Inline(data) => NbtTag::Compound(data.to_compound_nbt()),
+        }
+    }
+}
+
+impl ToCompoundNbt for InstrumentData {
+    fn to_compound_nbt(&self) -> NbtCompound {
+        let mut nbt = NbtCompound::new();
+
+        match &self.sound_event {
+            SoundEvent::ID(id) => nbt.insert("sound_event", id.as_str()),
+            SoundEvent::Inline(data) => nbt.insert("sound_event", data.to_compound_nbt()),
+        }
+        nbt.insert("use_duration", self.use_duration);
+        nbt.insert("range", self.range);
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for SoundEventData {
+    fn to_compound_nbt(&self) -> NbtCompound {
+        let mut nbt = NbtCompound::new();
+
+        nbt.insert("sound_id", self.sound_id.as_str());
+        insert_optional(&mut nbt, "range", self.range);
+
+        nbt
+    }
+}
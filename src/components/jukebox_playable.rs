@@ -1,6 +1,9 @@
 //! Jukebox Playable component.
 
-use crate::{traits::FromCompoundNbt, util::get_owned_string};
+use crate::{
+    traits::{FromCompoundNbt, ToCompoundNbt},
+    util::get_owned_string,
+};
 
 /// A Jukebox Playable component.
 #[derive(Debug, Clone, PartialEq)]
@@ -31,3 +34,14 @@ impl FromCompoundNbt for JukeboxPlayable {
         })
     }
 }
+
+impl ToCompoundNbt for JukeboxPlayable {
+    fn to_compound_nbt(&self) -> simdnbt::owned::NbtCompound {
+        let mut nbt = simdnbt::owned::NbtCompound::new();
+
+        nbt.insert("song", self.song.as_str());
+        nbt.insert("show_in_tooltip", self.show_in_tooltip);
+
+        nbt
+    }
+}
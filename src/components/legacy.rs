@@ -0,0 +1,436 @@
+//! Conversion of the item `tag` compound used before 1.20.5 into components.
+//!
+//! Before 1.20.5 items stored their data in a free-form `tag` compound, like `{display: {Name: '...'}, Damage: 3}`.
+//! The keys the game knows about are moved into their component, the same way the game upgrades them.
+//! Everything else ends up in `minecraft:custom_data`, so no data is lost.
+
+use crate::{
+    components::Components, error::SculkParseError, snbt, traits::FromCompoundNbt, util::write_nbt,
+};
+use simdnbt::{
+    borrow,
+    owned::{NbtCompound, NbtList, NbtTag},
+};
+
+/// `HideFlags` bit hiding enchantments.
+const HIDE_ENCHANTMENTS: i32 = 1;
+/// `HideFlags` bit hiding the unbreakable line.
+const HIDE_UNBREAKABLE: i32 = 4;
+/// `HideFlags` bit hiding stored enchantments, potion effects, book info and other item specific lines.
+const HIDE_ADDITIONAL: i32 = 32;
+/// `HideFlags` bit hiding the dyed color.
+const HIDE_DYE: i32 = 64;
+
+impl Components {
+    /// Converts the legacy `tag` compound of an item into components.
+    /// `item_id` is the id of the item the tag belongs to, it's used to give `BlockEntityTag` its block entity id.
+    pub fn from_legacy_tag(
+        item_id: &str,
+        tag: &borrow::NbtCompound,
+    ) -> Result<Self, SculkParseError> {
+        let mut nbt = NbtCompound::new();
+        nbt.insert("components", legacy_tag_to_components(item_id, tag));
+
+        let bytes = write_nbt(&nbt);
+        let base = borrow::read(&mut std::io::Cursor::new(bytes.as_slice()))
+            .map_err(|_| SculkParseError::InvalidField("tag".into()))?
+            .unwrap();
+
+        Components::from_compound_nbt(&base.as_compound())
+    }
+}
+
+/// Converts the legacy `tag` compound of an item into a compound of components, the way 1.20.5 writes them.
+/// Keys without a component are kept as SNBT in `minecraft:custom_data`.
+pub fn legacy_tag_to_components(item_id: &str, tag: &borrow::NbtCompound) -> NbtCompound {
+    let mut tag = tag.to_owned();
+    let mut components = NbtCompound::new();
+
+    let hide_flags = take_int(&mut tag, "HideFlags").unwrap_or(0);
+
+    if let Some(NbtTag::Compound(mut display)) = tag.remove("display") {
+        if let Some(NbtTag::String(name)) = display.remove("Name") {
+            components.insert("minecraft:custom_name", NbtTag::String(name));
+        }
+        if let Some(NbtTag::List(lore)) = display.remove("Lore") {
+            components.insert("minecraft:lore", lore);
+        }
+        if let Some(color) = take_int(&mut display, "color") {
+            let mut dyed_color = NbtCompound::new();
+            dyed_color.insert("rgb", color);
+            dyed_color.insert("show_in_tooltip", hide_flags & HIDE_DYE == 0);
+            components.insert("minecraft:dyed_color", dyed_color);
+        }
+        if let Some(color) = take_int(&mut display, "MapColor") {
+            components.insert("minecraft:map_color", color);
+        }
+
+        if !display.is_empty() {
+            tag.insert("display", display);
+        }
+    }
+
+    if let Some(NbtTag::List(list)) = tag.remove("Enchantments") {
+        components.insert(
+            "minecraft:enchantments",
+            enchantments(&list, hide_flags & HIDE_ENCHANTMENTS == 0),
+        );
+    }
+    if let Some(NbtTag::List(list)) = tag.remove("StoredEnchantments") {
+        components.insert(
+            "minecraft:stored_enchantments",
+            enchantments(&list, hide_flags & HIDE_ADDITIONAL == 0),
+        );
+    }
+
+    if let Some(NbtTag::Compound(mut block_entity)) = tag.remove("BlockEntityTag") {
+        if !block_entity.contains("id") {
+            block_entity.insert("id", block_entity_id(item_id));
+        }
+        components.insert("minecraft:block_entity_data", block_entity);
+    }
+
+    if let Some(owner) = tag.remove("SkullOwner") {
+        match owner {
+            NbtTag::String(name) => components.insert("minecraft:profile", NbtTag::String(name)),
+            NbtTag::Compound(owner) => components.insert("minecraft:profile", profile(&owner)),
+            other => tag.insert("SkullOwner", other),
+        }
+    }
+
+    if let Some(value) = take_int(&mut tag, "CustomModelData") {
+        components.insert("minecraft:custom_model_data", value);
+    }
+    if let Some(damage) = take_int(&mut tag, "Damage") {
+        if damage != 0 {
+            components.insert("minecraft:damage", damage);
+        }
+    }
+    if let Some(cost) = take_int(&mut tag, "RepairCost") {
+        if cost != 0 {
+            components.insert("minecraft:repair_cost", cost);
+        }
+    }
+    if let Some(map) = take_int(&mut tag, "map") {
+        components.insert("minecraft:map_id", map);
+    }
+    if take_int(&mut tag, "Unbreakable").unwrap_or(0) != 0 {
+        let mut unbreakable = NbtCompound::new();
+        unbreakable.insert("show_in_tooltip", hide_flags & HIDE_UNBREAKABLE == 0);
+        components.insert("minecraft:unbreakable", unbreakable);
+    }
+    if hide_flags & HIDE_ADDITIONAL != 0 {
+        components.insert("minecraft:hide_additional_tooltip", NbtCompound::new());
+    }
+
+    if !tag.is_empty() {
+        components.insert("minecraft:custom_data", snbt::to_snbt(&tag));
+    }
+
+    components
+}
+
+/// Removes a numeric tag, accepting any integer type since old versions weren't consistent about them.
+fn take_int(nbt: &mut NbtCompound, key: &str) -> Option<i32> {
+    let value = match nbt.get(key)? {
+        NbtTag::Byte(value) => *value as i32,
+        NbtTag::Short(value) => *value as i32,
+        NbtTag::Int(value) => *value,
+        NbtTag::Long(value) => *value as i32,
+        _ => return None,
+    };
+
+    nbt.remove(key);
+    Some(value)
+}
+
+/// `[{id: "minecraft:sharpness", lvl: 5s}]` into `{levels: {"minecraft:sharpness": 5}, show_in_tooltip: true}`.
+fn enchantments(list: &NbtList, show_in_tooltip: bool) -> NbtCompound {
+    let mut levels = NbtCompound::new();
+
+    for enchantment in list.compounds().unwrap_or_default() {
+        let mut enchantment = enchantment.clone();
+
+        let Some(id) = enchantment.string("id").map(|id| id.to_string()) else {
+            continue;
+        };
+        let level = take_int(&mut enchantment, "lvl").unwrap_or(1);
+
+        if !levels.contains(&id) {
+            levels.insert(id, level);
+        }
+    }
+
+    let mut nbt = NbtCompound::new();
+    nbt.insert("levels", levels);
+    nbt.insert("show_in_tooltip", show_in_tooltip);
+
+    nbt
+}
+
+/// `{Id: [I; ...], Name: "", Properties: {textures: [{Value: "", Signature: ""}]}}` into the profile compound.
+fn profile(owner: &NbtCompound) -> NbtCompound {
+    let mut nbt = NbtCompound::new();
+
+    if let Some(name) = owner.string("Name") {
+        nbt.insert("name", name.to_str().as_ref());
+    }
+    if let Some(id) = owner.int_array("Id") {
+        nbt.insert("id", NbtTag::IntArray(id.to_vec()));
+    }
+
+    if let Some(properties) = owner.compound("Properties") {
+        let mut list = vec![];
+
+        for (name, values) in properties.iter() {
+            let NbtTag::List(values) = values else {
+                continue;
+            };
+
+            for value in values.compounds().unwrap_or_default() {
+                let Some(texture) = value.string("Value") else {
+                    continue;
+                };
+
+                let mut property = NbtCompound::new();
+                property.insert("name", name.to_str().as_ref());
+                property.insert("value", texture.to_str().as_ref());
+                if let Some(signature) = value.string("Signature") {
+                    property.insert("signature", signature.to_str().as_ref());
+                }
+                list.push(property);
+            }
+        }
+
+        if !list.is_empty() {
+            nbt.insert("properties", NbtList::Compound(list));
+        }
+    }
+
+    nbt
+}
+
+/// The id of the block entity placed by an item, the same as the item id unless listed here.
+fn block_entity_id(item_id: &str) -> &str {
+    match item_id {
+        "minecraft:white_banner"
+        | "minecraft:orange_banner"
+        | "minecraft:magenta_banner"
+        | "minecraft:light_blue_banner"
+        | "minecraft:yellow_banner"
+        | "minecraft:lime_banner"
+        | "minecraft:pink_banner"
+        | "minecraft:gray_banner"
+        | "minecraft:light_gray_banner"
+        | "minecraft:cyan_banner"
+        | "minecraft:purple_banner"
+        | "minecraft:blue_banner"
+        | "minecraft:brown_banner"
+        | "minecraft:green_banner"
+        | "minecraft:red_banner"
+        | "minecraft:black_banner" => "minecraft:banner",
+        "minecraft:white_bed"
+        | "minecraft:orange_bed"
+        | "minecraft:magenta_bed"
+        | "minecraft:light_blue_bed"
+        | "minecraft:yellow_bed"
+        | "minecraft:lime_bed"
+        | "minecraft:pink_bed"
+        | "minecraft:gray_bed"
+        | "minecraft:light_gray_bed"
+        | "minecraft:cyan_bed"
+        | "minecraft:purple_bed"
+        | "minecraft:blue_bed"
+        | "minecraft:brown_bed"
+        | "minecraft:green_bed"
+        | "minecraft:red_bed"
+        | "minecraft:black_bed" => "minecraft:bed",
+        "minecraft:bee_nest" => "minecraft:beehive",
+        "minecraft:soul_campfire" => "minecraft:campfire",
+        "minecraft:chain_command_block" | "minecraft:repeating_command_block" => {
+            "minecraft:command_block"
+        }
+        "minecraft:oak_hanging_sign"
+        | "minecraft:spruce_hanging_sign"
+        | "minecraft:birch_hanging_sign"
+        | "minecraft:jungle_hanging_sign"
+        | "minecraft:acacia_hanging_sign"
+        | "minecraft:dark_oak_hanging_sign"
+        | "minecraft:mangrove_hanging_sign"
+        | "minecraft:cherry_hanging_sign"
+        | "minecraft:bamboo_hanging_sign"
+        | "minecraft:crimson_hanging_sign"
+        | "minecraft:warped_hanging_sign" => "minecraft:hanging_sign",
+        "minecraft:shulker_box"
+        | "minecraft:white_shulker_box"
+        | "minecraft:orange_shulker_box"
+        | "minecraft:magenta_shulker_box"
+        | "minecraft:light_blue_shulker_box"
+        | "minecraft:yellow_shulker_box"
+        | "minecraft:lime_shulker_box"
+        | "minecraft:pink_shulker_box"
+        | "minecraft:gray_shulker_box"
+        | "minecraft:light_gray_shulker_box"
+        | "minecraft:cyan_shulker_box"
+        | "minecraft:purple_shulker_box"
+        | "minecraft:blue_shulker_box"
+        | "minecraft:brown_shulker_box"
+        | "minecraft:green_shulker_box"
+        | "minecraft:red_shulker_box"
+        | "minecraft:black_shulker_box" => "minecraft:shulker_box",
+        "minecraft:oak_sign"
+        | "minecraft:spruce_sign"
+        | "minecraft:birch_sign"
+        | "minecraft:jungle_sign"
+        | "minecraft:acacia_sign"
+        | "minecraft:dark_oak_sign"
+        | "minecraft:mangrove_sign"
+        | "minecraft:cherry_sign"
+        | "minecraft:bamboo_sign"
+        | "minecraft:crimson_sign"
+        | "minecraft:warped_sign" => "minecraft:sign",
+        "minecraft:skeleton_skull"
+        | "minecraft:wither_skeleton_skull"
+        | "minecraft:zombie_head"
+        | "minecraft:player_head"
+        | "minecraft:creeper_head"
+        | "minecraft:dragon_head"
+        | "minecraft:piglin_head" => "minecraft:skull",
+        "minecraft:suspicious_sand" | "minecraft:suspicious_gravel" => "minecraft:brushable_block",
+        _ => item_id,
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn legacy_tag_test() {
+    use crate::{block_entities::skull::SkullProfile, components::Component, item::ItemWithNoSlot};
+
+    let item = snbt::parse(
+        r#"{
+            id: "minecraft:player_head",
+            Count: 1b,
+            tag: {
+                display: {Name: '{"text":"Trophy"}', Lore: ['"first"', '"second"']},
+                Enchantments: [{id: "minecraft:unbreaking", lvl: 3s}],
+                SkullOwner: {
+                    Id: [I; 1, 2, 3, 4],
+                    Name: "Notch",
+                    Properties: {textures: [{Value: "abc"}]}
+                },
+                BlockEntityTag: {note_block_sound: "minecraft:ambient.cave"},
+                CustomModelData: 7,
+                Damage: 4,
+                Unbreakable: 1b,
+                HideFlags: 37,
+                points: 10
+            }
+        }"#,
+    )
+    .unwrap();
+
+    let bytes = write_nbt(&item);
+    let base = borrow::read(&mut std::io::Cursor::new(bytes.as_slice()))
+        .unwrap()
+        .unwrap();
+    let item = ItemWithNoSlot::from_compound_nbt(&base.as_compound()).unwrap();
+    let components = item.components.unwrap();
+
+    assert_eq!(
+        components.get("minecraft:custom_name"),
+        Some(&Component::CustomName(r#"{"text":"Trophy"}"#.into()))
+    );
+    assert_eq!(
+        components.get("minecraft:lore"),
+        Some(&Component::Lore(vec![
+            r#""first""#.into(),
+            r#""second""#.into()
+        ]))
+    );
+    match components.get("minecraft:enchantments") {
+        Some(Component::Enchantments(enchantments)) => {
+            assert_eq!(enchantments.levels.get("minecraft:unbreaking"), Some(&3));
+            assert!(!enchantments.show_in_tooltip);
+        }
+        other => panic!("{other:?}"),
+    }
+    match components.get("minecraft:profile") {
+        Some(Component::Profile(SkullProfile::Profile(profile))) => {
+            assert_eq!(profile.name.as_deref(), Some("Notch"));
+            assert_eq!(profile.properties.as_ref().unwrap()[0].value, "abc");
+        }
+        other => panic!("{other:?}"),
+    }
+    match components.get("minecraft:block_entity_data") {
+        Some(Component::BlockEntityData(block_entity)) => {
+            assert_eq!(block_entity.base.id, "minecraft:skull")
+        }
+        other => panic!("{other:?}"),
+    }
+    assert_eq!(
+        components.get("minecraft:damage"),
+        Some(&Component::Damage(4))
+    );
+    assert!(matches!(
+        components.get("minecraft:unbreakable"),
+        Some(Component::Unbreakable(unbreakable)) if !unbreakable.show_in_tooltip
+    ));
+    assert!(components.contains_key("minecraft:hide_additional_tooltip"));
+    assert!(components.contains_key("minecraft:custom_model_data"));
+
+    match components.get("minecraft:custom_data") {
+        Some(Component::CustomData(data)) => {
+            let data = data.resolve().unwrap();
+            assert_eq!(data.int("points"), Some(10));
+            assert_eq!(data.len(), 1);
+        }
+        other => panic!("{other:?}"),
+    }
+
+    // items whose block entity id differs from their own
+    let text = r#"{color: "black", messages: ['""', '""', '""', '""']}"#;
+    for (item_id, block_entity_tag, block_entity_id) in [
+        (
+            "minecraft:oak_hanging_sign",
+            format!("{{front_text: {text}, back_text: {text}}}"),
+            "minecraft:hanging_sign",
+        ),
+        (
+            "minecraft:bee_nest",
+            "{bees: []}".into(),
+            "minecraft:beehive",
+        ),
+        (
+            "minecraft:soul_campfire",
+            "{CookingTimes: [I; 0, 0, 0, 0], CookingTotalTimes: [I; 0, 0, 0, 0], Items: []}".into(),
+            "minecraft:campfire",
+        ),
+        (
+            "minecraft:suspicious_sand",
+            r#"{LootTable: "minecraft:archaeology/desert_pyramid"}"#.into(),
+            "minecraft:brushable_block",
+        ),
+        (
+            "minecraft:chain_command_block",
+            r#"{Command: "say hi", LastExecution: 0L, LastOutput: "", SuccessCount: 0}"#.into(),
+            "minecraft:command_block",
+        ),
+    ] {
+        let item = snbt::parse(&format!(
+            r#"{{id: "{item_id}", Count: 1b, tag: {{BlockEntityTag: {block_entity_tag}}}}}"#
+        ))
+        .unwrap();
+        let bytes = write_nbt(&item);
+        let base = borrow::read(&mut std::io::Cursor::new(bytes.as_slice()))
+            .unwrap()
+            .unwrap();
+        let item = ItemWithNoSlot::from_compound_nbt(&base.as_compound()).unwrap();
+
+        match item.components.unwrap().get("minecraft:block_entity_data") {
+            Some(Component::BlockEntityData(block_entity)) => {
+                assert_eq!(block_entity.base.id, block_entity_id)
+            }
+            other => panic!("{item_id}: {other:?}"),
+        }
+    }
+}
@@ -1,9 +1,11 @@
 //! Lodestone Tracker component. This component is present in lodestone compasses.
 
 use crate::{
-    traits::FromCompoundNbt,
-    util::{get_int_array, get_owned_string},
+    error::SculkParseError,
+    traits::{FromCompoundNbt, ToCompoundNbt},
+    util::get_owned_string,
 };
+use simdnbt::owned::{NbtCompound, NbtTag};
 
 /// The Lodestone Tracker component.
 #[derive(Debug, Clone, PartialEq)]
@@ -35,7 +37,7 @@ impl FromCompoundNbt for LodestoneTracker {
     where
         Self: Sized,
     {
-        let target = if let Some(t) = nbt.compound("tracker") {
+        let target = if let Some(t) = nbt.compound("target") {
             Some(LodestoneTarget::from_compound_nbt(&t)?)
         } else {
             None
@@ -54,11 +56,38 @@ impl FromCompoundNbt for LodestoneTarget {
     where
         Self: Sized,
     {
-        let pos = get_int_array(&nbt, "pos")?;
-        let pos = [pos[0], pos[1], pos[2]];
+        let pos = match nbt.int_array("pos") {
+            Some(pos) if pos.len() == 3 => [pos[0], pos[1], pos[2]],
+            Some(_) => return Err(SculkParseError::InvalidField("pos".into())),
+            None => return Err(SculkParseError::MissingField("pos".into())),
+        };
 
         let dimension = get_owned_string(&nbt, "dimension")?;
 
         Ok(LodestoneTarget { pos, dimension })
     }
 }
+
+impl ToCompoundNbt for LodestoneTracker {
+    fn to_compound_nbt(&self) -> NbtCompound {
+        let mut nbt = NbtCompound::new();
+
+        if let Some(target) = &self.target {
+            nbt.insert("target", target.to_compound_nbt());
+        }
+        nbt.insert("tracked", self.tracked);
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for LodestoneTarget {
+    fn to_compound_nbt(&self) -> NbtCompound {
+        let mut nbt = NbtCompound::new();
+
+        nbt.insert("pos", NbtTag::IntArray(self.pos.to_vec()));
+        nbt.insert("dimension", self.dimension.as_str());
+
+        nbt
+    }
+}
@@ -1,6 +1,10 @@
 //! Map decorations component.
 
-use crate::{error::SculkParseError, kv::KVPair, traits::FromCompoundNbt};
+use crate::{
+    error::SculkParseError,
+    kv::KVPair,
+    traits::{FromCompoundNbt, ToCompoundNbt},
+};
 use std::collections::HashMap;
 
 /// Decorations on a map.
@@ -108,6 +112,49 @@ impl MapIconType {
     }
 }
 
+impl MapIconType {
+    /// Converts a `MapIconType` to a string.
+    pub fn to_str(&self) -> &str {
+        match self {
+            Self::Player => "player",
+            Self::Frame => "frame",
+            Self::RedMarker => "red_marker",
+            Self::BlueMarker => "blue_marker",
+            Self::TargetX => "target_x",
+            Self::TargetPoint => "target_point",
+            Self::PlayerOffMap => "player_off_map",
+            Self::PlayerOffLimits => "player_off_limits",
+            Self::Mansion => "mansion",
+            Self::Monument => "monument",
+            Self::BannerWhite => "banner_white",
+            Self::BannerOrange => "banner_orange",
+            Self::BanneMagenta => "banner_magenta",
+            Self::BannerLightBlue => "banner_light_blue",
+            Self::BannerYellow => "banner_yellow",
+            Self::BannerLime => "banner_lime",
+            Self::BannerPink => "banner_pink",
+            Self::BannerGray => "banner_gray",
+            Self::BannerLightGray => "banner_light_gray",
+            Self::BannerCyan => "banner_cyan",
+            Self::BannerPurple => "banner_purple",
+            Self::BannerBlue => "banner_blue",
+            Self::BannerBrown => "banner_brown",
+            Self::BannerGreen => "banner_green",
+            Self::BannerRed => "banner_red",
+            Self::BannerBlack => "banner_black",
+            Self::RedX => "red_x",
+            Self::VillageDesert => "village_desert",
+            Self::VillagePlains => "village_plains",
+            Self::VillageSavanna => "village_savanna",
+            Self::VillageSnowy => "village_snowy",
+            Self::VillageTaiga => "village_taiga",
+            Self::JungleTemple => "jungle_temple",
+            Self::SwampHut => "swamp_hut",
+            Self::Unknown(s) => s.as_str(),
+        }
+    }
+}
+
 impl FromCompoundNbt for MapDecorations {
     fn from_compound_nbt(
         nbt: &simdnbt::borrow::NbtCompound,
@@ -172,3 +219,34 @@ impl FromCompoundNbt for MapIcon {
         })
     }
 }
+
+impl ToCompoundNbt for MapDecorations {
+    fn to_compound_nbt(&self) -> simdnbt::owned::NbtCompound {
+        self.0.to_compound_nbt()
+    }
+}
+
+impl ToCompoundNbt for KVPair<MapIcon> {
+    fn to_compound_nbt(&self) -> simdnbt::owned::NbtCompound {
+        let mut nbt = simdnbt::owned::NbtCompound::new();
+
+        for (key, icon) in self.iter() {
+            nbt.insert(key.as_str(), icon.to_compound_nbt());
+        }
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for MapIcon {
+    fn to_compound_nbt(&self) -> simdnbt::owned::NbtCompound {
+        let mut nbt = simdnbt::owned::NbtCompound::new();
+
+        nbt.insert("type", self.r#type.to_str());
+        nbt.insert("x", self.x);
+        nbt.insert("z", self.z);
+        nbt.insert("rotation", self.rotation);
+
+        nbt
+    }
+}
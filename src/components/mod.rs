@@ -20,9 +20,10 @@ use crate::{
     item::ItemWithNoSlot,
     kv::KVPair,
     rarity::Rarity,
-    traits::FromCompoundNbt,
-    util::get_t_list,
+    traits::{FromCompoundNbt, ToCompoundNbt},
+    util::{compound_list, get_t_list, string_list},
 };
+use simdnbt::owned::{NbtCompound, NbtTag};
 
 pub mod attribute_modifiers;
 pub mod banner_patterns;
@@ -31,16 +32,21 @@ pub mod bees;
 pub mod block_state;
 pub mod bucket_entity_data;
 pub mod can_break;
+pub mod consumable;
 pub mod container;
 pub mod container_loot;
 pub mod custom_data;
+pub mod custom_model_data;
 pub mod dyed_color;
 pub mod enchantments;
+pub mod equippable;
 pub mod firework_explosion;
 pub mod fireworks;
 pub mod food;
+pub mod id_set;
 pub mod instrument;
 pub mod jukebox_playable;
+pub mod legacy;
 pub mod lodestone_tracker;
 pub mod map_decorations;
 pub mod potion_contents;
@@ -48,6 +54,7 @@ pub mod suspicious_stew_effects;
 pub mod tool;
 pub mod trim;
 pub mod unbreakable;
+pub mod use_cooldown;
 pub mod writable_book_content;
 pub mod written_book_content;
 
@@ -94,6 +101,37 @@ impl Components {
     pub fn get(&self, key: &str) -> Option<&Component> {
         self.0.get(key)
     }
+
+    /// Parses the `minecraft:custom_name` component as a text component.
+    #[cfg(feature = "text")]
+    pub fn custom_name_text(&self) -> Option<crate::text::TextComponent> {
+        match self.get("minecraft:custom_name")? {
+            Component::CustomName(name) => Some(crate::text::TextComponent::parse(name)),
+            _ => None,
+        }
+    }
+
+    /// Parses the `minecraft:item_name` component as a text component.
+    #[cfg(feature = "text")]
+    pub fn item_name_text(&self) -> Option<crate::text::TextComponent> {
+        match self.get("minecraft:item_name")? {
+            Component::ItemName(name) => Some(crate::text::TextComponent::parse(name)),
+            _ => None,
+        }
+    }
+
+    /// Parses every line of the `minecraft:lore` component as a text component.
+    #[cfg(feature = "text")]
+    pub fn lore_text(&self) -> Option<Vec<crate::text::TextComponent>> {
+        match self.get("minecraft:lore")? {
+            Component::Lore(lore) => Some(
+                lore.iter()
+                    .map(|line| crate::text::TextComponent::parse(line))
+                    .collect(),
+            ),
+            _ => None,
+        }
+    }
 }
 
 impl FromCompoundNbt for Components {
@@ -115,7 +153,9 @@ impl FromCompoundNbt for Components {
             let component: Component = match key.as_str() {
                 "minecraft:attribute_modifiers" => {
                     // since the root value is either list or compound, we need to pass parent nbt.
-                    Component::AttributeModifiers(AttributeModifier::from_compound_nbt(&nbt)?)
+                    Component::AttributeModifiers(AttributeModifier::from_compound_nbt(
+                        &nbt_components,
+                    )?)
                 }
                 "minecraft:banner_patterns" => {
                     let list = value.list().ok_or(SculkParseError::InvalidField(
@@ -160,13 +200,13 @@ impl FromCompoundNbt for Components {
                         bucket_entity_data::BucketEntityData::from_compound_nbt(&nbt)?,
                     )
                 }
-                "minecraft:bundle_conents" => {
+                "minecraft:bundle_contents" => {
                     let list = value.list().ok_or(SculkParseError::InvalidField(
-                        "minecraft:bundle_conents".into(),
+                        "minecraft:bundle_contents".into(),
                     ))?;
                     let items = get_t_list(
                         &list,
-                        "minecraft_bundle_conents",
+                        "minecraft:bundle_contents",
                         ItemWithNoSlot::from_compound_nbt,
                     )?;
 
@@ -196,6 +236,12 @@ impl FromCompoundNbt for Components {
 
                     Component::ChargedProjectiles(items)
                 }
+                "minecraft:consumable" => {
+                    let nbt = value
+                        .compound()
+                        .ok_or(SculkParseError::InvalidField("consumable".into()))?;
+                    Component::Consumable(consumable::Consumable::from_compound_nbt(&nbt)?)
+                }
                 "minecraft:container" => {
                     let items = if let Some(list) = value.list() {
                         let list = list
@@ -222,15 +268,12 @@ impl FromCompoundNbt for Components {
                         &nbt,
                     )?)
                 }
-                "minecraft:custom_data" => {
-                    Component::CustomData(custom_data::CustomData::from_compound_nbt(&nbt)?)
-                }
-                "minecraft:custom_model_data" => {
-                    let value = value.int().ok_or(SculkParseError::InvalidField(
-                        "minecraft:custom_model_data".into(),
-                    ))?;
-                    Component::CustomModelData(value)
-                }
+                "minecraft:custom_data" => Component::CustomData(
+                    custom_data::CustomData::from_compound_nbt(&nbt_components)?,
+                ),
+                "minecraft:custom_model_data" => Component::CustomModelData(
+                    custom_model_data::CustomModelData::from_nbt_tag(&value)?,
+                ),
                 "minecraft:custom_name" => {
                     let value = value
                         .string()
@@ -243,6 +286,15 @@ impl FromCompoundNbt for Components {
                         .ok_or(SculkParseError::InvalidField("damage".into()))?;
                     Component::Damage(value)
                 }
+                "minecraft:damage_resistant" => {
+                    let nbt = value
+                        .compound()
+                        .ok_or(SculkParseError::InvalidField("damage_resistant".into()))?;
+                    let types = nbt
+                        .string("types")
+                        .ok_or(SculkParseError::MissingField("types".into()))?;
+                    Component::DamageResistant(types.to_string())
+                }
                 "minecraft:debug_stick_state" => {
                     let nbt = value
                         .compound()
@@ -250,7 +302,14 @@ impl FromCompoundNbt for Components {
                     Component::DebugStickState(KVPair::from_compound_nbt(&nbt)?)
                 }
                 "minecraft:dyed_color" => {
-                    Component::DyedColor(dyed_color::DyedColor::from_compound_nbt(&nbt)?)
+                    Component::DyedColor(dyed_color::DyedColor::from_compound_nbt(&nbt_components)?)
+                }
+                "minecraft:enchantable" => {
+                    let value = value
+                        .compound()
+                        .and_then(|nbt| nbt.int("value"))
+                        .ok_or(SculkParseError::InvalidField("enchantable".into()))?;
+                    Component::Enchantable(value)
                 }
                 "minecraft:enchantment_glint_override" => {
                     let value = value.byte().ok_or(SculkParseError::InvalidField(
@@ -264,6 +323,12 @@ impl FromCompoundNbt for Components {
                         .ok_or(SculkParseError::InvalidField("enchantments".into()))?;
                     Component::Enchantments(enchantments::Enchantments::from_compound_nbt(&nbt)?)
                 }
+                "minecraft:equippable" => {
+                    let nbt = value
+                        .compound()
+                        .ok_or(SculkParseError::InvalidField("equippable".into()))?;
+                    Component::Equippable(equippable::Equippable::from_compound_nbt(&nbt)?)
+                }
                 "minecraft:entity_data" => {
                     let nbt = value
                         .compound()
@@ -296,6 +361,12 @@ impl FromCompoundNbt for Components {
                         .ok_or(SculkParseError::InvalidField("food".into()))?;
                     Component::Food(food::Food::from_compound_nbt(&nbt)?)
                 }
+                "minecraft:glider" => {
+                    value
+                        .compound()
+                        .ok_or(SculkParseError::InvalidField("minecraft:glider".into()))?;
+                    Component::Glider(true)
+                }
                 "minecraft:hide_additional_tooltip" => {
                     value.compound().ok_or(SculkParseError::InvalidField(
                         "minecraft:hide_additional_tooltip".into(),
@@ -317,6 +388,12 @@ impl FromCompoundNbt for Components {
                     ))?;
                     Component::IntangibleProjectile(value != 0)
                 }
+                "minecraft:item_model" => {
+                    let value = value
+                        .string()
+                        .ok_or(SculkParseError::InvalidField("item_model".into()))?;
+                    Component::ItemModel(value.to_string())
+                }
                 "minecraft:item_name" => {
                     let value = value
                         .string()
@@ -425,7 +502,7 @@ impl FromCompoundNbt for Components {
                     potion_contents::PotionContents::from_compound_nbt(&nbt_components)?,
                 ),
                 "minecraft:profile" => {
-                    Component::Profile(SkullProfile::from_component_compound_nbt(&nbt)?)
+                    Component::Profile(SkullProfile::from_component_compound_nbt(&nbt_components)?)
                 }
                 "minecraft:rarity" => {
                     let value = value
@@ -449,6 +526,13 @@ impl FromCompoundNbt for Components {
 
                     Component::Recipes(recipes)
                 }
+                "minecraft:repairable" => {
+                    let items = value
+                        .compound()
+                        .and_then(|nbt| nbt.get("items"))
+                        .ok_or(SculkParseError::InvalidField("repairable".into()))?;
+                    Component::Repairable(id_set::IdSet::from_nbt_tag(&items, "repairable")?)
+                }
                 "minecraft:repair_cost" => {
                     let value = value
                         .int()
@@ -482,6 +566,12 @@ impl FromCompoundNbt for Components {
                         .ok_or(SculkParseError::InvalidField("tool".into()))?;
                     Component::Tool(tool::Tool::from_compound_nbt(&nbt)?)
                 }
+                "minecraft:tooltip_style" => {
+                    let value = value
+                        .string()
+                        .ok_or(SculkParseError::InvalidField("tooltip_style".into()))?;
+                    Component::TooltipStyle(value.to_string())
+                }
                 "minecraft:trim" => {
                     let nbt = value
                         .compound()
@@ -489,7 +579,7 @@ impl FromCompoundNbt for Components {
                     Component::Trim(Trim::from_compound_nbt(&nbt)?)
                 }
                 "minecraft:unbreakable" => {
-                    if let Some(_) = nbt.compound("minecraft:unbreakable") {
+                    if nbt_components.compound("minecraft:unbreakable").is_some() {
                         let nbt = value
                             .compound()
                             .ok_or(SculkParseError::InvalidField("unbreakable".into()))?;
@@ -499,6 +589,18 @@ impl FromCompoundNbt for Components {
                         return Err(SculkParseError::InvalidField("unbreakable".into()));
                     }
                 }
+                "minecraft:use_cooldown" => {
+                    let nbt = value
+                        .compound()
+                        .ok_or(SculkParseError::InvalidField("use_cooldown".into()))?;
+                    Component::UseCooldown(use_cooldown::UseCooldown::from_compound_nbt(&nbt)?)
+                }
+                "minecraft:use_remainder" => {
+                    let nbt = value
+                        .compound()
+                        .ok_or(SculkParseError::InvalidField("use_remainder".into()))?;
+                    Component::UseRemainder(ItemWithNoSlot::from_compound_nbt(&nbt)?)
+                }
                 "minecraft:writable_book_content" => {
                     let nbt = value.compound().ok_or(SculkParseError::InvalidField(
                         "writable_book_content".into(),
@@ -525,6 +627,116 @@ impl FromCompoundNbt for Components {
     }
 }
 
+/// Writes the compound stored under the `components` key.
+impl ToCompoundNbt for Components {
+    fn to_compound_nbt(&self) -> NbtCompound {
+        let mut nbt = NbtCompound::new();
+
+        for (key, component) in self.0.iter() {
+            nbt.insert(key.as_str(), component.to_nbt_tag());
+        }
+
+        nbt
+    }
+}
+
+impl Component {
+    /// Converts the component into its Nbt value.  
+    /// Not every component is a compound, so this returns a tag instead.
+    pub fn to_nbt_tag(&self) -> NbtTag {
+        match self {
+            Component::AttributeModifiers(modifiers) => modifiers.to_nbt_tag(),
+            Component::BannerPatterns(patterns) => NbtTag::List(compound_list(patterns)),
+            Component::BaseColor(color) => color.to_nbt_tag(),
+            Component::Bees(bees) => NbtTag::List(compound_list(bees)),
+            Component::BlockEntityData(block_entity) => {
+                NbtTag::Compound(block_entity.to_compound_nbt())
+            }
+            Component::BlockState(state) => NbtTag::Compound(state.to_compound_nbt()),
+            Component::BucketEntityData(data) => NbtTag::Compound(data.to_compound_nbt()),
+            Component::BundleContents(items) => NbtTag::List(compound_list(items)),
+            Component::CanBreak(can_break) => NbtTag::Compound(can_break.to_compound_nbt()),
+            Component::CanPlaceOn(can_place_on) => NbtTag::Compound(can_place_on.to_compound_nbt()),
+            Component::ChargedProjectiles(items) => NbtTag::List(compound_list(items)),
+            Component::Consumable(consumable) => NbtTag::Compound(consumable.to_compound_nbt()),
+            Component::Container(items) => NbtTag::List(compound_list(items)),
+            Component::ContainerLoot(loot) => NbtTag::Compound(loot.to_compound_nbt()),
+            Component::CustomData(data) => data.to_nbt_tag(),
+            Component::CustomModelData(data) => data.to_nbt_tag(),
+            Component::CustomName(name) => NbtTag::String(name.as_str().into()),
+            Component::Damage(value) => NbtTag::Int(*value),
+            Component::DamageResistant(types) => {
+                let mut nbt = NbtCompound::new();
+                nbt.insert("types", types.as_str());
+                NbtTag::Compound(nbt)
+            }
+            Component::DebugStickState(state) => NbtTag::Compound(state.to_compound_nbt()),
+            Component::DyedColor(color) => color.to_nbt_tag(),
+            Component::Enchantable(value) => {
+                let mut nbt = NbtCompound::new();
+                nbt.insert("value", *value);
+                NbtTag::Compound(nbt)
+            }
+            Component::EnchantmentGlintOverride(value) => NbtTag::Byte(*value as i8),
+            Component::Enchantments(enchantments) => {
+                NbtTag::Compound(enchantments.to_compound_nbt())
+            }
+            Component::Equippable(equippable) => NbtTag::Compound(equippable.to_compound_nbt()),
+            Component::EntityData(entity) => NbtTag::Compound(entity.to_compound_nbt()),
+            Component::FireResistant(_) => NbtTag::Compound(NbtCompound::new()),
+            Component::FireworkExplosion(explosion) => {
+                NbtTag::Compound(explosion.to_compound_nbt())
+            }
+            Component::Fireworks(fireworks) => NbtTag::Compound(fireworks.to_compound_nbt()),
+            Component::Food(food) => NbtTag::Compound(food.to_compound_nbt()),
+            Component::Glider(_) => NbtTag::Compound(NbtCompound::new()),
+            Component::HideAdditionalTooltip(_) => NbtTag::Compound(NbtCompound::new()),
+            Component::HideTooltip(_) => NbtTag::Compound(NbtCompound::new()),
+            Component::Instrument(instrument) => instrument.to_nbt_tag(),
+            Component::IntangibleProjectile(value) => NbtTag::Byte(*value as i8),
+            Component::ItemModel(model) => NbtTag::String(model.as_str().into()),
+            Component::ItemName(name) => NbtTag::String(name.as_str().into()),
+            Component::JukeboxPlayable(playable) => NbtTag::Compound(playable.to_compound_nbt()),
+            Component::Lock(lock) => NbtTag::String(lock.as_str().into()),
+            Component::LodestoneTracker(tracker) => NbtTag::Compound(tracker.to_compound_nbt()),
+            Component::Lore(lore) => NbtTag::List(string_list(lore)),
+            Component::MapColor(color) => NbtTag::Int(color.clone().into()),
+            Component::MapDecorations(decorations) => {
+                NbtTag::Compound(decorations.to_compound_nbt())
+            }
+            Component::MapId(value) => NbtTag::Int(*value),
+            Component::MaxDamage(value) => NbtTag::Int(*value),
+            Component::MaxStackSize(value) => NbtTag::Int(*value),
+            Component::NoteBlockSound(sound) => NbtTag::String(sound.as_str().into()),
+            Component::OminousBottleAmplifier(value) => NbtTag::Int(*value),
+            Component::PotDecorations(decorations) => NbtTag::List(string_list(decorations)),
+            Component::PotionContents(contents) => contents.to_nbt_tag(),
+            Component::Profile(profile) => profile.to_nbt_tag(),
+            Component::Rarity(rarity) => NbtTag::String(rarity.to_str().into()),
+            Component::Recipes(recipes) => NbtTag::List(string_list(recipes)),
+            Component::Repairable(items) => {
+                let mut nbt = NbtCompound::new();
+                nbt.insert("items", items.to_nbt_tag());
+                NbtTag::Compound(nbt)
+            }
+            Component::RepairCost(value) => NbtTag::Int(*value),
+            Component::StoredEnchantments(enchantments) => {
+                NbtTag::Compound(enchantments.to_compound_nbt())
+            }
+            Component::SuspiciousStewEffects(effects) => NbtTag::List(compound_list(effects)),
+            Component::Tool(tool) => NbtTag::Compound(tool.to_compound_nbt()),
+            Component::TooltipStyle(style) => NbtTag::String(style.as_str().into()),
+            Component::Trim(trim) => NbtTag::Compound(trim.to_compound_nbt()),
+            Component::Unbreakable(unbreakable) => NbtTag::Compound(unbreakable.to_compound_nbt()),
+            Component::UseCooldown(cooldown) => NbtTag::Compound(cooldown.to_compound_nbt()),
+            Component::UseRemainder(item) => NbtTag::Compound(item.to_compound_nbt()),
+            Component::WritableBookContent(content) => NbtTag::Compound(content.to_compound_nbt()),
+            Component::WrittenBookContent(content) => NbtTag::Compound(content.to_compound_nbt()),
+            Component::Unknown(tag) => tag.clone(),
+        }
+    }
+}
+
 /// Represents a component in a block entity.
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -576,6 +788,10 @@ pub enum Component {
     /// `minecraft:charged_projectiles`
     ChargedProjectiles(Vec<ItemWithNoSlot>),
 
+    /// If set, this item can be eaten or drunk. Replaced the eating part of `food` in 1.21.2.
+    /// `minecraft:consumable`
+    Consumable(consumable::Consumable),
+
     /// The items contained in this [container](https://minecraft.wiki/w/Container).  
     /// `minecraft:container`
     Container(Vec<container::Container>),
@@ -588,9 +804,9 @@ pub enum Component {
     /// `minecraft:custom_data`
     CustomData(custom_data::CustomData),
 
-    /// A value used in the "custom_model_data" [item tag](https://minecraft.wiki/w/Model#Item_models) in the overrides of item models.  
+    /// Values used by item models to pick a model, a single integer before 1.21.4 and lists of values since.  
     /// `minecraft:custom_model_data`
-    CustomModelData(i32),
+    CustomModelData(custom_model_data::CustomModelData),
 
     /// The JSON text component to use as this item's name. See [Raw JSON text](https://minecraft.wiki/w/Raw_JSON_text_format) format.  
     /// `custom_name`
@@ -600,6 +816,10 @@ pub enum Component {
     /// `minecraft:damage`
     Damage(i32),
 
+    /// The damage type tag, like `#minecraft:is_fire`, of damage this item isn't hurt by as an entity. Replaced `fire_resistant` in 1.21.2.
+    /// `minecraft:damage_resistant`
+    DamageResistant(String),
+
     /// The selected block state properties used by this debug stick.  
     /// `minecraft:debug_stick_state`
     DebugStickState(KVPair<String>),
@@ -608,6 +828,10 @@ pub enum Component {
     /// `minecraft:dyed_color`
     DyedColor(dyed_color::DyedColor),
 
+    /// How well this item can be enchanted in an enchanting table, higher values give better enchantments.
+    /// `minecraft:enchantable`
+    Enchantable(i32),
+
     ///  Overrides the enchantment glint effect on this item. When true, this item will display a glint, even without enchantments. When false, this item will not display a glint, even with enchantments.  
     /// `minecraft:enchantment_glint_override`
     EnchantmentGlintOverride(bool),
@@ -616,6 +840,10 @@ pub enum Component {
     /// `minecraft:enchantments`
     Enchantments(enchantments::Enchantments),
 
+    /// If set, this item can be worn in an equipment slot.
+    /// `minecraft:equippable`
+    Equippable(equippable::Equippable),
+
     /// NBT applied to an [entity](https://minecraft.wiki/w/Entity) when created from an item.   
     /// `minecraft:entity_data`
     EntityData(Entity),
@@ -635,6 +863,10 @@ pub enum Component {
     /// `minecraft:food`
     Food(food::Food),
 
+    /// If set, this item lets the wearer glide like an elytra when equipped.
+    /// `minecraft:glider`
+    Glider(bool),
+
     /// If set, it will hide additional info on this item's tooltip.  
     /// `minecraft:hide_additional_tooltip`
     HideAdditionalTooltip(bool),
@@ -651,6 +883,10 @@ pub enum Component {
     /// `minecraft:intangible_projectile`
     IntangibleProjectile(bool),
 
+    /// The item model definition used to render this item, in `assets/<namespace>/items`.
+    /// `minecraft:item_model`
+    ItemModel(String),
+
     /// The default name of this item, as a JSON text component. See [Raw JSON text format](https://minecraft.wiki/w/Raw_JSON_text_format). Unlike the [custom_name](https://minecraft.wiki/w/Data_component_format#custom_name) component, this name cannot be changed through an anvil, and does not show in some labels, such as banner markers and item frames.  
     /// `minecraft:item_name`
     ItemName(String),
@@ -722,6 +958,10 @@ pub enum Component {
     /// `minecraft:recipes`
     Recipes(Vec<String>),
 
+    /// The items that can repair this item in an anvil.
+    /// `minecraft:repairable`
+    Repairable(id_set::IdSet),
+
     /// The number of experience levels to add to the base level cost when repairing, combining, or renaming this item with an anvil. Must be a non-negative integer, defaults to 0.  
     /// `minecraft:repair_cost`
     RepairCost(i32),
@@ -738,6 +978,10 @@ pub enum Component {
     /// `minecraft:tool`
     Tool(tool::Tool),
 
+    /// The tooltip sprites used for this item, in `textures/gui/sprites/tooltip`.
+    /// `minecraft:tooltip_style`
+    TooltipStyle(String),
+
     /// Contains the trim applied to this [armor](https://minecraft.wiki/w/Armor) piece.  
     /// `minecraft:trim`
     Trim(trim::Trim),
@@ -746,6 +990,14 @@ pub enum Component {
     /// `minecraft:unbreakable`
     Unbreakable(unbreakable::Unbreakable),
 
+    /// The cooldown applied after this item has been used.
+    /// `minecraft:use_cooldown`
+    UseCooldown(use_cooldown::UseCooldown),
+
+    /// The item this item turns into when it has been used up, like a bowl after eating stew.
+    /// `minecraft:use_remainder`
+    UseRemainder(ItemWithNoSlot),
+
     /// The contents of this [book and quill](https://minecraft.wiki/w/Book_and_quill).  
     /// `minecraft:writable_book_content`
     WritableBookContent(writable_book_content::WritableBookContent),
@@ -758,3 +1010,123 @@ pub enum Component {
     #[cfg_attr(feature = "serde", serde(skip))]
     Unknown(simdnbt::owned::NbtTag),
 }
+
+#[cfg(test)]
+#[test]
+fn components_1_21_2_test() {
+    use crate::{
+        util::write_nbt,
+        version::{with_version, DataVersion},
+    };
+
+    let item = crate::snbt::parse(
+        r##"{
+            id: "minecraft:golden_apple",
+            count: 1,
+            components: {
+                "minecraft:consumable": {
+                    consume_seconds: 2.0f,
+                    animation: "drink",
+                    sound: "minecraft:entity.generic.drink",
+                    on_consume_effects: [
+                        {type: "minecraft:apply_effects", effects: [{id: "minecraft:regeneration", duration: 100}]},
+                        {type: "minecraft:remove_effects", effects: ["minecraft:poison", "minecraft:wither"]},
+                        {type: "minecraft:clear_all_effects"}
+                    ]
+                },
+                "minecraft:equippable": {slot: "head", model: "minecraft:gold", allowed_entities: "#minecraft:skeletons"},
+                "minecraft:use_cooldown": {seconds: 1.5f, cooldown_group: "minecraft:apples"},
+                "minecraft:use_remainder": {id: "minecraft:bowl", count: 1},
+                "minecraft:item_model": "minecraft:apple",
+                "minecraft:tooltip_style": "minecraft:gold",
+                "minecraft:enchantable": {value: 10},
+                "minecraft:glider": {},
+                "minecraft:damage_resistant": {types: "#minecraft:is_fire"},
+                "minecraft:repairable": {items: "minecraft:gold_ingot"}
+            }
+        }"##,
+    )
+    .unwrap();
+    let bytes = write_nbt(&item);
+    let base = simdnbt::borrow::read(&mut std::io::Cursor::new(bytes.as_slice()))
+        .unwrap()
+        .unwrap();
+    let item = with_version(Some(DataVersion::V1_21_2), || {
+        ItemWithNoSlot::from_compound_nbt(&base.as_compound())
+    })
+    .unwrap();
+    let components = item.components.unwrap();
+
+    assert!(!components
+        .values()
+        .any(|component| matches!(component, Component::Unknown(_))));
+    match components.get("minecraft:consumable") {
+        Some(Component::Consumable(consumable)) => {
+            assert_eq!(consumable.animation, "drink");
+            assert!(consumable.has_consume_particles);
+            assert_eq!(consumable.on_consume_effects.len(), 3);
+            assert_eq!(
+                consumable.on_consume_effects[1],
+                consumable::ConsumeEffect::RemoveEffects(id_set::IdSet::Multiple(vec![
+                    "minecraft:poison".into(),
+                    "minecraft:wither".into()
+                ]))
+            );
+        }
+        other => panic!("{other:?}"),
+    }
+    match components.get("minecraft:equippable") {
+        Some(Component::Equippable(equippable)) => {
+            assert_eq!(equippable.asset_id.as_deref(), Some("minecraft:gold"));
+            assert!(equippable.swappable);
+        }
+        other => panic!("{other:?}"),
+    }
+    assert_eq!(
+        components.get("minecraft:enchantable"),
+        Some(&Component::Enchantable(10))
+    );
+
+    // Defaults are written out, and the equippable model is written as `asset_id`.
+    let written = ItemWithNoSlot {
+        id: item.id,
+        count: item.count,
+        components: Some(components.clone()),
+    };
+    let bytes = write_nbt(&written.to_compound_nbt());
+    let base = simdnbt::borrow::read(&mut std::io::Cursor::new(bytes.as_slice()))
+        .unwrap()
+        .unwrap();
+    let read = with_version(Some(DataVersion::V1_21_4), || {
+        ItemWithNoSlot::from_compound_nbt(&base.as_compound())
+    })
+    .unwrap();
+    assert_eq!(read.components, Some(components.clone()));
+
+    // Written for 1.21.2, the model goes back in `model`.
+    let bytes = with_version(Some(DataVersion::V1_21_2), || {
+        write_nbt(&written.to_compound_nbt())
+    });
+    let base = simdnbt::borrow::read(&mut std::io::Cursor::new(bytes.as_slice()))
+        .unwrap()
+        .unwrap();
+    let equippable = base
+        .as_compound()
+        .compound("components")
+        .and_then(|components| components.compound("minecraft:equippable"))
+        .unwrap();
+    assert_eq!(
+        equippable.string("model").map(|s| s.to_str()).as_deref(),
+        Some("minecraft:gold")
+    );
+    assert!(equippable.string("asset_id").is_none());
+
+    // A borrowed item parses its components later with the version it was read with.
+    let borrowed = with_version(Some(DataVersion::V1_21_2), || {
+        <crate::borrowed::ItemWithNoSlot as crate::traits::FromBorrowedCompoundNbt>::from_borrowed_compound_nbt(
+            &base.as_compound(),
+        )
+    })
+    .unwrap();
+    assert_eq!(borrowed.components().unwrap(), Some(components));
+}
@@ -2,9 +2,10 @@
 
 use crate::{
     color::RGB,
-    traits::FromCompoundNbt,
-    util::{get_owned_optional_string, get_t_compound_vec},
+    traits::{FromCompoundNbt, ToCompoundNbt},
+    util::{compound_list, get_owned_optional_string, get_t_compound_vec, insert_optional},
 };
+use simdnbt::owned::{NbtCompound, NbtTag};
 
 use super::food::EffectDetails;
 
@@ -76,3 +77,31 @@ impl FromCompoundNbt for PotionData {
         })
     }
 }
+
+impl PotionContents {
+    /// Converts the component into its Nbt value, either a string or a compound.
+    pub fn to_nbt_tag(&self) -> NbtTag {
+        match self {
+            PotionContents::String(potion) => NbtTag::String(potion.as_str().into()),
+            PotionContents::Compound(data) => NbtTag::Compound(data.to_compound_nbt()),
+        }
+    }
+}
+
+impl ToCompoundNbt for PotionData {
+    fn to_compound_nbt(&self) -> NbtCompound {
+        let mut nbt = NbtCompound::new();
+
+        insert_optional(&mut nbt, "potion", self.potion.clone());
+        insert_optional(
+            &mut nbt,
+            "custom_color",
+            self.custom_color.clone().map(i32::from),
+        );
+        if !self.custom_effects.is_empty() {
+            nbt.insert("custom_effects", compound_list(&self.custom_effects));
+        }
+
+        nbt
+    }
+}
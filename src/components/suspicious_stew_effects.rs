@@ -1,6 +1,9 @@
 //! The effects of a suspicious stew.
 
-use crate::{traits::FromCompoundNbt, util::get_owned_string};
+use crate::{
+    traits::{FromCompoundNbt, ToCompoundNbt},
+    util::get_owned_string,
+};
 
 #[cfg(feature = "serde")]
 fn default_duration() -> i32 {
@@ -32,3 +35,14 @@ impl FromCompoundNbt for SuspiciousStewEffects {
         Ok(SuspiciousStewEffects { id, duration })
     }
 }
+
+impl ToCompoundNbt for SuspiciousStewEffects {
+    fn to_compound_nbt(&self) -> simdnbt::owned::NbtCompound {
+        let mut nbt = simdnbt::owned::NbtCompound::new();
+
+        nbt.insert("id", self.id.as_str());
+        nbt.insert("duration", self.duration);
+
+        nbt
+    }
+}
@@ -1,6 +1,11 @@
 //! Tool component.
 
-use crate::{error::SculkParseError, traits::FromCompoundNbt};
+use crate::{
+    error::SculkParseError,
+    traits::{FromCompoundNbt, ToCompoundNbt},
+    util::{compound_list, insert_optional, string_list},
+};
+use simdnbt::owned::NbtCompound;
 
 #[cfg(feature = "serde")]
 fn default_f32() -> f32 {
@@ -124,3 +129,30 @@ impl FromCompoundNbt for ToolRules {
         })
     }
 }
+
+impl ToCompoundNbt for Tool {
+    fn to_compound_nbt(&self) -> NbtCompound {
+        let mut nbt = NbtCompound::new();
+
+        nbt.insert("default_mining_speed", self.default_mining_speed);
+        nbt.insert("damage_per_block", self.damage_per_block);
+        nbt.insert("rules", compound_list(&self.rules));
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for ToolRules {
+    fn to_compound_nbt(&self) -> NbtCompound {
+        let mut nbt = NbtCompound::new();
+
+        match &self.blocks {
+            ToolRulesBlocks::Single(block) => nbt.insert("blocks", block.as_str()),
+            ToolRulesBlocks::Multiple(blocks) => nbt.insert("blocks", string_list(blocks)),
+        }
+        insert_optional(&mut nbt, "speed", self.speed);
+        insert_optional(&mut nbt, "correct_for_drops", self.correct_for_drops);
+
+        nbt
+    }
+}
@@ -1,6 +1,9 @@
 //! Armor Trim component.
 
-use crate::{traits::FromCompoundNbt, util::get_owned_string};
+use crate::{
+    traits::{FromCompoundNbt, ToCompoundNbt},
+    util::get_owned_string,
+};
 
 /// An armor trim pattern.
 #[derive(Debug, Clone, PartialEq)]
@@ -36,3 +39,15 @@ impl FromCompoundNbt for Trim {
         })
     }
 }
+
+impl ToCompoundNbt for Trim {
+    fn to_compound_nbt(&self) -> simdnbt::owned::NbtCompound {
+        let mut nbt = simdnbt::owned::NbtCompound::new();
+
+        nbt.insert("pattern", self.pattern.as_str());
+        nbt.insert("material", self.material.as_str());
+        nbt.insert("show_in_tooltip", self.show_in_tooltip);
+
+        nbt
+    }
+}
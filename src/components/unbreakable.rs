@@ -1,6 +1,6 @@
 //! Unbreakable component.
 
-use crate::traits::FromCompoundNbt;
+use crate::traits::{FromCompoundNbt, ToCompoundNbt};
 
 /// funny silly small struct
 #[derive(Clone, Debug, PartialEq)]
@@ -23,3 +23,13 @@ impl FromCompoundNbt for Unbreakable {
         })
     }
 }
+
+impl ToCompoundNbt for Unbreakable {
+    fn to_compound_nbt(&self) -> simdnbt::owned::NbtCompound {
+        let mut nbt = simdnbt::owned::NbtCompound::new();
+
+        nbt.insert("show_in_tooltip", self.show_in_tooltip);
+
+        nbt
+    }
+}
@@ -0,0 +1,47 @@
+//! Use cooldown component. Since 1.21.2 this puts an item on cooldown after it has been used.
+
+use crate::{
+    error::SculkParseError,
+    traits::{FromCompoundNbt, ToCompoundNbt},
+    util::{get_owned_optional_string, insert_optional},
+};
+use simdnbt::owned::NbtCompound;
+
+/// The use cooldown component.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct UseCooldown {
+    /// The cooldown in seconds.
+    pub seconds: f32,
+
+    /// Items with the same group share their cooldown. Optional, defaults to the id of the item.
+    pub cooldown_group: Option<String>,
+}
+
+impl FromCompoundNbt for UseCooldown {
+    fn from_compound_nbt(nbt: &simdnbt::borrow::NbtCompound) -> Result<Self, SculkParseError>
+    where
+        Self: Sized,
+    {
+        let seconds = nbt
+            .float("seconds")
+            .ok_or(SculkParseError::MissingField("seconds".into()))?;
+        let cooldown_group = get_owned_optional_string(nbt, "cooldown_group");
+
+        Ok(UseCooldown {
+            seconds,
+            cooldown_group,
+        })
+    }
+}
+
+impl ToCompoundNbt for UseCooldown {
+    fn to_compound_nbt(&self) -> NbtCompound {
+        let mut nbt = NbtCompound::new();
+
+        nbt.insert("seconds", self.seconds);
+        insert_optional(&mut nbt, "cooldown_group", self.cooldown_group.clone());
+
+        nbt
+    }
+}
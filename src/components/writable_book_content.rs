@@ -2,9 +2,10 @@
 
 use crate::{
     error::SculkParseError,
-    traits::FromCompoundNbt,
-    util::{get_owned_optional_string, get_owned_string},
+    traits::{FromCompoundNbt, ToCompoundNbt},
+    util::{compound_list, get_owned_optional_string, get_owned_string, insert_optional},
 };
+use simdnbt::owned::NbtCompound;
 
 /// A book that can be written in-game.
 #[derive(Debug, Clone, PartialEq)]
@@ -87,3 +88,100 @@ impl FromCompoundNbt for BookTextData {
         Ok(BookTextData { raw, filtered })
     }
 }
+
+impl ToCompoundNbt for WritableBookContent {
+    fn to_compound_nbt(&self) -> NbtCompound {
+        let mut nbt = NbtCompound::new();
+
+        self.pages.insert_into(&mut nbt);
+
+        nbt
+    }
+}
+
+impl PageType {
+    /// Inserts the pages into the given compound under the `pages` key.
+    pub(crate) fn insert_into(&self, nbt: &mut NbtCompound) {
+        match self {
+            PageType::Single(page) => nbt.insert("pages", page.as_str()),
+            PageType::Multiple(pages) => nbt.insert("pages", compound_list(pages)),
+        }
+    }
+}
+
+#[cfg(feature = "text")]
+impl PageType {
+    /// Returns every page as a plain text component, the way writable book pages are stored.
+    pub fn text_components(&self) -> Vec<crate::text::TextComponent> {
+        match self {
+            PageType::Single(page) => vec![crate::text::TextComponent::text(page.as_str())],
+            PageType::Multiple(pages) => pages.iter().map(BookTextData::text).collect(),
+        }
+    }
+
+    /// Parses every page as a JSON text component, the way written book pages are stored.
+    /// Pages that aren't valid JSON text are kept as plain text.
+    pub fn json_components(&self) -> Vec<crate::text::TextComponent> {
+        match self {
+            PageType::Single(page) => vec![crate::text::TextComponent::parse(page)],
+            PageType::Multiple(pages) => pages.iter().map(BookTextData::json).collect(),
+        }
+    }
+}
+
+#[cfg(feature = "text")]
+impl BookTextData {
+    /// The raw text as a plain text component, used for writable book pages and book titles.
+    pub fn text(&self) -> crate::text::TextComponent {
+        crate::text::TextComponent::text(self.raw.as_str())
+    }
+
+    /// The filtered text as a plain text component.
+    pub fn filtered_text(&self) -> Option<crate::text::TextComponent> {
+        self.filtered
+            .as_deref()
+            .map(crate::text::TextComponent::text)
+    }
+
+    /// Parses the raw text as a JSON text component, used for written book pages.
+    pub fn json(&self) -> crate::text::TextComponent {
+        crate::text::TextComponent::parse(&self.raw)
+    }
+
+    /// Parses the filtered text as a JSON text component.
+    pub fn filtered_json(&self) -> Option<crate::text::TextComponent> {
+        self.filtered
+            .as_deref()
+            .map(crate::text::TextComponent::parse)
+    }
+}
+
+impl ToCompoundNbt for BookTextData {
+    fn to_compound_nbt(&self) -> NbtCompound {
+        let mut nbt = NbtCompound::new();
+
+        nbt.insert("raw", self.raw.as_str());
+        insert_optional(&mut nbt, "filtered", self.filtered.clone());
+
+        nbt
+    }
+}
+
+#[cfg(all(test, feature = "text"))]
+#[test]
+fn book_text_test() {
+    use crate::text::TextComponent;
+
+    let page = BookTextData {
+        raw: r#"{"text":"not json"}"#.into(),
+        filtered: None,
+    };
+    let pages = PageType::Multiple(vec![page.clone()]);
+
+    assert_eq!(page.text(), TextComponent::text(r#"{"text":"not json"}"#));
+    assert_eq!(pages.text_components(), vec![page.text()]);
+    assert_eq!(
+        pages.json_components(),
+        vec![TextComponent::text("not json")]
+    );
+}
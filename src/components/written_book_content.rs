@@ -1,7 +1,11 @@
 //! Written book content component.
 
 use super::writable_book_content::{BookTextData, PageType};
-use crate::{error::SculkParseError, traits::FromCompoundNbt, util::get_owned_string};
+use crate::{
+    error::SculkParseError,
+    traits::{FromCompoundNbt, ToCompoundNbt},
+    util::get_owned_string,
+};
 
 /// The content of a written book.
 #[derive(Debug, Clone, PartialEq)]
@@ -53,3 +57,30 @@ impl FromCompoundNbt for WrittenBookContent {
         })
     }
 }
+
+#[cfg(feature = "text")]
+impl WrittenBookContent {
+    /// Parses every page as a JSON text component.
+    pub fn page_components(&self) -> Vec<crate::text::TextComponent> {
+        self.pages.json_components()
+    }
+
+    /// The title as a plain text component, titles aren't JSON text.
+    pub fn title_component(&self) -> crate::text::TextComponent {
+        self.title.text()
+    }
+}
+
+impl ToCompoundNbt for WrittenBookContent {
+    fn to_compound_nbt(&self) -> simdnbt::owned::NbtCompound {
+        let mut nbt = simdnbt::owned::NbtCompound::new();
+
+        self.pages.insert_into(&mut nbt);
+        nbt.insert("title", self.title.to_compound_nbt());
+        nbt.insert("author", self.author.as_str());
+        nbt.insert("generation", self.generation);
+        nbt.insert("resolved", self.resolved);
+
+        nbt
+    }
+}
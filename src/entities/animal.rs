@@ -0,0 +1,348 @@
+//! Breedable and tameable mobs.
+
+use crate::{
+    error::SculkParseError,
+    item::ItemWithNoSlot,
+    traits::{FromCompoundNbt, ToCompoundNbt},
+    util::{get_bool, insert_optional, insert_unknown_tags},
+    uuid::Uuid,
+};
+use simdnbt::owned;
+
+use super::{get_optional_item, mob::Mob, take_unknown_tags};
+
+/// The tags parsed into [`Animal`].
+const ANIMAL_KEYS: [&str; 4] = ["Age", "ForcedAge", "InLove", "LoveCause"];
+
+/// The tags parsed into [`Sheep`].
+const SHEEP_KEYS: [&str; 2] = ["Color", "Sheared"];
+
+/// The tags parsed into [`Chicken`].
+const CHICKEN_KEYS: [&str; 2] = ["EggLayTime", "IsChickenJockey"];
+
+/// The tags parsed into [`Tameable`].
+const TAMEABLE_KEYS: [&str; 4] = ["Owner", "Sitting", "CollarColor", "variant"];
+
+/// The tags parsed into [`Horse`].
+const HORSE_KEYS: [&str; 7] = [
+    "Bred",
+    "EatingHaystack",
+    "Tame",
+    "Temper",
+    "Owner",
+    "SaddleItem",
+    "Variant",
+];
+
+/// A mob that can grow up and breed.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Animal {
+    /// The common mob tags.
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub mob: Mob,
+
+    /// The age of the mob in ticks. Negative for babies, positive values are the cooldown before it can breed again.
+    /// `Age`
+    pub age: i32,
+
+    /// A value of age which is assigned to this mob when it grows up. Incremented when a baby is fed.
+    /// `ForcedAge`
+    pub forced_age: Option<i32>,
+
+    /// Number of ticks until the mob loses its breeding hearts.
+    /// `InLove`
+    pub in_love: Option<i32>,
+
+    /// The player that made this mob breed.
+    /// `LoveCause`
+    pub love_cause: Option<Uuid>,
+
+    /// The tags of the entity that aren't parsed, written back as is.
+    /// Left empty when this is embedded in another kind, which keeps them itself.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub unknown_tags: owned::NbtCompound,
+}
+
+/// `minecraft:sheep`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Sheep {
+    /// The common animal tags.
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub animal: Animal,
+
+    /// The color of the wool, as a dye color id.
+    /// `Color`
+    pub color: i8,
+
+    /// true if the sheep has been sheared.
+    /// `Sheared`
+    pub sheared: bool,
+
+    /// The tags of the entity that aren't parsed, written back as is.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub unknown_tags: owned::NbtCompound,
+}
+
+/// `minecraft:chicken`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Chicken {
+    /// The common animal tags.
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub animal: Animal,
+
+    /// Number of ticks until the chicken lays an egg.
+    /// `EggLayTime`
+    pub egg_lay_time: i32,
+
+    /// true if the chicken is a jockey for a baby zombie, which allows it to despawn.
+    /// `IsChickenJockey`
+    pub is_chicken_jockey: bool,
+
+    /// The tags of the entity that aren't parsed, written back as is.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub unknown_tags: owned::NbtCompound,
+}
+
+/// A mob that can be tamed and ordered to sit, like wolves, cats and parrots.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Tameable {
+    /// The common animal tags.
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub animal: Animal,
+
+    /// The player that tamed the mob.
+    /// `Owner`
+    pub owner: Option<Uuid>,
+
+    /// true if the mob is sitting.
+    /// `Sitting`
+    pub sitting: bool,
+
+    /// The color of the collar, as a dye color id. Not used by parrots.
+    /// `CollarColor`
+    pub collar_color: Option<i8>,
+
+    /// The variant of the mob, a resource location for wolves and cats, an int for parrots.
+    /// `variant`
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub variant: Option<owned::NbtTag>,
+
+    /// The tags of the entity that aren't parsed, written back as is.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub unknown_tags: owned::NbtCompound,
+}
+
+/// A horse-like mob, horses, donkeys, mules, llamas and camels.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Horse {
+    /// The common animal tags.
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub animal: Animal,
+
+    /// true if the horse has been bred.
+    /// `Bred`
+    pub bred: bool,
+
+    /// true if the horse is grazing.
+    /// `EatingHaystack`
+    pub eating_haystack: bool,
+
+    /// true if the horse is tamed.
+    /// `Tame`
+    pub tame: bool,
+
+    /// Higher values make the horse easier to tame, increased by feeding.
+    /// `Temper`
+    pub temper: i32,
+
+    /// The player that tamed the horse.
+    /// `Owner`
+    pub owner: Option<Uuid>,
+
+    /// The saddle the horse is wearing.
+    /// `SaddleItem`
+    pub saddle_item: Option<ItemWithNoSlot>,
+
+    /// The coat color and markings of a horse.
+    /// `Variant`
+    pub variant: Option<i32>,
+
+    /// The tags of the entity that aren't parsed, written back as is.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub unknown_tags: owned::NbtCompound,
+}
+
+impl FromCompoundNbt for Animal {
+    fn from_compound_nbt(nbt: &simdnbt::borrow::NbtCompound) -> Result<Self, SculkParseError>
+    where
+        Self: Sized,
+    {
+        let mut mob = Mob::from_compound_nbt(nbt)?;
+        let unknown_tags = take_unknown_tags(&mut mob.unknown_tags, &ANIMAL_KEYS);
+
+        Ok(Animal {
+            mob,
+            age: nbt.int("Age").unwrap_or(0),
+            forced_age: nbt.int("ForcedAge"),
+            in_love: nbt.int("InLove"),
+            love_cause: Uuid::from_int_array(nbt, "LoveCause")?,
+            unknown_tags,
+        })
+    }
+}
+
+impl FromCompoundNbt for Sheep {
+    fn from_compound_nbt(nbt: &simdnbt::borrow::NbtCompound) -> Result<Self, SculkParseError>
+    where
+        Self: Sized,
+    {
+        let mut animal = Animal::from_compound_nbt(nbt)?;
+        let unknown_tags = take_unknown_tags(&mut animal.unknown_tags, &SHEEP_KEYS);
+
+        Ok(Sheep {
+            animal,
+            color: nbt.byte("Color").unwrap_or(0),
+            sheared: get_bool(nbt, "Sheared"),
+            unknown_tags,
+        })
+    }
+}
+
+impl FromCompoundNbt for Chicken {
+    fn from_compound_nbt(nbt: &simdnbt::borrow::NbtCompound) -> Result<Self, SculkParseError>
+    where
+        Self: Sized,
+    {
+        let mut animal = Animal::from_compound_nbt(nbt)?;
+        let unknown_tags = take_unknown_tags(&mut animal.unknown_tags, &CHICKEN_KEYS);
+
+        Ok(Chicken {
+            animal,
+            egg_lay_time: nbt.int("EggLayTime").unwrap_or(0),
+            is_chicken_jockey: get_bool(nbt, "IsChickenJockey"),
+            unknown_tags,
+        })
+    }
+}
+
+impl FromCompoundNbt for Tameable {
+    fn from_compound_nbt(nbt: &simdnbt::borrow::NbtCompound) -> Result<Self, SculkParseError>
+    where
+        Self: Sized,
+    {
+        let mut animal = Animal::from_compound_nbt(nbt)?;
+        let unknown_tags = take_unknown_tags(&mut animal.unknown_tags, &TAMEABLE_KEYS);
+
+        Ok(Tameable {
+            animal,
+            owner: Uuid::from_int_array(nbt, "Owner")?,
+            sitting: get_bool(nbt, "Sitting"),
+            collar_color: nbt.byte("CollarColor"),
+            variant: nbt.get("variant").map(|tag| tag.to_owned()),
+            unknown_tags,
+        })
+    }
+}
+
+impl FromCompoundNbt for Horse {
+    fn from_compound_nbt(nbt: &simdnbt::borrow::NbtCompound) -> Result<Self, SculkParseError>
+    where
+        Self: Sized,
+    {
+        let mut animal = Animal::from_compound_nbt(nbt)?;
+        let unknown_tags = take_unknown_tags(&mut animal.unknown_tags, &HORSE_KEYS);
+
+        Ok(Horse {
+            animal,
+            bred: get_bool(nbt, "Bred"),
+            eating_haystack: get_bool(nbt, "EatingHaystack"),
+            tame: get_bool(nbt, "Tame"),
+            temper: nbt.int("Temper").unwrap_or(0),
+            owner: Uuid::from_int_array(nbt, "Owner")?,
+            saddle_item: get_optional_item(nbt, "SaddleItem")?,
+            variant: nbt.int("Variant"),
+            unknown_tags,
+        })
+    }
+}
+
+impl ToCompoundNbt for Animal {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = self.mob.to_compound_nbt();
+
+        nbt.insert("Age", self.age);
+        insert_optional(&mut nbt, "ForcedAge", self.forced_age);
+        insert_optional(&mut nbt, "InLove", self.in_love);
+        insert_optional(&mut nbt, "LoveCause", self.love_cause);
+
+        insert_unknown_tags(&mut nbt, &self.unknown_tags);
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for Sheep {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = self.animal.to_compound_nbt();
+
+        nbt.insert("Color", self.color);
+        nbt.insert("Sheared", self.sheared);
+
+        insert_unknown_tags(&mut nbt, &self.unknown_tags);
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for Chicken {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = self.animal.to_compound_nbt();
+
+        nbt.insert("EggLayTime", self.egg_lay_time);
+        nbt.insert("IsChickenJockey", self.is_chicken_jockey);
+
+        insert_unknown_tags(&mut nbt, &self.unknown_tags);
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for Tameable {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = self.animal.to_compound_nbt();
+
+        insert_optional(&mut nbt, "Owner", self.owner);
+        nbt.insert("Sitting", self.sitting);
+        insert_optional(&mut nbt, "CollarColor", self.collar_color);
+        insert_optional(&mut nbt, "variant", self.variant.clone());
+
+        insert_unknown_tags(&mut nbt, &self.unknown_tags);
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for Horse {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = self.animal.to_compound_nbt();
+
+        nbt.insert("Bred", self.bred);
+        nbt.insert("EatingHaystack", self.eating_haystack);
+        nbt.insert("Tame", self.tame);
+        nbt.insert("Temper", self.temper);
+        insert_optional(&mut nbt, "Owner", self.owner);
+        if let Some(item) = &self.saddle_item {
+            nbt.insert("SaddleItem", item.to_compound_nbt());
+        }
+        insert_optional(&mut nbt, "Variant", self.variant);
+
+        insert_unknown_tags(&mut nbt, &self.unknown_tags);
+
+        nbt
+    }
+}
@@ -0,0 +1,176 @@
+//! Falling blocks and primed TNT.
+
+use crate::{
+    chunk::section::Palette,
+    error::SculkParseError,
+    traits::{FromCompoundNbt, ToCompoundNbt},
+    util::{get_bool, insert_optional, insert_unknown_tags},
+    uuid::Uuid,
+};
+use simdnbt::owned;
+
+use super::get_unknown_entity_tags;
+
+/// The tags parsed into [`FallingBlock`].
+const FALLING_BLOCK_KEYS: [&str; 8] = [
+    "BlockState",
+    "CancelDrop",
+    "DropItem",
+    "FallHurtAmount",
+    "FallHurtMax",
+    "HurtEntities",
+    "TileEntityData",
+    "Time",
+];
+
+/// The tags parsed into [`Tnt`].
+const TNT_KEYS: [&str; 4] = ["fuse", "block_state", "explosion_power", "owner"];
+
+/// `minecraft:falling_block`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FallingBlock {
+    /// The block that is falling.
+    /// `BlockState`
+    pub block_state: Option<Palette>,
+
+    /// true if the block is destroyed instead of placed when it lands.
+    /// `CancelDrop`
+    pub cancel_drop: bool,
+
+    /// true if the block drops as an item when it can't be placed.
+    /// `DropItem`
+    pub drop_item: bool,
+
+    /// The damage dealt per block fallen to entities it lands on.
+    /// `FallHurtAmount`
+    pub fall_hurt_amount: Option<f32>,
+
+    /// The maximum damage dealt to entities it lands on.
+    /// `FallHurtMax`
+    pub fall_hurt_max: Option<i32>,
+
+    /// true if the block damages entities it lands on.
+    /// `HurtEntities`
+    pub hurt_entities: bool,
+
+    /// The block entity data of the block, applied when it lands.
+    /// `TileEntityData`
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub tile_entity_data: Option<owned::NbtCompound>,
+
+    /// Number of ticks the block has been falling for.
+    /// `Time`
+    pub time: i32,
+
+    /// The tags of the entity that aren't parsed, written back as is.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub unknown_tags: owned::NbtCompound,
+}
+
+/// `minecraft:tnt`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Tnt {
+    /// Number of ticks until the TNT explodes.
+    /// `fuse`
+    pub fuse: i16,
+
+    /// The block rendered as the primed TNT.
+    /// `block_state`
+    pub block_state: Option<Palette>,
+
+    /// The power of the explosion.
+    /// `explosion_power`
+    pub explosion_power: Option<f32>,
+
+    /// The entity that ignited the TNT.
+    /// `owner`
+    pub owner: Option<Uuid>,
+
+    /// The tags of the entity that aren't parsed, written back as is.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub unknown_tags: owned::NbtCompound,
+}
+
+impl FromCompoundNbt for FallingBlock {
+    fn from_compound_nbt(nbt: &simdnbt::borrow::NbtCompound) -> Result<Self, SculkParseError>
+    where
+        Self: Sized,
+    {
+        let unknown_tags = get_unknown_entity_tags(nbt, &[&FALLING_BLOCK_KEYS]);
+
+        Ok(FallingBlock {
+            block_state: nbt
+                .compound("BlockState")
+                .map(|nbt| Palette::from_compound_nbt(&nbt))
+                .transpose()?,
+            cancel_drop: get_bool(nbt, "CancelDrop"),
+            drop_item: get_bool(nbt, "DropItem"),
+            fall_hurt_amount: nbt.float("FallHurtAmount"),
+            fall_hurt_max: nbt.int("FallHurtMax"),
+            hurt_entities: get_bool(nbt, "HurtEntities"),
+            tile_entity_data: nbt.compound("TileEntityData").map(|nbt| nbt.to_owned()),
+            time: nbt.int("Time").unwrap_or(0),
+            unknown_tags,
+        })
+    }
+}
+
+impl FromCompoundNbt for Tnt {
+    fn from_compound_nbt(nbt: &simdnbt::borrow::NbtCompound) -> Result<Self, SculkParseError>
+    where
+        Self: Sized,
+    {
+        let unknown_tags = get_unknown_entity_tags(nbt, &[&TNT_KEYS]);
+
+        Ok(Tnt {
+            fuse: nbt.short("fuse").unwrap_or(80),
+            block_state: nbt
+                .compound("block_state")
+                .map(|nbt| Palette::from_compound_nbt(&nbt))
+                .transpose()?,
+            explosion_power: nbt.float("explosion_power"),
+            owner: Uuid::from_int_array(nbt, "owner")?,
+            unknown_tags,
+        })
+    }
+}
+
+impl ToCompoundNbt for FallingBlock {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        if let Some(state) = &self.block_state {
+            nbt.insert("BlockState", state.to_compound_nbt());
+        }
+        nbt.insert("CancelDrop", self.cancel_drop);
+        nbt.insert("DropItem", self.drop_item);
+        insert_optional(&mut nbt, "FallHurtAmount", self.fall_hurt_amount);
+        insert_optional(&mut nbt, "FallHurtMax", self.fall_hurt_max);
+        nbt.insert("HurtEntities", self.hurt_entities);
+        insert_optional(&mut nbt, "TileEntityData", self.tile_entity_data.clone());
+        nbt.insert("Time", self.time);
+
+        insert_unknown_tags(&mut nbt, &self.unknown_tags);
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for Tnt {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        nbt.insert("fuse", self.fuse);
+        if let Some(state) = &self.block_state {
+            nbt.insert("block_state", state.to_compound_nbt());
+        }
+        insert_optional(&mut nbt, "explosion_power", self.explosion_power);
+        insert_optional(&mut nbt, "owner", self.owner);
+
+        insert_unknown_tags(&mut nbt, &self.unknown_tags);
+
+        nbt
+    }
+}
@@ -0,0 +1,260 @@
+//! Item frames, paintings and armor stands.
+
+use crate::{
+    error::SculkParseError,
+    item::ItemWithNoSlot,
+    traits::{FromCompoundNbt, ToCompoundNbt},
+    util::{get_bool, get_owned_optional_string, insert_optional, insert_unknown_tags},
+};
+use simdnbt::owned;
+
+use super::{get_optional_item, get_unknown_entity_tags, mob::Mob, take_unknown_tags};
+
+/// The tags parsed into [`ItemFrame`].
+const ITEM_FRAME_KEYS: [&str; 9] = [
+    "TileX",
+    "TileY",
+    "TileZ",
+    "Facing",
+    "Fixed",
+    "Invisible",
+    "Item",
+    "ItemDropChance",
+    "ItemRotation",
+];
+
+/// The tags parsed into [`Painting`].
+const PAINTING_KEYS: [&str; 5] = ["TileX", "TileY", "TileZ", "facing", "variant"];
+
+/// The tags parsed into [`ArmorStand`].
+const ARMOR_STAND_KEYS: [&str; 7] = [
+    "DisabledSlots",
+    "Invisible",
+    "Marker",
+    "NoBasePlate",
+    "Pose",
+    "ShowArms",
+    "Small",
+];
+
+/// `minecraft:item_frame` and `minecraft:glow_item_frame`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ItemFrame {
+    /// The block the item frame is attached to.
+    /// `TileX`, `TileY` and `TileZ`
+    pub tile: Option<[i32; 3]>,
+
+    /// The direction the item frame is facing, 0 is down, 1 up, 2 north, 3 south, 4 west and 5 east.
+    /// `Facing`
+    pub facing: i8,
+
+    /// true if the item frame can't be broken and its item can't be rotated or removed.
+    /// `Fixed`
+    pub fixed: bool,
+
+    /// true if the item frame itself is invisible.
+    /// `Invisible`
+    pub invisible: bool,
+
+    /// The item in the frame.
+    /// `Item`
+    pub item: Option<ItemWithNoSlot>,
+
+    /// The chance of the item dropping when the frame is broken.
+    /// `ItemDropChance`
+    pub item_drop_chance: Option<f32>,
+
+    /// The number of times the item has been rotated by 45 degrees.
+    /// `ItemRotation`
+    pub item_rotation: i8,
+
+    /// The tags of the entity that aren't parsed, written back as is.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub unknown_tags: owned::NbtCompound,
+}
+
+/// `minecraft:painting`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Painting {
+    /// The block the painting is attached to.
+    /// `TileX`, `TileY` and `TileZ`
+    pub tile: Option<[i32; 3]>,
+
+    /// The direction the painting is facing, 0 is south, 1 west, 2 north and 3 east.
+    /// `facing`
+    pub facing: i8,
+
+    /// The painting variant, like `minecraft:kebab`.
+    /// `variant`
+    pub variant: Option<String>,
+
+    /// The tags of the entity that aren't parsed, written back as is.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub unknown_tags: owned::NbtCompound,
+}
+
+/// `minecraft:armor_stand`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ArmorStand {
+    /// The common living entity tags.
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub mob: Mob,
+
+    /// A bit field of the slots that can't be added to, changed or removed.
+    /// `DisabledSlots`
+    pub disabled_slots: i32,
+
+    /// true if the armor stand is invisible, its items are still visible.
+    /// `Invisible`
+    pub invisible: bool,
+
+    /// true if the armor stand has a tiny hitbox and can't be interacted with.
+    /// `Marker`
+    pub marker: bool,
+
+    /// true if the armor stand has no base plate.
+    /// `NoBasePlate`
+    pub no_base_plate: bool,
+
+    /// The rotation of each body part, `Body`, `Head`, `LeftArm`, `RightArm`, `LeftLeg` and `RightLeg`.
+    /// `Pose`
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub pose: Option<owned::NbtCompound>,
+
+    /// true if the armor stand has arms.
+    /// `ShowArms`
+    pub show_arms: bool,
+
+    /// true if the armor stand is small.
+    /// `Small`
+    pub small: bool,
+
+    /// The tags of the entity that aren't parsed, written back as is.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub unknown_tags: owned::NbtCompound,
+}
+
+impl FromCompoundNbt for ItemFrame {
+    fn from_compound_nbt(nbt: &simdnbt::borrow::NbtCompound) -> Result<Self, SculkParseError>
+    where
+        Self: Sized,
+    {
+        let unknown_tags = get_unknown_entity_tags(nbt, &[&ITEM_FRAME_KEYS]);
+
+        Ok(ItemFrame {
+            tile: get_tile(nbt),
+            facing: nbt.byte("Facing").unwrap_or(0),
+            fixed: get_bool(nbt, "Fixed"),
+            invisible: get_bool(nbt, "Invisible"),
+            item: get_optional_item(nbt, "Item")?,
+            item_drop_chance: nbt.float("ItemDropChance"),
+            item_rotation: nbt.byte("ItemRotation").unwrap_or(0),
+            unknown_tags,
+        })
+    }
+}
+
+impl FromCompoundNbt for Painting {
+    fn from_compound_nbt(nbt: &simdnbt::borrow::NbtCompound) -> Result<Self, SculkParseError>
+    where
+        Self: Sized,
+    {
+        let unknown_tags = get_unknown_entity_tags(nbt, &[&PAINTING_KEYS]);
+
+        Ok(Painting {
+            tile: get_tile(nbt),
+            facing: nbt.byte("facing").unwrap_or(0),
+            variant: get_owned_optional_string(nbt, "variant"),
+            unknown_tags,
+        })
+    }
+}
+
+impl FromCompoundNbt for ArmorStand {
+    fn from_compound_nbt(nbt: &simdnbt::borrow::NbtCompound) -> Result<Self, SculkParseError>
+    where
+        Self: Sized,
+    {
+        let mut mob = Mob::from_compound_nbt(nbt)?;
+        let unknown_tags = take_unknown_tags(&mut mob.unknown_tags, &ARMOR_STAND_KEYS);
+
+        Ok(ArmorStand {
+            mob,
+            disabled_slots: nbt.int("DisabledSlots").unwrap_or(0),
+            invisible: get_bool(nbt, "Invisible"),
+            marker: get_bool(nbt, "Marker"),
+            no_base_plate: get_bool(nbt, "NoBasePlate"),
+            pose: nbt.compound("Pose").map(|pose| pose.to_owned()),
+            show_arms: get_bool(nbt, "ShowArms"),
+            small: get_bool(nbt, "Small"),
+            unknown_tags,
+        })
+    }
+}
+
+impl ToCompoundNbt for ItemFrame {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        insert_tile(&mut nbt, self.tile);
+        nbt.insert("Facing", self.facing);
+        nbt.insert("Fixed", self.fixed);
+        nbt.insert("Invisible", self.invisible);
+        if let Some(item) = &self.item {
+            nbt.insert("Item", item.to_compound_nbt());
+        }
+        insert_optional(&mut nbt, "ItemDropChance", self.item_drop_chance);
+        nbt.insert("ItemRotation", self.item_rotation);
+
+        insert_unknown_tags(&mut nbt, &self.unknown_tags);
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for Painting {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        insert_tile(&mut nbt, self.tile);
+        nbt.insert("facing", self.facing);
+        insert_optional(&mut nbt, "variant", self.variant.clone());
+
+        insert_unknown_tags(&mut nbt, &self.unknown_tags);
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for ArmorStand {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = self.mob.to_compound_nbt();
+
+        nbt.insert("DisabledSlots", self.disabled_slots);
+        nbt.insert("Invisible", self.invisible);
+        nbt.insert("Marker", self.marker);
+        nbt.insert("NoBasePlate", self.no_base_plate);
+        insert_optional(&mut nbt, "Pose", self.pose.clone());
+        nbt.insert("ShowArms", self.show_arms);
+        nbt.insert("Small", self.small);
+
+        insert_unknown_tags(&mut nbt, &self.unknown_tags);
+
+        nbt
+    }
+}
+
+fn get_tile(nbt: &simdnbt::borrow::NbtCompound) -> Option<[i32; 3]> {
+    Some([nbt.int("TileX")?, nbt.int("TileY")?, nbt.int("TileZ")?])
+}
+
+fn insert_tile(nbt: &mut owned::NbtCompound, tile: Option<[i32; 3]>) {
+    if let Some([x, y, z]) = tile {
+        nbt.insert("TileX", x);
+        nbt.insert("TileY", y);
+        nbt.insert("TileZ", z);
+    }
+}
@@ -0,0 +1,381 @@
+//! Block, item and text display entities.
+
+use crate::{
+    chunk::section::Palette,
+    error::SculkParseError,
+    item::ItemWithNoSlot,
+    traits::{FromCompoundNbt, ToCompoundNbt},
+    util::{get_bool, get_owned_optional_string, insert_optional, insert_unknown_tags},
+};
+use simdnbt::owned;
+
+use super::{get_optional_item, get_unknown_entity_tags};
+
+/// The tags parsed into [`Display`].
+const DISPLAY_KEYS: [&str; 12] = [
+    "billboard",
+    "brightness",
+    "glow_color_override",
+    "height",
+    "width",
+    "interpolation_duration",
+    "start_interpolation",
+    "teleport_duration",
+    "shadow_radius",
+    "shadow_strength",
+    "view_range",
+    "transformation",
+];
+
+/// The tags parsed into [`BlockDisplay`].
+const BLOCK_DISPLAY_KEYS: [&str; 1] = ["block_state"];
+
+/// The tags parsed into [`ItemDisplay`].
+const ITEM_DISPLAY_KEYS: [&str; 2] = ["item", "item_display"];
+
+/// The tags parsed into [`TextDisplay`].
+const TEXT_DISPLAY_KEYS: [&str; 8] = [
+    "text",
+    "alignment",
+    "background",
+    "default_background",
+    "line_width",
+    "see_through",
+    "shadow",
+    "text_opacity",
+];
+
+/// The tags shared by every display entity.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Display {
+    /// How the display rotates to face the player, `fixed`, `vertical`, `horizontal` or `center`.
+    /// `billboard`
+    pub billboard: Option<String>,
+
+    /// Overrides the light levels used to render the display.
+    /// `brightness`
+    pub brightness: Option<Brightness>,
+
+    /// Overrides the color of the glowing outline, -1 to use the team color.
+    /// `glow_color_override`
+    pub glow_color_override: Option<i32>,
+
+    /// The height of the culling box, 0 disables culling.
+    /// `height`
+    pub height: Option<f32>,
+
+    /// The width of the culling box, 0 disables culling.
+    /// `width`
+    pub width: Option<f32>,
+
+    /// Number of ticks the interpolation to a new transformation takes.
+    /// `interpolation_duration`
+    pub interpolation_duration: Option<i32>,
+
+    /// The delay in ticks before the interpolation starts.
+    /// `start_interpolation`
+    pub start_interpolation: Option<i32>,
+
+    /// Number of ticks the interpolation to a new position takes.
+    /// `teleport_duration`
+    pub teleport_duration: Option<i32>,
+
+    /// The size of the shadow.
+    /// `shadow_radius`
+    pub shadow_radius: Option<f32>,
+
+    /// The opacity of the shadow.
+    /// `shadow_strength`
+    pub shadow_strength: Option<f32>,
+
+    /// The maximum render distance, multiplied by 64 blocks.
+    /// `view_range`
+    pub view_range: Option<f32>,
+
+    /// The transformation applied to the display, either a compound of translation, rotations and scale or a list of 16 floats forming a matrix.
+    /// `transformation`
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub transformation: Option<owned::NbtTag>,
+}
+
+/// The light levels used to render a display entity.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Brightness {
+    /// The block light level, from 0 to 15.
+    /// `block`
+    pub block: i32,
+
+    /// The sky light level, from 0 to 15.
+    /// `sky`
+    pub sky: i32,
+}
+
+/// `minecraft:block_display`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BlockDisplay {
+    /// The common display tags.
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub display: Display,
+
+    /// The block being displayed.
+    /// `block_state`
+    pub block_state: Option<Palette>,
+
+    /// The tags of the entity that aren't parsed, written back as is.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub unknown_tags: owned::NbtCompound,
+}
+
+/// `minecraft:item_display`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ItemDisplay {
+    /// The common display tags.
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub display: Display,
+
+    /// The item being displayed.
+    /// `item`
+    pub item: Option<ItemWithNoSlot>,
+
+    /// The model transform used to render the item, like `none`, `head` or `gui`.
+    /// `item_display`
+    pub item_display: Option<String>,
+
+    /// The tags of the entity that aren't parsed, written back as is.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub unknown_tags: owned::NbtCompound,
+}
+
+/// `minecraft:text_display`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TextDisplay {
+    /// The common display tags.
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub display: Display,
+
+    /// The JSON text component being displayed.
+    /// `text`
+    pub text: Option<String>,
+
+    /// The alignment of the text, `center`, `left` or `right`.
+    /// `alignment`
+    pub alignment: Option<String>,
+
+    /// The ARGB color of the background.
+    /// `background`
+    pub background: Option<i32>,
+
+    /// true if the default background color is used.
+    /// `default_background`
+    pub default_background: bool,
+
+    /// The maximum width of a line before it wraps.
+    /// `line_width`
+    pub line_width: Option<i32>,
+
+    /// true if the text is visible through blocks.
+    /// `see_through`
+    pub see_through: bool,
+
+    /// true if the text has a shadow.
+    /// `shadow`
+    pub shadow: bool,
+
+    /// The opacity of the text, -1 is fully opaque.
+    /// `text_opacity`
+    pub text_opacity: Option<i8>,
+
+    /// The tags of the entity that aren't parsed, written back as is.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub unknown_tags: owned::NbtCompound,
+}
+
+impl FromCompoundNbt for Display {
+    fn from_compound_nbt(nbt: &simdnbt::borrow::NbtCompound) -> Result<Self, SculkParseError>
+    where
+        Self: Sized,
+    {
+        let brightness = nbt
+            .compound("brightness")
+            .map(|nbt| Brightness::from_compound_nbt(&nbt))
+            .transpose()?;
+
+        Ok(Display {
+            billboard: get_owned_optional_string(nbt, "billboard"),
+            brightness,
+            glow_color_override: nbt.int("glow_color_override"),
+            height: nbt.float("height"),
+            width: nbt.float("width"),
+            interpolation_duration: nbt.int("interpolation_duration"),
+            start_interpolation: nbt.int("start_interpolation"),
+            teleport_duration: nbt.int("teleport_duration"),
+            shadow_radius: nbt.float("shadow_radius"),
+            shadow_strength: nbt.float("shadow_strength"),
+            view_range: nbt.float("view_range"),
+            transformation: nbt.get("transformation").map(|tag| tag.to_owned()),
+        })
+    }
+}
+
+impl FromCompoundNbt for Brightness {
+    fn from_compound_nbt(nbt: &simdnbt::borrow::NbtCompound) -> Result<Self, SculkParseError>
+    where
+        Self: Sized,
+    {
+        let block = nbt
+            .int("block")
+            .ok_or(SculkParseError::MissingField("block".into()))?;
+        let sky = nbt
+            .int("sky")
+            .ok_or(SculkParseError::MissingField("sky".into()))?;
+
+        Ok(Brightness { block, sky })
+    }
+}
+
+impl FromCompoundNbt for BlockDisplay {
+    fn from_compound_nbt(nbt: &simdnbt::borrow::NbtCompound) -> Result<Self, SculkParseError>
+    where
+        Self: Sized,
+    {
+        let unknown_tags = get_unknown_entity_tags(nbt, &[&DISPLAY_KEYS, &BLOCK_DISPLAY_KEYS]);
+
+        Ok(BlockDisplay {
+            display: Display::from_compound_nbt(nbt)?,
+            block_state: nbt
+                .compound("block_state")
+                .map(|nbt| Palette::from_compound_nbt(&nbt))
+                .transpose()?,
+            unknown_tags,
+        })
+    }
+}
+
+impl FromCompoundNbt for ItemDisplay {
+    fn from_compound_nbt(nbt: &simdnbt::borrow::NbtCompound) -> Result<Self, SculkParseError>
+    where
+        Self: Sized,
+    {
+        let unknown_tags = get_unknown_entity_tags(nbt, &[&DISPLAY_KEYS, &ITEM_DISPLAY_KEYS]);
+
+        Ok(ItemDisplay {
+            display: Display::from_compound_nbt(nbt)?,
+            item: get_optional_item(nbt, "item")?,
+            item_display: get_owned_optional_string(nbt, "item_display"),
+            unknown_tags,
+        })
+    }
+}
+
+impl FromCompoundNbt for TextDisplay {
+    fn from_compound_nbt(nbt: &simdnbt::borrow::NbtCompound) -> Result<Self, SculkParseError>
+    where
+        Self: Sized,
+    {
+        let unknown_tags = get_unknown_entity_tags(nbt, &[&DISPLAY_KEYS, &TEXT_DISPLAY_KEYS]);
+
+        Ok(TextDisplay {
+            display: Display::from_compound_nbt(nbt)?,
+            text: get_owned_optional_string(nbt, "text"),
+            alignment: get_owned_optional_string(nbt, "alignment"),
+            background: nbt.int("background"),
+            default_background: get_bool(nbt, "default_background"),
+            line_width: nbt.int("line_width"),
+            see_through: get_bool(nbt, "see_through"),
+            shadow: get_bool(nbt, "shadow"),
+            text_opacity: nbt.byte("text_opacity"),
+            unknown_tags,
+        })
+    }
+}
+
+impl ToCompoundNbt for Display {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        insert_optional(&mut nbt, "billboard", self.billboard.clone());
+        if let Some(brightness) = &self.brightness {
+            nbt.insert("brightness", brightness.to_compound_nbt());
+        }
+        insert_optional(&mut nbt, "glow_color_override", self.glow_color_override);
+        insert_optional(&mut nbt, "height", self.height);
+        insert_optional(&mut nbt, "width", self.width);
+        insert_optional(
+            &mut nbt,
+            "interpolation_duration",
+            self.interpolation_duration,
+        );
+        insert_optional(&mut nbt, "start_interpolation", self.start_interpolation);
+        insert_optional(&mut nbt, "teleport_duration", self.teleport_duration);
+        insert_optional(&mut nbt, "shadow_radius", self.shadow_radius);
+        insert_optional(&mut nbt, "shadow_strength", self.shadow_strength);
+        insert_optional(&mut nbt, "view_range", self.view_range);
+        insert_optional(&mut nbt, "transformation", self.transformation.clone());
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for Brightness {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        nbt.insert("block", self.block);
+        nbt.insert("sky", self.sky);
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for BlockDisplay {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = self.display.to_compound_nbt();
+
+        if let Some(state) = &self.block_state {
+            nbt.insert("block_state", state.to_compound_nbt());
+        }
+
+        insert_unknown_tags(&mut nbt, &self.unknown_tags);
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for ItemDisplay {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = self.display.to_compound_nbt();
+
+        if let Some(item) = &self.item {
+            nbt.insert("item", item.to_compound_nbt());
+        }
+        insert_optional(&mut nbt, "item_display", self.item_display.clone());
+
+        insert_unknown_tags(&mut nbt, &self.unknown_tags);
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for TextDisplay {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = self.display.to_compound_nbt();
+
+        insert_optional(&mut nbt, "text", self.text.clone());
+        insert_optional(&mut nbt, "alignment", self.alignment.clone());
+        insert_optional(&mut nbt, "background", self.background);
+        nbt.insert("default_background", self.default_background);
+        insert_optional(&mut nbt, "line_width", self.line_width);
+        nbt.insert("see_through", self.see_through);
+        nbt.insert("shadow", self.shadow);
+        insert_optional(&mut nbt, "text_opacity", self.text_opacity);
+
+        insert_unknown_tags(&mut nbt, &self.unknown_tags);
+
+        nbt
+    }
+}
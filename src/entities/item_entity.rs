@@ -0,0 +1,146 @@
+//! Dropped items and experience orbs.
+
+use crate::{
+    error::SculkParseError,
+    item::ItemWithNoSlot,
+    traits::{FromCompoundNbt, ToCompoundNbt},
+    util::{insert_optional, insert_unknown_tags},
+    uuid::Uuid,
+};
+use simdnbt::owned;
+
+use super::{get_optional_item, get_unknown_entity_tags};
+
+/// The tags parsed into [`ItemEntity`].
+const ITEM_ENTITY_KEYS: [&str; 6] = ["Age", "Health", "Item", "Owner", "PickupDelay", "Thrower"];
+
+/// The tags parsed into [`ExperienceOrb`].
+const EXPERIENCE_ORB_KEYS: [&str; 4] = ["Age", "Count", "Health", "Value"];
+
+/// `minecraft:item`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ItemEntity {
+    /// Number of ticks the item has existed, it despawns at 6000. -32768 stops the age from increasing.
+    /// `Age`
+    pub age: i16,
+
+    /// The health of the item, it is destroyed when this reaches 0.
+    /// `Health`
+    pub health: i16,
+
+    /// The item stack.
+    /// `Item`
+    pub item: Option<ItemWithNoSlot>,
+
+    /// Only this player can pick up the item.
+    /// `Owner`
+    pub owner: Option<Uuid>,
+
+    /// Number of ticks until the item can be picked up, 32767 means it can never be picked up.
+    /// `PickupDelay`
+    pub pickup_delay: i16,
+
+    /// The entity that dropped the item.
+    /// `Thrower`
+    pub thrower: Option<Uuid>,
+
+    /// The tags of the entity that aren't parsed, written back as is.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub unknown_tags: owned::NbtCompound,
+}
+
+/// `minecraft:experience_orb`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ExperienceOrb {
+    /// Number of ticks the orb has existed, it despawns at 6000.
+    /// `Age`
+    pub age: i16,
+
+    /// Number of orbs merged into this one.
+    /// `Count`
+    pub count: i32,
+
+    /// The health of the orb, it is destroyed when this reaches 0.
+    /// `Health`
+    pub health: i16,
+
+    /// The amount of experience the orb gives.
+    /// `Value`
+    pub value: i16,
+
+    /// The tags of the entity that aren't parsed, written back as is.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub unknown_tags: owned::NbtCompound,
+}
+
+impl FromCompoundNbt for ItemEntity {
+    fn from_compound_nbt(nbt: &simdnbt::borrow::NbtCompound) -> Result<Self, SculkParseError>
+    where
+        Self: Sized,
+    {
+        let unknown_tags = get_unknown_entity_tags(nbt, &[&ITEM_ENTITY_KEYS]);
+
+        Ok(ItemEntity {
+            age: nbt.short("Age").unwrap_or(0),
+            health: nbt.short("Health").unwrap_or(5),
+            item: get_optional_item(nbt, "Item")?,
+            owner: Uuid::from_int_array(nbt, "Owner")?,
+            pickup_delay: nbt.short("PickupDelay").unwrap_or(0),
+            thrower: Uuid::from_int_array(nbt, "Thrower")?,
+            unknown_tags,
+        })
+    }
+}
+
+impl FromCompoundNbt for ExperienceOrb {
+    fn from_compound_nbt(nbt: &simdnbt::borrow::NbtCompound) -> Result<Self, SculkParseError>
+    where
+        Self: Sized,
+    {
+        let unknown_tags = get_unknown_entity_tags(nbt, &[&EXPERIENCE_ORB_KEYS]);
+
+        Ok(ExperienceOrb {
+            age: nbt.short("Age").unwrap_or(0),
+            count: nbt.int("Count").unwrap_or(1),
+            health: nbt.short("Health").unwrap_or(5),
+            value: nbt.short("Value").unwrap_or(0),
+            unknown_tags,
+        })
+    }
+}
+
+impl ToCompoundNbt for ItemEntity {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        nbt.insert("Age", self.age);
+        nbt.insert("Health", self.health);
+        if let Some(item) = &self.item {
+            nbt.insert("Item", item.to_compound_nbt());
+        }
+        insert_optional(&mut nbt, "Owner", self.owner);
+        nbt.insert("PickupDelay", self.pickup_delay);
+        insert_optional(&mut nbt, "Thrower", self.thrower);
+
+        insert_unknown_tags(&mut nbt, &self.unknown_tags);
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for ExperienceOrb {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        nbt.insert("Age", self.age);
+        nbt.insert("Count", self.count);
+        nbt.insert("Health", self.health);
+        nbt.insert("Value", self.value);
+
+        insert_unknown_tags(&mut nbt, &self.unknown_tags);
+
+        nbt
+    }
+}
@@ -0,0 +1,450 @@
+//! Tags shared by every living entity and mob.
+
+use crate::{
+    components::food::EffectDetails,
+    error::SculkParseError,
+    item::ItemWithNoSlot,
+    traits::{FromCompoundNbt, ToCompoundNbt},
+    util::{
+        compound_list, float_list, get_owned_optional_string, get_owned_string, get_t_compound_vec,
+        insert_optional, insert_unknown_tags,
+    },
+    uuid::Uuid,
+    version::{self, DataVersion},
+};
+use simdnbt::owned;
+
+use super::{get_item_slots, get_optional_item, get_unknown_entity_tags, item_slot_list};
+
+/// The tags parsed into [`Mob`], including the ones of older versions.
+const MOB_KEYS: [&str; 24] = [
+    "AbsorptionAmount",
+    "active_effects",
+    "attributes",
+    "Attributes",
+    "Brain",
+    "DeathTime",
+    "FallFlying",
+    "Health",
+    "HurtByTimestamp",
+    "HurtTime",
+    "ArmorItems",
+    "ArmorDropChances",
+    "HandItems",
+    "HandDropChances",
+    "body_armor_item",
+    "body_armor_drop_chance",
+    "CanPickUpLoot",
+    "DeathLootTable",
+    "DeathLootTableSeed",
+    "LeftHanded",
+    "leash",
+    "Leash",
+    "NoAI",
+    "PersistenceRequired",
+];
+
+/// The tags of a living entity, used by every mob and the armor stand.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Mob {
+    /// How much absorption health the entity has.
+    /// `AbsorptionAmount`
+    pub absorption_amount: Option<f32>,
+
+    /// The status effects currently applied to the entity.
+    /// `active_effects`
+    pub active_effects: Vec<EffectDetails>,
+
+    /// The attributes of the entity, like its max health or movement speed.
+    /// `attributes`
+    pub attributes: Vec<Attribute>,
+
+    /// The memories of the entity's AI, the contents depend on the entity.
+    /// `Brain`
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub brain: Option<owned::NbtCompound>,
+
+    /// Number of ticks the entity has been dead for, used for the death animation.
+    /// `DeathTime`
+    pub death_time: Option<i16>,
+
+    /// true if the entity is gliding with an elytra.
+    /// `FallFlying`
+    pub fall_flying: Option<bool>,
+
+    /// The amount of health the entity has, in half hearts.
+    /// `Health`
+    pub health: Option<f32>,
+
+    /// The last time the entity was damaged, measured in the number of ticks since the entity's creation.
+    /// `HurtByTimestamp`
+    pub hurt_by_timestamp: Option<i32>,
+
+    /// Number of ticks the entity turns red after being hit.
+    /// `HurtTime`
+    pub hurt_time: Option<i16>,
+
+    /// The items the entity is wearing, feet, legs, chest and then head. Empty slots are `None`.
+    /// `ArmorItems`
+    pub armor_items: Vec<Option<ItemWithNoSlot>>,
+
+    /// The chance of each armor item dropping when the entity dies.
+    /// `ArmorDropChances`
+    pub armor_drop_chances: Vec<f32>,
+
+    /// The items the entity is holding, main hand and then off hand. Empty slots are `None`.
+    /// `HandItems`
+    pub hand_items: Vec<Option<ItemWithNoSlot>>,
+
+    /// The chance of each held item dropping when the entity dies.
+    /// `HandDropChances`
+    pub hand_drop_chances: Vec<f32>,
+
+    /// The armor worn on the body, used by horses and wolves.
+    /// `body_armor_item`
+    pub body_armor_item: Option<ItemWithNoSlot>,
+
+    /// The chance of the body armor dropping when the entity dies.
+    /// `body_armor_drop_chance`
+    pub body_armor_drop_chance: Option<f32>,
+
+    /// true if the mob can pick up items.
+    /// `CanPickUpLoot`
+    pub can_pick_up_loot: Option<bool>,
+
+    /// The loot table used when the mob dies, if not the default one.
+    /// `DeathLootTable`
+    pub death_loot_table: Option<String>,
+
+    /// The seed for the death loot table.
+    /// `DeathLootTableSeed`
+    pub death_loot_table_seed: Option<i64>,
+
+    /// true if the mob is left handed.
+    /// `LeftHanded`
+    pub left_handed: Option<bool>,
+
+    /// What the mob is leashed to, if anything.
+    /// `leash`
+    pub leash: Option<Leash>,
+
+    /// true if the mob has no AI and doesn't move on its own.
+    /// `NoAI`
+    pub no_ai: Option<bool>,
+
+    /// true if the mob never despawns.
+    /// `PersistenceRequired`
+    pub persistence_required: Option<bool>,
+
+    /// The tags of the entity that aren't parsed, written back as is.
+    /// Left empty when this is embedded in another kind, which keeps them itself.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub unknown_tags: owned::NbtCompound,
+}
+
+/// An attribute of a living entity.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Attribute {
+    /// The resource location of the attribute, like `minecraft:generic.max_health`.
+    /// `id`
+    pub id: String,
+
+    /// The base value of the attribute, before modifiers.
+    /// `base`
+    pub base: f64,
+
+    /// The modifiers currently applied to the attribute.
+    /// `modifiers`
+    pub modifiers: Vec<AttributeModifier>,
+}
+
+/// A modifier applied to an entity attribute.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AttributeModifier {
+    /// The resource location identifying the modifier.
+    /// `id`
+    pub id: String,
+
+    /// Amount of change from the modifier.
+    /// `amount`
+    pub amount: f64,
+
+    /// How the amount is applied, `add_value`, `add_multiplied_base` or `add_multiplied_total`.
+    /// `operation`
+    pub operation: String,
+}
+
+/// What a mob is leashed to.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Leash {
+    /// Leashed to another entity.
+    Entity(Uuid),
+
+    /// Leashed to a fence at the given block position.
+    Position([i32; 3]),
+}
+
+impl FromCompoundNbt for Mob {
+    fn from_compound_nbt(nbt: &simdnbt::borrow::NbtCompound) -> Result<Self, SculkParseError>
+    where
+        Self: Sized,
+    {
+        let active_effects =
+            get_t_compound_vec(nbt, "active_effects", EffectDetails::from_compound_nbt)?;
+        let attributes = get_attributes(nbt)?;
+
+        let leash = if let Some(position) = nbt.int_array("leash") {
+            Some(Leash::Position(position.as_slice().try_into().map_err(
+                |_| SculkParseError::InvalidField("leash".into()),
+            )?))
+        } else if let Some(leash) = nbt.compound("leash") {
+            let uuid = Uuid::from_int_array(&leash, "UUID")?
+                .ok_or(SculkParseError::InvalidField("leash".into()))?;
+            Some(Leash::Entity(uuid))
+        } else if let Some(leash) = nbt.compound("Leash") {
+            // Before 1.20.5 both kinds of leashes were a compound.
+            match (
+                leash.int_array("UUID"),
+                leash.int("X"),
+                leash.int("Y"),
+                leash.int("Z"),
+            ) {
+                (Some(uuid), ..) => Some(Leash::Entity(Uuid::try_from(uuid.as_slice())?)),
+                (None, Some(x), Some(y), Some(z)) => Some(Leash::Position([x, y, z])),
+                _ => return Err(SculkParseError::InvalidField("Leash".into())),
+            }
+        } else {
+            None
+        };
+
+        let unknown_tags = get_unknown_entity_tags(nbt, &[&MOB_KEYS]);
+
+        Ok(Mob {
+            absorption_amount: nbt.float("AbsorptionAmount"),
+            active_effects,
+            attributes,
+            brain: nbt.compound("Brain").map(|b| b.to_owned()),
+            death_time: nbt.short("DeathTime"),
+            fall_flying: nbt.byte("FallFlying").map(|b| b != 0),
+            health: nbt.float("Health"),
+            hurt_by_timestamp: nbt.int("HurtByTimestamp"),
+            hurt_time: nbt.short("HurtTime"),
+            armor_items: get_item_slots(nbt, "ArmorItems")?,
+            armor_drop_chances: get_floats(nbt, "ArmorDropChances"),
+            hand_items: get_item_slots(nbt, "HandItems")?,
+            hand_drop_chances: get_floats(nbt, "HandDropChances"),
+            body_armor_item: get_optional_item(nbt, "body_armor_item")?,
+            body_armor_drop_chance: nbt.float("body_armor_drop_chance"),
+            can_pick_up_loot: nbt.byte("CanPickUpLoot").map(|b| b != 0),
+            death_loot_table: get_owned_optional_string(nbt, "DeathLootTable"),
+            death_loot_table_seed: nbt.long("DeathLootTableSeed"),
+            left_handed: nbt.byte("LeftHanded").map(|b| b != 0),
+            leash,
+            no_ai: nbt.byte("NoAI").map(|b| b != 0),
+            persistence_required: nbt.byte("PersistenceRequired").map(|b| b != 0),
+            unknown_tags,
+        })
+    }
+}
+
+impl FromCompoundNbt for Attribute {
+    fn from_compound_nbt(nbt: &simdnbt::borrow::NbtCompound) -> Result<Self, SculkParseError>
+    where
+        Self: Sized,
+    {
+        // Before 1.20.5 the keys were capitalized.
+        if is_legacy_attribute(nbt, "Name") {
+            return Ok(Attribute {
+                id: get_owned_string(nbt, "Name")?,
+                base: nbt
+                    .double("Base")
+                    .ok_or(SculkParseError::MissingField("Base".into()))?,
+                modifiers: get_t_compound_vec(
+                    nbt,
+                    "Modifiers",
+                    AttributeModifier::from_compound_nbt,
+                )?,
+            });
+        }
+
+        let id = get_owned_string(nbt, "id")?;
+        let base = nbt
+            .double("base")
+            .ok_or(SculkParseError::MissingField("base".into()))?;
+        let modifiers = get_t_compound_vec(nbt, "modifiers", AttributeModifier::from_compound_nbt)?;
+
+        Ok(Attribute {
+            id,
+            base,
+            modifiers,
+        })
+    }
+}
+
+impl FromCompoundNbt for AttributeModifier {
+    fn from_compound_nbt(nbt: &simdnbt::borrow::NbtCompound) -> Result<Self, SculkParseError>
+    where
+        Self: Sized,
+    {
+        // Before 1.20.5 the keys were capitalized and the operation was a number,
+        // and until 1.21 modifiers were identified by a uuid and a name instead of an id.
+        if is_legacy_attribute(nbt, "Amount") {
+            let operation = match nbt.int("Operation") {
+                Some(operation) => operation_name(operation)?,
+                None => return Err(SculkParseError::MissingField("Operation".into())),
+            };
+
+            return Ok(AttributeModifier {
+                id: get_owned_string(nbt, "Name")?,
+                amount: nbt
+                    .double("Amount")
+                    .ok_or(SculkParseError::MissingField("Amount".into()))?,
+                operation,
+            });
+        }
+
+        let id = match get_owned_optional_string(nbt, "id") {
+            Some(id) => id,
+            None => get_owned_string(nbt, "name")?,
+        };
+        let amount = nbt
+            .double("amount")
+            .ok_or(SculkParseError::MissingField("amount".into()))?;
+        let operation = match nbt.int("operation") {
+            Some(operation) => operation_name(operation)?,
+            None => get_owned_string(nbt, "operation")?,
+        };
+
+        Ok(AttributeModifier {
+            id,
+            amount,
+            operation,
+        })
+    }
+}
+
+/// Reads the attributes of a living entity, the list was named `Attributes` before 1.20.5.
+pub(crate) fn get_attributes(
+    nbt: &simdnbt::borrow::NbtCompound,
+) -> Result<Vec<Attribute>, SculkParseError> {
+    match is_legacy_attribute(nbt, "Attributes") {
+        true => get_t_compound_vec(nbt, "Attributes", Attribute::from_compound_nbt),
+        false => get_t_compound_vec(nbt, "attributes", Attribute::from_compound_nbt),
+    }
+}
+
+/// true if attributes are stored with capitalized keys, which they are before 1.20.5.
+/// Without a data version the presence of the capitalized `key` decides.
+fn is_legacy_attribute(nbt: &simdnbt::borrow::NbtCompound, key: &str) -> bool {
+    match version::current() {
+        Some(version) => version < DataVersion::V1_20_5,
+        None => nbt.contains(key),
+    }
+}
+
+/// The name of a numeric attribute modifier operation, as stored before 1.20.5.
+fn operation_name(operation: i32) -> Result<String, SculkParseError> {
+    match operation {
+        0 => Ok("add_value".into()),
+        1 => Ok("add_multiplied_base".into()),
+        2 => Ok("add_multiplied_total".into()),
+        _ => Err(SculkParseError::InvalidField("Operation".into())),
+    }
+}
+
+impl ToCompoundNbt for Mob {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        insert_optional(&mut nbt, "AbsorptionAmount", self.absorption_amount);
+        if !self.active_effects.is_empty() {
+            nbt.insert("active_effects", compound_list(&self.active_effects));
+        }
+        if !self.attributes.is_empty() {
+            nbt.insert("attributes", compound_list(&self.attributes));
+        }
+        insert_optional(&mut nbt, "Brain", self.brain.clone());
+        insert_optional(&mut nbt, "DeathTime", self.death_time);
+        insert_optional(&mut nbt, "FallFlying", self.fall_flying);
+        insert_optional(&mut nbt, "Health", self.health);
+        insert_optional(&mut nbt, "HurtByTimestamp", self.hurt_by_timestamp);
+        insert_optional(&mut nbt, "HurtTime", self.hurt_time);
+        if !self.armor_items.is_empty() {
+            nbt.insert("ArmorItems", item_slot_list(&self.armor_items));
+        }
+        if !self.armor_drop_chances.is_empty() {
+            nbt.insert("ArmorDropChances", float_list(&self.armor_drop_chances));
+        }
+        if !self.hand_items.is_empty() {
+            nbt.insert("HandItems", item_slot_list(&self.hand_items));
+        }
+        if !self.hand_drop_chances.is_empty() {
+            nbt.insert("HandDropChances", float_list(&self.hand_drop_chances));
+        }
+        if let Some(item) = &self.body_armor_item {
+            nbt.insert("body_armor_item", item.to_compound_nbt());
+        }
+        insert_optional(
+            &mut nbt,
+            "body_armor_drop_chance",
+            self.body_armor_drop_chance,
+        );
+        insert_optional(&mut nbt, "CanPickUpLoot", self.can_pick_up_loot);
+        insert_optional(&mut nbt, "DeathLootTable", self.death_loot_table.clone());
+        insert_optional(&mut nbt, "DeathLootTableSeed", self.death_loot_table_seed);
+        insert_optional(&mut nbt, "LeftHanded", self.left_handed);
+        match &self.leash {
+            Some(Leash::Entity(uuid)) => {
+                let mut leash = owned::NbtCompound::new();
+                leash.insert("UUID", *uuid);
+                nbt.insert("leash", leash);
+            }
+            Some(Leash::Position(pos)) => {
+                nbt.insert("leash", owned::NbtTag::IntArray(pos.to_vec()));
+            }
+            None => (),
+        }
+        insert_optional(&mut nbt, "NoAI", self.no_ai);
+        insert_optional(&mut nbt, "PersistenceRequired", self.persistence_required);
+
+        insert_unknown_tags(&mut nbt, &self.unknown_tags);
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for Attribute {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        nbt.insert("id", self.id.as_str());
+        nbt.insert("base", self.base);
+        if !self.modifiers.is_empty() {
+            nbt.insert("modifiers", compound_list(&self.modifiers));
+        }
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for AttributeModifier {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        nbt.insert("id", self.id.as_str());
+        nbt.insert("amount", self.amount);
+        nbt.insert("operation", self.operation.as_str());
+
+        nbt
+    }
+}
+
+fn get_floats(nbt: &simdnbt::borrow::NbtCompound, key: &'static str) -> Vec<f32> {
+    nbt.list(key)
+        .and_then(|list| list.floats())
+        .unwrap_or_default()
+}
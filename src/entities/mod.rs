@@ -0,0 +1,578 @@
+use crate::{
+    error::SculkParseError,
+    item::ItemWithNoSlot,
+    traits::{FromCompoundNbt, ToCompoundNbt},
+    util::{get_owned_string, get_unknown_tags},
+};
+use simdnbt::owned;
+
+pub mod animal;
+pub mod block;
+pub mod decoration;
+pub mod display;
+pub mod item_entity;
+pub mod mob;
+pub mod monster;
+pub mod projectile;
+pub mod vehicle;
+pub mod villager;
+
+/// The tags shared by every entity, these are parsed into [`crate::entity::Entity`] and never kept in [`EntityKind::Unknown`].
+const BASE_KEYS: [&str; 20] = [
+    "Air",
+    "CustomName",
+    "CustomNameVisible",
+    "FallDistance",
+    "Fire",
+    "Glowing",
+    "HasVisualFire",
+    "id",
+    "Invulnerable",
+    "Motion",
+    "NoGravity",
+    "OnGround",
+    "Passengers",
+    "PortalCooldown",
+    "Pos",
+    "Rotation",
+    "Silent",
+    "Tags",
+    "TicksFrozen",
+    "UUID",
+];
+
+/// Keeps the tags of an entity that aren't in [`BASE_KEYS`] or in any of the `known` lists.
+pub(crate) fn get_unknown_entity_tags(
+    nbt: &simdnbt::borrow::NbtCompound,
+    known: &[&[&str]],
+) -> owned::NbtCompound {
+    let mut unknown = get_unknown_tags(nbt, &BASE_KEYS);
+    for key in known.iter().flat_map(|keys| keys.iter()) {
+        unknown.remove(key);
+    }
+
+    unknown
+}
+
+/// Takes the unknown tags kept by an embedded kind, without the `known` tags of the kind embedding it.
+pub(crate) fn take_unknown_tags(
+    unknown: &mut owned::NbtCompound,
+    known: &[&str],
+) -> owned::NbtCompound {
+    let mut unknown = std::mem::take(unknown);
+    for key in known {
+        unknown.remove(key);
+    }
+
+    unknown
+}
+
+/// Represents unique data specific to an entity.
+/// Mobs without their own variant are parsed as [`EntityKind::Mob`] or [`EntityKind::Animal`], which parse the tags shared by every mob and keep the others in `unknown_tags`.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EntityKind {
+    /// `minecraft:cow`, `minecraft:mooshroom`, `minecraft:pig`, `minecraft:rabbit`, `minecraft:turtle`, `minecraft:bee`, `minecraft:fox`, `minecraft:panda`, `minecraft:polar_bear`, `minecraft:goat`, `minecraft:frog`, `minecraft:axolotl`, `minecraft:sniffer`, `minecraft:armadillo`, `minecraft:hoglin`, `minecraft:strider`, `minecraft:ocelot`
+    Animal(animal::Animal),
+
+    /// `minecraft:armor_stand`
+    ArmorStand(decoration::ArmorStand),
+
+    /// `minecraft:arrow`, `minecraft:spectral_arrow`
+    Arrow(projectile::Arrow),
+
+    /// `minecraft:block_display`
+    BlockDisplay(display::BlockDisplay),
+
+    /// `minecraft:boat`, `minecraft:chest_boat`
+    Boat(vehicle::Boat),
+
+    /// `minecraft:chicken`
+    Chicken(animal::Chicken),
+
+    /// `minecraft:chest_minecart`, `minecraft:hopper_minecart`
+    ContainerMinecart(vehicle::ContainerMinecart),
+
+    /// `minecraft:creeper`
+    Creeper(monster::Creeper),
+
+    /// `minecraft:enderman`
+    Enderman(monster::Enderman),
+
+    /// `minecraft:experience_orb`
+    ExperienceOrb(item_entity::ExperienceOrb),
+
+    /// `minecraft:falling_block`
+    FallingBlock(block::FallingBlock),
+
+    /// `minecraft:fireball`, `minecraft:small_fireball`, `minecraft:dragon_fireball`, `minecraft:wither_skull`, `minecraft:wind_charge`, `minecraft:breeze_wind_charge`
+    Fireball(projectile::Fireball),
+
+    /// `minecraft:firework_rocket`
+    FireworkRocket(projectile::FireworkRocket),
+
+    /// `minecraft:horse`, `minecraft:donkey`, `minecraft:mule`, `minecraft:skeleton_horse`, `minecraft:zombie_horse`, `minecraft:llama`, `minecraft:trader_llama`, `minecraft:camel`
+    Horse(animal::Horse),
+
+    /// `minecraft:item`
+    Item(item_entity::ItemEntity),
+
+    /// `minecraft:item_display`
+    ItemDisplay(display::ItemDisplay),
+
+    /// `minecraft:item_frame`, `minecraft:glow_item_frame`
+    ItemFrame(decoration::ItemFrame),
+
+    /// `minecraft:minecart`
+    Minecart(vehicle::Minecart),
+
+    /// Every other mob, like `minecraft:skeleton`, `minecraft:spider`, `minecraft:iron_golem` or `minecraft:squid`
+    Mob(mob::Mob),
+
+    /// `minecraft:painting`
+    Painting(decoration::Painting),
+
+    /// `minecraft:sheep`
+    Sheep(animal::Sheep),
+
+    /// `minecraft:slime`, `minecraft:magma_cube`
+    Slime(monster::Slime),
+
+    /// `minecraft:wolf`, `minecraft:cat`, `minecraft:parrot`
+    Tameable(animal::Tameable),
+
+    /// `minecraft:text_display`
+    TextDisplay(display::TextDisplay),
+
+    /// `minecraft:snowball`, `minecraft:egg`, `minecraft:ender_pearl`, `minecraft:potion`, `minecraft:experience_bottle`
+    ThrownItem(projectile::ThrownItem),
+
+    /// `minecraft:tnt`
+    Tnt(block::Tnt),
+
+    /// `minecraft:trident`
+    Trident(projectile::Trident),
+
+    /// `minecraft:villager`
+    Villager(villager::Villager),
+
+    /// `minecraft:wandering_trader`
+    WanderingTrader(villager::WanderingTrader),
+
+    /// `minecraft:zombie`, `minecraft:husk`, `minecraft:drowned`, `minecraft:zombie_villager`, `minecraft:zombified_piglin`
+    Zombie(monster::Zombie),
+
+    /// Any other entity, holding every tag that isn't shared by all entities.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    Unknown(owned::NbtCompound),
+}
+
+impl FromCompoundNbt for EntityKind {
+    fn from_compound_nbt(nbt: &simdnbt::borrow::NbtCompound) -> Result<Self, SculkParseError>
+    where
+        Self: Sized,
+    {
+        use animal::{Animal, Chicken, Horse, Sheep, Tameable};
+        use block::{FallingBlock, Tnt};
+        use decoration::{ArmorStand, ItemFrame, Painting};
+        use display::{BlockDisplay, ItemDisplay, TextDisplay};
+        use item_entity::{ExperienceOrb, ItemEntity};
+        use mob::Mob;
+        use monster::{Creeper, Enderman, Slime, Zombie};
+        use projectile::{Arrow, Fireball, FireworkRocket, ThrownItem, Trident};
+        use vehicle::{Boat, ContainerMinecart, Minecart};
+        use villager::{Villager, WanderingTrader};
+
+        let id = get_owned_string(nbt, "id").map_err(|_| {
+            SculkParseError::MissingField(
+                "EntityKind requires a parent entity tag, no / invalid id found".into(),
+            )
+        })?;
+
+        let kind = match id.as_str() {
+            "minecraft:cow"
+            | "minecraft:mooshroom"
+            | "minecraft:pig"
+            | "minecraft:rabbit"
+            | "minecraft:turtle"
+            | "minecraft:bee"
+            | "minecraft:fox"
+            | "minecraft:panda"
+            | "minecraft:polar_bear"
+            | "minecraft:goat"
+            | "minecraft:frog"
+            | "minecraft:axolotl"
+            | "minecraft:sniffer"
+            | "minecraft:armadillo"
+            | "minecraft:hoglin"
+            | "minecraft:strider"
+            | "minecraft:ocelot" => EntityKind::Animal(Animal::from_compound_nbt(nbt)?),
+            "minecraft:armor_stand" => EntityKind::ArmorStand(ArmorStand::from_compound_nbt(nbt)?),
+            "minecraft:arrow" | "minecraft:spectral_arrow" => {
+                EntityKind::Arrow(Arrow::from_compound_nbt(nbt)?)
+            }
+            "minecraft:block_display" => {
+                EntityKind::BlockDisplay(BlockDisplay::from_compound_nbt(nbt)?)
+            }
+            "minecraft:boat" | "minecraft:chest_boat" => {
+                EntityKind::Boat(Boat::from_compound_nbt(nbt)?)
+            }
+            "minecraft:chicken" => EntityKind::Chicken(Chicken::from_compound_nbt(nbt)?),
+            "minecraft:chest_minecart" | "minecraft:hopper_minecart" => {
+                EntityKind::ContainerMinecart(ContainerMinecart::from_compound_nbt(nbt)?)
+            }
+            "minecraft:creeper" => EntityKind::Creeper(Creeper::from_compound_nbt(nbt)?),
+            "minecraft:enderman" => EntityKind::Enderman(Enderman::from_compound_nbt(nbt)?),
+            "minecraft:experience_orb" => {
+                EntityKind::ExperienceOrb(ExperienceOrb::from_compound_nbt(nbt)?)
+            }
+            "minecraft:falling_block" => {
+                EntityKind::FallingBlock(FallingBlock::from_compound_nbt(nbt)?)
+            }
+            "minecraft:fireball"
+            | "minecraft:small_fireball"
+            | "minecraft:dragon_fireball"
+            | "minecraft:wither_skull"
+            | "minecraft:wind_charge"
+            | "minecraft:breeze_wind_charge" => {
+                EntityKind::Fireball(Fireball::from_compound_nbt(nbt)?)
+            }
+            "minecraft:firework_rocket" => {
+                EntityKind::FireworkRocket(FireworkRocket::from_compound_nbt(nbt)?)
+            }
+            "minecraft:horse"
+            | "minecraft:donkey"
+            | "minecraft:mule"
+            | "minecraft:skeleton_horse"
+            | "minecraft:zombie_horse"
+            | "minecraft:llama"
+            | "minecraft:trader_llama"
+            | "minecraft:camel" => EntityKind::Horse(Horse::from_compound_nbt(nbt)?),
+            "minecraft:item" => EntityKind::Item(ItemEntity::from_compound_nbt(nbt)?),
+            "minecraft:item_display" => {
+                EntityKind::ItemDisplay(ItemDisplay::from_compound_nbt(nbt)?)
+            }
+            "minecraft:item_frame" | "minecraft:glow_item_frame" => {
+                EntityKind::ItemFrame(ItemFrame::from_compound_nbt(nbt)?)
+            }
+            "minecraft:minecart" => EntityKind::Minecart(Minecart::from_compound_nbt(nbt)?),
+            "minecraft:allay"
+            | "minecraft:bat"
+            | "minecraft:blaze"
+            | "minecraft:bogged"
+            | "minecraft:breeze"
+            | "minecraft:cave_spider"
+            | "minecraft:cod"
+            | "minecraft:dolphin"
+            | "minecraft:elder_guardian"
+            | "minecraft:ender_dragon"
+            | "minecraft:endermite"
+            | "minecraft:evoker"
+            | "minecraft:ghast"
+            | "minecraft:giant"
+            | "minecraft:glow_squid"
+            | "minecraft:guardian"
+            | "minecraft:illusioner"
+            | "minecraft:iron_golem"
+            | "minecraft:phantom"
+            | "minecraft:piglin"
+            | "minecraft:piglin_brute"
+            | "minecraft:pillager"
+            | "minecraft:pufferfish"
+            | "minecraft:ravager"
+            | "minecraft:salmon"
+            | "minecraft:shulker"
+            | "minecraft:silverfish"
+            | "minecraft:skeleton"
+            | "minecraft:snow_golem"
+            | "minecraft:spider"
+            | "minecraft:squid"
+            | "minecraft:stray"
+            | "minecraft:tadpole"
+            | "minecraft:tropical_fish"
+            | "minecraft:vex"
+            | "minecraft:vindicator"
+            | "minecraft:warden"
+            | "minecraft:witch"
+            | "minecraft:wither"
+            | "minecraft:wither_skeleton"
+            | "minecraft:zoglin" => EntityKind::Mob(Mob::from_compound_nbt(nbt)?),
+            "minecraft:painting" => EntityKind::Painting(Painting::from_compound_nbt(nbt)?),
+            "minecraft:sheep" => EntityKind::Sheep(Sheep::from_compound_nbt(nbt)?),
+            "minecraft:slime" | "minecraft:magma_cube" => {
+                EntityKind::Slime(Slime::from_compound_nbt(nbt)?)
+            }
+            "minecraft:wolf" | "minecraft:cat" | "minecraft:parrot" => {
+                EntityKind::Tameable(Tameable::from_compound_nbt(nbt)?)
+            }
+            "minecraft:text_display" => {
+                EntityKind::TextDisplay(TextDisplay::from_compound_nbt(nbt)?)
+            }
+            "minecraft:snowball"
+            | "minecraft:egg"
+            | "minecraft:ender_pearl"
+            | "minecraft:potion"
+            | "minecraft:experience_bottle" => {
+                EntityKind::ThrownItem(ThrownItem::from_compound_nbt(nbt)?)
+            }
+            "minecraft:tnt" => EntityKind::Tnt(Tnt::from_compound_nbt(nbt)?),
+            "minecraft:trident" => EntityKind::Trident(Trident::from_compound_nbt(nbt)?),
+            "minecraft:villager" => EntityKind::Villager(Villager::from_compound_nbt(nbt)?),
+            "minecraft:wandering_trader" => {
+                EntityKind::WanderingTrader(WanderingTrader::from_compound_nbt(nbt)?)
+            }
+            "minecraft:zombie"
+            | "minecraft:husk"
+            | "minecraft:drowned"
+            | "minecraft:zombie_villager"
+            | "minecraft:zombified_piglin" => EntityKind::Zombie(Zombie::from_compound_nbt(nbt)?),
+            _ => EntityKind::Unknown(get_unknown_tags(nbt, &BASE_KEYS)),
+        };
+
+        Ok(kind)
+    }
+}
+
+impl ToCompoundNbt for EntityKind {
+    /// Only writes the fields specific to the kind, the base fields (`id`, `Pos`, `UUID`, ...) are written by the entity.
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        match self {
+            EntityKind::Animal(animal) => animal.to_compound_nbt(),
+            EntityKind::ArmorStand(armor_stand) => armor_stand.to_compound_nbt(),
+            EntityKind::Arrow(arrow) => arrow.to_compound_nbt(),
+            EntityKind::BlockDisplay(display) => display.to_compound_nbt(),
+            EntityKind::Boat(boat) => boat.to_compound_nbt(),
+            EntityKind::Chicken(chicken) => chicken.to_compound_nbt(),
+            EntityKind::ContainerMinecart(minecart) => minecart.to_compound_nbt(),
+            EntityKind::Creeper(creeper) => creeper.to_compound_nbt(),
+            EntityKind::Enderman(enderman) => enderman.to_compound_nbt(),
+            EntityKind::ExperienceOrb(orb) => orb.to_compound_nbt(),
+            EntityKind::FallingBlock(falling_block) => falling_block.to_compound_nbt(),
+            EntityKind::Fireball(fireball) => fireball.to_compound_nbt(),
+            EntityKind::FireworkRocket(rocket) => rocket.to_compound_nbt(),
+            EntityKind::Horse(horse) => horse.to_compound_nbt(),
+            EntityKind::Item(item) => item.to_compound_nbt(),
+            EntityKind::ItemDisplay(display) => display.to_compound_nbt(),
+            EntityKind::ItemFrame(item_frame) => item_frame.to_compound_nbt(),
+            EntityKind::Minecart(minecart) => minecart.to_compound_nbt(),
+            EntityKind::Mob(mob) => mob.to_compound_nbt(),
+            EntityKind::Painting(painting) => painting.to_compound_nbt(),
+            EntityKind::Sheep(sheep) => sheep.to_compound_nbt(),
+            EntityKind::Slime(slime) => slime.to_compound_nbt(),
+            EntityKind::Tameable(tameable) => tameable.to_compound_nbt(),
+            EntityKind::TextDisplay(display) => display.to_compound_nbt(),
+            EntityKind::ThrownItem(thrown) => thrown.to_compound_nbt(),
+            EntityKind::Tnt(tnt) => tnt.to_compound_nbt(),
+            EntityKind::Trident(trident) => trident.to_compound_nbt(),
+            EntityKind::Villager(villager) => villager.to_compound_nbt(),
+            EntityKind::WanderingTrader(trader) => trader.to_compound_nbt(),
+            EntityKind::Zombie(zombie) => zombie.to_compound_nbt(),
+            EntityKind::Unknown(nbt) => nbt.clone(),
+        }
+    }
+}
+
+/// Reads a list of item slots, where empty slots are stored as empty compounds.
+pub(crate) fn get_item_slots(
+    nbt: &simdnbt::borrow::NbtCompound,
+    key: &'static str,
+) -> Result<Vec<Option<ItemWithNoSlot>>, SculkParseError> {
+    let list = match nbt.list(key) {
+        Some(list) => list,
+        None => return Ok(vec![]),
+    };
+
+    if list.empty() {
+        return Ok(vec![]);
+    }
+
+    let mut slots = vec![];
+
+    for item in list
+        .compounds()
+        .ok_or(SculkParseError::InvalidField(key.into()))?
+    {
+        if item.contains("id") {
+            slots.push(Some(ItemWithNoSlot::from_compound_nbt(&item)?));
+        } else {
+            slots.push(None);
+        }
+    }
+
+    Ok(slots)
+}
+
+/// Reads a single item, an empty compound is treated as no item.
+pub(crate) fn get_optional_item(
+    nbt: &simdnbt::borrow::NbtCompound,
+    key: &'static str,
+) -> Result<Option<ItemWithNoSlot>, SculkParseError> {
+    match nbt.compound(key) {
+        Some(item) if item.contains("id") => Ok(Some(ItemWithNoSlot::from_compound_nbt(&item)?)),
+        _ => Ok(None),
+    }
+}
+
+/// Writes a list of item slots, empty slots are written as empty compounds.
+pub(crate) fn item_slot_list(slots: &[Option<ItemWithNoSlot>]) -> owned::NbtList {
+    if slots.is_empty() {
+        return owned::NbtList::Empty;
+    }
+
+    owned::NbtList::Compound(
+        slots
+            .iter()
+            .map(|slot| match slot {
+                Some(item) => item.to_compound_nbt(),
+                None => owned::NbtCompound::new(),
+            })
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+#[test]
+fn entity_kind_test() {
+    use crate::{entity::Entity, util::write_nbt};
+    use std::io::Cursor;
+
+    let mut zombie = owned::NbtCompound::new();
+    zombie.insert("id", "minecraft:zombie");
+    zombie.insert("Health", 17.5f32);
+    zombie.insert("IsBaby", true);
+    zombie.insert(
+        "HandItems",
+        owned::NbtList::Compound(vec![
+            ItemWithNoSlot {
+                id: "minecraft:iron_shovel".into(),
+                count: 1,
+                components: None,
+            }
+            .to_compound_nbt(),
+            owned::NbtCompound::new(),
+        ]),
+    );
+
+    let mut sheep = owned::NbtCompound::new();
+    sheep.insert("id", "minecraft:sheep");
+    sheep.insert("Color", 14i8);
+    sheep.insert("Age", -200);
+    sheep.insert("Passengers", owned::NbtList::Compound(vec![zombie]));
+
+    let mut unknown = owned::NbtCompound::new();
+    unknown.insert("id", "minecraft:marker");
+    unknown.insert("data", owned::NbtCompound::new());
+    unknown.insert("UUID", owned::NbtTag::IntArray(vec![1, 2, 3, 4]));
+
+    let parse = |nbt: &owned::NbtCompound| {
+        let bytes = write_nbt(nbt);
+        let nbt = simdnbt::borrow::read(&mut Cursor::new(&bytes))
+            .unwrap()
+            .unwrap();
+        Entity::from_compound_nbt(&nbt.as_compound()).unwrap()
+    };
+
+    let entity = parse(&sheep);
+    let EntityKind::Sheep(sheep) = &entity.kind else {
+        panic!("expected a sheep, got {:?}", entity.kind);
+    };
+    assert_eq!(sheep.color, 14);
+    assert_eq!(sheep.animal.age, -200);
+
+    let EntityKind::Zombie(zombie) = &entity.passengers[0].kind else {
+        panic!("expected a zombie");
+    };
+    assert!(zombie.is_baby);
+    assert_eq!(zombie.mob.health, Some(17.5));
+    assert_eq!(
+        zombie.mob.hand_items[0].as_ref().unwrap().id,
+        "minecraft:iron_shovel"
+    );
+    assert_eq!(zombie.mob.hand_items[1], None);
+
+    assert_eq!(parse(&entity.to_compound_nbt()), entity);
+
+    let marker = parse(&unknown);
+    let EntityKind::Unknown(data) = &marker.kind else {
+        panic!("expected an unknown entity");
+    };
+    assert!(data.compound("data").is_some());
+    assert!(data.get("UUID").is_none());
+    assert_eq!(parse(&marker.to_compound_nbt()), marker);
+}
+
+#[cfg(test)]
+#[test]
+fn entity_unknown_tags_test() {
+    use crate::{entity::Entity, util::write_nbt};
+    use std::io::Cursor;
+
+    let mut shulker = owned::NbtCompound::new();
+    shulker.insert("id", "minecraft:shulker");
+    shulker.insert("Health", 30f32);
+    shulker.insert("Color", 5i8);
+    shulker.insert("Peek", 0i8);
+
+    let mut zombie_villager = owned::NbtCompound::new();
+    zombie_villager.insert("id", "minecraft:zombie_villager");
+    zombie_villager.insert("IsBaby", true);
+    zombie_villager.insert("ConversionTime", 1200);
+
+    let mut wolf = owned::NbtCompound::new();
+    wolf.insert("id", "minecraft:wolf");
+    wolf.insert("Age", 0);
+    wolf.insert("CollarColor", 14i8);
+    wolf.insert("AngerTime", 40);
+
+    let parse = |nbt: &owned::NbtCompound| {
+        let bytes = write_nbt(nbt);
+        let nbt = simdnbt::borrow::read(&mut Cursor::new(&bytes))
+            .unwrap()
+            .unwrap();
+        Entity::from_compound_nbt(&nbt.as_compound()).unwrap()
+    };
+
+    let entity = parse(&shulker);
+    let EntityKind::Mob(mob) = &entity.kind else {
+        panic!("expected a mob, got {:?}", entity.kind);
+    };
+    assert_eq!(mob.health, Some(30.0));
+    assert_eq!(mob.unknown_tags.byte("Color"), Some(5));
+    assert!(mob.unknown_tags.get("Health").is_none());
+    assert!(mob.unknown_tags.get("id").is_none());
+
+    let written = entity.to_compound_nbt();
+    assert_eq!(written.byte("Color"), Some(5));
+    assert_eq!(written.byte("Peek"), Some(0));
+    assert_eq!(parse(&written), entity);
+
+    let entity = parse(&zombie_villager);
+    let EntityKind::Zombie(zombie) = &entity.kind else {
+        panic!("expected a zombie, got {:?}", entity.kind);
+    };
+    assert!(zombie.mob.unknown_tags.is_empty());
+    assert_eq!(zombie.unknown_tags.int("ConversionTime"), Some(1200));
+    assert!(zombie.unknown_tags.get("IsBaby").is_none());
+    assert_eq!(entity.to_compound_nbt().int("ConversionTime"), Some(1200));
+
+    let entity = parse(&wolf);
+    let EntityKind::Tameable(tameable) = &entity.kind else {
+        panic!("expected a tameable, got {:?}", entity.kind);
+    };
+    assert_eq!(tameable.collar_color, Some(14));
+    assert_eq!(tameable.unknown_tags.int("AngerTime"), Some(40));
+    assert!(tameable.unknown_tags.get("CollarColor").is_none());
+    assert!(tameable.animal.unknown_tags.is_empty());
+    assert_eq!(parse(&entity.to_compound_nbt()), entity);
+
+    // uuids that aren't 4 integers are rejected instead of padded
+    wolf.insert("Owner", owned::NbtTag::IntArray(vec![1, 2, 3]));
+    let bytes = write_nbt(&wolf);
+    let nbt = simdnbt::borrow::read(&mut Cursor::new(&bytes))
+        .unwrap()
+        .unwrap();
+    assert!(matches!(
+        Entity::from_compound_nbt(&nbt.as_compound()),
+        Err(SculkParseError::InvalidField(field)) if field == "UUID"
+    ));
+}
@@ -0,0 +1,261 @@
+//! Hostile mobs with their own tags.
+
+use crate::{
+    chunk::section::Palette,
+    error::SculkParseError,
+    traits::{FromCompoundNbt, ToCompoundNbt},
+    util::{get_bool, insert_optional, insert_unknown_tags},
+};
+use simdnbt::owned;
+
+use super::{mob::Mob, take_unknown_tags};
+
+/// The tags parsed into [`Zombie`].
+const ZOMBIE_KEYS: [&str; 4] = [
+    "IsBaby",
+    "CanBreakDoors",
+    "DrownedConversionTime",
+    "InWaterTime",
+];
+
+/// The tags parsed into [`Creeper`].
+const CREEPER_KEYS: [&str; 4] = ["ExplosionRadius", "Fuse", "ignited", "powered"];
+
+/// The tags parsed into [`Enderman`].
+const ENDERMAN_KEYS: [&str; 1] = ["carriedBlockState"];
+
+/// The tags parsed into [`Slime`].
+const SLIME_KEYS: [&str; 2] = ["Size", "wasOnGround"];
+
+/// Zombies, husks, drowned, zombie villagers and zombified piglins.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Zombie {
+    /// The common mob tags.
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub mob: Mob,
+
+    /// true if the zombie is a baby.
+    /// `IsBaby`
+    pub is_baby: bool,
+
+    /// true if the zombie can break doors.
+    /// `CanBreakDoors`
+    pub can_break_doors: bool,
+
+    /// Number of ticks until the zombie converts into a drowned, -1 if it isn't converting.
+    /// `DrownedConversionTime`
+    pub drowned_conversion_time: Option<i32>,
+
+    /// Number of ticks the zombie has been under water.
+    /// `InWaterTime`
+    pub in_water_time: Option<i32>,
+
+    /// The tags of the entity that aren't parsed, written back as is.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub unknown_tags: owned::NbtCompound,
+}
+
+/// `minecraft:creeper`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Creeper {
+    /// The common mob tags.
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub mob: Mob,
+
+    /// The radius of the explosion.
+    /// `ExplosionRadius`
+    pub explosion_radius: i8,
+
+    /// Number of ticks the creeper takes to explode once ignited.
+    /// `Fuse`
+    pub fuse: i16,
+
+    /// true if the creeper was ignited by flint and steel.
+    /// `ignited`
+    pub ignited: bool,
+
+    /// true if the creeper was struck by lightning.
+    /// `powered`
+    pub powered: bool,
+
+    /// The tags of the entity that aren't parsed, written back as is.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub unknown_tags: owned::NbtCompound,
+}
+
+/// `minecraft:enderman`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Enderman {
+    /// The common mob tags.
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub mob: Mob,
+
+    /// The block the enderman is holding.
+    /// `carriedBlockState`
+    pub carried_block_state: Option<Palette>,
+
+    /// The tags of the entity that aren't parsed, written back as is.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub unknown_tags: owned::NbtCompound,
+}
+
+/// `minecraft:slime` and `minecraft:magma_cube`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Slime {
+    /// The common mob tags.
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub mob: Mob,
+
+    /// The size of the slime, 0 is the smallest.
+    /// `Size`
+    pub size: i32,
+
+    /// true if the slime is touching the ground.
+    /// `wasOnGround`
+    pub was_on_ground: bool,
+
+    /// The tags of the entity that aren't parsed, written back as is.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub unknown_tags: owned::NbtCompound,
+}
+
+impl FromCompoundNbt for Zombie {
+    fn from_compound_nbt(nbt: &simdnbt::borrow::NbtCompound) -> Result<Self, SculkParseError>
+    where
+        Self: Sized,
+    {
+        let mut mob = Mob::from_compound_nbt(nbt)?;
+        let unknown_tags = take_unknown_tags(&mut mob.unknown_tags, &ZOMBIE_KEYS);
+
+        Ok(Zombie {
+            mob,
+            is_baby: get_bool(nbt, "IsBaby"),
+            can_break_doors: get_bool(nbt, "CanBreakDoors"),
+            drowned_conversion_time: nbt.int("DrownedConversionTime"),
+            in_water_time: nbt.int("InWaterTime"),
+            unknown_tags,
+        })
+    }
+}
+
+impl FromCompoundNbt for Creeper {
+    fn from_compound_nbt(nbt: &simdnbt::borrow::NbtCompound) -> Result<Self, SculkParseError>
+    where
+        Self: Sized,
+    {
+        let mut mob = Mob::from_compound_nbt(nbt)?;
+        let unknown_tags = take_unknown_tags(&mut mob.unknown_tags, &CREEPER_KEYS);
+
+        Ok(Creeper {
+            mob,
+            explosion_radius: nbt.byte("ExplosionRadius").unwrap_or(3),
+            fuse: nbt.short("Fuse").unwrap_or(30),
+            ignited: get_bool(nbt, "ignited"),
+            powered: get_bool(nbt, "powered"),
+            unknown_tags,
+        })
+    }
+}
+
+impl FromCompoundNbt for Enderman {
+    fn from_compound_nbt(nbt: &simdnbt::borrow::NbtCompound) -> Result<Self, SculkParseError>
+    where
+        Self: Sized,
+    {
+        let mut mob = Mob::from_compound_nbt(nbt)?;
+        let unknown_tags = take_unknown_tags(&mut mob.unknown_tags, &ENDERMAN_KEYS);
+
+        Ok(Enderman {
+            mob,
+            carried_block_state: nbt
+                .compound("carriedBlockState")
+                .map(|nbt| Palette::from_compound_nbt(&nbt))
+                .transpose()?,
+            unknown_tags,
+        })
+    }
+}
+
+impl FromCompoundNbt for Slime {
+    fn from_compound_nbt(nbt: &simdnbt::borrow::NbtCompound) -> Result<Self, SculkParseError>
+    where
+        Self: Sized,
+    {
+        let mut mob = Mob::from_compound_nbt(nbt)?;
+        let unknown_tags = take_unknown_tags(&mut mob.unknown_tags, &SLIME_KEYS);
+
+        Ok(Slime {
+            mob,
+            size: nbt.int("Size").unwrap_or(0),
+            was_on_ground: get_bool(nbt, "wasOnGround"),
+            unknown_tags,
+        })
+    }
+}
+
+impl ToCompoundNbt for Zombie {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = self.mob.to_compound_nbt();
+
+        nbt.insert("IsBaby", self.is_baby);
+        nbt.insert("CanBreakDoors", self.can_break_doors);
+        insert_optional(
+            &mut nbt,
+            "DrownedConversionTime",
+            self.drowned_conversion_time,
+        );
+        insert_optional(&mut nbt, "InWaterTime", self.in_water_time);
+
+        insert_unknown_tags(&mut nbt, &self.unknown_tags);
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for Creeper {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = self.mob.to_compound_nbt();
+
+        nbt.insert("ExplosionRadius", self.explosion_radius);
+        nbt.insert("Fuse", self.fuse);
+        nbt.insert("ignited", self.ignited);
+        if self.powered {
+            nbt.insert("powered", true);
+        }
+
+        insert_unknown_tags(&mut nbt, &self.unknown_tags);
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for Enderman {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = self.mob.to_compound_nbt();
+
+        if let Some(state) = &self.carried_block_state {
+            nbt.insert("carriedBlockState", state.to_compound_nbt());
+        }
+
+        insert_unknown_tags(&mut nbt, &self.unknown_tags);
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for Slime {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = self.mob.to_compound_nbt();
+
+        nbt.insert("Size", self.size);
+        nbt.insert("wasOnGround", self.was_on_ground);
+
+        insert_unknown_tags(&mut nbt, &self.unknown_tags);
+
+        nbt
+    }
+}
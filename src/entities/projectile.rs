@@ -0,0 +1,419 @@
+//! Arrows, thrown items, fireballs and other projectiles.
+
+use crate::{
+    chunk::section::Palette,
+    error::SculkParseError,
+    item::ItemWithNoSlot,
+    traits::{FromCompoundNbt, ToCompoundNbt},
+    util::{get_bool, get_owned_optional_string, insert_optional, insert_unknown_tags},
+    uuid::Uuid,
+};
+use simdnbt::owned;
+
+use super::{get_optional_item, get_unknown_entity_tags, take_unknown_tags};
+
+/// The tags parsed into [`Projectile`].
+const PROJECTILE_KEYS: [&str; 3] = ["Owner", "LeftOwner", "HasBeenShot"];
+
+/// The tags parsed into [`Arrow`].
+const ARROW_KEYS: [&str; 12] = [
+    "crit",
+    "damage",
+    "inGround",
+    "life",
+    "pickup",
+    "PierceLevel",
+    "shake",
+    "ShotFromCrossbow",
+    "SoundEvent",
+    "inBlockState",
+    "item",
+    "weapon",
+];
+
+/// The tags parsed into [`Trident`].
+const TRIDENT_KEYS: [&str; 1] = ["DealtDamage"];
+
+/// The tags parsed into [`ThrownItem`].
+const THROWN_ITEM_KEYS: [&str; 1] = ["Item"];
+
+/// The tags parsed into [`Fireball`].
+const FIREBALL_KEYS: [&str; 3] = ["acceleration_power", "ExplosionPower", "Item"];
+
+/// The tags parsed into [`FireworkRocket`].
+const FIREWORK_ROCKET_KEYS: [&str; 4] = ["Life", "LifeTime", "ShotAtAngle", "FireworksItem"];
+
+/// The tags shared by every projectile.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Projectile {
+    /// The entity that shot or threw the projectile.
+    /// `Owner`
+    pub owner: Option<Uuid>,
+
+    /// true if the projectile has left the hitbox of its owner.
+    /// `LeftOwner`
+    pub left_owner: bool,
+
+    /// true if the projectile has been shot.
+    /// `HasBeenShot`
+    pub has_been_shot: bool,
+}
+
+/// `minecraft:arrow` and `minecraft:spectral_arrow`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Arrow {
+    /// The common projectile tags.
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub projectile: Projectile,
+
+    /// true if the arrow was fully charged and deals critical damage.
+    /// `crit`
+    pub crit: bool,
+
+    /// The damage the arrow deals, before being multiplied by its velocity.
+    /// `damage`
+    pub damage: f64,
+
+    /// true if the arrow is stuck in a block.
+    /// `inGround`
+    pub in_ground: bool,
+
+    /// Number of ticks the arrow has been stuck in a block, it despawns at 1200.
+    /// `life`
+    pub life: i16,
+
+    /// 0 if the arrow can't be picked up, 1 if it can be picked up by players, 2 if only creative players can pick it up.
+    /// `pickup`
+    pub pickup: i8,
+
+    /// Number of entities the arrow can pass through.
+    /// `PierceLevel`
+    pub pierce_level: i8,
+
+    /// Number of ticks the arrow shakes after hitting a block.
+    /// `shake`
+    pub shake: i8,
+
+    /// true if the arrow was shot from a crossbow.
+    /// `ShotFromCrossbow`
+    pub shot_from_crossbow: bool,
+
+    /// The sound played when the arrow hits something.
+    /// `SoundEvent`
+    pub sound_event: Option<String>,
+
+    /// The block the arrow is stuck in.
+    /// `inBlockState`
+    pub in_block_state: Option<Palette>,
+
+    /// The item given when the arrow is picked up.
+    /// `item`
+    pub item: Option<ItemWithNoSlot>,
+
+    /// The bow or crossbow the arrow was shot from.
+    /// `weapon`
+    pub weapon: Option<ItemWithNoSlot>,
+
+    /// The tags of the entity that aren't parsed, written back as is.
+    /// Left empty when this is embedded in another kind, which keeps them itself.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub unknown_tags: owned::NbtCompound,
+}
+
+/// `minecraft:trident`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Trident {
+    /// The common arrow tags.
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub arrow: Arrow,
+
+    /// true if the trident has already damaged an entity.
+    /// `DealtDamage`
+    pub dealt_damage: bool,
+
+    /// The tags of the entity that aren't parsed, written back as is.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub unknown_tags: owned::NbtCompound,
+}
+
+/// `minecraft:snowball`, `minecraft:egg`, `minecraft:ender_pearl`, `minecraft:potion` and `minecraft:experience_bottle`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ThrownItem {
+    /// The common projectile tags.
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub projectile: Projectile,
+
+    /// The item that was thrown, used for its texture and effects.
+    /// `Item`
+    pub item: Option<ItemWithNoSlot>,
+
+    /// The tags of the entity that aren't parsed, written back as is.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub unknown_tags: owned::NbtCompound,
+}
+
+/// `minecraft:fireball`, `minecraft:small_fireball`, `minecraft:dragon_fireball`, `minecraft:wither_skull`, `minecraft:wind_charge` and `minecraft:breeze_wind_charge`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Fireball {
+    /// The common projectile tags.
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub projectile: Projectile,
+
+    /// How much the fireball accelerates each tick.
+    /// `acceleration_power`
+    pub acceleration_power: Option<f64>,
+
+    /// The power of the explosion, only used by ghast fireballs.
+    /// `ExplosionPower`
+    pub explosion_power: Option<i8>,
+
+    /// The item used for the texture of the fireball.
+    /// `Item`
+    pub item: Option<ItemWithNoSlot>,
+
+    /// The tags of the entity that aren't parsed, written back as is.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub unknown_tags: owned::NbtCompound,
+}
+
+/// `minecraft:firework_rocket`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FireworkRocket {
+    /// The common projectile tags.
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub projectile: Projectile,
+
+    /// Number of ticks the rocket has been flying for.
+    /// `Life`
+    pub life: i32,
+
+    /// Number of ticks before the rocket explodes.
+    /// `LifeTime`
+    pub life_time: i32,
+
+    /// true if the rocket was shot from a crossbow or dispenser at an angle.
+    /// `ShotAtAngle`
+    pub shot_at_angle: bool,
+
+    /// The firework item, holding the explosions of the rocket.
+    /// `FireworksItem`
+    pub fireworks_item: Option<ItemWithNoSlot>,
+
+    /// The tags of the entity that aren't parsed, written back as is.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub unknown_tags: owned::NbtCompound,
+}
+
+impl FromCompoundNbt for Projectile {
+    fn from_compound_nbt(nbt: &simdnbt::borrow::NbtCompound) -> Result<Self, SculkParseError>
+    where
+        Self: Sized,
+    {
+        Ok(Projectile {
+            owner: Uuid::from_int_array(nbt, "Owner")?,
+            left_owner: get_bool(nbt, "LeftOwner"),
+            has_been_shot: get_bool(nbt, "HasBeenShot"),
+        })
+    }
+}
+
+impl FromCompoundNbt for Arrow {
+    fn from_compound_nbt(nbt: &simdnbt::borrow::NbtCompound) -> Result<Self, SculkParseError>
+    where
+        Self: Sized,
+    {
+        let unknown_tags = get_unknown_entity_tags(nbt, &[&PROJECTILE_KEYS, &ARROW_KEYS]);
+
+        Ok(Arrow {
+            projectile: Projectile::from_compound_nbt(nbt)?,
+            crit: get_bool(nbt, "crit"),
+            damage: nbt.double("damage").unwrap_or(2.0),
+            in_ground: get_bool(nbt, "inGround"),
+            life: nbt.short("life").unwrap_or(0),
+            pickup: nbt.byte("pickup").unwrap_or(0),
+            pierce_level: nbt.byte("PierceLevel").unwrap_or(0),
+            shake: nbt.byte("shake").unwrap_or(0),
+            shot_from_crossbow: get_bool(nbt, "ShotFromCrossbow"),
+            sound_event: get_owned_optional_string(nbt, "SoundEvent"),
+            in_block_state: nbt
+                .compound("inBlockState")
+                .map(|nbt| Palette::from_compound_nbt(&nbt))
+                .transpose()?,
+            item: get_optional_item(nbt, "item")?,
+            weapon: get_optional_item(nbt, "weapon")?,
+            unknown_tags,
+        })
+    }
+}
+
+impl FromCompoundNbt for Trident {
+    fn from_compound_nbt(nbt: &simdnbt::borrow::NbtCompound) -> Result<Self, SculkParseError>
+    where
+        Self: Sized,
+    {
+        let mut arrow = Arrow::from_compound_nbt(nbt)?;
+        let unknown_tags = take_unknown_tags(&mut arrow.unknown_tags, &TRIDENT_KEYS);
+
+        Ok(Trident {
+            arrow,
+            dealt_damage: get_bool(nbt, "DealtDamage"),
+            unknown_tags,
+        })
+    }
+}
+
+impl FromCompoundNbt for ThrownItem {
+    fn from_compound_nbt(nbt: &simdnbt::borrow::NbtCompound) -> Result<Self, SculkParseError>
+    where
+        Self: Sized,
+    {
+        let unknown_tags = get_unknown_entity_tags(nbt, &[&PROJECTILE_KEYS, &THROWN_ITEM_KEYS]);
+
+        Ok(ThrownItem {
+            projectile: Projectile::from_compound_nbt(nbt)?,
+            item: get_optional_item(nbt, "Item")?,
+            unknown_tags,
+        })
+    }
+}
+
+impl FromCompoundNbt for Fireball {
+    fn from_compound_nbt(nbt: &simdnbt::borrow::NbtCompound) -> Result<Self, SculkParseError>
+    where
+        Self: Sized,
+    {
+        let unknown_tags = get_unknown_entity_tags(nbt, &[&PROJECTILE_KEYS, &FIREBALL_KEYS]);
+
+        Ok(Fireball {
+            projectile: Projectile::from_compound_nbt(nbt)?,
+            acceleration_power: nbt.double("acceleration_power"),
+            explosion_power: nbt.byte("ExplosionPower"),
+            item: get_optional_item(nbt, "Item")?,
+            unknown_tags,
+        })
+    }
+}
+
+impl FromCompoundNbt for FireworkRocket {
+    fn from_compound_nbt(nbt: &simdnbt::borrow::NbtCompound) -> Result<Self, SculkParseError>
+    where
+        Self: Sized,
+    {
+        let unknown_tags = get_unknown_entity_tags(nbt, &[&PROJECTILE_KEYS, &FIREWORK_ROCKET_KEYS]);
+
+        Ok(FireworkRocket {
+            projectile: Projectile::from_compound_nbt(nbt)?,
+            life: nbt.int("Life").unwrap_or(0),
+            life_time: nbt.int("LifeTime").unwrap_or(0),
+            shot_at_angle: get_bool(nbt, "ShotAtAngle"),
+            fireworks_item: get_optional_item(nbt, "FireworksItem")?,
+            unknown_tags,
+        })
+    }
+}
+
+impl ToCompoundNbt for Projectile {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        insert_optional(&mut nbt, "Owner", self.owner);
+        nbt.insert("LeftOwner", self.left_owner);
+        nbt.insert("HasBeenShot", self.has_been_shot);
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for Arrow {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = self.projectile.to_compound_nbt();
+
+        nbt.insert("crit", self.crit);
+        nbt.insert("damage", self.damage);
+        nbt.insert("inGround", self.in_ground);
+        nbt.insert("life", self.life);
+        nbt.insert("pickup", self.pickup);
+        nbt.insert("PierceLevel", self.pierce_level);
+        nbt.insert("shake", self.shake);
+        nbt.insert("ShotFromCrossbow", self.shot_from_crossbow);
+        insert_optional(&mut nbt, "SoundEvent", self.sound_event.clone());
+        if let Some(state) = &self.in_block_state {
+            nbt.insert("inBlockState", state.to_compound_nbt());
+        }
+        if let Some(item) = &self.item {
+            nbt.insert("item", item.to_compound_nbt());
+        }
+        if let Some(weapon) = &self.weapon {
+            nbt.insert("weapon", weapon.to_compound_nbt());
+        }
+
+        insert_unknown_tags(&mut nbt, &self.unknown_tags);
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for Trident {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = self.arrow.to_compound_nbt();
+
+        nbt.insert("DealtDamage", self.dealt_damage);
+
+        insert_unknown_tags(&mut nbt, &self.unknown_tags);
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for ThrownItem {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = self.projectile.to_compound_nbt();
+
+        if let Some(item) = &self.item {
+            nbt.insert("Item", item.to_compound_nbt());
+        }
+
+        insert_unknown_tags(&mut nbt, &self.unknown_tags);
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for Fireball {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = self.projectile.to_compound_nbt();
+
+        insert_optional(&mut nbt, "acceleration_power", self.acceleration_power);
+        insert_optional(&mut nbt, "ExplosionPower", self.explosion_power);
+        if let Some(item) = &self.item {
+            nbt.insert("Item", item.to_compound_nbt());
+        }
+
+        insert_unknown_tags(&mut nbt, &self.unknown_tags);
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for FireworkRocket {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = self.projectile.to_compound_nbt();
+
+        nbt.insert("Life", self.life);
+        nbt.insert("LifeTime", self.life_time);
+        nbt.insert("ShotAtAngle", self.shot_at_angle);
+        if let Some(item) = &self.fireworks_item {
+            nbt.insert("FireworksItem", item.to_compound_nbt());
+        }
+
+        insert_unknown_tags(&mut nbt, &self.unknown_tags);
+
+        nbt
+    }
+}
@@ -0,0 +1,209 @@
+//! Minecarts and boats.
+
+use crate::{
+    chunk::section::Palette,
+    error::SculkParseError,
+    item::Item,
+    traits::{FromCompoundNbt, ToCompoundNbt},
+    util::{
+        compound_list, get_bool, get_loot_table_data, get_owned_optional_string,
+        get_t_compound_vec, insert_loot_table_data, insert_optional, insert_unknown_tags,
+    },
+};
+use simdnbt::owned;
+
+use super::{get_unknown_entity_tags, take_unknown_tags};
+
+/// The tags parsed into [`Minecart`].
+const MINECART_KEYS: [&str; 3] = ["CustomDisplayTile", "DisplayOffset", "DisplayState"];
+
+/// The tags parsed into [`ContainerMinecart`].
+const CONTAINER_MINECART_KEYS: [&str; 4] = ["Items", "Enabled", "LootTable", "LootTableSeed"];
+
+/// The tags parsed into [`Boat`].
+const BOAT_KEYS: [&str; 4] = ["Type", "Items", "LootTable", "LootTableSeed"];
+
+/// `minecraft:minecart` and the tags shared by every minecart.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Minecart {
+    /// true if the minecart displays a custom block.
+    /// `CustomDisplayTile`
+    pub custom_display_tile: bool,
+
+    /// The offset of the displayed block in pixels, positive values move it up.
+    /// `DisplayOffset`
+    pub display_offset: Option<i32>,
+
+    /// The custom block displayed in the minecart.
+    /// `DisplayState`
+    pub display_state: Option<Palette>,
+
+    /// The tags of the entity that aren't parsed, written back as is.
+    /// Left empty when this is embedded in another kind, which keeps them itself.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub unknown_tags: owned::NbtCompound,
+}
+
+/// `minecraft:chest_minecart` and `minecraft:hopper_minecart`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ContainerMinecart {
+    /// The common minecart tags.
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub minecart: Minecart,
+
+    /// The items in the minecart.
+    /// `Items`
+    pub items: Vec<Item>,
+
+    /// true if the hopper minecart picks up items, not used by chest minecarts.
+    /// `Enabled`
+    pub enabled: Option<bool>,
+
+    /// Loot table to be used to fill the minecart when it is next opened.
+    /// `LootTable`
+    pub loot_table: Option<String>,
+
+    /// Seed for generating the loot table.
+    /// `LootTableSeed`
+    pub loot_table_seed: Option<i64>,
+
+    /// The tags of the entity that aren't parsed, written back as is.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub unknown_tags: owned::NbtCompound,
+}
+
+/// `minecraft:boat` and `minecraft:chest_boat`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Boat {
+    /// The wood type of the boat, like `oak` or `bamboo`.
+    /// `Type`
+    pub r#type: Option<String>,
+
+    /// The items in a chest boat, empty for regular boats.
+    /// `Items`
+    pub items: Vec<Item>,
+
+    /// Loot table to be used to fill the chest boat when it is next opened.
+    /// `LootTable`
+    pub loot_table: Option<String>,
+
+    /// Seed for generating the loot table.
+    /// `LootTableSeed`
+    pub loot_table_seed: Option<i64>,
+
+    /// The tags of the entity that aren't parsed, written back as is.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub unknown_tags: owned::NbtCompound,
+}
+
+impl FromCompoundNbt for Minecart {
+    fn from_compound_nbt(nbt: &simdnbt::borrow::NbtCompound) -> Result<Self, SculkParseError>
+    where
+        Self: Sized,
+    {
+        let unknown_tags = get_unknown_entity_tags(nbt, &[&MINECART_KEYS]);
+
+        Ok(Minecart {
+            custom_display_tile: get_bool(nbt, "CustomDisplayTile"),
+            display_offset: nbt.int("DisplayOffset"),
+            display_state: nbt
+                .compound("DisplayState")
+                .map(|nbt| Palette::from_compound_nbt(&nbt))
+                .transpose()?,
+            unknown_tags,
+        })
+    }
+}
+
+impl FromCompoundNbt for ContainerMinecart {
+    fn from_compound_nbt(nbt: &simdnbt::borrow::NbtCompound) -> Result<Self, SculkParseError>
+    where
+        Self: Sized,
+    {
+        let loot_table = get_loot_table_data(nbt);
+
+        let mut minecart = Minecart::from_compound_nbt(nbt)?;
+        let unknown_tags = take_unknown_tags(&mut minecart.unknown_tags, &CONTAINER_MINECART_KEYS);
+
+        Ok(ContainerMinecart {
+            minecart,
+            items: get_t_compound_vec(nbt, "Items", Item::from_compound_nbt)?,
+            enabled: nbt.byte("Enabled").map(|b| b != 0),
+            loot_table: loot_table.loot_table,
+            loot_table_seed: loot_table.loot_table_seed,
+            unknown_tags,
+        })
+    }
+}
+
+impl FromCompoundNbt for Boat {
+    fn from_compound_nbt(nbt: &simdnbt::borrow::NbtCompound) -> Result<Self, SculkParseError>
+    where
+        Self: Sized,
+    {
+        let loot_table = get_loot_table_data(nbt);
+
+        let unknown_tags = get_unknown_entity_tags(nbt, &[&BOAT_KEYS]);
+
+        Ok(Boat {
+            r#type: get_owned_optional_string(nbt, "Type"),
+            items: get_t_compound_vec(nbt, "Items", Item::from_compound_nbt)?,
+            loot_table: loot_table.loot_table,
+            loot_table_seed: loot_table.loot_table_seed,
+            unknown_tags,
+        })
+    }
+}
+
+impl ToCompoundNbt for Minecart {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        if self.custom_display_tile {
+            nbt.insert("CustomDisplayTile", true);
+        }
+        insert_optional(&mut nbt, "DisplayOffset", self.display_offset);
+        if let Some(state) = &self.display_state {
+            nbt.insert("DisplayState", state.to_compound_nbt());
+        }
+
+        insert_unknown_tags(&mut nbt, &self.unknown_tags);
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for ContainerMinecart {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = self.minecart.to_compound_nbt();
+
+        if !self.items.is_empty() {
+            nbt.insert("Items", compound_list(&self.items));
+        }
+        insert_optional(&mut nbt, "Enabled", self.enabled);
+        insert_loot_table_data(&mut nbt, &self.loot_table, self.loot_table_seed);
+
+        insert_unknown_tags(&mut nbt, &self.unknown_tags);
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for Boat {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        insert_optional(&mut nbt, "Type", self.r#type.clone());
+        if !self.items.is_empty() {
+            nbt.insert("Items", compound_list(&self.items));
+        }
+        insert_loot_table_data(&mut nbt, &self.loot_table, self.loot_table_seed);
+
+        insert_unknown_tags(&mut nbt, &self.unknown_tags);
+
+        nbt
+    }
+}
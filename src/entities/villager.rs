@@ -0,0 +1,395 @@
+//! Villagers and wandering traders.
+
+use crate::{
+    error::SculkParseError,
+    item::ItemWithNoSlot,
+    traits::{FromCompoundNbt, ToCompoundNbt},
+    util::{
+        compound_list, get_bool, get_owned_string, get_t_compound_vec, insert_optional,
+        insert_unknown_tags,
+    },
+    uuid::Uuid,
+};
+use simdnbt::owned;
+
+use super::{animal::Animal, get_optional_item, take_unknown_tags};
+
+/// The tags parsed into [`Villager`].
+const VILLAGER_KEYS: [&str; 9] = [
+    "VillagerData",
+    "Offers",
+    "Gossips",
+    "Inventory",
+    "Xp",
+    "LastRestock",
+    "LastGossipDecay",
+    "RestocksToday",
+    "Willing",
+];
+
+/// The tags parsed into [`WanderingTrader`].
+const WANDERING_TRADER_KEYS: [&str; 4] = ["Offers", "Inventory", "DespawnDelay", "wander_target"];
+
+/// `minecraft:villager`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Villager {
+    /// The common animal tags.
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub animal: Animal,
+
+    /// The profession, biome type and level of the villager.
+    /// `VillagerData`
+    pub villager_data: VillagerData,
+
+    /// The trades the villager offers.
+    /// `Offers`
+    pub offers: Vec<TradeOffer>,
+
+    /// The gossip the villager has heard about other entities.
+    /// `Gossips`
+    pub gossips: Vec<Gossip>,
+
+    /// The items the villager has picked up, like food and seeds.
+    /// `Inventory`
+    pub inventory: Vec<ItemWithNoSlot>,
+
+    /// How much trading experience the villager has.
+    /// `Xp`
+    pub xp: i32,
+
+    /// The game tick when the villager last restocked its trades.
+    /// `LastRestock`
+    pub last_restock: i64,
+
+    /// The game tick when the gossip of the villager last decayed.
+    /// `LastGossipDecay`
+    pub last_gossip_decay: Option<i64>,
+
+    /// How many times the villager has restocked today.
+    /// `RestocksToday`
+    pub restocks_today: i32,
+
+    /// true if the villager is willing to breed.
+    /// `Willing`
+    pub willing: bool,
+
+    /// The tags of the entity that aren't parsed, written back as is.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub unknown_tags: owned::NbtCompound,
+}
+
+/// `minecraft:wandering_trader`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WanderingTrader {
+    /// The common animal tags.
+    #[cfg_attr(feature = "serde", serde(flatten))]
+    pub animal: Animal,
+
+    /// The trades the wandering trader offers.
+    /// `Offers`
+    pub offers: Vec<TradeOffer>,
+
+    /// The items the wandering trader has picked up.
+    /// `Inventory`
+    pub inventory: Vec<ItemWithNoSlot>,
+
+    /// Number of ticks until the wandering trader despawns.
+    /// `DespawnDelay`
+    pub despawn_delay: i32,
+
+    /// The block position the wandering trader is walking to.
+    /// `wander_target`
+    pub wander_target: Option<[i32; 3]>,
+
+    /// The tags of the entity that aren't parsed, written back as is.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub unknown_tags: owned::NbtCompound,
+}
+
+/// The profession, biome type and level of a villager.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VillagerData {
+    /// The career level of the villager, from 1 (novice) to 5 (master).
+    /// `level`
+    pub level: i32,
+
+    /// The profession of the villager, like `minecraft:farmer`.
+    /// `profession`
+    pub profession: String,
+
+    /// The biome type of the villager, like `minecraft:plains`.
+    /// `type`
+    pub r#type: String,
+}
+
+/// A single trade of a villager or wandering trader.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TradeOffer {
+    /// The first item the player has to give.
+    /// `buy`
+    pub buy: ItemWithNoSlot,
+
+    /// The optional second item the player has to give.
+    /// `buyB`
+    pub buy_b: Option<ItemWithNoSlot>,
+
+    /// The item the player receives.
+    /// `sell`
+    pub sell: ItemWithNoSlot,
+
+    /// How many times the trade can be used before it needs to be restocked.
+    /// `maxUses`
+    pub max_uses: i32,
+
+    /// How many times the trade has been used since the last restock.
+    /// `uses`
+    pub uses: i32,
+
+    /// How much experience the villager gets from the trade.
+    /// `xp`
+    pub xp: i32,
+
+    /// true if the trade gives the player experience orbs.
+    /// `rewardExp`
+    pub reward_exp: bool,
+
+    /// The multiplier applied to the price by demand and reputation.
+    /// `priceMultiplier`
+    pub price_multiplier: f32,
+
+    /// A modifier added to the price of the first item, from reputation and effects.
+    /// `specialPrice`
+    pub special_price: i32,
+
+    /// The demand for the trade, raising the price when it is traded often.
+    /// `demand`
+    pub demand: i32,
+}
+
+/// A piece of gossip a villager has about another entity.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Gossip {
+    /// The type of gossip, like `major_negative`, `minor_positive` or `trading`.
+    /// `Type`
+    pub r#type: String,
+
+    /// The strength of the gossip.
+    /// `Value`
+    pub value: i32,
+
+    /// The entity the gossip is about.
+    /// `Target`
+    pub target: Uuid,
+}
+
+impl FromCompoundNbt for Villager {
+    fn from_compound_nbt(nbt: &simdnbt::borrow::NbtCompound) -> Result<Self, SculkParseError>
+    where
+        Self: Sized,
+    {
+        let villager_data = nbt
+            .compound("VillagerData")
+            .map(|nbt| VillagerData::from_compound_nbt(&nbt))
+            .ok_or(SculkParseError::MissingField("VillagerData".into()))??;
+
+        let mut animal = Animal::from_compound_nbt(nbt)?;
+        let unknown_tags = take_unknown_tags(&mut animal.unknown_tags, &VILLAGER_KEYS);
+
+        Ok(Villager {
+            animal,
+            villager_data,
+            offers: get_offers(nbt)?,
+            gossips: get_t_compound_vec(nbt, "Gossips", Gossip::from_compound_nbt)?,
+            inventory: get_t_compound_vec(nbt, "Inventory", ItemWithNoSlot::from_compound_nbt)?,
+            xp: nbt.int("Xp").unwrap_or(0),
+            last_restock: nbt.long("LastRestock").unwrap_or(0),
+            last_gossip_decay: nbt.long("LastGossipDecay"),
+            restocks_today: nbt.int("RestocksToday").unwrap_or(0),
+            willing: get_bool(nbt, "Willing"),
+            unknown_tags,
+        })
+    }
+}
+
+impl FromCompoundNbt for WanderingTrader {
+    fn from_compound_nbt(nbt: &simdnbt::borrow::NbtCompound) -> Result<Self, SculkParseError>
+    where
+        Self: Sized,
+    {
+        let wander_target = nbt
+            .int_array("wander_target")
+            .map(|pos| {
+                pos.as_slice()
+                    .try_into()
+                    .map_err(|_| SculkParseError::InvalidField("wander_target".into()))
+            })
+            .transpose()?;
+
+        let mut animal = Animal::from_compound_nbt(nbt)?;
+        let unknown_tags = take_unknown_tags(&mut animal.unknown_tags, &WANDERING_TRADER_KEYS);
+
+        Ok(WanderingTrader {
+            animal,
+            offers: get_offers(nbt)?,
+            inventory: get_t_compound_vec(nbt, "Inventory", ItemWithNoSlot::from_compound_nbt)?,
+            despawn_delay: nbt.int("DespawnDelay").unwrap_or(0),
+            wander_target,
+            unknown_tags,
+        })
+    }
+}
+
+impl FromCompoundNbt for VillagerData {
+    fn from_compound_nbt(nbt: &simdnbt::borrow::NbtCompound) -> Result<Self, SculkParseError>
+    where
+        Self: Sized,
+    {
+        Ok(VillagerData {
+            level: nbt.int("level").unwrap_or(1),
+            profession: get_owned_string(nbt, "profession")?,
+            r#type: get_owned_string(nbt, "type")?,
+        })
+    }
+}
+
+impl FromCompoundNbt for TradeOffer {
+    fn from_compound_nbt(nbt: &simdnbt::borrow::NbtCompound) -> Result<Self, SculkParseError>
+    where
+        Self: Sized,
+    {
+        let buy =
+            get_optional_item(nbt, "buy")?.ok_or(SculkParseError::MissingField("buy".into()))?;
+        let sell =
+            get_optional_item(nbt, "sell")?.ok_or(SculkParseError::MissingField("sell".into()))?;
+
+        Ok(TradeOffer {
+            buy,
+            buy_b: get_optional_item(nbt, "buyB")?,
+            sell,
+            max_uses: nbt.int("maxUses").unwrap_or(4),
+            uses: nbt.int("uses").unwrap_or(0),
+            xp: nbt.int("xp").unwrap_or(1),
+            reward_exp: nbt.byte("rewardExp").map(|b| b != 0).unwrap_or(true),
+            price_multiplier: nbt.float("priceMultiplier").unwrap_or(0.0),
+            special_price: nbt.int("specialPrice").unwrap_or(0),
+            demand: nbt.int("demand").unwrap_or(0),
+        })
+    }
+}
+
+impl FromCompoundNbt for Gossip {
+    fn from_compound_nbt(nbt: &simdnbt::borrow::NbtCompound) -> Result<Self, SculkParseError>
+    where
+        Self: Sized,
+    {
+        let target = Uuid::from_int_array(nbt, "Target")?
+            .ok_or(SculkParseError::MissingField("Target".into()))?;
+
+        Ok(Gossip {
+            r#type: get_owned_string(nbt, "Type")?,
+            value: nbt.int("Value").unwrap_or(0),
+            target,
+        })
+    }
+}
+
+impl ToCompoundNbt for Villager {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = self.animal.to_compound_nbt();
+
+        nbt.insert("VillagerData", self.villager_data.to_compound_nbt());
+        nbt.insert("Offers", offers_compound(&self.offers));
+        nbt.insert("Gossips", compound_list(&self.gossips));
+        nbt.insert("Inventory", compound_list(&self.inventory));
+        nbt.insert("Xp", self.xp);
+        nbt.insert("LastRestock", self.last_restock);
+        insert_optional(&mut nbt, "LastGossipDecay", self.last_gossip_decay);
+        nbt.insert("RestocksToday", self.restocks_today);
+        nbt.insert("Willing", self.willing);
+
+        insert_unknown_tags(&mut nbt, &self.unknown_tags);
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for WanderingTrader {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = self.animal.to_compound_nbt();
+
+        nbt.insert("Offers", offers_compound(&self.offers));
+        nbt.insert("Inventory", compound_list(&self.inventory));
+        nbt.insert("DespawnDelay", self.despawn_delay);
+        if let Some(target) = self.wander_target {
+            nbt.insert("wander_target", owned::NbtTag::IntArray(target.to_vec()));
+        }
+
+        insert_unknown_tags(&mut nbt, &self.unknown_tags);
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for VillagerData {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        nbt.insert("level", self.level);
+        nbt.insert("profession", self.profession.as_str());
+        nbt.insert("type", self.r#type.as_str());
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for TradeOffer {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        nbt.insert("buy", self.buy.to_compound_nbt());
+        if let Some(buy_b) = &self.buy_b {
+            nbt.insert("buyB", buy_b.to_compound_nbt());
+        }
+        nbt.insert("sell", self.sell.to_compound_nbt());
+        nbt.insert("maxUses", self.max_uses);
+        nbt.insert("uses", self.uses);
+        nbt.insert("xp", self.xp);
+        nbt.insert("rewardExp", self.reward_exp);
+        nbt.insert("priceMultiplier", self.price_multiplier);
+        nbt.insert("specialPrice", self.special_price);
+        nbt.insert("demand", self.demand);
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for Gossip {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        nbt.insert("Type", self.r#type.as_str());
+        nbt.insert("Value", self.value);
+        nbt.insert("Target", self.target);
+
+        nbt
+    }
+}
+
+/// Trades are stored in a `Recipes` list inside the `Offers` compound.
+fn get_offers(nbt: &simdnbt::borrow::NbtCompound) -> Result<Vec<TradeOffer>, SculkParseError> {
+    match nbt.compound("Offers") {
+        Some(offers) => get_t_compound_vec(&offers, "Recipes", TradeOffer::from_compound_nbt),
+        None => Ok(vec![]),
+    }
+}
+
+fn offers_compound(offers: &[TradeOffer]) -> owned::NbtCompound {
+    let mut nbt = owned::NbtCompound::new();
+    nbt.insert("Recipes", compound_list(offers));
+    nbt
+}
@@ -1,9 +1,14 @@
 use crate::{
+    entities::EntityKind,
     error::SculkParseError,
-    traits::FromCompoundNbt,
-    util::{get_optional_name, get_owned_optional_string, get_owned_string, get_t_compound_vec},
+    traits::{FromCompoundNbt, ToCompoundNbt},
+    util::{
+        compound_list, get_optional_name, get_owned_optional_string, get_owned_string,
+        get_t_compound_vec, insert_optional, string_list,
+    },
     uuid::Uuid,
 };
+use simdnbt::owned::{self, NbtList};
 
 // TODO: Might has well merge MaybeEntity into Entity now that like everything is just OPTIONALS
 // i love incomplete documentation and unclear guesses or something
@@ -111,8 +116,9 @@ pub struct Entity {
     /// This entity's Universally Unique IDentifier.
     /// `UUID`
     pub uuid: Option<Uuid>,
-    //
-    // TODO: Add entity specific data field like block entites, low priority as it allows very specific narrow block entity -> entity data handling
+
+    /// The specific data of the entity.
+    pub kind: EntityKind,
 }
 
 /// A maybe entity.  
@@ -222,6 +228,16 @@ pub struct MaybeEntity {
     pub uuid: Option<Uuid>,
 }
 
+#[cfg(feature = "text")]
+impl Entity {
+    /// Parses the custom name as a text component.
+    pub fn custom_name_text(&self) -> Option<crate::text::TextComponent> {
+        self.custom_name
+            .as_deref()
+            .map(crate::text::TextComponent::parse)
+    }
+}
+
 impl FromCompoundNbt for Entity {
     fn from_compound_nbt(
         nbt: &simdnbt::borrow::NbtCompound,
@@ -261,7 +277,7 @@ impl FromCompoundNbt for Entity {
         let on_ground = nbt.byte("OnGround").map(|b| b != 0);
 
         let passengers: Vec<Entity> =
-            get_t_compound_vec(&nbt, "passengers", Entity::from_compound_nbt)?;
+            get_t_compound_vec(&nbt, "Passengers", Entity::from_compound_nbt)?;
 
         let portal_cooldown = nbt.int("PortalCooldown");
 
@@ -310,7 +326,8 @@ impl FromCompoundNbt for Entity {
         };
 
         let ticks_frozen = nbt.int("TicksFrozen");
-        let uuid = nbt.int_array("UUID").map(Uuid::from);
+        let uuid = Uuid::from_int_array(nbt, "UUID")?;
+        let kind = EntityKind::from_compound_nbt(nbt)?;
 
         Ok(Entity {
             air,
@@ -333,10 +350,21 @@ impl FromCompoundNbt for Entity {
             tags,
             ticks_frozen,
             uuid,
+            kind,
         })
     }
 }
 
+#[cfg(feature = "text")]
+impl MaybeEntity {
+    /// Parses the custom name as a text component.
+    pub fn custom_name_text(&self) -> Option<crate::text::TextComponent> {
+        self.custom_name
+            .as_deref()
+            .map(crate::text::TextComponent::parse)
+    }
+}
+
 impl FromCompoundNbt for MaybeEntity {
     fn from_compound_nbt(
         nbt: &simdnbt::borrow::NbtCompound,
@@ -373,7 +401,7 @@ impl FromCompoundNbt for MaybeEntity {
         let on_ground = nbt.byte("OnGround").map(|b| b != 0);
 
         let passengers: Option<Vec<Entity>> =
-            match get_t_compound_vec(&nbt, "passengers", Entity::from_compound_nbt) {
+            match get_t_compound_vec(&nbt, "Passengers", Entity::from_compound_nbt) {
                 Ok(passengers) => Some(passengers),
                 Err(SculkParseError::MissingField(_)) => None,
                 Err(e) => return Err(e),
@@ -428,7 +456,7 @@ impl FromCompoundNbt for MaybeEntity {
         };
 
         let ticks_frozen = nbt.int("TicksFrozen");
-        let uuid = nbt.int_array("UUID").map(Uuid::from);
+        let uuid = Uuid::from_int_array(nbt, "UUID")?;
 
         Ok(MaybeEntity {
             air,
@@ -455,6 +483,125 @@ impl FromCompoundNbt for MaybeEntity {
     }
 }
 
+/// Writes the fields shared by [`Entity`] and [`MaybeEntity`], except for `id`, `Passengers` and `Tags`.
+#[allow(clippy::too_many_arguments)]
+fn insert_entity_fields(
+    nbt: &mut owned::NbtCompound,
+    air: Option<i16>,
+    custom_name: &Option<String>,
+    custom_name_visible: Option<bool>,
+    fall_distance: Option<f32>,
+    fire: Option<i16>,
+    glowing: Option<bool>,
+    has_visual_fire: Option<bool>,
+    invulnerable: Option<bool>,
+    motion: Option<[f64; 3]>,
+    no_gravity: Option<bool>,
+    on_ground: Option<bool>,
+    portal_cooldown: Option<i32>,
+    pos: Option<[f64; 3]>,
+    rotation: Option<[f32; 2]>,
+    silent: Option<bool>,
+    ticks_frozen: Option<i32>,
+    uuid: Option<Uuid>,
+) {
+    insert_optional(nbt, "Air", air);
+    insert_optional(nbt, "CustomName", custom_name.clone());
+    insert_optional(nbt, "CustomNameVisible", custom_name_visible);
+    insert_optional(nbt, "FallDistance", fall_distance);
+    insert_optional(nbt, "Fire", fire);
+    insert_optional(nbt, "Glowing", glowing);
+    insert_optional(nbt, "HasVisualFire", has_visual_fire);
+    insert_optional(nbt, "Invulnerable", invulnerable);
+    insert_optional(nbt, "Motion", motion.map(|m| NbtList::Double(m.to_vec())));
+    insert_optional(nbt, "NoGravity", no_gravity);
+    insert_optional(nbt, "OnGround", on_ground);
+    insert_optional(nbt, "PortalCooldown", portal_cooldown);
+    insert_optional(nbt, "Pos", pos.map(|p| NbtList::Double(p.to_vec())));
+    insert_optional(
+        nbt,
+        "Rotation",
+        rotation.map(|r| NbtList::Float(r.to_vec())),
+    );
+    insert_optional(nbt, "Silent", silent);
+    insert_optional(nbt, "TicksFrozen", ticks_frozen);
+    insert_optional(nbt, "UUID", uuid);
+}
+
+impl ToCompoundNbt for Entity {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        nbt.insert("id", self.id.as_str());
+        insert_entity_fields(
+            &mut nbt,
+            self.air,
+            &self.custom_name,
+            self.custom_name_visible,
+            self.fall_distance,
+            self.fire,
+            self.glowing,
+            self.has_visual_fire,
+            self.invulnerable,
+            self.motion,
+            self.no_gravity,
+            self.on_ground,
+            self.portal_cooldown,
+            self.pos,
+            self.rotation,
+            self.silent,
+            self.ticks_frozen,
+            self.uuid,
+        );
+
+        if !self.passengers.is_empty() {
+            nbt.insert("Passengers", compound_list(&self.passengers));
+        }
+        if !self.tags.is_empty() {
+            nbt.insert("Tags", string_list(&self.tags));
+        }
+        nbt.extend(self.kind.to_compound_nbt());
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for MaybeEntity {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        insert_optional(&mut nbt, "id", self.id.clone());
+        insert_entity_fields(
+            &mut nbt,
+            self.air,
+            &self.custom_name,
+            self.custom_name_visible,
+            self.fall_distance,
+            self.fire,
+            self.glowing,
+            self.has_visual_fire,
+            self.invulnerable,
+            self.motion,
+            self.no_gravity,
+            self.on_ground,
+            self.portal_cooldown,
+            self.pos,
+            self.rotation,
+            self.silent,
+            self.ticks_frozen,
+            self.uuid,
+        );
+        insert_optional(
+            &mut nbt,
+            "Passengers",
+            self.passengers.as_deref().map(compound_list),
+        );
+        insert_optional(&mut nbt, "Tags", self.tags.as_deref().map(string_list));
+
+        nbt
+    }
+}
+
 // FUTURE ENTITY PLAN
 
 // Entity Enum
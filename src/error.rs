@@ -26,4 +26,40 @@ pub enum SculkParseError {
     /// Error when the block entity is unsupported.
     #[error("Unsupported block entity: {0}")]
     UnsupportedBlockEntity(String),
+
+    /// Error when reading a file fails.
+    #[error("IO error: {0}")]
+    IoError(#[from] std::io::Error),
+
+    /// Error when a region file or its chunk data is malformed.
+    #[error("Invalid region: {0}")]
+    InvalidRegion(String),
+
+    /// Error when a SNBT string can't be parsed.
+    #[error("Invalid SNBT: {0}")]
+    InvalidSnbt(String),
+
+    /// Error when a JSON text component can't be parsed.
+    #[error("Invalid text component: {0}")]
+    InvalidTextComponent(String),
+
+    /// Error when a UUID string can't be parsed.
+    #[error("Invalid UUID: {0}")]
+    InvalidUuid(String),
+
+    /// Error when a JSON file, like advancements or statistics, can't be parsed.
+    #[error("Invalid JSON: {0}")]
+    InvalidJson(String),
+
+    /// Error when data is from a version whose format isn't supported.
+    #[error("Unsupported data version {0}: {1}")]
+    UnsupportedDataVersion(i32, String),
+
+    /// Error when a chunk uses an unknown compression scheme.
+    #[error("Unsupported compression type: {0}")]
+    UnsupportedCompression(u8),
+
+    /// Error when an image, like a rendered map, can't be encoded.
+    #[error("Image error: {0}")]
+    ImageError(String),
 }
@@ -1,8 +1,11 @@
 use crate::{
     components::Components,
-    traits::FromCompoundNbt,
-    util::{get_optional_components, get_owned_string},
+    traits::{FromCompoundNbt, ToCompoundNbt},
+    util::{
+        get_item_count, get_optional_item_components, get_owned_string, insert_optional_components,
+    },
 };
+use simdnbt::owned;
 
 /// Represents an item in an inventory slot.
 #[derive(Debug, Clone, PartialEq)]
@@ -53,11 +56,9 @@ impl FromCompoundNbt for Item {
 
         let id = get_owned_string(&nbt, "id")?;
 
-        let count = nbt
-            .int("count")
-            .unwrap_or_else(|| nbt.byte("Count").unwrap_or(1) as i32);
+        let count = get_item_count(nbt);
 
-        let components = get_optional_components(&nbt)?;
+        let components = get_optional_item_components(&nbt)?;
 
         Ok(Item {
             slot,
@@ -77,11 +78,9 @@ impl FromCompoundNbt for ItemWithNoSlot {
     {
         let id = get_owned_string(&nbt, "id")?;
 
-        let count = nbt
-            .int("count")
-            .unwrap_or_else(|| nbt.byte("Count").unwrap_or(1) as i32);
+        let count = get_item_count(nbt);
 
-        let components = get_optional_components(&nbt)?;
+        let components = get_optional_item_components(&nbt)?;
 
         Ok(ItemWithNoSlot {
             id,
@@ -90,3 +89,28 @@ impl FromCompoundNbt for ItemWithNoSlot {
         })
     }
 }
+
+impl ToCompoundNbt for Item {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        nbt.insert("Slot", self.slot);
+        nbt.insert("id", self.id.as_str());
+        nbt.insert("count", self.count);
+        insert_optional_components(&mut nbt, &self.components);
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for ItemWithNoSlot {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        nbt.insert("id", self.id.as_str());
+        nbt.insert("count", self.count);
+        insert_optional_components(&mut nbt, &self.components);
+
+        nbt
+    }
+}
@@ -1,4 +1,7 @@
-use crate::{error::SculkParseError, traits::FromCompoundNbt};
+use crate::{
+    error::SculkParseError,
+    traits::{FromCompoundNbt, ToCompoundNbt},
+};
 use simdnbt::borrow::NbtCompound;
 use std::{
     collections::HashMap,
@@ -92,3 +95,39 @@ impl FromCompoundNbt for KVPair<simdnbt::owned::NbtCompound> {
         Ok(KVPair::new(map))
     }
 }
+
+impl ToCompoundNbt for KVPair<String> {
+    fn to_compound_nbt(&self) -> simdnbt::owned::NbtCompound {
+        let mut nbt = simdnbt::owned::NbtCompound::new();
+
+        for (key, value) in self.iter() {
+            nbt.insert(key.as_str(), value.as_str());
+        }
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for KVPair<i32> {
+    fn to_compound_nbt(&self) -> simdnbt::owned::NbtCompound {
+        let mut nbt = simdnbt::owned::NbtCompound::new();
+
+        for (key, value) in self.iter() {
+            nbt.insert(key.as_str(), *value);
+        }
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for KVPair<simdnbt::owned::NbtCompound> {
+    fn to_compound_nbt(&self) -> simdnbt::owned::NbtCompound {
+        let mut nbt = simdnbt::owned::NbtCompound::new();
+
+        for (key, value) in self.iter() {
+            nbt.insert(key.as_str(), value.clone());
+        }
+
+        nbt
+    }
+}
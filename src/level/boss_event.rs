@@ -3,10 +3,11 @@
 use crate::{
     error::SculkParseError,
     kv::KVPair,
-    traits::FromCompoundNbt,
+    traits::{FromCompoundNbt, ToCompoundNbt},
     util::{get_bool, get_owned_string},
     uuid::Uuid,
 };
+use simdnbt::owned;
 
 use std::collections::HashMap;
 
@@ -87,6 +88,20 @@ impl From<&str> for BossEventOverlay {
     }
 }
 
+impl BossEventOverlay {
+    /// Returns the overlay as it is stored in the Nbt.
+    pub fn to_str(&self) -> &str {
+        match self {
+            BossEventOverlay::Progress => "progress",
+            BossEventOverlay::Notched6 => "notched_6",
+            BossEventOverlay::Notched10 => "notched_10",
+            BossEventOverlay::Notched12 => "notched_12",
+            BossEventOverlay::Notched20 => "notched_20",
+            BossEventOverlay::Unknown(value) => value,
+        }
+    }
+}
+
 impl FromCompoundNbt for KVPair<CustomBossEvent> {
     fn from_compound_nbt(nbt: &simdnbt::borrow::NbtCompound) -> Result<Self, SculkParseError>
     where
@@ -108,6 +123,14 @@ impl FromCompoundNbt for KVPair<CustomBossEvent> {
     }
 }
 
+#[cfg(feature = "text")]
+impl CustomBossEvent {
+    /// Parses the display name as a text component.
+    pub fn name_text(&self) -> crate::text::TextComponent {
+        crate::text::TextComponent::parse(&self.name)
+    }
+}
+
 impl FromCompoundNbt for CustomBossEvent {
     fn from_compound_nbt(
         nbt: &simdnbt::borrow::NbtCompound,
@@ -166,3 +189,34 @@ impl FromCompoundNbt for CustomBossEvent {
         })
     }
 }
+
+impl ToCompoundNbt for KVPair<CustomBossEvent> {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        for (key, value) in self.iter() {
+            nbt.insert(key.as_str(), value.to_compound_nbt());
+        }
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for CustomBossEvent {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        nbt.insert("Players", Uuid::to_nbt_list(&self.players));
+        nbt.insert("Color", self.color.as_str());
+        nbt.insert("CreateWorldFog", self.create_world_fog);
+        nbt.insert("DarkenScreen", self.darken_screen);
+        nbt.insert("Max", self.max);
+        nbt.insert("Value", self.value);
+        nbt.insert("Name", self.name.as_str());
+        nbt.insert("Overlay", self.overlay.to_str());
+        nbt.insert("PlayBossMusic", self.play_boss_music);
+        nbt.insert("Visible", self.visible);
+
+        nbt
+    }
+}
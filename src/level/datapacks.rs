@@ -1,7 +1,11 @@
 //! Lists what datapacks are enabled and disabled in a world.  
 //! You may think at first this is the actual datapacks but nah nah.
 
-use crate::traits::FromCompoundNbt;
+use crate::{
+    traits::{FromCompoundNbt, ToCompoundNbt},
+    util::string_list,
+};
+use simdnbt::owned;
 
 /// What datapacks are enabled and disabled in a world.
 #[derive(Debug, Clone, PartialEq)]
@@ -54,3 +58,14 @@ impl FromCompoundNbt for Datapacks {
         Ok(Self { disabled, enabled })
     }
 }
+
+impl ToCompoundNbt for Datapacks {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        nbt.insert("Disabled", string_list(&self.disabled));
+        nbt.insert("Enabled", string_list(&self.enabled));
+
+        nbt
+    }
+}
@@ -1,6 +1,12 @@
 //! Dimension specific data for a world.  
 
-use crate::{error::SculkParseError, traits::FromCompoundNbt, util::get_bool};
+use crate::{
+    error::SculkParseError,
+    traits::{FromCompoundNbt, ToCompoundNbt},
+    util::get_bool,
+    uuid::Uuid,
+};
+use simdnbt::owned;
 
 /// Data for a dimension.
 #[derive(Debug, Clone, PartialEq)]
@@ -39,13 +45,13 @@ pub struct DragonFight {
     /// `DragonKilled`
     pub dragon_killed: bool,
 
-    /// The least significant bits of the current Ender Dragon's [Universally Unique IDentifier](http://docs.oracle.com/javase/6/docs/api/java/util/UUID.html). This is joined with DragonUUIDMost to form the dragon's unique ID.  
-    /// `DragonUUIDLeast`
-    pub dragon_uuid_least: Option<i64>,
+    /// The current Ender Dragon's [Universally Unique IDentifier](http://docs.oracle.com/javase/6/docs/api/java/util/UUID.html), stored as its most and least significant bits.  
+    /// `DragonUUIDMost` and `DragonUUIDLeast`
+    pub dragon_uuid: Option<Uuid>,
 
-    /// The most significant bits of the current Ender Dragon's [Universally Unique IDentifier](http://docs.oracle.com/javase/6/docs/api/java/util/UUID.html). This is joined with DragonUUIDLeast to form the dragon's unique ID.  
-    /// `DragonUUIDMost`
-    pub dragon_uuid_most: Option<i64>,
+    /// If the End has to be scanned for an existing dragon and portal, true until the End is first loaded.  
+    /// `NeedsStateScanning`
+    pub needs_state_scanning: bool,
 
     /// If the ender dragon has ever been defeated. Used to determine EXP given by dragon.  
     /// `PreviouslyKilled`
@@ -117,17 +123,23 @@ impl FromCompoundNbt for DragonFight {
             .ok_or(SculkParseError::MissingField("Gateways".into()))?;
         let dragon_killed = get_bool(&nbt, "DragonKilled");
 
-        let dragon_uuid_least = nbt.long("DragonUUIDLeast");
-        let dragon_uuid_most = nbt.long("DragonUUIDMost");
+        let dragon_uuid = match (nbt.long("DragonUUIDMost"), nbt.long("DragonUUIDLeast")) {
+            (Some(most), Some(least)) => Some(Uuid::from_most_least(most, least)),
+            _ => None,
+        };
 
+        let needs_state_scanning = nbt
+            .byte("NeedsStateScanning")
+            .map(|b| b != 0)
+            .unwrap_or(true);
         let previously_killed = get_bool(&nbt, "PreviouslyKilled");
 
         Ok(Self {
             exit_portal_location,
             gateways,
             dragon_killed,
-            dragon_uuid_least,
-            dragon_uuid_most,
+            dragon_uuid,
+            needs_state_scanning,
             previously_killed,
         })
     }
@@ -153,3 +165,56 @@ impl FromCompoundNbt for ExitPortalLocation {
         Ok(Self { x, y, z })
     }
 }
+
+impl ToCompoundNbt for DimensionData {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        nbt.insert("1", self.end.to_compound_nbt());
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for EndData {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        nbt.insert("DragonFight", self.dragon_fight.to_compound_nbt());
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for DragonFight {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        if let Some(location) = &self.exit_portal_location {
+            nbt.insert("ExitPortalLocation", location.to_compound_nbt());
+        }
+        nbt.insert("Gateways", owned::NbtTag::IntArray(self.gateways.clone()));
+        nbt.insert("DragonKilled", self.dragon_killed);
+        if let Some(uuid) = self.dragon_uuid {
+            let (most, least) = uuid.to_most_least();
+            nbt.insert("DragonUUIDLeast", least);
+            nbt.insert("DragonUUIDMost", most);
+        }
+        nbt.insert("NeedsStateScanning", self.needs_state_scanning);
+        nbt.insert("PreviouslyKilled", self.previously_killed);
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for ExitPortalLocation {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        nbt.insert("X", self.x);
+        nbt.insert("Y", self.y);
+        nbt.insert("Z", self.z);
+
+        nbt
+    }
+}
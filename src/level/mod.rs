@@ -7,10 +7,14 @@ use crate::{
     error::SculkParseError,
     kv::KVPair,
     player::{game_type::GameType, Player},
-    traits::FromCompoundNbt,
-    util::{get_bool, get_owned_string},
+    traits::{FromCompoundNbt, ToCompoundNbt},
+    util::{
+        get_bool, get_owned_string, get_unknown_tags, insert_optional, insert_unknown_tags,
+        string_list,
+    },
     uuid::Uuid,
 };
+use simdnbt::owned;
 
 pub mod boss_event;
 pub mod datapacks;
@@ -132,8 +136,9 @@ pub struct Level {
     pub level_name: String,
 
     ///  true if the map generator should place structures such as villages, strongholds, and mineshafts. Defaults to 1. Always 1 if the world type is Customized.  
+    /// Moved into `WorldGenSettings` in 1.16, so it doesn't exist in newer worlds.  
     /// `MapFeatures`
-    pub map_features: bool,
+    pub map_features: Option<bool>,
 
     /// The state of the Singleplayer player. This overrides the <player>.dat file with the same name as the Singleplayer player. This is saved by Servers only if it already exists, otherwise it is not saved for server worlds. See [Player.dat Format](https://minecraft.wiki/w/Player.dat_format#NBT_Structure).  
     /// `Player`
@@ -151,10 +156,18 @@ pub struct Level {
     /// `RandomSeed`
     pub random_seed: Option<i64>,
 
+    /// The brands of every server software that has opened the world, like `vanilla` or `fabric`.  
+    /// `ServerBrands`
+    pub server_brands: Vec<String>,
+
     /// The estimated size in bytes of the level. Currently not modified or used by Minecraft, but was previously.  
     /// `SizeOnDisk`
     pub size_on_disk: Option<i64>,
 
+    /// The rotation players face when they spawn at the world spawn.  
+    /// `SpawnAngle`
+    pub spawn_angle: f32,
+
     /// The X coordinate of the world spawn.  
     /// `SpawnX`
     pub spawn_x: i32,
@@ -202,8 +215,62 @@ pub struct Level {
     ///  true if the world was opened in a modified version.  
     /// `WasModded`
     pub was_modded: bool,
+
+    /// Every tag in `Data` that isn't parsed, like `ScheduledEvents`, written back as is.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub unknown_tags: owned::NbtCompound,
 }
 
+/// The tags in `Data` that are parsed into [`Level`].
+const LEVEL_KEYS: [&str; 46] = [
+    "allowCommands",
+    "BorderCenterX",
+    "BorderCenterZ",
+    "BorderDamagePerBlock",
+    "BorderSafeZone",
+    "BorderSize",
+    "BorderSizeLerpTarget",
+    "BorderSizeLerpTime",
+    "BorderWarningBlocks",
+    "BorderWarningTime",
+    "clearWeatherTime",
+    "CustomBossEvents",
+    "DataPacks",
+    "DataVersion",
+    "DayTime",
+    "Difficulty",
+    "DifficultyLocked",
+    "DragonFight",
+    "enabled_features",
+    "GameRules",
+    "GameType",
+    "hardcore",
+    "initialized",
+    "LastPlayed",
+    "LevelName",
+    "MapFeatures",
+    "Player",
+    "raining",
+    "rainTime",
+    "RandomSeed",
+    "ServerBrands",
+    "SizeOnDisk",
+    "SpawnAngle",
+    "SpawnX",
+    "SpawnY",
+    "SpawnZ",
+    "thundering",
+    "thunderTime",
+    "Time",
+    "version",
+    "Version",
+    "WanderingTraderId",
+    "WanderingTraderSpawnChance",
+    "WanderingTraderSpawnDelay",
+    "WasModded",
+    "WorldGenSettings",
+];
+
 /// More detailed information about the Minecraft version the world was saved in.
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -273,6 +340,17 @@ impl Difficulty {
             _ => Difficulty::Unknown(b),
         }
     }
+
+    /// Converts a Difficulty back to an i8.
+    pub fn to_i8(&self) -> i8 {
+        match self {
+            Difficulty::Peaceful => 0,
+            Difficulty::Easy => 1,
+            Difficulty::Normal => 2,
+            Difficulty::Hard => 3,
+            Difficulty::Unknown(b) => *b,
+        }
+    }
 }
 
 impl FromCompoundNbt for Level {
@@ -332,21 +410,22 @@ impl FromCompoundNbt for Level {
             .map(|nbt| DragonFight::from_compound_nbt(&nbt))
             .ok_or(SculkParseError::MissingField("DragonFight".into()))??;
 
-        let enabled_features = if let Some(nbt) = nbt.list("enabled_features") {
-            let list = nbt
-                .strings()
-                .ok_or(SculkParseError::InvalidField("enabled_features".into()))?;
+        let enabled_features =
+            if let Some(nbt) = nbt.list("enabled_features").filter(|l| !l.empty()) {
+                let list = nbt
+                    .strings()
+                    .ok_or(SculkParseError::InvalidField("enabled_features".into()))?;
 
-            let mut vec: Vec<String> = vec![];
+                let mut vec: Vec<String> = vec![];
 
-            for item in list.iter() {
-                vec.push((*item).to_string());
-            }
+                for item in list.iter() {
+                    vec.push((*item).to_string());
+                }
 
-            vec
-        } else {
-            vec![]
-        };
+                vec
+            } else {
+                vec![]
+            };
 
         let game_rules: KVPair<String> = nbt
             .compound("GameRules")
@@ -371,7 +450,7 @@ impl FromCompoundNbt for Level {
             .ok_or(SculkParseError::MissingField("LastPlayed".into()))?;
 
         let level_name = get_owned_string(&nbt, "LevelName")?;
-        let map_features = nbt.byte("MapFeatures").map(|b| b != 0).unwrap_or(true);
+        let map_features = nbt.byte("MapFeatures").map(|b| b != 0);
 
         let player = if let Some(nbt) = nbt.compound("Player") {
             Some(Player::from_compound_nbt(&nbt)?)
@@ -385,7 +464,12 @@ impl FromCompoundNbt for Level {
             .ok_or(SculkParseError::MissingField("rainTime".into()))?;
 
         let random_seed = nbt.long("RandomSeed");
+        let server_brands = match nbt.list("ServerBrands").and_then(|l| l.strings()) {
+            Some(brands) => brands.iter().map(|b| b.to_string()).collect(),
+            None => vec![],
+        };
         let size_on_disk = nbt.long("SizeOnDisk");
+        let spawn_angle = nbt.float("SpawnAngle").unwrap_or(0.0);
 
         let spawn_x = nbt
             .int("SpawnX")
@@ -463,7 +547,9 @@ impl FromCompoundNbt for Level {
             raining,
             rain_time,
             random_seed,
+            server_brands,
             size_on_disk,
+            spawn_angle,
             spawn_x,
             spawn_y,
             spawn_z,
@@ -476,6 +562,7 @@ impl FromCompoundNbt for Level {
             wandering_trader_spawn_chance,
             wandering_trader_spawn_delay,
             was_modded,
+            unknown_tags: get_unknown_tags(&nbt, &LEVEL_KEYS),
         })
     }
 }
@@ -493,26 +580,113 @@ impl Level {
     }
 }
 
+impl ToCompoundNbt for VersionData {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        nbt.insert("Id", self.id);
+        nbt.insert("Name", self.name.as_str());
+        nbt.insert("Series", self.series.as_str());
+        nbt.insert("Snapshot", self.snapshot);
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for Level {
+    /// Writes the level wrapped in the root `Data` compound, like the `level.dat` file.
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut data = owned::NbtCompound::new();
+
+        data.insert("allowCommands", self.allow_commands);
+        data.insert("BorderCenterX", self.border_center_x);
+        data.insert("BorderCenterZ", self.border_center_z);
+        data.insert("BorderDamagePerBlock", self.border_damage_per_block);
+        data.insert("BorderSize", self.border_size);
+        data.insert("BorderSafeZone", self.border_safe_zone);
+        data.insert("BorderSizeLerpTarget", self.border_size_lerp_target);
+        data.insert("BorderSizeLerpTime", self.border_size_lerp_time);
+        data.insert("BorderWarningBlocks", self.border_warning_blocks);
+        data.insert("BorderWarningTime", self.border_warning_time);
+        data.insert("clearWeatherTime", self.clear_weather_time);
+        data.insert(
+            "CustomBossEvents",
+            self.custom_boss_events.to_compound_nbt(),
+        );
+        data.insert("DataPacks", self.datapacks.to_compound_nbt());
+        data.insert("DataVersion", self.data_version);
+        data.insert("DayTime", self.day_time);
+        data.insert("Difficulty", self.difficulty.to_i8());
+        data.insert("DifficultyLocked", self.difficulty_locked);
+        data.insert("DragonFight", self.dimension_data.to_compound_nbt());
+        if !self.enabled_features.is_empty() {
+            data.insert("enabled_features", string_list(&self.enabled_features));
+        }
+        data.insert("GameRules", self.game_rules.to_compound_nbt());
+        data.insert(
+            "WorldGenSettings",
+            self.world_gen_settings.to_compound_nbt(),
+        );
+        data.insert("GameType", self.game_type.to_i32());
+        data.insert("hardcore", self.hardcore);
+        data.insert("initialized", self.initialized);
+        data.insert("LastPlayed", self.last_played);
+        data.insert("LevelName", self.level_name.as_str());
+        insert_optional(&mut data, "MapFeatures", self.map_features);
+        if let Some(player) = &self.player {
+            data.insert("Player", player.to_compound_nbt());
+        }
+        data.insert("raining", self.raining);
+        data.insert("rainTime", self.rain_time);
+        insert_optional(&mut data, "RandomSeed", self.random_seed);
+        if !self.server_brands.is_empty() {
+            data.insert("ServerBrands", string_list(&self.server_brands));
+        }
+        insert_optional(&mut data, "SizeOnDisk", self.size_on_disk);
+        data.insert("SpawnAngle", self.spawn_angle);
+        data.insert("SpawnX", self.spawn_x);
+        data.insert("SpawnY", self.spawn_y);
+        data.insert("SpawnZ", self.spawn_z);
+        data.insert("thundering", self.thundering);
+        data.insert("thunderTime", self.thunder_time);
+        data.insert("Time", self.time);
+        data.insert("version", self.version);
+        data.insert("Version", self.version_data.to_compound_nbt());
+        insert_optional(&mut data, "WanderingTraderId", self.wandering_trader_id);
+        data.insert(
+            "WanderingTraderSpawnChance",
+            self.wandering_trader_spawn_chance,
+        );
+        data.insert(
+            "WanderingTraderSpawnDelay",
+            self.wandering_trader_spawn_delay,
+        );
+        data.insert("WasModded", self.was_modded);
+        insert_unknown_tags(&mut data, &self.unknown_tags);
+
+        let mut nbt = owned::NbtCompound::new();
+        nbt.insert("Data", data);
+
+        nbt
+    }
+}
+
 #[cfg(test)]
 #[test]
 fn simple_level_test() {
-    use flate2::read::GzDecoder;
-    use std::io::{Cursor, Read};
-
-    let mut file = std::fs::File::open("test_data/level.dat").unwrap();
-    let mut contents = Vec::new();
-    file.read_to_end(&mut contents).unwrap();
-    let mut src = &contents[..];
-
-    let mut src_decoder = GzDecoder::new(&mut src);
-    let mut input = Vec::new();
-    if src_decoder.read_to_end(&mut input).is_err() {
-        input = contents;
-    }
-    let mut input_stream = Cursor::new(&input[..]);
+    use std::io::Cursor;
+
+    let input = crate::util::read_test_data("test_data/level.dat");
+    let nbt = simdnbt::borrow::read(&mut Cursor::new(&input[..]))
+        .unwrap()
+        .unwrap();
 
-    let nbt = simdnbt::borrow::read(&mut input_stream).unwrap().unwrap();
-    let nbt = nbt.as_compound();
+    let _ = Level::from_compound_nbt(&nbt.as_compound()).unwrap();
+}
 
-    let _ = Level::from_compound_nbt(&nbt).unwrap();
+#[cfg(test)]
+#[test]
+fn level_round_trip() {
+    let input = crate::util::read_test_data("test_data/level.dat");
+    crate::util::assert_round_trip::<Level>(&input);
 }
@@ -1,6 +1,12 @@
 //! Settings used when generating the world.  
 
-use crate::{error::SculkParseError, kv::KVPair, traits::FromCompoundNbt};
+use crate::{
+    error::SculkParseError,
+    kv::KVPair,
+    traits::{FromCompoundNbt, ToCompoundNbt},
+    util::insert_optional,
+};
+use simdnbt::owned;
 
 /// Settings about the world generation.
 #[derive(Debug, Clone, PartialEq)]
@@ -54,3 +60,16 @@ impl FromCompoundNbt for WorldGenSettings {
         })
     }
 }
+
+impl ToCompoundNbt for WorldGenSettings {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        insert_optional(&mut nbt, "bonus_chest", self.bonus_chest);
+        nbt.insert("seed", self.seed);
+        nbt.insert("generate_features", self.generate_features);
+        nbt.insert("dimensions", self.dimensions.to_compound_nbt());
+
+        nbt
+    }
+}
@@ -17,25 +17,41 @@
 // Pub mod declarations.
 pub mod block_entities;
 pub mod block_entity;
+pub mod borrowed;
 pub mod chunk;
 pub mod color;
 pub mod components;
+pub mod entities;
 pub mod entity;
 pub mod error;
 pub mod item;
 pub mod level;
 pub mod map;
 pub mod player;
+pub mod poi;
 pub mod rarity;
+pub mod region;
+pub mod snbt;
 pub mod traits;
 pub mod uuid;
+pub mod version;
+pub mod world;
 
 // Internal modules.
 mod kv;
 mod util;
 
 #[cfg(feature = "stats")]
-mod statistics;
+pub mod statistics;
+
+#[cfg(feature = "parallel")]
+pub mod parallel;
+
+#[cfg(feature = "text")]
+pub mod text;
+
+#[cfg(feature = "advancements")]
+pub mod advancements;
 
 // Re-export the modules.
 pub use util::MC_VERSION;
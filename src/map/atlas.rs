@@ -0,0 +1,463 @@
+//! Stitching many maps into one large image.
+//!
+//! Every map covers a square of the world, `128 * 2^scale` blocks wide and centered on `xCenter` and `zCenter`.
+//! An atlas places every map of the same dimension and scale at its position,
+//! covering the bounding box of all of them, so maps far apart from each other make for a large image.
+//! Atlases wider or taller than [`MapAtlas::MAX_SIZE`] pixels aren't built.
+
+use super::{Map, MapBrightness, MapColor};
+use crate::{color::Color, error::SculkParseError};
+use std::collections::BTreeMap;
+
+/// The area of the world a map covers, in blocks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MapBounds {
+    /// The lowest x coordinate covered.
+    pub min_x: i32,
+
+    /// The lowest z coordinate covered.
+    pub min_z: i32,
+
+    /// The x coordinate right after the last one covered.
+    pub max_x: i32,
+
+    /// The z coordinate right after the last one covered.
+    pub max_z: i32,
+}
+
+impl MapBounds {
+    /// If the block at `x` and `z` is within the bounds.
+    pub fn contains(&self, x: i32, z: i32) -> bool {
+        (self.min_x..self.max_x).contains(&x) && (self.min_z..self.max_z).contains(&z)
+    }
+}
+
+impl Map {
+    /// How many blocks wide every pixel of the map is, `2^scale`.
+    pub fn blocks_per_pixel(&self) -> i32 {
+        1 << self.scale.clamp(0, 4)
+    }
+
+    /// The area of the world the map covers.
+    pub fn bounds(&self) -> MapBounds {
+        let half = Map::SIZE as i32 / 2 * self.blocks_per_pixel();
+
+        MapBounds {
+            min_x: self.x_center - half,
+            min_z: self.z_center - half,
+            max_x: self.x_center + half,
+            max_z: self.z_center + half,
+        }
+    }
+}
+
+/// What a marker on an atlas comes from.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum MapMarkerKind {
+    /// A banner added to a map by using it on the banner.
+    Banner {
+        /// The color of the banner.
+        color: Color,
+
+        /// The custom name of the banner, in JSON text.
+        name: Option<String>,
+    },
+
+    /// An item frame holding the map.
+    Frame {
+        /// The id of the marker.
+        entity_id: i32,
+
+        /// The rotation of the marker, ranging from 0 to 360.
+        rotation: i32,
+    },
+}
+
+/// A banner or frame marker placed on an atlas.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MapMarker {
+    /// What the marker is.
+    pub kind: MapMarkerKind,
+
+    /// The block position of the marker in the world.
+    pub pos: [i32; 3],
+
+    /// The column of the pixel the marker is on, from the left of the atlas.
+    pub pixel_x: u32,
+
+    /// The row of the pixel the marker is on, from the top of the atlas.
+    pub pixel_y: u32,
+}
+
+/// Many maps of the same dimension and scale stitched into one image.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MapAtlas {
+    /// Resource location of the dimension every map is in.
+    pub dimension: String,
+
+    /// The scale every map has.
+    pub scale: i8,
+
+    /// The area of the world the atlas covers, the top left pixel is at `min_x` and `min_z`.
+    pub bounds: MapBounds,
+
+    /// Width of the image in pixels.
+    pub width: u32,
+
+    /// Height of the image in pixels.
+    pub height: u32,
+
+    /// The color of every pixel as RGBA, row by row from the top left corner.
+    /// Pixels no map has explored are `[0, 0, 0, 0]`.
+    pub pixels: Vec<u8>,
+
+    /// Every banner and frame marker of the maps, each position only once.
+    pub markers: Vec<MapMarker>,
+}
+
+impl MapAtlas {
+    /// The most pixels an atlas can be wide or tall, 128 maps side by side.
+    /// A full atlas of this size takes 1 GiB of memory.
+    pub const MAX_SIZE: u32 = 128 * Map::SIZE as u32;
+
+    /// Stitches maps into one atlas per dimension and scale, sorted by both.
+    /// Where maps overlap, the one with the highest `DataVersion` is on top.
+    ///
+    /// Fails if the maps of a dimension and scale are spread over more than [`MapAtlas::MAX_SIZE`] pixels.
+    pub fn build<'a, I: IntoIterator<Item = &'a Map>>(
+        maps: I,
+    ) -> Result<Vec<MapAtlas>, SculkParseError> {
+        MapAtlas::build_by(maps, |map| map.data_version)
+    }
+
+    /// Stitches maps into one atlas per dimension and scale, sorted by both.
+    /// Where maps overlap, the one with the highest value returned by `priority` is on top,
+    /// maps with the same priority are drawn in the order they were given so the later one ends up on top.
+    /// Transparent pixels never cover the pixels of another map.
+    ///
+    /// Fails if the maps of a dimension and scale are spread over more than [`MapAtlas::MAX_SIZE`] pixels.
+    pub fn build_by<'a, I, F>(maps: I, priority: F) -> Result<Vec<MapAtlas>, SculkParseError>
+    where
+        I: IntoIterator<Item = &'a Map>,
+        F: Fn(&Map) -> i32,
+    {
+        let mut groups: BTreeMap<(&str, i8), Vec<&Map>> = BTreeMap::new();
+        for map in maps {
+            groups
+                .entry((map.dimension.as_str(), map.scale))
+                .or_default()
+                .push(map);
+        }
+
+        groups
+            .into_iter()
+            .map(|((dimension, scale), mut maps)| {
+                // Stable, so ties keep the order they were given in.
+                maps.sort_by_key(|map| priority(map));
+                MapAtlas::stitch(dimension, scale, &maps)
+            })
+            .collect()
+    }
+
+    /// Draws maps in order onto a new atlas, all of them have to share the dimension and scale.
+    fn stitch(dimension: &str, scale: i8, maps: &[&Map]) -> Result<MapAtlas, SculkParseError> {
+        let bounds = maps.iter().map(|map| map.bounds()).fold(
+            MapBounds {
+                min_x: i32::MAX,
+                min_z: i32::MAX,
+                max_x: i32::MIN,
+                max_z: i32::MIN,
+            },
+            |a, b| MapBounds {
+                min_x: a.min_x.min(b.min_x),
+                min_z: a.min_z.min(b.min_z),
+                max_x: a.max_x.max(b.max_x),
+                max_z: a.max_z.max(b.max_z),
+            },
+        );
+
+        let blocks_per_pixel = maps[0].blocks_per_pixel() as i64;
+        let width = (bounds.max_x as i64 - bounds.min_x as i64).div_euclid(blocks_per_pixel);
+        let height = (bounds.max_z as i64 - bounds.min_z as i64).div_euclid(blocks_per_pixel);
+        if width > Self::MAX_SIZE as i64 || height > Self::MAX_SIZE as i64 {
+            return Err(SculkParseError::ImageError(format!(
+                "The {dimension} maps of scale {scale} span {width}x{height} pixels, more than {} pixels wide or tall",
+                Self::MAX_SIZE
+            )));
+        }
+        let (width, height) = (width as u32, height as u32);
+
+        let mut atlas = MapAtlas {
+            dimension: dimension.to_string(),
+            scale,
+            bounds,
+            width,
+            height,
+            pixels: vec![0; width as usize * height as usize * 4],
+            markers: vec![],
+        };
+
+        for map in maps {
+            atlas.draw(map);
+        }
+
+        Ok(atlas)
+    }
+
+    /// Draws a map over the atlas and adds its markers, replacing the markers at the same position.
+    fn draw(&mut self, map: &Map) {
+        let map_bounds = map.bounds();
+        let Some((left, top)) = self.pixel_at(map_bounds.min_x, map_bounds.min_z) else {
+            return;
+        };
+        let rgba = map.to_rgba();
+
+        for row in 0..Map::SIZE {
+            let y = top as usize + row;
+            if y >= self.height as usize {
+                break;
+            }
+
+            for column in 0..Map::SIZE {
+                let x = left as usize + column;
+                if x >= self.width as usize {
+                    break;
+                }
+
+                let from = (row * Map::SIZE + column) * 4;
+                if rgba[from + 3] == 0 {
+                    continue;
+                }
+
+                let to = (y * self.width as usize + x) * 4;
+                self.pixels[to..to + 4].copy_from_slice(&rgba[from..from + 4]);
+            }
+        }
+
+        let banners = map.banners.iter().map(|banner| {
+            let kind = MapMarkerKind::Banner {
+                color: banner.color.clone(),
+                name: banner.name.clone(),
+            };
+            (kind, &banner.pos)
+        });
+        let frames = map.frames.iter().map(|frame| {
+            let kind = MapMarkerKind::Frame {
+                entity_id: frame.entity_id,
+                rotation: frame.rotation,
+            };
+            (kind, &frame.pos)
+        });
+
+        for (kind, pos) in banners.chain(frames) {
+            let Some((pixel_x, pixel_y)) = self.pixel_at(pos.x, pos.z) else {
+                continue;
+            };
+
+            let marker = MapMarker {
+                kind,
+                pos: [pos.x, pos.y, pos.z],
+                pixel_x,
+                pixel_y,
+            };
+
+            match self.markers.iter_mut().find(|other| marker.same_as(other)) {
+                Some(other) => *other = marker,
+                None => self.markers.push(marker),
+            }
+        }
+    }
+
+    /// The pixel the block at `x` and `z` is drawn on, `None` if the atlas doesn't cover it.
+    pub fn pixel_at(&self, x: i32, z: i32) -> Option<(u32, u32)> {
+        if !self.bounds.contains(x, z) {
+            return None;
+        }
+
+        let blocks_per_pixel = 1 << self.scale.clamp(0, 4);
+        Some((
+            (x - self.bounds.min_x).div_euclid(blocks_per_pixel) as u32,
+            (z - self.bounds.min_z).div_euclid(blocks_per_pixel) as u32,
+        ))
+    }
+
+    /// Draws every marker as a small square onto the pixels, banners in their color and frames in green.
+    /// The pixels are left alone by [`MapAtlas::build`], so the markers can be drawn in other ways too.
+    pub fn draw_markers(&mut self) {
+        const RADIUS: i64 = 2;
+
+        for marker in &self.markers {
+            let rgb = match &marker.kind {
+                MapMarkerKind::Banner { color, .. } => {
+                    u32::from_str_radix(&color.to_hex()[1..], 16).unwrap_or(0xffffff)
+                }
+                MapMarkerKind::Frame { .. } => MapColor::ALL[7].rgb_with(MapBrightness::High),
+            };
+            let [_, r, g, b] = rgb.to_be_bytes();
+
+            for dy in -RADIUS - 1..=RADIUS + 1 {
+                for dx in -RADIUS - 1..=RADIUS + 1 {
+                    let x = marker.pixel_x as i64 + dx;
+                    let y = marker.pixel_y as i64 + dy;
+                    if x < 0 || y < 0 || x >= self.width as i64 || y >= self.height as i64 {
+                        continue;
+                    }
+
+                    // A black outline so the marker stands out on any color.
+                    let outline = dx.abs() > RADIUS || dy.abs() > RADIUS;
+                    let i = (y as usize * self.width as usize + x as usize) * 4;
+                    self.pixels[i..i + 4].copy_from_slice(&match outline {
+                        true => [0, 0, 0, 255],
+                        false => [r, g, b, 255],
+                    });
+                }
+            }
+        }
+    }
+
+    /// Encodes the atlas as a PNG image, unexplored pixels are transparent.
+    #[cfg(feature = "png")]
+    pub fn to_png(&self) -> Result<Vec<u8>, crate::error::SculkParseError> {
+        let mut bytes = Vec::new();
+        super::color::write_png(&mut bytes, &self.pixels, self.width, self.height)?;
+
+        Ok(bytes)
+    }
+
+    /// Writes the atlas as a PNG image to a file, unexplored pixels are transparent.
+    #[cfg(feature = "png")]
+    pub fn write_png(
+        &self,
+        path: impl AsRef<std::path::Path>,
+    ) -> Result<(), crate::error::SculkParseError> {
+        let file = std::io::BufWriter::new(std::fs::File::create(path)?);
+        super::color::write_png(file, &self.pixels, self.width, self.height)
+    }
+}
+
+impl MapMarker {
+    /// If both markers are the same banner or frame, which may have changed since.
+    fn same_as(&self, other: &MapMarker) -> bool {
+        let same_kind = match (&self.kind, &other.kind) {
+            (MapMarkerKind::Banner { .. }, MapMarkerKind::Banner { .. }) => true,
+            (
+                MapMarkerKind::Frame { entity_id, .. },
+                MapMarkerKind::Frame {
+                    entity_id: other_id,
+                    ..
+                },
+            ) => entity_id == other_id,
+            _ => false,
+        };
+
+        same_kind && self.pos == other.pos
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn map_atlas_test() {
+    use super::{MapBanner, MapPos};
+
+    let map = |x_center: i32, color: u8, data_version: i32| Map {
+        scale: 0,
+        dimension: "minecraft:overworld".into(),
+        tracking_position: true,
+        unlimited_tracking: false,
+        locked: false,
+        x_center,
+        z_center: 64,
+        banners: vec![],
+        frames: vec![],
+        colors: vec![color; Map::SIZE * Map::SIZE],
+        data_version,
+        unknown_tags: simdnbt::owned::NbtCompound::new(),
+    };
+
+    let mut west = map(64, 6, 3953);
+    west.banners.push(MapBanner {
+        color: Color::Red,
+        name: None,
+        pos: MapPos {
+            x: 10,
+            y: 70,
+            z: 20,
+        },
+    });
+    let east = map(192, 34, 3953);
+    let mut old = map(128, 46, 1343);
+    old.colors[..Map::SIZE].fill(0);
+    old.banners.push(MapBanner {
+        color: Color::Blue,
+        name: None,
+        pos: MapPos {
+            x: 10,
+            y: 70,
+            z: 20,
+        },
+    });
+    let mut nether = map(0, 6, 3953);
+    nether.dimension = "minecraft:the_nether".into();
+
+    assert_eq!(
+        east.bounds(),
+        MapBounds {
+            min_x: 128,
+            min_z: 0,
+            max_x: 256,
+            max_z: 128
+        }
+    );
+
+    let atlases = MapAtlas::build([&old, &west, &east, &nether]).unwrap();
+    assert_eq!(atlases.len(), 2);
+    assert_eq!(atlases[1].dimension, "minecraft:the_nether");
+
+    let atlas = &atlases[0];
+    assert_eq!((atlas.width, atlas.height), (256, 128));
+    let pixel = |atlas: &MapAtlas, x: usize, y: usize| {
+        let i = (y * atlas.width as usize + x) * 4;
+        atlas.pixels[i..i + 4].to_vec()
+    };
+    // the newer maps cover the old one in the middle
+    assert_eq!(pixel(atlas, 100, 50), MapColor::rgba(6));
+    assert_eq!(pixel(atlas, 200, 50), MapColor::rgba(34));
+    assert_eq!(atlas.markers.len(), 1);
+    assert_eq!(
+        (atlas.markers[0].pixel_x, atlas.markers[0].pixel_y),
+        (10, 20)
+    );
+    assert!(matches!(
+        atlas.markers[0].kind,
+        MapMarkerKind::Banner {
+            color: Color::Red,
+            ..
+        }
+    ));
+
+    // unless the old one is preferred, its transparent row still shows the maps below
+    let mut atlases = MapAtlas::build_by([&old, &west, &east], |map| -map.data_version).unwrap();
+    let atlas = &mut atlases[0];
+    assert_eq!(pixel(atlas, 100, 50), MapColor::rgba(46));
+    assert_eq!(pixel(atlas, 100, 0), MapColor::rgba(6));
+    assert_eq!(pixel(atlas, 10, 50), MapColor::rgba(6));
+    assert!(matches!(
+        atlas.markers[0].kind,
+        MapMarkerKind::Banner {
+            color: Color::Blue,
+            ..
+        }
+    ));
+
+    atlas.draw_markers();
+    assert_eq!(pixel(atlas, 10, 20), vec![0x33, 0x4c, 0xb2, 255]);
+    assert_eq!(pixel(atlas, 13, 20), vec![0, 0, 0, 255]);
+
+    // maps too far apart aren't stitched into a huge image
+    let far = map(MapAtlas::MAX_SIZE as i32 * 2, 6, 3953);
+    assert!(MapAtlas::build([&west, &far]).is_err());
+}
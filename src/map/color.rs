@@ -0,0 +1,276 @@
+//! The colors of map items.
+//! [Minecraft Wiki](https://minecraft.wiki/w/Map_item_format#Map_colors)
+
+use super::Map;
+
+/// A base color of a map, every block has one of these.
+/// Each byte in [`Map::colors`] is `base id * 4 + brightness`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct MapColor {
+    /// The id of the base color, 0 being transparent.
+    pub id: u8,
+
+    /// The name of the color in the game's code, like `grass`.
+    pub name: &'static str,
+
+    /// The color at [`MapBrightness::High`], which is the color itself.
+    pub rgb: u32,
+}
+
+/// The brightness of a map color, the lowest 2 bits of a color byte.
+/// Maps shade a pixel by comparing the height of the block to the one north of it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum MapBrightness {
+    /// Lower than the block to the north.
+    Low = 0,
+    /// Same height as the block to the north.
+    Normal = 1,
+    /// Higher than the block to the north.
+    High = 2,
+    /// Never used by the game itself.
+    Lowest = 3,
+}
+
+impl MapBrightness {
+    /// Every brightness, in the order of their ids.
+    pub const ALL: [MapBrightness; 4] = [
+        MapBrightness::Low,
+        MapBrightness::Normal,
+        MapBrightness::High,
+        MapBrightness::Lowest,
+    ];
+
+    /// The brightness of the lowest 2 bits of a color byte.
+    pub fn from_id(id: u8) -> MapBrightness {
+        MapBrightness::ALL[(id & 3) as usize]
+    }
+
+    /// What every channel of the base color is multiplied by, out of 255.
+    pub fn multiplier(&self) -> u32 {
+        match self {
+            MapBrightness::Low => 180,
+            MapBrightness::Normal => 220,
+            MapBrightness::High => 255,
+            MapBrightness::Lowest => 135,
+        }
+    }
+}
+
+const fn color(id: u8, name: &'static str, rgb: u32) -> MapColor {
+    MapColor { id, name, rgb }
+}
+
+impl MapColor {
+    /// Every base color as of 1.21.4, indexed by their id.
+    pub const ALL: [MapColor; 62] = [
+        color(0, "none", 0x000000),
+        color(1, "grass", 0x7fb238),
+        color(2, "sand", 0xf7e9a3),
+        color(3, "wool", 0xc7c7c7),
+        color(4, "fire", 0xff0000),
+        color(5, "ice", 0xa0a0ff),
+        color(6, "metal", 0xa7a7a7),
+        color(7, "plant", 0x007c00),
+        color(8, "snow", 0xffffff),
+        color(9, "clay", 0xa4a8b8),
+        color(10, "dirt", 0x976d4d),
+        color(11, "stone", 0x707070),
+        color(12, "water", 0x4040ff),
+        color(13, "wood", 0x8f7748),
+        color(14, "quartz", 0xfffcf5),
+        color(15, "color_orange", 0xd87f33),
+        color(16, "color_magenta", 0xb24cd8),
+        color(17, "color_light_blue", 0x6699d8),
+        color(18, "color_yellow", 0xe5e533),
+        color(19, "color_light_green", 0x7fcc19),
+        color(20, "color_pink", 0xf27fa5),
+        color(21, "color_gray", 0x4c4c4c),
+        color(22, "color_light_gray", 0x999999),
+        color(23, "color_cyan", 0x4c7f99),
+        color(24, "color_purple", 0x7f3fb2),
+        color(25, "color_blue", 0x334cb2),
+        color(26, "color_brown", 0x664c33),
+        color(27, "color_green", 0x667f33),
+        color(28, "color_red", 0x993333),
+        color(29, "color_black", 0x191919),
+        color(30, "gold", 0xfaee4d),
+        color(31, "diamond", 0x5cdbd5),
+        color(32, "lapis", 0x4a80ff),
+        color(33, "emerald", 0x00d93a),
+        color(34, "podzol", 0x815631),
+        color(35, "nether", 0x700200),
+        color(36, "terracotta_white", 0xd1b1a1),
+        color(37, "terracotta_orange", 0x9f5224),
+        color(38, "terracotta_magenta", 0x95576c),
+        color(39, "terracotta_light_blue", 0x706c8a),
+        color(40, "terracotta_yellow", 0xba8524),
+        color(41, "terracotta_light_green", 0x677535),
+        color(42, "terracotta_pink", 0xa04d4e),
+        color(43, "terracotta_gray", 0x392923),
+        color(44, "terracotta_light_gray", 0x876b62),
+        color(45, "terracotta_cyan", 0x575c5c),
+        color(46, "terracotta_purple", 0x7a4958),
+        color(47, "terracotta_blue", 0x4c3e5c),
+        color(48, "terracotta_brown", 0x4c3223),
+        color(49, "terracotta_green", 0x4c522a),
+        color(50, "terracotta_red", 0x8e3c2e),
+        color(51, "terracotta_black", 0x251610),
+        color(52, "crimson_nylium", 0xbd3031),
+        color(53, "crimson_stem", 0x943f61),
+        color(54, "crimson_hyphae", 0x5c191d),
+        color(55, "warped_nylium", 0x167e86),
+        color(56, "warped_stem", 0x3a8e8c),
+        color(57, "warped_hyphae", 0x562c3e),
+        color(58, "warped_wart_block", 0x14b485),
+        color(59, "deepslate", 0x646464),
+        color(60, "raw_iron", 0xd8af93),
+        color(61, "glow_lichen", 0x7fa796),
+    ];
+
+    /// The base color with this id.
+    pub fn from_id(id: u8) -> Option<&'static MapColor> {
+        MapColor::ALL.get(id as usize)
+    }
+
+    /// The base color and brightness of a byte from [`Map::colors`].
+    /// `None` for base colors added after 1.21.4.
+    pub fn from_byte(byte: u8) -> Option<(&'static MapColor, MapBrightness)> {
+        Some((MapColor::from_id(byte / 4)?, MapBrightness::from_id(byte)))
+    }
+
+    /// The color with a brightness applied, as `0xRRGGBB`.
+    pub fn rgb_with(&self, brightness: MapBrightness) -> u32 {
+        let multiplier = brightness.multiplier();
+        let channel = |shift: u32| ((self.rgb >> shift) & 0xff) * multiplier / 255;
+
+        channel(16) << 16 | channel(8) << 8 | channel(0)
+    }
+
+    /// The RGBA color of a byte from [`Map::colors`].
+    /// The base color 0 is fully transparent, and so are unknown colors so maps from newer versions still render.
+    pub fn rgba(byte: u8) -> [u8; 4] {
+        match MapColor::from_byte(byte) {
+            Some((color, brightness)) if color.id != 0 => {
+                let [_, r, g, b] = color.rgb_with(brightness).to_be_bytes();
+                [r, g, b, 255]
+            }
+            _ => [0, 0, 0, 0],
+        }
+    }
+}
+
+impl Map {
+    /// Width and height of a map in pixels.
+    pub const SIZE: usize = 128;
+
+    /// The color of every pixel as RGBA, row by row from the top left corner.
+    /// Transparent pixels, the ones the map hasn't explored yet, are `[0, 0, 0, 0]`.
+    pub fn to_rgba(&self) -> Vec<u8> {
+        let mut pixels = Vec::with_capacity(Map::SIZE * Map::SIZE * 4);
+
+        for i in 0..Map::SIZE * Map::SIZE {
+            pixels.extend(MapColor::rgba(self.colors.get(i).copied().unwrap_or(0)));
+        }
+
+        pixels
+    }
+
+    /// Encodes the map as a 128x128 PNG image, unexplored pixels are transparent.
+    #[cfg(feature = "png")]
+    pub fn to_png(&self) -> Result<Vec<u8>, crate::error::SculkParseError> {
+        let mut bytes = Vec::new();
+        write_png(
+            &mut bytes,
+            &self.to_rgba(),
+            Map::SIZE as u32,
+            Map::SIZE as u32,
+        )?;
+
+        Ok(bytes)
+    }
+
+    /// Writes the map as a 128x128 PNG image to a file, unexplored pixels are transparent.
+    #[cfg(feature = "png")]
+    pub fn write_png(
+        &self,
+        path: impl AsRef<std::path::Path>,
+    ) -> Result<(), crate::error::SculkParseError> {
+        let file = std::io::BufWriter::new(std::fs::File::create(path)?);
+        write_png(file, &self.to_rgba(), Map::SIZE as u32, Map::SIZE as u32)
+    }
+}
+
+/// Encodes RGBA pixels as a PNG image.
+#[cfg(feature = "png")]
+pub(crate) fn write_png(
+    writer: impl std::io::Write,
+    rgba: &[u8],
+    width: u32,
+    height: u32,
+) -> Result<(), crate::error::SculkParseError> {
+    use crate::error::SculkParseError;
+
+    let mut encoder = png::Encoder::new(writer, width, height);
+    encoder.set_color(png::ColorType::Rgba);
+    encoder.set_depth(png::BitDepth::Eight);
+
+    let mut writer = encoder
+        .write_header()
+        .map_err(|e| SculkParseError::ImageError(e.to_string()))?;
+    writer
+        .write_image_data(rgba)
+        .map_err(|e| SculkParseError::ImageError(e.to_string()))?;
+    writer
+        .finish()
+        .map_err(|e| SculkParseError::ImageError(e.to_string()))
+}
+
+#[cfg(test)]
+#[test]
+fn map_color_test() {
+    assert!(MapColor::ALL
+        .iter()
+        .enumerate()
+        .all(|(i, color)| color.id as usize == i));
+
+    // grass at every brightness
+    assert_eq!(MapColor::rgba(4), [89, 125, 39, 255]);
+    assert_eq!(MapColor::rgba(5), [109, 153, 48, 255]);
+    assert_eq!(MapColor::rgba(6), [127, 178, 56, 255]);
+    assert_eq!(MapColor::rgba(7), [67, 94, 29, 255]);
+
+    assert_eq!(MapColor::rgba(0), [0, 0, 0, 0]);
+    assert_eq!(MapColor::rgba(3), [0, 0, 0, 0]);
+    assert_eq!(MapColor::rgba(255), [0, 0, 0, 0]);
+
+    let mut map = Map {
+        scale: 0,
+        dimension: "minecraft:overworld".into(),
+        tracking_position: true,
+        unlimited_tracking: false,
+        locked: false,
+        x_center: 0,
+        z_center: 0,
+        banners: vec![],
+        frames: vec![],
+        colors: vec![0; Map::SIZE * Map::SIZE],
+        data_version: 3953,
+        unknown_tags: simdnbt::owned::NbtCompound::new(),
+    };
+    map.colors[129] = 34;
+
+    let rgba = map.to_rgba();
+    assert_eq!(rgba.len(), 128 * 128 * 4);
+    assert_eq!(&rgba[129 * 4..130 * 4], &[255, 255, 255, 255]);
+    assert_eq!(&rgba[..4], &[0, 0, 0, 0]);
+
+    #[cfg(feature = "png")]
+    {
+        let png = map.to_png().unwrap();
+        let decoder = png::Decoder::new(png.as_slice());
+        let mut reader = decoder.read_info().unwrap();
+        let mut pixels = vec![0; reader.output_buffer_size()];
+        reader.next_frame(&mut pixels).unwrap();
+        assert_eq!(pixels, rgba);
+    }
+}
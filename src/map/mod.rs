@@ -1,9 +1,18 @@
 use crate::{
     color::Color,
     error::SculkParseError,
-    traits::FromCompoundNbt,
-    util::{get_bool, get_owned_optional_string, get_owned_string, get_t_compound_vec},
+    traits::{FromCompoundNbt, ToCompoundNbt},
+    util::{
+        compound_list, get_bool, get_owned_optional_string, get_owned_string, get_t_compound_vec,
+        get_unknown_tags, insert_unknown_tags,
+    },
 };
+use simdnbt::owned;
+
+mod atlas;
+mod color;
+pub use atlas::*;
+pub use color::*;
 
 /// Represents a map in the game.
 #[derive(Debug, Clone, PartialEq)]
@@ -19,10 +28,14 @@ pub struct Map {
     /// `trackingPosition`
     pub tracking_position: bool,
 
-    /// true has been locked in a cartography table.  
+    /// true if the map shows the player's position even when they are far outside of it.  
     /// `unlimitedTracking`
     pub unlimited_tracking: bool,
 
+    /// true if the map has been locked in a cartography table.  
+    /// `locked`
+    pub locked: bool,
+
     /// Center of map according to real world by X.  
     /// `xCenter`
     pub x_center: i32,
@@ -43,8 +56,26 @@ pub struct Map {
     /// The version the map was created. If not present, defaults to 1343 (1.12.2)
     /// `DataVersion`
     pub data_version: i32,
+
+    /// Every tag in `data` that isn't parsed, written back as is.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub unknown_tags: owned::NbtCompound,
 }
 
+/// The tags in `data` that are parsed into [`Map`].
+const MAP_KEYS: [&str; 10] = [
+    "banners",
+    "colors",
+    "dimension",
+    "frames",
+    "locked",
+    "scale",
+    "trackingPosition",
+    "unlimitedTracking",
+    "xCenter",
+    "zCenter",
+];
+
 /// The position of a map marker/banner.
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
@@ -189,6 +220,7 @@ impl FromCompoundNbt for Map {
         let dimension = get_owned_string(&nbt, "dimension")?;
         let tracking_position = nbt.byte("trackingPosition").map(|b| b != 0).unwrap_or(true);
         let unlimited_tracking = get_bool(&nbt, "unlimitedTracking");
+        let locked = get_bool(&nbt, "locked");
 
         let x_center = nbt
             .int("xCenter")
@@ -211,36 +243,97 @@ impl FromCompoundNbt for Map {
             dimension,
             tracking_position,
             unlimited_tracking,
+            locked,
             x_center,
             z_center,
             banners,
             frames,
             colors,
             data_version,
+            unknown_tags: get_unknown_tags(&nbt, &MAP_KEYS),
         })
     }
 }
 
+impl ToCompoundNbt for MapPos {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        nbt.insert("X", self.x);
+        nbt.insert("Y", self.y);
+        nbt.insert("Z", self.z);
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for MapFrame {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        nbt.insert("EntityId", self.entity_id);
+        nbt.insert("Rotation", self.rotation);
+        nbt.insert("Pos", self.pos.to_compound_nbt());
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for MapBanner {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        nbt.insert("Color", self.color.to_str());
+        if let Some(name) = &self.name {
+            nbt.insert("Name", name.as_str());
+        }
+        nbt.insert("Pos", self.pos.to_compound_nbt());
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for Map {
+    /// Writes the map with everything but `DataVersion` wrapped in the `data` compound, like the `map_<id>.dat` file.
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut data = owned::NbtCompound::new();
+
+        data.insert("scale", self.scale);
+        data.insert("dimension", self.dimension.as_str());
+        data.insert("trackingPosition", self.tracking_position);
+        data.insert("unlimitedTracking", self.unlimited_tracking);
+        data.insert("locked", self.locked);
+        data.insert("xCenter", self.x_center);
+        data.insert("zCenter", self.z_center);
+        data.insert("banners", compound_list(&self.banners));
+        data.insert("frames", compound_list(&self.frames));
+        data.insert("colors", owned::NbtTag::ByteArray(self.colors.clone()));
+        insert_unknown_tags(&mut data, &self.unknown_tags);
+
+        let mut nbt = owned::NbtCompound::new();
+        nbt.insert("data", data);
+        nbt.insert("DataVersion", self.data_version);
+
+        nbt
+    }
+}
+
 #[cfg(test)]
 #[test]
 fn map_test() {
-    use flate2::read::GzDecoder;
-    use std::io::{Cursor, Read};
-
-    let mut file = std::fs::File::open("test_data/map_0.dat").unwrap();
-    let mut contents = Vec::new();
-    file.read_to_end(&mut contents).unwrap();
-    let mut src = &contents[..];
-
-    let mut src_decoder = GzDecoder::new(&mut src);
-    let mut input = Vec::new();
-    if src_decoder.read_to_end(&mut input).is_err() {
-        input = contents;
-    }
-    let mut input_stream = Cursor::new(&input[..]);
+    use std::io::Cursor;
+
+    let input = crate::util::read_test_data("test_data/map_0.dat");
+    let nbt = simdnbt::borrow::read(&mut Cursor::new(&input[..]))
+        .unwrap()
+        .unwrap();
 
-    let nbt = simdnbt::borrow::read(&mut input_stream).unwrap().unwrap();
-    let nbt = nbt.as_compound();
+    let _ = Map::from_compound_nbt(&nbt.as_compound()).unwrap();
+}
 
-    let _ = Map::from_compound_nbt(&nbt).unwrap();
+#[cfg(test)]
+#[test]
+fn map_round_trip() {
+    let input = crate::util::read_test_data("test_data/map_0.dat");
+    crate::util::assert_round_trip::<Map>(&input);
 }
@@ -0,0 +1,257 @@
+//! Parsing every chunk of a region or dimension across threads, using [`rayon`].
+//!
+//! Every scan takes a parse function that turns the decompressed chunk bytes into any value,
+//! so large worlds can be reduced to just the data that's needed without keeping every [`Chunk`] in memory.
+//!
+//! ```no_run
+//! use sculk::{chunk::LazyChunk, parallel::{self, ScanOptions}, world::World};
+//!
+//! let world = World::open("world").unwrap();
+//! let options = ScanOptions::new().on_progress(|progress| {
+//!     println!("{}/{} regions", progress.done, progress.total);
+//! });
+//!
+//! let regions = parallel::scan_dimension(&world.overworld(), &options, |bytes| {
+//!     LazyChunk::from_bytes(bytes).map(|chunk| chunk.inhabited_time)
+//! })
+//! .unwrap();
+//! ```
+
+use crate::{
+    chunk::{Chunk, MinimalChunk},
+    error::SculkParseError,
+    region::{Region, REGION_WIDTH},
+    world::Dimension,
+};
+use rayon::prelude::*;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+/// The result of parsing a single chunk.
+#[derive(Debug)]
+pub struct ScannedChunk<T> {
+    /// The absolute X coordinate of the chunk.
+    pub x: i32,
+
+    /// The absolute Z coordinate of the chunk.
+    pub z: i32,
+
+    /// The parsed chunk, or why it couldn't be read or parsed.
+    pub result: Result<T, SculkParseError>,
+}
+
+/// The result of scanning a single region of a dimension.
+#[derive(Debug)]
+pub struct ScannedRegion<T> {
+    /// The X coordinate of the region.
+    pub x: i32,
+
+    /// The Z coordinate of the region.
+    pub z: i32,
+
+    /// Every parsed chunk in the region, or why the region file couldn't be opened.
+    pub chunks: Result<Vec<ScannedChunk<T>>, SculkParseError>,
+}
+
+/// How far along a scan is.
+/// Counted in chunks for region scans, and in regions for dimension scans.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScanProgress {
+    /// The amount of finished chunks or regions.
+    pub done: usize,
+
+    /// The total amount of chunks or regions.
+    pub total: usize,
+}
+
+/// Progress reporting and cancellation of a scan.
+#[derive(Default)]
+pub struct ScanOptions<'a> {
+    progress: Option<Box<dyn Fn(ScanProgress) + Send + Sync + 'a>>,
+    cancel: Option<&'a AtomicBool>,
+}
+
+impl<'a> ScanOptions<'a> {
+    /// Options without progress reporting or cancellation.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Calls `callback` every time a chunk or region is finished.
+    /// The callback is called from the worker threads, so calls can arrive out of order.
+    pub fn on_progress<F: Fn(ScanProgress) + Send + Sync + 'a>(mut self, callback: F) -> Self {
+        self.progress = Some(Box::new(callback));
+        self
+    }
+
+    /// Stops the scan once `cancel` is set to true.
+    /// Chunks and regions that haven't started yet are skipped and left out of the results.
+    pub fn cancel_on(mut self, cancel: &'a AtomicBool) -> Self {
+        self.cancel = Some(cancel);
+        self
+    }
+
+    /// true if the scan has been cancelled.
+    pub fn is_cancelled(&self) -> bool {
+        self.cancel
+            .is_some_and(|cancel| cancel.load(Ordering::Relaxed))
+    }
+
+    fn report(&self, done: &AtomicUsize, total: usize) {
+        let done = done.fetch_add(1, Ordering::Relaxed) + 1;
+
+        if let Some(progress) = &self.progress {
+            progress(ScanProgress { done, total });
+        }
+    }
+}
+
+/// Parses every chunk of a region in parallel with `parse`, which receives the decompressed chunk bytes.
+/// The results are in the same order as [`Region::chunk_positions`].
+pub fn scan_region<T, F>(region: &Region, options: &ScanOptions, parse: F) -> Vec<ScannedChunk<T>>
+where
+    T: Send,
+    F: Fn(&[u8]) -> Result<T, SculkParseError> + Sync,
+{
+    let positions: Vec<(usize, usize)> = region.chunk_positions().collect();
+    let total = positions.len();
+    let done = AtomicUsize::new(0);
+
+    positions
+        .into_par_iter()
+        .filter_map(|(x, z)| {
+            if options.is_cancelled() {
+                return None;
+            }
+
+            let result = match region.chunk_bytes(x, z) {
+                Ok(Some(bytes)) => parse(&bytes),
+                Ok(None) => return None,
+                Err(e) => Err(e),
+            };
+            options.report(&done, total);
+
+            Some(ScannedChunk {
+                x: region.x * REGION_WIDTH as i32 + x as i32,
+                z: region.z * REGION_WIDTH as i32 + z as i32,
+                result,
+            })
+        })
+        .collect()
+}
+
+/// Opens and parses every region of a dimension in parallel with `parse`, which receives the decompressed chunk bytes.
+/// The chunks of each region are parsed in parallel as well.
+/// The results are in the same order as [`Dimension::region_coordinates`].
+pub fn scan_dimension<T, F>(
+    dimension: &Dimension,
+    options: &ScanOptions,
+    parse: F,
+) -> Result<Vec<ScannedRegion<T>>, SculkParseError>
+where
+    T: Send,
+    F: Fn(&[u8]) -> Result<T, SculkParseError> + Sync,
+{
+    let coordinates = dimension.region_coordinates()?;
+    let total = coordinates.len();
+    let done = AtomicUsize::new(0);
+
+    // Progress is reported per region, so the regions get their own options without a callback.
+    let region_options = ScanOptions {
+        progress: None,
+        cancel: options.cancel,
+    };
+
+    Ok(coordinates
+        .into_par_iter()
+        .filter_map(|(x, z)| {
+            if options.is_cancelled() {
+                return None;
+            }
+
+            let chunks = match dimension.region(x, z) {
+                Ok(Some(region)) => Ok(scan_region(&region, &region_options, &parse)),
+                Ok(None) => return None,
+                Err(e) => Err(e),
+            };
+            options.report(&done, total);
+
+            Some(ScannedRegion { x, z, chunks })
+        })
+        .collect())
+}
+
+/// Parses every chunk of a region in parallel.
+pub fn region_chunks(region: &Region, options: &ScanOptions) -> Vec<ScannedChunk<Chunk>> {
+    scan_region(region, options, Chunk::from_bytes)
+}
+
+/// Parses every chunk of a region in parallel as a [`MinimalChunk`].
+pub fn region_minimal_chunks(
+    region: &Region,
+    options: &ScanOptions,
+) -> Vec<ScannedChunk<MinimalChunk>> {
+    scan_region(region, options, MinimalChunk::from_bytes)
+}
+
+/// Parses every chunk of a dimension in parallel.
+pub fn dimension_chunks(
+    dimension: &Dimension,
+    options: &ScanOptions,
+) -> Result<Vec<ScannedRegion<Chunk>>, SculkParseError> {
+    scan_dimension(dimension, options, Chunk::from_bytes)
+}
+
+/// Parses every chunk of a dimension in parallel as a [`MinimalChunk`].
+pub fn dimension_minimal_chunks(
+    dimension: &Dimension,
+    options: &ScanOptions,
+) -> Result<Vec<ScannedRegion<MinimalChunk>>, SculkParseError> {
+    scan_dimension(dimension, options, MinimalChunk::from_bytes)
+}
+
+#[cfg(test)]
+#[test]
+fn parallel_scan_test() {
+    use crate::region::CompressionType;
+    use std::sync::Mutex;
+
+    let nbt = std::fs::read("test_data/chest.nbt").unwrap();
+
+    let mut region = Region::new(-1, 2);
+    for i in 0..40 {
+        region
+            .set_chunk_bytes(i % 32, i / 32, &nbt, CompressionType::Zlib)
+            .unwrap();
+    }
+    let region = Region::from_bytes(-1, 2, region.to_bytes()).unwrap();
+
+    let progress = Mutex::new(vec![]);
+    let options = ScanOptions::new().on_progress(|p| progress.lock().unwrap().push(p));
+    let scanned = scan_region(&region, &options, |bytes| Ok(bytes.len()));
+
+    assert_eq!(scanned.len(), 40);
+    assert_eq!((scanned[0].x, scanned[0].z), (-32, 64));
+    assert_eq!((scanned[39].x, scanned[39].z), (-25, 65));
+    assert!(scanned
+        .iter()
+        .all(|c| *c.result.as_ref().unwrap() == nbt.len()));
+
+    let mut progress = progress.lock().unwrap().clone();
+    progress.sort_by_key(|p| p.done);
+    assert_eq!(
+        progress.last(),
+        Some(&ScanProgress {
+            done: 40,
+            total: 40
+        })
+    );
+
+    // A block entity isn't a chunk, so every chunk fails on its own.
+    let chunks = region_chunks(&region, &ScanOptions::new());
+    assert_eq!(chunks.len(), 40);
+    assert!(chunks.iter().all(|c| c.result.is_err()));
+
+    let cancel = AtomicBool::new(true);
+    let options = ScanOptions::new().cancel_on(&cancel);
+    assert!(scan_region(&region, &options, |bytes| Ok(bytes.len())).is_empty());
+}
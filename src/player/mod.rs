@@ -1,15 +1,21 @@
 use crate::{
     components::food::EffectDetails,
+    entities::mob::{get_attributes, Attribute},
     entity::Entity,
     error::SculkParseError,
     item::Item,
-    traits::FromCompoundNbt,
-    util::{get_bool, get_owned_optional_string, get_owned_string, get_t_compound_vec},
+    traits::{FromCompoundNbt, ToCompoundNbt},
+    util::{
+        compound_list, get_bool, get_owned_optional_string, get_owned_string, get_t_compound_vec,
+        get_unknown_tags, insert_optional, insert_unknown_tags, string_list,
+    },
     uuid::Uuid,
+    version::{self, Versioned},
 };
 use abilities::Abilities;
 use game_type::GameType;
 use recipe_book::RecipeBook;
+use simdnbt::owned;
 
 pub mod abilities;
 pub mod game_type;
@@ -146,8 +152,75 @@ pub struct Player {
     /// The total amount of experience the player has collected over time; used for the score upon death.  
     /// `XpTotal`
     pub xp_total: i32,
+
+    /// Every tag that isn't parsed, like ones added by newer versions or mods, written back as is.
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub unknown_tags: owned::NbtCompound,
 }
 
+/// The tags parsed into [`Player`] and [`PlayerEntity`].
+const PLAYER_KEYS: [&str; 59] = [
+    "abilities",
+    "AbsorptionAmount",
+    "ActiveEffects",
+    "active_effects",
+    "Air",
+    "Attributes",
+    "attributes",
+    "Brain",
+    "DataVersion",
+    "DeathTime",
+    "Dimension",
+    "EnderItems",
+    "enteredNetherPosition",
+    "FallDistance",
+    "FallFlying",
+    "Fire",
+    "foodExhaustionLevel",
+    "foodLevel",
+    "foodSaturationLevel",
+    "foodTickTimer",
+    "HasVisualFire",
+    "Health",
+    "HurtByTimestamp",
+    "HurtTime",
+    "Inventory",
+    "Invulnerable",
+    "LastDeathLocation",
+    "LeftHanded",
+    "Motion",
+    "NoGravity",
+    "OnGround",
+    "Passengers",
+    "playerGameType",
+    "PortalCooldown",
+    "Pos",
+    "previousPlayerGameType",
+    "recipeBook",
+    "RootVehicle",
+    "Rotation",
+    "Score",
+    "seenCredits",
+    "SelectedItemSlot",
+    "ShoulderEntityLeft",
+    "ShoulderEntityRight",
+    "Silent",
+    "SleepTimer",
+    "SpawnDimension",
+    "SpawnForced",
+    "SpawnX",
+    "SpawnY",
+    "SpawnZ",
+    "Tags",
+    "TicksFrozen",
+    "UUID",
+    "warden_spawn_tracker",
+    "XpLevel",
+    "XpP",
+    "XpSeed",
+    "XpTotal",
+];
+
 #[derive(Debug, Clone, PartialEq)]
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct PlayerEntity {
@@ -239,11 +312,17 @@ pub struct PlayerEntity {
     /// The list of potion effects on this mob. May not exist.  
     #[cfg_attr(feature = "serde", serde(default))]
     pub active_effects: Vec<EffectDetails>,
-    //
-    // /// A list of Attributes for this mob. These are used for many purposes in internal calculations, and can be considered a mob's "statistics"
-    // /// TODO: This is a bit more complex than other field so uh fix this at some point
-    // pub attributes: Vec<Attribute>,
-    //
+
+    /// A list of Attributes for this mob. These are used for many purposes in internal calculations, and can be considered a mob's "statistics"  
+    /// `attributes`
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub attributes: Vec<Attribute>,
+
+    /// The memories of the player's AI, which players only use to track when they last slept or woke up.  
+    /// `Brain`
+    #[cfg_attr(feature = "serde", serde(skip))]
+    pub brain: Option<owned::NbtCompound>,
+
     /// Number of ticks the mob has been dead for. Controls death animations. 0 when alive.  
     /// `DeathTime`
     pub death_time: i16,
@@ -260,6 +339,10 @@ pub struct PlayerEntity {
     /// `HurtByTimestamp`
     pub hurt_by_timestamp: i32,
 
+    /// Number of ticks the player turns red after being hit. 0 when not recently hit.  
+    /// `HurtTime`
+    pub hurt_time: Option<i16>,
+
     /// the mob renders the main hand as being left.
     /// `LeftHanded`
     pub left_handed: bool,
@@ -348,7 +431,7 @@ impl FromCompoundNbt for PlayerEntity {
         let on_ground = get_bool(&nbt, "OnGround");
 
         let passengers: Vec<Entity> =
-            get_t_compound_vec(&nbt, "passengers", Entity::from_compound_nbt)?;
+            get_t_compound_vec(&nbt, "Passengers", Entity::from_compound_nbt)?;
 
         let portal_cooldown = nbt
             .int("PortalCooldown")
@@ -395,15 +478,18 @@ impl FromCompoundNbt for PlayerEntity {
         };
 
         let ticks_frozen = nbt.int("TicksFrozen");
-        let uuid = nbt
-            .int_array("UUID")
-            .map(Uuid::from)
+        let uuid = Uuid::from_int_array(nbt, "UUID")?
             .ok_or(SculkParseError::MissingField("UUID".into()))?;
 
         let absorption_amount = nbt.float("AbsorptionAmount");
 
-        let active_effects =
-            get_t_compound_vec(&nbt, "ActiveEffects", EffectDetails::from_compound_nbt)?;
+        // Renamed from `ActiveEffects` in 1.20.2.
+        let active_effects = match nbt.contains("active_effects") {
+            true => get_t_compound_vec(nbt, "active_effects", EffectDetails::from_compound_nbt)?,
+            false => get_t_compound_vec(nbt, "ActiveEffects", EffectDetails::from_compound_nbt)?,
+        };
+        let attributes = get_attributes(nbt)?;
+        let brain = nbt.compound("Brain").map(|b| b.to_owned());
 
         let death_time = nbt.short("DeathTime").unwrap_or(0);
 
@@ -416,6 +502,7 @@ impl FromCompoundNbt for PlayerEntity {
             .int("HurtByTimestamp")
             .ok_or(SculkParseError::MissingField("HurtByTimestamp".into()))?;
 
+        let hurt_time = nbt.short("HurtTime");
         let left_handed = get_bool(&nbt, "LeftHanded");
 
         Ok(PlayerEntity {
@@ -437,10 +524,13 @@ impl FromCompoundNbt for PlayerEntity {
             uuid,
             absorption_amount,
             active_effects,
+            attributes,
+            brain,
             death_time,
             fall_flying,
             health,
             hurt_by_timestamp,
+            hurt_time,
             left_handed,
         })
     }
@@ -451,6 +541,13 @@ impl FromCompoundNbt for Player {
     where
         Self: Sized,
     {
+        version::with_version(Player::version_of(nbt), || Player::parse(nbt))
+    }
+}
+
+impl Player {
+    /// Parses the player, with the data version of the file already set for nested parsers.
+    fn parse(nbt: &simdnbt::borrow::NbtCompound) -> Result<Self, SculkParseError> {
         let player_entity = PlayerEntity::from_compound_nbt(nbt)?;
 
         let abilities = nbt
@@ -588,11 +685,10 @@ impl FromCompoundNbt for Player {
             xp_p,
             xp_seed,
             xp_total,
+            unknown_tags: get_unknown_tags(nbt, &PLAYER_KEYS),
         })
     }
-}
 
-impl Player {
     /// Returns the data version of the player.
     pub fn get_data_version(nbt: &simdnbt::borrow::NbtCompound) -> Result<i32, SculkParseError> {
         nbt.int("DataVersion")
@@ -632,11 +728,7 @@ impl FromCompoundNbt for Vechile {
     where
         Self: Sized,
     {
-        let attach = if let Some(attach) = nbt.int_array("attach") {
-            Some(Uuid::from(attach))
-        } else {
-            None
-        };
+        let attach = Uuid::from_int_array(nbt, "attach")?;
 
         let entity = if let Some(entity) = nbt.compound("Entity") {
             Some(Entity::from_compound_nbt(&entity)?)
@@ -683,26 +775,187 @@ impl FromCompoundNbt for NetherPosition {
     }
 }
 
+impl ToCompoundNbt for PlayerEntity {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        nbt.insert("Air", self.air);
+        nbt.insert("FallDistance", self.fall_distance);
+        nbt.insert("Fire", self.fire);
+        // Like the game, flags that are usually false are only written when set.
+        if self.has_visual_fire {
+            nbt.insert("HasVisualFire", true);
+        }
+        nbt.insert("Invulnerable", self.invulnerable);
+        nbt.insert("Motion", owned::NbtList::Double(self.motion.to_vec()));
+        if self.no_gravity {
+            nbt.insert("NoGravity", true);
+        }
+        nbt.insert("OnGround", self.on_ground);
+        if !self.passengers.is_empty() {
+            nbt.insert("Passengers", compound_list(&self.passengers));
+        }
+        nbt.insert("PortalCooldown", self.portal_cooldown);
+        nbt.insert("Pos", owned::NbtList::Double(self.pos.to_vec()));
+        nbt.insert("Rotation", owned::NbtList::Float(self.rotation.to_vec()));
+        insert_optional(&mut nbt, "Silent", self.silent);
+        if !self.tags.is_empty() {
+            nbt.insert("Tags", string_list(&self.tags));
+        }
+        insert_optional(&mut nbt, "TicksFrozen", self.ticks_frozen);
+        nbt.insert("UUID", self.uuid);
+        insert_optional(&mut nbt, "AbsorptionAmount", self.absorption_amount);
+        if !self.active_effects.is_empty() {
+            nbt.insert("active_effects", compound_list(&self.active_effects));
+        }
+        if !self.attributes.is_empty() {
+            nbt.insert("attributes", compound_list(&self.attributes));
+        }
+        insert_optional(&mut nbt, "Brain", self.brain.clone());
+        nbt.insert("DeathTime", self.death_time);
+        nbt.insert("FallFlying", self.fall_flying);
+        nbt.insert("Health", self.health);
+        nbt.insert("HurtByTimestamp", self.hurt_by_timestamp);
+        insert_optional(&mut nbt, "HurtTime", self.hurt_time);
+        if self.left_handed {
+            nbt.insert("LeftHanded", true);
+        }
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for Player {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        version::with_version(Some(self.version()), || self.write())
+    }
+}
+
+impl Player {
+    /// Writes the player, with its data version set for nested writers that depend on it.
+    fn write(&self) -> owned::NbtCompound {
+        let mut nbt = self.entity.to_compound_nbt();
+
+        nbt.insert("abilities", self.abilities.to_compound_nbt());
+        nbt.insert("DataVersion", self.data_version);
+        nbt.insert("Dimension", self.dimension.as_str());
+        nbt.insert("EnderItems", compound_list(&self.ender_items));
+        if let Some(position) = &self.entered_nether_position {
+            nbt.insert("enteredNetherPosition", position.to_compound_nbt());
+        }
+        nbt.insert("foodExhaustionLevel", self.food_exhaustion_level);
+        nbt.insert("foodLevel", self.food_level);
+        nbt.insert("foodSaturationLevel", self.food_saturation_level);
+        nbt.insert("foodTickTimer", self.food_tick_timer);
+        nbt.insert("Inventory", compound_list(&self.inventory));
+        if let Some(location) = &self.last_death_location {
+            nbt.insert("LastDeathLocation", location.to_compound_nbt());
+        }
+        nbt.insert("playerGameType", self.player_game_type.to_i32());
+        insert_optional(
+            &mut nbt,
+            "previousPlayerGameType",
+            self.previous_player_game_type
+                .as_ref()
+                .map(GameType::to_i32),
+        );
+        nbt.insert("recipeBook", self.recipe_book.to_compound_nbt());
+        if let Some(vehicle) = &self.root_vechile {
+            nbt.insert("RootVehicle", vehicle.to_compound_nbt());
+        }
+        nbt.insert("Score", self.score);
+        nbt.insert("seenCredits", self.seen_credits);
+        nbt.insert("SelectedItemSlot", self.selected_item_slot);
+        if let Some(entity) = &self.shoulder_entity_left {
+            nbt.insert("ShoulderEntityLeft", entity.to_compound_nbt());
+        }
+        if let Some(entity) = &self.shoulder_entity_right {
+            nbt.insert("ShoulderEntityRight", entity.to_compound_nbt());
+        }
+        nbt.insert("SleepTimer", self.sleep_timer);
+        insert_optional(&mut nbt, "SpawnDimension", self.spawn_dimension.clone());
+        insert_optional(&mut nbt, "SpawnForced", self.spawn_forced);
+        insert_optional(&mut nbt, "SpawnX", self.spawn_x);
+        insert_optional(&mut nbt, "SpawnY", self.spawn_y);
+        insert_optional(&mut nbt, "SpawnZ", self.spawn_z);
+        nbt.insert(
+            "warden_spawn_tracker",
+            self.warden_spawn_tracker.to_compound_nbt(),
+        );
+        nbt.insert("XpLevel", self.xp_level);
+        nbt.insert("XpP", self.xp_p);
+        nbt.insert("XpSeed", self.xp_seed);
+        nbt.insert("XpTotal", self.xp_total);
+        insert_unknown_tags(&mut nbt, &self.unknown_tags);
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for WardenTracker {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        nbt.insert("warning_level", self.warning_level);
+        nbt.insert("cooldown_ticks", self.cooldown_ticks);
+        nbt.insert("ticks_since_last_warning", self.ticks_since_last_warning);
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for Vechile {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        insert_optional(&mut nbt, "attach", self.attach);
+        if let Some(entity) = &self.entity {
+            nbt.insert("Entity", entity.to_compound_nbt());
+        }
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for DeathLocation {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        nbt.insert("dimension", self.dimension.as_str());
+        nbt.insert("pos", owned::NbtTag::IntArray(self.pos.clone()));
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for NetherPosition {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        nbt.insert("x", self.x);
+        nbt.insert("y", self.y);
+        nbt.insert("z", self.z);
+
+        nbt
+    }
+}
+
 #[cfg(test)]
 #[test]
 fn complex_player_dat() {
-    use flate2::read::GzDecoder;
-    use std::io::{Cursor, Read};
-
-    let mut file = std::fs::File::open("test_data/player_data.dat").unwrap();
-    let mut contents = Vec::new();
-    file.read_to_end(&mut contents).unwrap();
-    let mut src = &contents[..];
-
-    let mut src_decoder = GzDecoder::new(&mut src);
-    let mut input = Vec::new();
-    if src_decoder.read_to_end(&mut input).is_err() {
-        input = contents;
-    }
-    let mut input_stream = Cursor::new(&input[..]);
+    use std::io::Cursor;
 
-    let nbt = simdnbt::borrow::read(&mut input_stream).unwrap().unwrap();
-    let nbt = nbt.as_compound();
+    let input = crate::util::read_test_data("test_data/player_data.dat");
+    let nbt = simdnbt::borrow::read(&mut Cursor::new(&input[..]))
+        .unwrap()
+        .unwrap();
 
-    let _ = Player::from_compound_nbt(&nbt).unwrap();
+    let _ = Player::from_compound_nbt(&nbt.as_compound()).unwrap();
+}
+
+#[cfg(test)]
+#[test]
+fn player_round_trip() {
+    let input = crate::util::read_test_data("test_data/player_data.dat");
+    crate::util::assert_round_trip::<Player>(&input);
 }
@@ -1,6 +1,11 @@
 //! Structures and parsers for a player's recipe book.
 
-use crate::{error::SculkParseError, traits::FromCompoundNbt, util::get_bool};
+use crate::{
+    error::SculkParseError,
+    traits::{FromCompoundNbt, ToCompoundNbt},
+    util::{get_bool, string_list},
+};
+use simdnbt::owned;
 
 /// A player's recipe book.
 #[derive(Debug, Clone, PartialEq)]
@@ -111,3 +116,31 @@ impl FromCompoundNbt for RecipeBook {
         })
     }
 }
+
+impl ToCompoundNbt for RecipeBook {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        nbt.insert("recipes", string_list(&self.recipes));
+        nbt.insert("toBeDisplayed", string_list(&self.to_be_displayed));
+        nbt.insert("isFilteringCraftable", self.is_filtering_craftable);
+        nbt.insert("isGuiOpen", self.is_gui_open);
+        nbt.insert(
+            "isFurnaceFilteringCraftable",
+            self.is_furnace_filtering_craftable,
+        );
+        nbt.insert("isFurnaceGuiOpen", self.is_furnace_gui_open);
+        nbt.insert(
+            "isBlastingFurnaceFilteringCraftable",
+            self.is_blasting_furnace_filtering_craftable,
+        );
+        nbt.insert("isBlastingFurnaceGuiOpen", self.is_blast_furnace_gui_open);
+        nbt.insert(
+            "isSmokerFilteringCraftable",
+            self.is_smoker_filtering_craftable,
+        );
+        nbt.insert("isSmokerGuiOpen", self.is_smoker_gui_open);
+
+        nbt
+    }
+}
@@ -0,0 +1,259 @@
+//! Points of interest, stored in the `poi/r.X.Z.mca` region files.
+//! [Minecraft Wiki](https://minecraft.wiki/w/Poi_format)
+
+use crate::{
+    error::SculkParseError,
+    traits::{FromCompoundNbt, ToCompoundNbt},
+    util::{compound_list, get_owned_string, get_t_compound_vec},
+};
+use simdnbt::owned;
+use std::{collections::HashMap, io::Cursor};
+
+/// The points of interest in a chunk.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PoiChunk {
+    /// Version of the poi chunk NBT structure.
+    /// `DataVersion`
+    pub data_version: i32,
+
+    /// The sections of the chunk, keyed by their section Y coordinate.
+    /// `Sections`
+    pub sections: HashMap<i32, PoiSection>,
+}
+
+/// A 16x16x16 section of points of interest.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PoiSection {
+    /// false if the section needs to be refreshed by the game because the blocks in it changed.
+    /// `Valid`
+    pub valid: bool,
+
+    /// The points of interest in this section.
+    /// `Records`
+    pub records: Vec<PoiRecord>,
+}
+
+/// A single point of interest, like a villager workstation, bed or nether portal.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PoiRecord {
+    /// The point of interest type, like `minecraft:home` or `minecraft:nether_portal`.
+    /// `type`
+    pub r#type: String,
+
+    /// The block position of the point of interest.
+    /// `pos`
+    pub pos: [i32; 3],
+
+    /// How many more villagers can claim this point of interest.
+    /// `free_tickets`
+    pub free_tickets: i32,
+}
+
+impl PoiChunk {
+    pub fn from_bytes(bytes: &[u8]) -> Result<Self, SculkParseError> {
+        let nbt = simdnbt::borrow::read(&mut Cursor::new(bytes))?;
+
+        let nbt = match nbt {
+            simdnbt::borrow::Nbt::Some(nbt) => nbt,
+            simdnbt::borrow::Nbt::None => return Err(SculkParseError::NoNbt),
+        };
+        let compound = nbt.as_compound();
+
+        PoiChunk::from_compound_nbt(&compound)
+    }
+
+    /// Returns the section containing the given block Y coordinate.
+    pub fn section_at(&self, y: i32) -> Option<&PoiSection> {
+        self.sections.get(&(y >> 4))
+    }
+
+    /// Returns the point of interest at the given block position.
+    pub fn record_at(&self, x: i32, y: i32, z: i32) -> Option<&PoiRecord> {
+        self.section_at(y)?.record_at(x, y, z)
+    }
+
+    /// Iterates over every point of interest in the chunk.
+    pub fn records(&self) -> impl Iterator<Item = &PoiRecord> {
+        self.sections
+            .values()
+            .flat_map(|section| section.records.iter())
+    }
+
+    /// Iterates over every point of interest of the given type, like `minecraft:home`.
+    pub fn records_of_type<'a>(&'a self, r#type: &'a str) -> impl Iterator<Item = &'a PoiRecord> {
+        self.records().filter(move |record| record.r#type == r#type)
+    }
+}
+
+impl PoiSection {
+    /// Returns the point of interest at the given block position.
+    pub fn record_at(&self, x: i32, y: i32, z: i32) -> Option<&PoiRecord> {
+        self.records.iter().find(|record| record.pos == [x, y, z])
+    }
+}
+
+impl FromCompoundNbt for PoiChunk {
+    fn from_compound_nbt(nbt: &simdnbt::borrow::NbtCompound) -> Result<Self, SculkParseError>
+    where
+        Self: Sized,
+    {
+        let data_version = nbt
+            .int("DataVersion")
+            .ok_or(SculkParseError::MissingField("DataVersion".into()))?;
+
+        let mut sections = HashMap::new();
+        if let Some(nbt) = nbt.compound("Sections") {
+            for (key, value) in nbt.iter() {
+                let y = key
+                    .to_str()
+                    .parse::<i32>()
+                    .map_err(|_| SculkParseError::InvalidField("Sections".into()))?;
+                let section = value
+                    .compound()
+                    .ok_or(SculkParseError::InvalidField("Sections".into()))?;
+
+                sections.insert(y, PoiSection::from_compound_nbt(&section)?);
+            }
+        }
+
+        Ok(PoiChunk {
+            data_version,
+            sections,
+        })
+    }
+}
+
+impl FromCompoundNbt for PoiSection {
+    fn from_compound_nbt(nbt: &simdnbt::borrow::NbtCompound) -> Result<Self, SculkParseError>
+    where
+        Self: Sized,
+    {
+        let valid = nbt
+            .byte("Valid")
+            .map(|b| b != 0)
+            .ok_or(SculkParseError::MissingField("Valid".into()))?;
+        let records = get_t_compound_vec(nbt, "Records", PoiRecord::from_compound_nbt)?;
+
+        Ok(PoiSection { valid, records })
+    }
+}
+
+impl FromCompoundNbt for PoiRecord {
+    fn from_compound_nbt(nbt: &simdnbt::borrow::NbtCompound) -> Result<Self, SculkParseError>
+    where
+        Self: Sized,
+    {
+        let r#type = get_owned_string(nbt, "type")?;
+
+        let pos = nbt
+            .int_array("pos")
+            .ok_or(SculkParseError::MissingField("pos".into()))?;
+        let pos: [i32; 3] = pos
+            .as_slice()
+            .try_into()
+            .map_err(|_| SculkParseError::InvalidField("pos".into()))?;
+
+        let free_tickets = nbt
+            .int("free_tickets")
+            .ok_or(SculkParseError::MissingField("free_tickets".into()))?;
+
+        Ok(PoiRecord {
+            r#type,
+            pos,
+            free_tickets,
+        })
+    }
+}
+
+impl ToCompoundNbt for PoiChunk {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut sections: Vec<(&i32, &PoiSection)> = self.sections.iter().collect();
+        sections.sort_by_key(|(y, _)| **y);
+
+        let mut sections_nbt = owned::NbtCompound::new();
+        for (y, section) in sections {
+            sections_nbt.insert(y.to_string(), section.to_compound_nbt());
+        }
+
+        let mut nbt = owned::NbtCompound::new();
+        nbt.insert("Sections", sections_nbt);
+        nbt.insert("DataVersion", self.data_version);
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for PoiSection {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        nbt.insert("Valid", self.valid);
+        nbt.insert("Records", compound_list(&self.records));
+
+        nbt
+    }
+}
+
+impl ToCompoundNbt for PoiRecord {
+    fn to_compound_nbt(&self) -> owned::NbtCompound {
+        let mut nbt = owned::NbtCompound::new();
+
+        nbt.insert("free_tickets", self.free_tickets);
+        nbt.insert("pos", owned::NbtTag::IntArray(self.pos.to_vec()));
+        nbt.insert("type", self.r#type.as_str());
+
+        nbt
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn poi_test() {
+    let record = |r#type: &str, pos: [i32; 3], free_tickets: i32| PoiRecord {
+        r#type: r#type.into(),
+        pos,
+        free_tickets,
+    };
+
+    let chunk = PoiChunk {
+        data_version: 3953,
+        sections: HashMap::from([
+            (
+                -1,
+                PoiSection {
+                    valid: true,
+                    records: vec![record("minecraft:nether_portal", [3, -12, 40], 0)],
+                },
+            ),
+            (
+                4,
+                PoiSection {
+                    valid: true,
+                    records: vec![
+                        record("minecraft:home", [1, 70, 33], 1),
+                        record("minecraft:farmer", [2, 71, 34], 0),
+                        record("minecraft:home", [4, 70, 33], 0),
+                    ],
+                },
+            ),
+        ]),
+    };
+
+    let chunk = PoiChunk::from_bytes(&chunk.to_bytes()).unwrap();
+
+    assert_eq!(
+        chunk.record_at(3, -12, 40).unwrap().r#type,
+        "minecraft:nether_portal"
+    );
+    assert_eq!(
+        chunk.record_at(2, 71, 34).unwrap().r#type,
+        "minecraft:farmer"
+    );
+    assert_eq!(chunk.record_at(2, 70, 34), None);
+    assert_eq!(chunk.section_at(79).unwrap().records.len(), 3);
+    assert_eq!(chunk.records().count(), 4);
+    assert_eq!(chunk.records_of_type("minecraft:home").count(), 2);
+}
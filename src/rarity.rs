@@ -16,6 +16,16 @@ pub enum Rarity {
 }
 
 impl Rarity {
+    /// Converts a `Rarity` to a string.
+    pub fn to_str(&self) -> &'static str {
+        match self {
+            Self::Common => "common",
+            Self::Uncommon => "uncommon",
+            Self::Rare => "rare",
+            Self::Epic => "epic",
+        }
+    }
+
     /// Converts a string to a `Rarity`.
     pub fn from_str(s: &str) -> Result<Self, SculkParseError> {
         match s {
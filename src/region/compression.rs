@@ -0,0 +1,311 @@
+//! Compression schemes used for chunk data inside region files.
+
+use crate::error::SculkParseError;
+use flate2::{
+    read::{GzDecoder, ZlibDecoder},
+    write::{GzEncoder, ZlibEncoder},
+    Compression,
+};
+use std::io::{Read, Write};
+
+/// The magic bytes every LZ4 block stream starts with.
+const LZ4_MAGIC: &[u8; 8] = b"LZ4Block";
+/// Magic + token + compressed length + decompressed length + checksum.
+const LZ4_HEADER_LENGTH: usize = LZ4_MAGIC.len() + 1 + 4 + 4 + 4;
+/// The block is stored as is.
+const LZ4_METHOD_RAW: u8 = 0x10;
+/// The block is LZ4 compressed.
+const LZ4_METHOD_LZ4: u8 = 0x20;
+/// The block size lz4-java uses by default, 64 KiB.
+const LZ4_BLOCK_SIZE: usize = 1 << 16;
+/// The seed lz4-java uses for the xxHash32 block checksums.
+const LZ4_CHECKSUM_SEED: u32 = 0x9747b28c;
+
+/// The compression scheme of a chunk, stored in the byte right after the chunk length.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum CompressionType {
+    /// GZip (RFC1952), unused in practice.
+    /// `1`
+    Gzip,
+
+    /// Zlib (RFC1950), the default.
+    /// `2`
+    Zlib,
+
+    /// Uncompressed, since 1.15.1.
+    /// `3`
+    Uncompressed,
+
+    /// LZ4 in the `LZ4Block` stream format, since 24w04a.
+    /// `4`
+    Lz4,
+}
+
+impl CompressionType {
+    /// Returns the compression type for the given id.
+    /// The external chunk flag (`128`) must already be stripped.
+    pub fn from_id(id: u8) -> Result<Self, SculkParseError> {
+        match id {
+            1 => Ok(CompressionType::Gzip),
+            2 => Ok(CompressionType::Zlib),
+            3 => Ok(CompressionType::Uncompressed),
+            4 => Ok(CompressionType::Lz4),
+            _ => Err(SculkParseError::UnsupportedCompression(id)),
+        }
+    }
+
+    /// Returns the id stored in the region file for this compression type.
+    pub fn id(&self) -> u8 {
+        match self {
+            CompressionType::Gzip => 1,
+            CompressionType::Zlib => 2,
+            CompressionType::Uncompressed => 3,
+            CompressionType::Lz4 => 4,
+        }
+    }
+
+    /// Decompresses the given bytes into raw NBT bytes.
+    pub fn decompress(&self, bytes: &[u8]) -> Result<Vec<u8>, SculkParseError> {
+        match self {
+            CompressionType::Gzip => {
+                let mut output = Vec::new();
+                GzDecoder::new(bytes).read_to_end(&mut output)?;
+                Ok(output)
+            }
+            CompressionType::Zlib => {
+                let mut output = Vec::new();
+                ZlibDecoder::new(bytes).read_to_end(&mut output)?;
+                Ok(output)
+            }
+            CompressionType::Uncompressed => Ok(bytes.to_vec()),
+            CompressionType::Lz4 => decompress_lz4_blocks(bytes),
+        }
+    }
+
+    /// Compresses raw NBT bytes with this compression type.
+    pub fn compress(&self, bytes: &[u8]) -> Result<Vec<u8>, SculkParseError> {
+        match self {
+            CompressionType::Gzip => {
+                let mut encoder = GzEncoder::new(Vec::new(), Compression::default());
+                encoder.write_all(bytes)?;
+                Ok(encoder.finish()?)
+            }
+            CompressionType::Zlib => {
+                let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+                encoder.write_all(bytes)?;
+                Ok(encoder.finish()?)
+            }
+            CompressionType::Uncompressed => Ok(bytes.to_vec()),
+            CompressionType::Lz4 => Ok(compress_lz4_blocks(bytes)),
+        }
+    }
+}
+
+/// Decompresses a stream written by lz4-java's `LZ4BlockOutputStream`, which is what Minecraft uses.
+/// Each block has a 21 byte header followed by its data, the stream ends with an empty block.
+fn decompress_lz4_blocks(mut bytes: &[u8]) -> Result<Vec<u8>, SculkParseError> {
+    let mut output = Vec::new();
+
+    while !bytes.is_empty() {
+        if bytes.len() < LZ4_HEADER_LENGTH || &bytes[..LZ4_MAGIC.len()] != LZ4_MAGIC {
+            return Err(SculkParseError::InvalidRegion(
+                "Invalid LZ4 block header".into(),
+            ));
+        }
+
+        let method = bytes[8] & 0xF0;
+        let compressed_length = read_i32_le(&bytes[9..13]);
+        let original_length = read_i32_le(&bytes[13..17]);
+        bytes = &bytes[LZ4_HEADER_LENGTH..];
+
+        // The lengths are checked before anything is allocated for them, no block is larger than the block size.
+        let (compressed_length, original_length) = match (
+            usize::try_from(compressed_length),
+            usize::try_from(original_length),
+        ) {
+            (Ok(compressed), Ok(original))
+                if compressed <= LZ4_BLOCK_SIZE && original <= LZ4_BLOCK_SIZE =>
+            {
+                (compressed, original)
+            }
+            _ => {
+                return Err(SculkParseError::InvalidRegion(format!(
+                    "Invalid LZ4 block lengths: {compressed_length} compressed, {original_length} original"
+                )))
+            }
+        };
+
+        if compressed_length == 0 && original_length == 0 {
+            break;
+        }
+
+        if bytes.len() < compressed_length {
+            return Err(SculkParseError::InvalidRegion("Truncated LZ4 block".into()));
+        }
+        let block = &bytes[..compressed_length];
+        bytes = &bytes[compressed_length..];
+
+        match method {
+            LZ4_METHOD_RAW => output.extend_from_slice(block),
+            LZ4_METHOD_LZ4 => {
+                let start = output.len();
+                output.resize(start + original_length, 0);
+                let written = lz4_flex::block::decompress_into(block, &mut output[start..])
+                    .map_err(|e| SculkParseError::InvalidRegion(e.to_string()))?;
+                if written != original_length {
+                    return Err(SculkParseError::InvalidRegion(
+                        "LZ4 block decompressed to the wrong length".into(),
+                    ));
+                }
+            }
+            _ => {
+                return Err(SculkParseError::InvalidRegion(format!(
+                    "Unknown LZ4 block method: {method:#x}"
+                )))
+            }
+        }
+    }
+
+    Ok(output)
+}
+
+/// Compresses bytes into the same stream format `LZ4BlockOutputStream` writes.
+/// Blocks that don't shrink are stored raw, and every block carries an xxHash32 checksum of its contents.
+fn compress_lz4_blocks(bytes: &[u8]) -> Vec<u8> {
+    // The low bits of the token store log2 of the block size, relative to 1 KiB.
+    let level = (usize::BITS - (LZ4_BLOCK_SIZE - 1).leading_zeros() - 10) as u8;
+    let mut output = Vec::new();
+
+    for block in bytes.chunks(LZ4_BLOCK_SIZE) {
+        let compressed = lz4_flex::block::compress(block);
+        let (method, data) = if compressed.len() < block.len() {
+            (LZ4_METHOD_LZ4, &compressed[..])
+        } else {
+            (LZ4_METHOD_RAW, block)
+        };
+
+        // lz4-java only keeps the lower 28 bits of the checksum.
+        let checksum = xxhash32(block, LZ4_CHECKSUM_SEED) & 0x0FFF_FFFF;
+
+        output.extend_from_slice(LZ4_MAGIC);
+        output.push(method | level);
+        output.extend_from_slice(&(data.len() as i32).to_le_bytes());
+        output.extend_from_slice(&(block.len() as i32).to_le_bytes());
+        output.extend_from_slice(&checksum.to_le_bytes());
+        output.extend_from_slice(data);
+    }
+
+    // The stream is terminated by an empty raw block.
+    output.extend_from_slice(LZ4_MAGIC);
+    output.push(LZ4_METHOD_RAW | level);
+    output.extend_from_slice(&[0; 12]);
+
+    output
+}
+
+const XXH_PRIME32_1: u32 = 0x9E3779B1;
+const XXH_PRIME32_2: u32 = 0x85EBCA77;
+const XXH_PRIME32_3: u32 = 0xC2B2AE3D;
+const XXH_PRIME32_4: u32 = 0x27D4EB2F;
+const XXH_PRIME32_5: u32 = 0x165667B1;
+
+/// The 32-bit xxHash of the given bytes.
+fn xxhash32(bytes: &[u8], seed: u32) -> u32 {
+    fn round(acc: u32, lane: u32) -> u32 {
+        acc.wrapping_add(lane.wrapping_mul(XXH_PRIME32_2))
+            .rotate_left(13)
+            .wrapping_mul(XXH_PRIME32_1)
+    }
+
+    fn read_u32(bytes: &[u8]) -> u32 {
+        u32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+    }
+
+    let mut stripes = bytes.chunks_exact(16);
+    let mut hash = if bytes.len() >= 16 {
+        let mut acc = [
+            seed.wrapping_add(XXH_PRIME32_1).wrapping_add(XXH_PRIME32_2),
+            seed.wrapping_add(XXH_PRIME32_2),
+            seed,
+            seed.wrapping_sub(XXH_PRIME32_1),
+        ];
+
+        for stripe in &mut stripes {
+            for (i, acc) in acc.iter_mut().enumerate() {
+                *acc = round(*acc, read_u32(&stripe[i * 4..]));
+            }
+        }
+
+        acc[0]
+            .rotate_left(1)
+            .wrapping_add(acc[1].rotate_left(7))
+            .wrapping_add(acc[2].rotate_left(12))
+            .wrapping_add(acc[3].rotate_left(18))
+    } else {
+        seed.wrapping_add(XXH_PRIME32_5)
+    };
+
+    hash = hash.wrapping_add(bytes.len() as u32);
+
+    let mut rest = stripes.remainder().chunks_exact(4);
+    for lane in &mut rest {
+        hash = hash
+            .wrapping_add(read_u32(lane).wrapping_mul(XXH_PRIME32_3))
+            .rotate_left(17)
+            .wrapping_mul(XXH_PRIME32_4);
+    }
+    for byte in rest.remainder() {
+        hash = hash
+            .wrapping_add((*byte as u32).wrapping_mul(XXH_PRIME32_5))
+            .rotate_left(11)
+            .wrapping_mul(XXH_PRIME32_1);
+    }
+
+    hash ^= hash >> 15;
+    hash = hash.wrapping_mul(XXH_PRIME32_2);
+    hash ^= hash >> 13;
+    hash = hash.wrapping_mul(XXH_PRIME32_3);
+    hash ^ (hash >> 16)
+}
+
+fn read_i32_le(bytes: &[u8]) -> i32 {
+    i32::from_le_bytes([bytes[0], bytes[1], bytes[2], bytes[3]])
+}
+
+#[cfg(test)]
+#[test]
+fn compression_round_trip() {
+    assert_eq!(xxhash32(b"", 0), 0x02CC5D05);
+    assert_eq!(xxhash32(b"abc", 0), 0x32D153FF);
+    assert_eq!(
+        xxhash32(b"Nobody inspects the spammish repetition", 0),
+        0xE2293B2F
+    );
+
+    let nbt = std::fs::read("test_data/player_data.dat").unwrap();
+    let nbt = CompressionType::Gzip.decompress(&nbt).unwrap();
+
+    for compression in [
+        CompressionType::Gzip,
+        CompressionType::Zlib,
+        CompressionType::Uncompressed,
+        CompressionType::Lz4,
+    ] {
+        let compressed = compression.compress(&nbt).unwrap();
+        assert_eq!(compression.decompress(&compressed).unwrap(), nbt);
+    }
+
+    // negative or oversized lengths are rejected before allocating
+    let mut compressed = CompressionType::Lz4.compress(&nbt).unwrap();
+    compressed[13..17].copy_from_slice(&(-1i32).to_le_bytes());
+    assert!(matches!(
+        CompressionType::Lz4.decompress(&compressed),
+        Err(SculkParseError::InvalidRegion(_))
+    ));
+    compressed[13..17].copy_from_slice(&i32::MAX.to_le_bytes());
+    assert!(matches!(
+        CompressionType::Lz4.decompress(&compressed),
+        Err(SculkParseError::InvalidRegion(_))
+    ));
+}
@@ -0,0 +1,402 @@
+//! Reading and writing of Anvil region files (`r.X.Z.mca`).
+//!
+//! A region file holds 32x32 chunks. It starts with an 8 KiB header,
+//! the first 4 KiB are the chunk locations and the second 4 KiB are the last modification timestamps.
+//! Chunk data is stored in 4 KiB sectors after the header.
+
+use crate::{
+    chunk::{Chunk, EntityChunk, MinimalChunk},
+    error::SculkParseError,
+    poi::PoiChunk,
+};
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+};
+
+pub use compression::CompressionType;
+pub use write::MAX_SECTOR_COUNT;
+
+pub mod compression;
+mod write;
+
+/// The size of one sector in a region file.
+pub const SECTOR_SIZE: usize = 4096;
+/// The amount of chunks along one axis of a region.
+pub const REGION_WIDTH: usize = 32;
+/// The amount of chunks in a region.
+pub const CHUNK_COUNT: usize = REGION_WIDTH * REGION_WIDTH;
+/// The size of the location and timestamp tables.
+pub const HEADER_SIZE: usize = SECTOR_SIZE * 2;
+
+/// Set on the compression byte if the chunk data is stored in an external `c.X.Z.mcc` file.
+const EXTERNAL_FLAG: u8 = 128;
+
+/// Where a chunk is stored inside of a region file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ChunkLocation {
+    /// The offset in 4 KiB sectors from the start of the file.
+    pub offset: u32,
+
+    /// The amount of 4 KiB sectors the chunk occupies.
+    pub sector_count: u8,
+}
+
+/// A region file, containing up to 32x32 chunks.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Region {
+    /// The X coordinate of the region.
+    pub x: i32,
+
+    /// The Z coordinate of the region.
+    pub z: i32,
+
+    /// The folder the region was read from, used to find external `.mcc` chunks.
+    directory: Option<PathBuf>,
+
+    /// The raw region file.
+    bytes: Vec<u8>,
+
+    locations: Vec<Option<ChunkLocation>>,
+    timestamps: Vec<u32>,
+
+    /// External chunk data that hasn't been saved yet, by chunk index.
+    /// `None` marks an external file that should be deleted on save.
+    external: HashMap<usize, Option<Vec<u8>>>,
+}
+
+impl Region {
+    /// Opens a region file, the region coordinates are taken from the `r.X.Z.mca` file name.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, SculkParseError> {
+        let path = path.as_ref();
+        let (x, z) = Region::coordinates_from_path(path)?;
+        let bytes = std::fs::read(path)?;
+
+        let mut region = Region::from_bytes(x, z, bytes)?;
+        region.directory = path.parent().map(Path::to_path_buf);
+
+        Ok(region)
+    }
+
+    /// Parses a region from its raw bytes.
+    /// Chunks stored in external `.mcc` files can't be read from a region created this way.
+    pub fn from_bytes(x: i32, z: i32, bytes: Vec<u8>) -> Result<Self, SculkParseError> {
+        // Empty region files are written by the game and are valid.
+        if bytes.is_empty() {
+            return Ok(Region {
+                x,
+                z,
+                directory: None,
+                bytes,
+                locations: vec![None; CHUNK_COUNT],
+                timestamps: vec![0; CHUNK_COUNT],
+                external: HashMap::new(),
+            });
+        }
+
+        if bytes.len() < HEADER_SIZE {
+            return Err(SculkParseError::InvalidRegion(format!(
+                "Region header is {} bytes, expected {}",
+                bytes.len(),
+                HEADER_SIZE
+            )));
+        }
+
+        let mut locations = Vec::with_capacity(CHUNK_COUNT);
+        let mut timestamps = Vec::with_capacity(CHUNK_COUNT);
+
+        for i in 0..CHUNK_COUNT {
+            let entry = &bytes[i * 4..i * 4 + 4];
+            let offset = u32::from_be_bytes([0, entry[0], entry[1], entry[2]]);
+            let sector_count = entry[3];
+
+            locations.push(if offset == 0 && sector_count == 0 {
+                None
+            } else {
+                Some(ChunkLocation {
+                    offset,
+                    sector_count,
+                })
+            });
+
+            let timestamp = &bytes[SECTOR_SIZE + i * 4..SECTOR_SIZE + i * 4 + 4];
+            timestamps.push(u32::from_be_bytes([
+                timestamp[0],
+                timestamp[1],
+                timestamp[2],
+                timestamp[3],
+            ]));
+        }
+
+        Ok(Region {
+            x,
+            z,
+            directory: None,
+            bytes,
+            locations,
+            timestamps,
+            external: HashMap::new(),
+        })
+    }
+
+    /// Parses the region coordinates from a `r.X.Z.mca` file name.
+    pub fn coordinates_from_path<P: AsRef<Path>>(path: P) -> Result<(i32, i32), SculkParseError> {
+        let path = path.as_ref();
+        let invalid = || SculkParseError::InvalidRegion(format!("Invalid file name: {path:?}"));
+
+        let name = path
+            .file_name()
+            .and_then(|n| n.to_str())
+            .ok_or_else(invalid)?;
+        let mut parts = name.split('.');
+
+        match (parts.next(), parts.next(), parts.next(), parts.next()) {
+            (Some("r"), Some(x), Some(z), Some("mca")) if parts.next().is_none() => Ok((
+                x.parse().map_err(|_| invalid())?,
+                z.parse().map_err(|_| invalid())?,
+            )),
+            _ => Err(invalid()),
+        }
+    }
+
+    /// Returns the coordinates of the region that contains the given absolute chunk coordinates.
+    pub fn region_of(chunk_x: i32, chunk_z: i32) -> (i32, i32) {
+        (chunk_x >> 5, chunk_z >> 5)
+    }
+
+    /// Returns where a chunk is stored, `None` if the chunk hasn't been generated.
+    pub fn location(&self, x: usize, z: usize) -> Result<Option<ChunkLocation>, SculkParseError> {
+        Ok(self.locations[Region::index(x, z)?])
+    }
+
+    /// Returns the last time the chunk was saved, in seconds since the Unix epoch.
+    pub fn timestamp(&self, x: usize, z: usize) -> Result<u32, SculkParseError> {
+        Ok(self.timestamps[Region::index(x, z)?])
+    }
+
+    /// Returns true if the region contains the chunk at the given local coordinates.
+    pub fn has_chunk(&self, x: usize, z: usize) -> bool {
+        matches!(self.location(x, z), Ok(Some(_)))
+    }
+
+    /// Iterates over the local coordinates of every chunk stored in the region.
+    pub fn chunk_positions(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.locations
+            .iter()
+            .enumerate()
+            .filter(|(_, location)| location.is_some())
+            .map(|(i, _)| (i % REGION_WIDTH, i / REGION_WIDTH))
+    }
+
+    /// Returns the decompressed NBT bytes of the chunk at the given local coordinates (0..32).
+    pub fn chunk_bytes(&self, x: usize, z: usize) -> Result<Option<Vec<u8>>, SculkParseError> {
+        let location = match self.location(x, z)? {
+            Some(location) => location,
+            None => return Ok(None),
+        };
+
+        let start = location.offset as usize * SECTOR_SIZE;
+        if location.offset < 2 || start + 5 > self.bytes.len() {
+            return Err(SculkParseError::InvalidRegion(format!(
+                "Chunk {x}, {z} points outside of the region"
+            )));
+        }
+
+        let length = u32::from_be_bytes([
+            self.bytes[start],
+            self.bytes[start + 1],
+            self.bytes[start + 2],
+            self.bytes[start + 3],
+        ]) as usize;
+        let compression = self.bytes[start + 4];
+
+        // The length includes the compression byte.
+        let data_start = start + 5;
+        let data_end = data_start + length.saturating_sub(1);
+        if length == 0 || data_end > self.bytes.len() {
+            return Err(SculkParseError::InvalidRegion(format!(
+                "Chunk {x}, {z} has an invalid length: {length}"
+            )));
+        }
+
+        if compression & EXTERNAL_FLAG != 0 {
+            let compression = CompressionType::from_id(compression & !EXTERNAL_FLAG)?;
+            if let Some(Some(bytes)) = self.external.get(&Region::index(x, z)?) {
+                return Ok(Some(compression.decompress(bytes)?));
+            }
+            let bytes = std::fs::read(self.external_path(x, z)?)?;
+
+            return Ok(Some(compression.decompress(&bytes)?));
+        }
+
+        let compression = CompressionType::from_id(compression)?;
+        Ok(Some(
+            compression.decompress(&self.bytes[data_start..data_end])?,
+        ))
+    }
+
+    /// Returns the chunk at the given local coordinates (0..32).
+    pub fn get_chunk(&self, x: usize, z: usize) -> Result<Option<Chunk>, SculkParseError> {
+        self.chunk_bytes(x, z)?
+            .map(|bytes| Chunk::from_bytes(&bytes))
+            .transpose()
+    }
+
+    /// Returns the minimal chunk at the given local coordinates (0..32).
+    pub fn get_minimal_chunk(
+        &self,
+        x: usize,
+        z: usize,
+    ) -> Result<Option<MinimalChunk>, SculkParseError> {
+        self.chunk_bytes(x, z)?
+            .map(|bytes| MinimalChunk::from_bytes(&bytes))
+            .transpose()
+    }
+
+    /// Returns the entity chunk at the given local coordinates (0..32).
+    /// Only valid for regions in the `entities` folder.
+    pub fn get_entity_chunk(
+        &self,
+        x: usize,
+        z: usize,
+    ) -> Result<Option<EntityChunk>, SculkParseError> {
+        self.chunk_bytes(x, z)?
+            .map(|bytes| EntityChunk::from_bytes(&bytes))
+            .transpose()
+    }
+
+    /// Returns the poi chunk at the given local coordinates (0..32).
+    /// Only valid for regions in the `poi` folder.
+    pub fn get_poi_chunk(&self, x: usize, z: usize) -> Result<Option<PoiChunk>, SculkParseError> {
+        self.chunk_bytes(x, z)?
+            .map(|bytes| PoiChunk::from_bytes(&bytes))
+            .transpose()
+    }
+
+    /// Returns the chunk at the given absolute chunk coordinates.
+    pub fn get_chunk_at(
+        &self,
+        chunk_x: i32,
+        chunk_z: i32,
+    ) -> Result<Option<Chunk>, SculkParseError> {
+        let (x, z) = self.local_coordinates(chunk_x, chunk_z)?;
+        self.get_chunk(x, z)
+    }
+
+    /// Returns the minimal chunk at the given absolute chunk coordinates.
+    pub fn get_minimal_chunk_at(
+        &self,
+        chunk_x: i32,
+        chunk_z: i32,
+    ) -> Result<Option<MinimalChunk>, SculkParseError> {
+        let (x, z) = self.local_coordinates(chunk_x, chunk_z)?;
+        self.get_minimal_chunk(x, z)
+    }
+
+    /// Returns the entity chunk at the given absolute chunk coordinates.
+    /// Only valid for regions in the `entities` folder.
+    pub fn get_entity_chunk_at(
+        &self,
+        chunk_x: i32,
+        chunk_z: i32,
+    ) -> Result<Option<EntityChunk>, SculkParseError> {
+        let (x, z) = self.local_coordinates(chunk_x, chunk_z)?;
+        self.get_entity_chunk(x, z)
+    }
+
+    /// Returns the poi chunk at the given absolute chunk coordinates.
+    /// Only valid for regions in the `poi` folder.
+    pub fn get_poi_chunk_at(
+        &self,
+        chunk_x: i32,
+        chunk_z: i32,
+    ) -> Result<Option<PoiChunk>, SculkParseError> {
+        let (x, z) = self.local_coordinates(chunk_x, chunk_z)?;
+        self.get_poi_chunk(x, z)
+    }
+
+    /// Iterates over every chunk stored in the region.
+    pub fn chunks(&self) -> impl Iterator<Item = Result<Chunk, SculkParseError>> + '_ {
+        self.chunk_positions()
+            .filter_map(|(x, z)| self.get_chunk(x, z).transpose())
+    }
+
+    /// Iterates over every entity chunk stored in the region.
+    /// Only valid for regions in the `entities` folder.
+    pub fn entity_chunks(&self) -> impl Iterator<Item = Result<EntityChunk, SculkParseError>> + '_ {
+        self.chunk_positions()
+            .filter_map(|(x, z)| self.get_entity_chunk(x, z).transpose())
+    }
+
+    /// Converts absolute chunk coordinates into local coordinates inside this region.
+    pub fn local_coordinates(
+        &self,
+        chunk_x: i32,
+        chunk_z: i32,
+    ) -> Result<(usize, usize), SculkParseError> {
+        if Region::region_of(chunk_x, chunk_z) != (self.x, self.z) {
+            return Err(SculkParseError::InvalidRegion(format!(
+                "Chunk {chunk_x}, {chunk_z} is not inside region {}, {}",
+                self.x, self.z
+            )));
+        }
+
+        Ok((
+            chunk_x.rem_euclid(REGION_WIDTH as i32) as usize,
+            chunk_z.rem_euclid(REGION_WIDTH as i32) as usize,
+        ))
+    }
+
+    fn external_path(&self, x: usize, z: usize) -> Result<PathBuf, SculkParseError> {
+        let directory = self.directory.as_ref().ok_or_else(|| {
+            SculkParseError::InvalidRegion(format!(
+                "Chunk {x}, {z} is stored externally but the region has no directory"
+            ))
+        })?;
+
+        let chunk_x = self.x * REGION_WIDTH as i32 + x as i32;
+        let chunk_z = self.z * REGION_WIDTH as i32 + z as i32;
+
+        Ok(directory.join(format!("c.{chunk_x}.{chunk_z}.mcc")))
+    }
+
+    fn index(x: usize, z: usize) -> Result<usize, SculkParseError> {
+        if x >= REGION_WIDTH || z >= REGION_WIDTH {
+            return Err(SculkParseError::InvalidRegion(format!(
+                "Local chunk coordinates out of range: {x}, {z}"
+            )));
+        }
+
+        Ok(x + z * REGION_WIDTH)
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn region_chunk_bytes() {
+    use flate2::{write::ZlibEncoder, Compression};
+    use std::io::Write;
+
+    let nbt = std::fs::read("test_data/chest.nbt").unwrap();
+
+    let mut encoder = ZlibEncoder::new(Vec::new(), Compression::default());
+    encoder.write_all(&nbt).unwrap();
+    let compressed = encoder.finish().unwrap();
+
+    // One chunk at local 3, 5, stored in the sector right after the header.
+    let mut bytes = vec![0u8; HEADER_SIZE];
+    let index = 3 + 5 * REGION_WIDTH;
+    bytes[index * 4..index * 4 + 4].copy_from_slice(&[0, 0, 2, 1]);
+    bytes.extend_from_slice(&(compressed.len() as u32 + 1).to_be_bytes());
+    bytes.push(CompressionType::Zlib.id());
+    bytes.extend_from_slice(&compressed);
+    bytes.resize(HEADER_SIZE + SECTOR_SIZE, 0);
+
+    let region = Region::from_bytes(-1, 0, bytes).unwrap();
+
+    assert_eq!(region.chunk_positions().collect::<Vec<_>>(), vec![(3, 5)]);
+    assert_eq!(region.chunk_bytes(3, 5).unwrap().unwrap(), nbt);
+    assert_eq!(region.chunk_bytes(0, 0).unwrap(), None);
+    assert_eq!(region.local_coordinates(-29, 5).unwrap(), (3, 5));
+}
@@ -0,0 +1,341 @@
+//! Writing chunks back into region files.
+//!
+//! Chunks are given the first run of free sectors they fit in, or appended to the end of the file.
+//! Chunks that need more than 255 sectors (about 1 MiB) are stored in an external `c.X.Z.mcc` file,
+//! leaving a one sector stub in the region.
+
+use super::{
+    ChunkLocation, CompressionType, Region, CHUNK_COUNT, EXTERNAL_FLAG, HEADER_SIZE, REGION_WIDTH,
+    SECTOR_SIZE,
+};
+use crate::{chunk::Chunk, error::SculkParseError, traits::ToCompoundNbt};
+use std::{
+    collections::HashMap,
+    path::{Path, PathBuf},
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+/// The most sectors a single chunk can occupy inside of a region file.
+pub const MAX_SECTOR_COUNT: usize = u8::MAX as usize;
+
+impl Region {
+    /// Creates an empty region with no chunks.
+    pub fn new(x: i32, z: i32) -> Self {
+        Region {
+            x,
+            z,
+            directory: None,
+            bytes: vec![0; HEADER_SIZE],
+            locations: vec![None; CHUNK_COUNT],
+            timestamps: vec![0; CHUNK_COUNT],
+            external: HashMap::new(),
+        }
+    }
+
+    /// Serializes and stores a chunk at the given local coordinates (0..32).
+    pub fn set_chunk(
+        &mut self,
+        x: usize,
+        z: usize,
+        chunk: &Chunk,
+        compression: CompressionType,
+    ) -> Result<(), SculkParseError> {
+        self.set_chunk_bytes(x, z, &chunk.to_bytes(), compression)
+    }
+
+    /// Serializes and stores a chunk at the given absolute chunk coordinates.
+    pub fn set_chunk_at(
+        &mut self,
+        chunk_x: i32,
+        chunk_z: i32,
+        chunk: &Chunk,
+        compression: CompressionType,
+    ) -> Result<(), SculkParseError> {
+        let (x, z) = self.local_coordinates(chunk_x, chunk_z)?;
+        self.set_chunk(x, z, chunk, compression)
+    }
+
+    /// Compresses and stores raw NBT bytes as the chunk at the given local coordinates (0..32).
+    /// The chunk timestamp is set to the current time.
+    pub fn set_chunk_bytes(
+        &mut self,
+        x: usize,
+        z: usize,
+        bytes: &[u8],
+        compression: CompressionType,
+    ) -> Result<(), SculkParseError> {
+        let index = Region::index(x, z)?;
+        let compressed = compression.compress(bytes)?;
+
+        // The chunk data is prefixed by its length and the compression byte.
+        let sector_count = (compressed.len() + 5).div_ceil(SECTOR_SIZE);
+        let (data, compression_id) = if sector_count > MAX_SECTOR_COUNT {
+            self.external.insert(index, Some(compressed));
+            (vec![], compression.id() | EXTERNAL_FLAG)
+        } else {
+            // The old external file is deleted once the region is saved.
+            if self.is_external(index) {
+                self.external.insert(index, None);
+            }
+            (compressed, compression.id())
+        };
+
+        self.locations[index] = None;
+        let sector_count = (data.len() + 5).div_ceil(SECTOR_SIZE);
+        let offset = self.allocate(sector_count);
+
+        let start = offset * SECTOR_SIZE;
+        let end = start + sector_count * SECTOR_SIZE;
+        if self.bytes.len() < end {
+            self.bytes.resize(end, 0);
+        }
+
+        let sectors = &mut self.bytes[start..end];
+        sectors.fill(0);
+        sectors[..4].copy_from_slice(&(data.len() as u32 + 1).to_be_bytes());
+        sectors[4] = compression_id;
+        sectors[5..5 + data.len()].copy_from_slice(&data);
+
+        self.locations[index] = Some(ChunkLocation {
+            offset: offset as u32,
+            sector_count: sector_count as u8,
+        });
+        self.timestamps[index] = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map(|d| d.as_secs() as u32)
+            .unwrap_or_default();
+        self.write_header(index);
+
+        Ok(())
+    }
+
+    /// Removes the chunk at the given local coordinates (0..32), freeing its sectors.
+    /// Returns true if there was a chunk to remove.
+    pub fn remove_chunk(&mut self, x: usize, z: usize) -> Result<bool, SculkParseError> {
+        let index = Region::index(x, z)?;
+        if self.locations[index].is_none() {
+            return Ok(false);
+        }
+
+        if self.is_external(index) {
+            self.external.insert(index, None);
+        }
+
+        self.locations[index] = None;
+        self.timestamps[index] = 0;
+        self.write_header(index);
+
+        Ok(true)
+    }
+
+    /// Moves every chunk as close to the start of the file as possible, removing all free sectors.
+    /// Returns the amount of sectors that were freed.
+    pub fn compact(&mut self) -> usize {
+        let sector_count = self.bytes.len().div_ceil(SECTOR_SIZE);
+
+        let mut order: Vec<usize> = (0..CHUNK_COUNT)
+            .filter(|i| self.locations[*i].is_some())
+            .collect();
+        order.sort_by_key(|i| self.locations[*i].map(|l| l.offset));
+
+        let mut bytes = self.bytes[..HEADER_SIZE.min(self.bytes.len())].to_vec();
+        bytes.resize(HEADER_SIZE, 0);
+
+        for index in order {
+            let Some(location) = self.locations[index] else {
+                continue;
+            };
+
+            let start = location.offset as usize * SECTOR_SIZE;
+            let end = (start + location.sector_count as usize * SECTOR_SIZE).min(self.bytes.len());
+            let offset = bytes.len() / SECTOR_SIZE;
+
+            bytes.extend_from_slice(&self.bytes[start.min(end)..end]);
+            bytes.resize((offset + location.sector_count as usize) * SECTOR_SIZE, 0);
+
+            self.locations[index] = Some(ChunkLocation {
+                offset: offset as u32,
+                sector_count: location.sector_count,
+            });
+        }
+
+        self.bytes = bytes;
+        for index in 0..CHUNK_COUNT {
+            self.write_header(index);
+        }
+
+        sector_count.saturating_sub(self.bytes.len() / SECTOR_SIZE)
+    }
+
+    /// Returns the raw bytes of the region file.
+    /// Externally stored chunk data isn't included, use [`Region::save`] to also write the `.mcc` files.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        // The game writes regions without chunks as empty files.
+        if self.locations.iter().all(Option::is_none) {
+            return vec![];
+        }
+
+        let mut bytes = self.bytes.clone();
+        bytes.resize(bytes.len().div_ceil(SECTOR_SIZE) * SECTOR_SIZE, 0);
+        bytes
+    }
+
+    /// Writes the region to the given path, along with any external `c.X.Z.mcc` chunk files next to it.
+    /// External files of chunks that were removed or shrunk back into the region are deleted.
+    pub fn save<P: AsRef<Path>>(&mut self, path: P) -> Result<(), SculkParseError> {
+        let path = path.as_ref();
+        let directory = path.parent().map(Path::to_path_buf).unwrap_or_default();
+
+        // Copy the external chunks over when saving into a different folder.
+        if self.directory.as_ref() != Some(&directory) {
+            for index in 0..CHUNK_COUNT {
+                if self.is_external(index) && !self.external.contains_key(&index) {
+                    let bytes = std::fs::read(self.external_path_at(index)?)?;
+                    self.external.insert(index, Some(bytes));
+                }
+            }
+        }
+
+        std::fs::write(path, self.to_bytes())?;
+        self.directory = Some(directory);
+
+        for (index, data) in std::mem::take(&mut self.external) {
+            let external_path = self.external_path_at(index)?;
+
+            match data {
+                Some(data) => std::fs::write(external_path, data)?,
+                None => match std::fs::remove_file(external_path) {
+                    Err(e) if e.kind() != std::io::ErrorKind::NotFound => return Err(e.into()),
+                    _ => (),
+                },
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Finds the first run of free sectors that fits the given amount, after the header.
+    fn allocate(&self, sector_count: usize) -> usize {
+        let header_sectors = HEADER_SIZE / SECTOR_SIZE;
+        let total = self.bytes.len().div_ceil(SECTOR_SIZE).max(header_sectors);
+
+        let mut used = vec![false; total];
+        used[..header_sectors].fill(true);
+        for location in self.locations.iter().flatten() {
+            let start = location.offset as usize;
+            let end = (start + location.sector_count as usize).min(total);
+            used[start.min(end)..end].fill(true);
+        }
+
+        let mut run_start = header_sectors;
+        for (sector, used) in used.iter().enumerate().skip(header_sectors) {
+            if *used {
+                run_start = sector + 1;
+            } else if sector + 1 - run_start == sector_count {
+                return run_start;
+            }
+        }
+
+        run_start
+    }
+
+    /// Returns true if the chunk at the given index currently points to an external file.
+    fn is_external(&self, index: usize) -> bool {
+        match self.locations[index] {
+            Some(location) => self
+                .bytes
+                .get(location.offset as usize * SECTOR_SIZE + 4)
+                .is_some_and(|compression| compression & EXTERNAL_FLAG != 0),
+            None => false,
+        }
+    }
+
+    fn external_path_at(&self, index: usize) -> Result<PathBuf, SculkParseError> {
+        self.external_path(index % REGION_WIDTH, index / REGION_WIDTH)
+    }
+
+    /// Writes the location and timestamp of a chunk into the header.
+    fn write_header(&mut self, index: usize) {
+        if self.bytes.len() < HEADER_SIZE {
+            self.bytes.resize(HEADER_SIZE, 0);
+        }
+
+        let location = match self.locations[index] {
+            Some(location) => {
+                let offset = location.offset.to_be_bytes();
+                [offset[1], offset[2], offset[3], location.sector_count]
+            }
+            None => [0; 4],
+        };
+
+        self.bytes[index * 4..index * 4 + 4].copy_from_slice(&location);
+        self.bytes[SECTOR_SIZE + index * 4..SECTOR_SIZE + index * 4 + 4]
+            .copy_from_slice(&self.timestamps[index].to_be_bytes());
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn region_write() {
+    let nbt = std::fs::read("test_data/chest.nbt").unwrap();
+
+    let mut region = Region::new(2, -3);
+    region
+        .set_chunk_bytes(0, 0, &nbt, CompressionType::Zlib)
+        .unwrap();
+    region
+        .set_chunk_bytes(31, 31, &nbt, CompressionType::Lz4)
+        .unwrap();
+    region
+        .set_chunk_bytes(4, 7, &nbt, CompressionType::Uncompressed)
+        .unwrap();
+
+    // A removed chunk leaves a hole which the next chunk that fits is placed in.
+    assert!(region.remove_chunk(31, 31).unwrap());
+    region
+        .set_chunk_bytes(5, 5, &nbt, CompressionType::Gzip)
+        .unwrap();
+    assert_eq!(region.location(5, 5).unwrap().unwrap().offset, 3);
+
+    // An uncompressed chunk bigger than the sector limit is stored externally.
+    let large = vec![7u8; MAX_SECTOR_COUNT * SECTOR_SIZE];
+    region
+        .set_chunk_bytes(1, 0, &large, CompressionType::Uncompressed)
+        .unwrap();
+    assert_eq!(region.location(1, 0).unwrap().unwrap().sector_count, 1);
+
+    region.remove_chunk(0, 0).unwrap();
+    assert_eq!(region.compact(), 1);
+
+    let directory = std::env::temp_dir().join(format!("sculk_region_write_{}", std::process::id()));
+    std::fs::create_dir_all(&directory).unwrap();
+    let path = directory.join("r.2.-3.mca");
+    region.save(&path).unwrap();
+
+    let read = Region::open(&path).unwrap();
+    assert_eq!(
+        read.chunk_positions().collect::<Vec<_>>(),
+        vec![(1, 0), (5, 5), (4, 7)]
+    );
+    assert_eq!(read.chunk_bytes(4, 7).unwrap().unwrap(), nbt);
+    assert_eq!(read.chunk_bytes(5, 5).unwrap().unwrap(), nbt);
+    assert_eq!(read.chunk_bytes(1, 0).unwrap().unwrap(), large);
+    assert!(directory.join("c.65.-96.mcc").exists());
+
+    // Shrinking the chunk back into the region deletes its external file.
+    region
+        .set_chunk_bytes(1, 0, &nbt, CompressionType::Zlib)
+        .unwrap();
+    region.save(&path).unwrap();
+    assert!(!directory.join("c.65.-96.mcc").exists());
+    assert_eq!(
+        Region::open(&path)
+            .unwrap()
+            .chunk_bytes(1, 0)
+            .unwrap()
+            .unwrap(),
+        nbt
+    );
+
+    std::fs::remove_dir_all(directory).unwrap();
+}
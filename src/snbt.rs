@@ -0,0 +1,576 @@
+//! Parsing and printing of SNBT (stringified Nbt), the text format used in commands like `/data get`.
+//!
+//! ```rust
+//! use sculk::snbt;
+//!
+//! let nbt = snbt::parse("{id: \"minecraft:stone\", count: 2, tag: [I; 1, 2, 3]}").unwrap();
+//! assert_eq!(nbt.int("count"), Some(2));
+//!
+//! assert_eq!(snbt::to_snbt(&nbt), "{id:\"minecraft:stone\",count:2,tag:[I;1,2,3]}");
+//! ```
+
+use crate::error::SculkParseError;
+use simdnbt::{
+    owned::{NbtCompound, NbtList, NbtTag},
+    Mutf8Str,
+};
+use std::fmt::Write;
+
+/// The same max depth simdnbt uses.
+const MAX_DEPTH: usize = 512;
+
+/// The indentation used by [`to_snbt_pretty`].
+const INDENT: &str = "    ";
+
+/// Parses an SNBT compound, like `{Count: 1b, id: "minecraft:stone"}`.
+pub fn parse(input: &str) -> Result<NbtCompound, SculkParseError> {
+    match parse_tag(input)? {
+        NbtTag::Compound(compound) => Ok(compound),
+        _ => Err(SculkParseError::InvalidSnbt(
+            "Expected a compound at the root".into(),
+        )),
+    }
+}
+
+/// Parses any SNBT value, like `12s`, `"text"` or `[1, 2, 3]`.
+pub fn parse_tag(input: &str) -> Result<NbtTag, SculkParseError> {
+    let mut parser = Parser { input, pos: 0 };
+
+    let tag = parser.value(0)?;
+    parser.skip_whitespace();
+
+    if parser.pos != input.len() {
+        return Err(parser.error("Trailing data"));
+    }
+
+    Ok(tag)
+}
+
+/// Formats a compound as compact SNBT, without any whitespace.
+pub fn to_snbt(nbt: &NbtCompound) -> String {
+    let mut out = String::new();
+    Printer { pretty: false }.compound(&mut out, nbt, 0);
+    out
+}
+
+/// Formats a compound as SNBT, with every compound entry and nested list on its own indented line.
+pub fn to_snbt_pretty(nbt: &NbtCompound) -> String {
+    let mut out = String::new();
+    Printer { pretty: true }.compound(&mut out, nbt, 0);
+    out
+}
+
+/// Formats any tag as compact SNBT, without any whitespace.
+pub fn tag_to_snbt(tag: &NbtTag) -> String {
+    let mut out = String::new();
+    Printer { pretty: false }.tag(&mut out, tag, 0);
+    out
+}
+
+/// Formats any tag as SNBT, with every compound entry and nested list on its own indented line.
+pub fn tag_to_snbt_pretty(tag: &NbtTag) -> String {
+    let mut out = String::new();
+    Printer { pretty: true }.tag(&mut out, tag, 0);
+    out
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn error(&self, message: &str) -> SculkParseError {
+        SculkParseError::InvalidSnbt(format!("{message} at position {}", self.pos))
+    }
+
+    fn peek(&self) -> Option<char> {
+        self.input[self.pos..].chars().next()
+    }
+
+    fn skip_whitespace(&mut self) {
+        while let Some(c) = self.peek().filter(|c| c.is_whitespace()) {
+            self.pos += c.len_utf8();
+        }
+    }
+
+    /// Skips whitespace and consumes `expected` if it's the next character.
+    fn eat(&mut self, expected: char) -> bool {
+        self.skip_whitespace();
+
+        if self.peek() == Some(expected) {
+            self.pos += 1;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn expect(&mut self, expected: char) -> Result<(), SculkParseError> {
+        match self.eat(expected) {
+            true => Ok(()),
+            false => Err(self.error(&format!("Expected '{expected}'"))),
+        }
+    }
+
+    fn value(&mut self, depth: usize) -> Result<NbtTag, SculkParseError> {
+        if depth > MAX_DEPTH {
+            return Err(self.error("Too deeply nested"));
+        }
+
+        self.skip_whitespace();
+        match self.peek() {
+            Some('{') => self.compound(depth).map(NbtTag::Compound),
+            Some('[') => self.list_or_array(depth),
+            Some('"' | '\'') => Ok(NbtTag::String(self.quoted_string()?.into())),
+            Some(_) => {
+                let token = self.unquoted_string();
+                if token.is_empty() {
+                    return Err(self.error("Expected a value"));
+                }
+                Ok(parse_primitive(token))
+            }
+            None => Err(self.error("Expected a value")),
+        }
+    }
+
+    fn compound(&mut self, depth: usize) -> Result<NbtCompound, SculkParseError> {
+        self.expect('{')?;
+        let mut compound = NbtCompound::new();
+
+        while !self.eat('}') {
+            self.skip_whitespace();
+            let key = match self.peek() {
+                Some('"' | '\'') => self.quoted_string()?,
+                _ => self.unquoted_string().to_string(),
+            };
+            if key.is_empty() {
+                return Err(self.error("Expected a key"));
+            }
+
+            self.expect(':')?;
+            let value = self.value(depth + 1)?;
+
+            // Later duplicate keys overwrite earlier ones, like in game.
+            match compound.get_mut(&key) {
+                Some(existing) => *existing = value,
+                None => compound.insert(key, value),
+            }
+
+            if !self.eat(',') {
+                self.expect('}')?;
+                break;
+            }
+        }
+
+        Ok(compound)
+    }
+
+    fn list_or_array(&mut self, depth: usize) -> Result<NbtTag, SculkParseError> {
+        self.expect('[')?;
+
+        // Arrays start with their type, like `[I;`.
+        let rest = &self.input[self.pos..];
+        let prefix = rest.trim_start();
+        let array_type = match prefix.as_bytes() {
+            [t @ (b'B' | b'I' | b'L'), rest @ ..]
+                if rest.trim_ascii_start().first() == Some(&b';') =>
+            {
+                Some(*t)
+            }
+            _ => None,
+        };
+
+        let Some(array_type) = array_type else {
+            return self.list(depth).map(NbtTag::List);
+        };
+
+        self.pos += rest.len() - prefix.len() + 1;
+        self.expect(';')?;
+
+        let mut values = vec![];
+        while !self.eat(']') {
+            values.push(self.value(depth + 1)?);
+
+            if !self.eat(',') {
+                self.expect(']')?;
+                break;
+            }
+        }
+
+        let invalid = || self.error("Invalid array element");
+        Ok(match array_type {
+            b'B' => NbtTag::ByteArray(
+                values
+                    .into_iter()
+                    .map(|v| match v {
+                        NbtTag::Byte(b) => Ok(b as u8),
+                        _ => Err(invalid()),
+                    })
+                    .collect::<Result<_, _>>()?,
+            ),
+            b'I' => NbtTag::IntArray(
+                values
+                    .into_iter()
+                    .map(|v| match v {
+                        NbtTag::Byte(b) => Ok(b as i32),
+                        NbtTag::Short(s) => Ok(s as i32),
+                        NbtTag::Int(i) => Ok(i),
+                        _ => Err(invalid()),
+                    })
+                    .collect::<Result<_, _>>()?,
+            ),
+            _ => NbtTag::LongArray(
+                values
+                    .into_iter()
+                    .map(|v| match v {
+                        NbtTag::Byte(b) => Ok(b as i64),
+                        NbtTag::Short(s) => Ok(s as i64),
+                        NbtTag::Int(i) => Ok(i as i64),
+                        NbtTag::Long(l) => Ok(l),
+                        _ => Err(invalid()),
+                    })
+                    .collect::<Result<_, _>>()?,
+            ),
+        })
+    }
+
+    fn list(&mut self, depth: usize) -> Result<NbtList, SculkParseError> {
+        let mut values = vec![];
+
+        while !self.eat(']') {
+            values.push(self.value(depth + 1)?);
+
+            if !self.eat(',') {
+                self.expect(']')?;
+                break;
+            }
+        }
+
+        list_from_values(values).ok_or_else(|| self.error("Lists can't have mixed types"))
+    }
+
+    fn quoted_string(&mut self) -> Result<String, SculkParseError> {
+        let quote = self.peek().ok_or_else(|| self.error("Expected a string"))?;
+        self.pos += 1;
+
+        let mut string = String::new();
+        let mut chars = self.input[self.pos..].char_indices();
+
+        while let Some((i, c)) = chars.next() {
+            match c {
+                c if c == quote => {
+                    self.pos += i + 1;
+                    return Ok(string);
+                }
+                '\\' => {
+                    let escaped = match chars.next() {
+                        Some((_, 'n')) => '\n',
+                        Some((_, 't')) => '\t',
+                        Some((_, 'r')) => '\r',
+                        Some((_, 'b')) => '\u{8}',
+                        Some((_, 'f')) => '\u{c}',
+                        Some((_, 's')) => ' ',
+                        Some((_, 'u')) => {
+                            let hex: String = chars.by_ref().take(4).map(|(_, c)| c).collect();
+                            u32::from_str_radix(&hex, 16)
+                                .ok()
+                                .and_then(char::from_u32)
+                                .ok_or_else(|| self.error("Invalid unicode escape"))?
+                        }
+                        Some((_, c @ ('\\' | '"' | '\''))) => c,
+                        _ => return Err(self.error("Invalid escape")),
+                    };
+                    string.push(escaped);
+                }
+                c => string.push(c),
+            }
+        }
+
+        Err(self.error("Unterminated string"))
+    }
+
+    fn unquoted_string(&mut self) -> &'a str {
+        let start = self.pos;
+        let len = self.input[start..]
+            .find(|c: char| !is_unquoted_char(c))
+            .unwrap_or(self.input.len() - start);
+
+        self.pos += len;
+        &self.input[start..start + len]
+    }
+}
+
+fn is_unquoted_char(c: char) -> bool {
+    c.is_ascii_alphanumeric() || matches!(c, '_' | '-' | '.' | '+')
+}
+
+/// Parses an unquoted token as a number or boolean, falling back to a string like the game does.
+fn parse_primitive(token: &str) -> NbtTag {
+    match token {
+        "true" => return NbtTag::Byte(1),
+        "false" => return NbtTag::Byte(0),
+        _ => {}
+    }
+
+    let (number, suffix) = match token.char_indices().last() {
+        Some((i, c)) if c.is_ascii_alphabetic() => (&token[..i], Some(c.to_ascii_lowercase())),
+        _ => (token, None),
+    };
+
+    let is_integer = {
+        let digits = number.strip_prefix(['-', '+']).unwrap_or(number);
+        !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit())
+    };
+    let is_decimal = {
+        let digits = number.strip_prefix(['-', '+']).unwrap_or(number);
+        digits.bytes().any(|b| b.is_ascii_digit())
+            && digits
+                .bytes()
+                .all(|b| b.is_ascii_digit() || matches!(b, b'.' | b'e' | b'E' | b'-' | b'+'))
+    };
+
+    let tag = match suffix {
+        Some('b') if is_integer => number.parse().ok().map(NbtTag::Byte),
+        Some('s') if is_integer => number.parse().ok().map(NbtTag::Short),
+        Some('l') if is_integer => number.parse().ok().map(NbtTag::Long),
+        Some('f') if is_decimal => number.parse().ok().map(NbtTag::Float),
+        Some('d') if is_decimal => number.parse().ok().map(NbtTag::Double),
+        None if is_integer => number.parse().ok().map(NbtTag::Int),
+        None if is_decimal && number.contains('.') => number.parse().ok().map(NbtTag::Double),
+        _ => None,
+    };
+
+    tag.unwrap_or_else(|| NbtTag::String(token.into()))
+}
+
+/// Builds a typed list, `None` if the values don't all have the same type.
+fn list_from_values(values: Vec<NbtTag>) -> Option<NbtList> {
+    let Some(first) = values.first() else {
+        return Some(NbtList::Empty);
+    };
+    let id = first.id();
+    if values.iter().any(|v| v.id() != id) {
+        return None;
+    }
+
+    macro_rules! collect {
+        ($variant:ident) => {
+            NbtList::$variant(
+                values
+                    .into_iter()
+                    .filter_map(|v| match v {
+                        NbtTag::$variant(v) => Some(v),
+                        _ => None,
+                    })
+                    .collect(),
+            )
+        };
+    }
+
+    Some(match first {
+        NbtTag::Byte(_) => collect!(Byte),
+        NbtTag::Short(_) => collect!(Short),
+        NbtTag::Int(_) => collect!(Int),
+        NbtTag::Long(_) => collect!(Long),
+        NbtTag::Float(_) => collect!(Float),
+        NbtTag::Double(_) => collect!(Double),
+        NbtTag::ByteArray(_) => collect!(ByteArray),
+        NbtTag::String(_) => collect!(String),
+        NbtTag::List(_) => collect!(List),
+        NbtTag::Compound(_) => collect!(Compound),
+        NbtTag::IntArray(_) => collect!(IntArray),
+        NbtTag::LongArray(_) => collect!(LongArray),
+    })
+}
+
+struct Printer {
+    pretty: bool,
+}
+
+impl Printer {
+    fn tag(&self, out: &mut String, tag: &NbtTag, depth: usize) {
+        match tag {
+            NbtTag::Byte(b) => _ = write!(out, "{b}b"),
+            NbtTag::Short(s) => _ = write!(out, "{s}s"),
+            NbtTag::Int(i) => _ = write!(out, "{i}"),
+            NbtTag::Long(l) => _ = write!(out, "{l}L"),
+            NbtTag::Float(f) => _ = write!(out, "{f:?}f"),
+            NbtTag::Double(d) => _ = write!(out, "{d:?}d"),
+            NbtTag::ByteArray(a) => {
+                self.array(out, "B", a.iter().map(|b| format!("{}B", *b as i8)))
+            }
+            NbtTag::String(s) => quote(out, &s.to_str()),
+            NbtTag::List(list) => self.list(out, list, depth),
+            NbtTag::Compound(compound) => self.compound(out, compound, depth),
+            NbtTag::IntArray(a) => self.array(out, "I", a.iter().map(i32::to_string)),
+            NbtTag::LongArray(a) => self.array(out, "L", a.iter().map(|l| format!("{l}L"))),
+        }
+    }
+
+    fn array(&self, out: &mut String, prefix: &str, values: impl Iterator<Item = String>) {
+        let separator = if self.pretty { ", " } else { "," };
+        let values: Vec<String> = values.collect();
+
+        out.push('[');
+        out.push_str(prefix);
+        out.push(';');
+        if self.pretty && !values.is_empty() {
+            out.push(' ');
+        }
+        out.push_str(&values.join(separator));
+        out.push(']');
+    }
+
+    fn compound(&self, out: &mut String, compound: &NbtCompound, depth: usize) {
+        if compound.is_empty() {
+            out.push_str("{}");
+            return;
+        }
+
+        out.push('{');
+        for (i, (key, value)) in compound.iter().enumerate() {
+            if i > 0 {
+                out.push(',');
+            }
+            self.newline(out, depth + 1);
+            key_to_snbt(out, key);
+            out.push(':');
+            if self.pretty {
+                out.push(' ');
+            }
+            self.tag(out, value, depth + 1);
+        }
+        self.newline(out, depth);
+        out.push('}');
+    }
+
+    fn list(&self, out: &mut String, list: &NbtList, depth: usize) {
+        let values = list.as_nbt_tags();
+        if values.is_empty() {
+            out.push_str("[]");
+            return;
+        }
+
+        // Lists of numbers and strings stay on one line, even when pretty printing.
+        let nested = matches!(
+            list,
+            NbtList::List(_)
+                | NbtList::Compound(_)
+                | NbtList::ByteArray(_)
+                | NbtList::IntArray(_)
+                | NbtList::LongArray(_)
+        );
+
+        out.push('[');
+        for (i, value) in values.iter().enumerate() {
+            if i > 0 {
+                out.push(',');
+                if self.pretty && !nested {
+                    out.push(' ');
+                }
+            }
+            if nested {
+                self.newline(out, depth + 1);
+            }
+            self.tag(out, value, depth + 1);
+        }
+        if nested {
+            self.newline(out, depth);
+        }
+        out.push(']');
+    }
+
+    fn newline(&self, out: &mut String, depth: usize) {
+        if self.pretty {
+            out.push('\n');
+            out.push_str(&INDENT.repeat(depth));
+        }
+    }
+}
+
+fn key_to_snbt(out: &mut String, key: &Mutf8Str) {
+    let key = key.to_str();
+
+    if !key.is_empty() && key.chars().all(is_unquoted_char) {
+        out.push_str(&key);
+    } else {
+        quote(out, &key);
+    }
+}
+
+/// Quotes a string with double quotes, or single quotes if it contains double quotes.
+fn quote(out: &mut String, string: &str) {
+    let quote = if string.contains('"') && !string.contains('\'') {
+        '\''
+    } else {
+        '"'
+    };
+
+    out.push(quote);
+    for c in string.chars() {
+        match c {
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\t' => out.push_str("\\t"),
+            '\r' => out.push_str("\\r"),
+            c if c == quote => {
+                out.push('\\');
+                out.push(c);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push(quote);
+}
+
+#[cfg(test)]
+#[test]
+fn snbt_test() {
+    let input = r#"{
+        id: "minecraft:diamond_sword", Count: 1b, 'quoted key': 'it''s',
+        Damage: 3s, time: 12L, speed: 0.5f, scale: 1.5, Unbreakable: true,
+        name: "say \"hi\"\n", plain: hello.world, big: 2147483648,
+        ints: [I; 1, -2, 3], bytes: [B; 1b, 2b], longs: [L; 4L, 5L],
+        list: [1, 2, 3], empty: [], nested: [{a: 1}, {a: 2, b: []}],
+    }"#;
+    // `'it''s'` is two strings next to each other, which isn't valid.
+    assert!(parse(input).is_err());
+
+    let input = input.replace("'it''s'", r#"'it\'s'"#);
+    let nbt = parse(&input).unwrap();
+
+    assert_eq!(nbt.byte("Count"), Some(1));
+    assert_eq!(nbt.string("quoted key").unwrap().to_str(), "it's");
+    assert_eq!(nbt.short("Damage"), Some(3));
+    assert_eq!(nbt.long("time"), Some(12));
+    assert_eq!(nbt.float("speed"), Some(0.5));
+    assert_eq!(nbt.double("scale"), Some(1.5));
+    assert_eq!(nbt.byte("Unbreakable"), Some(1));
+    assert_eq!(nbt.string("name").unwrap().to_str(), "say \"hi\"\n");
+    assert_eq!(nbt.string("plain").unwrap().to_str(), "hello.world");
+    assert_eq!(nbt.string("big").unwrap().to_str(), "2147483648");
+    assert_eq!(nbt.int_array("ints"), Some(&[1, -2, 3][..]));
+    assert_eq!(nbt.byte_array("bytes"), Some(&[1, 2][..]));
+    assert_eq!(nbt.long_array("longs"), Some(&[4, 5][..]));
+    assert_eq!(nbt.list("list"), Some(&NbtList::Int(vec![1, 2, 3])));
+    assert_eq!(nbt.list("empty"), Some(&NbtList::Empty));
+
+    // Both formats parse back into the same compound.
+    assert_eq!(parse(&to_snbt(&nbt)).unwrap(), nbt);
+    assert_eq!(parse(&to_snbt_pretty(&nbt)).unwrap(), nbt);
+
+    let mut small = NbtCompound::new();
+    small.insert("a", 1);
+    small.insert("b c", NbtList::Compound(vec![NbtCompound::new()]));
+    assert_eq!(to_snbt(&small), r#"{a:1,"b c":[{}]}"#);
+    assert_eq!(
+        to_snbt_pretty(&small),
+        "{\n    a: 1,\n    \"b c\": [\n        {}\n    ]\n}"
+    );
+
+    assert!(parse("[1, 2]").is_err());
+    assert!(parse("{a: [1, 2b]}").is_err());
+    assert!(parse("{a: 1} b").is_err());
+    assert!(parse("{a: \"unterminated}").is_err());
+}
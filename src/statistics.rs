@@ -1,49 +1,386 @@
+//! The statistics of a player, stored in `stats/<uuid>.json`.
+//! [Minecraft Wiki](https://minecraft.wiki/w/Statistics)
+//!
+//! ```rust
+//! use sculk::statistics::Statistics;
+//!
+//! let stats = Statistics::from_json(r#"{
+//!     "stats": {
+//!         "minecraft:mined": { "minecraft:diamond_ore": 12 },
+//!         "minecraft:custom": { "minecraft:play_time": 72000 }
+//!     },
+//!     "DataVersion": 3953
+//! }"#).unwrap();
+//!
+//! assert_eq!(stats.mined("minecraft:diamond_ore"), 12);
+//! assert_eq!(stats.play_time(), 72000);
+//! ```
+
+use crate::error::SculkParseError;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::{collections::HashMap, path::Path};
 
+/// The root of a statistics file.
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct StatsRoot {
+    /// The statistics of the player.
+    /// `stats`
     pub stats: Statistics,
 
+    /// Version of the statistics file.
+    /// `DataVersion`
     #[serde(rename = "DataVersion")]
     pub data_version: i32,
 }
 
-#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+/// Every statistic of a player, grouped by their type.
+/// Every type is keyed by the resource location of a block, item, entity or custom statistic.
+#[derive(Debug, Clone, PartialEq, Default, Serialize, Deserialize)]
 pub struct Statistics {
     /// A multitude of generic statistics related to a player's actions. Players' statistics increase automatically when they perform the action relevant to the statistic names.  
     #[serde(rename = "minecraft:custom")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub custom: Option<HashMap<String, i32>>,
 
     /// Statistic related to the number of blocks a player mined.  
     #[serde(rename = "minecraft:mined")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub mined: Option<HashMap<String, i32>>,
 
     /// Statistics related to the number of items a player ran their durability negative.  
     #[serde(rename = "minecraft:broken")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub broken: Option<HashMap<String, i32>>,
 
     /// Statistics related to the number of items crafted, smelted, etc.  
     #[serde(rename = "minecraft:crafted")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub crafted: Option<HashMap<String, i32>>,
 
     /// Statistics related to the number of block or item used.  
     #[serde(rename = "minecraft:used")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub used: Option<HashMap<String, i32>>,
 
     /// Statistics related to the number of dropped items a player picked up.  
     #[serde(rename = "minecraft:picked_up")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub picked_up: Option<HashMap<String, i32>>,
 
     /// Statistics related to the number of items that droped.  
     #[serde(rename = "minecraft:dropped")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub dropped: Option<HashMap<String, i32>>,
 
     /// Statistics related to the number of entities a player killed.  
     #[serde(rename = "minecraft:killed")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub killed: Option<HashMap<String, i32>>,
 
     /// Statistics related to the times of a player being killed by entities.  
     #[serde(rename = "minecraft:killed_by")]
+    #[serde(default, skip_serializing_if = "Option::is_none")]
     pub killed_by: Option<HashMap<String, i32>>,
 }
+
+/// The types of statistics.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum StatType {
+    /// `minecraft:custom`
+    Custom,
+    /// `minecraft:mined`
+    Mined,
+    /// `minecraft:broken`
+    Broken,
+    /// `minecraft:crafted`
+    Crafted,
+    /// `minecraft:used`
+    Used,
+    /// `minecraft:picked_up`
+    PickedUp,
+    /// `minecraft:dropped`
+    Dropped,
+    /// `minecraft:killed`
+    Killed,
+    /// `minecraft:killed_by`
+    KilledBy,
+}
+
+impl StatType {
+    /// Every statistic type.
+    pub const ALL: [StatType; 9] = [
+        StatType::Custom,
+        StatType::Mined,
+        StatType::Broken,
+        StatType::Crafted,
+        StatType::Used,
+        StatType::PickedUp,
+        StatType::Dropped,
+        StatType::Killed,
+        StatType::KilledBy,
+    ];
+}
+
+impl StatsRoot {
+    /// Parses a statistics file.
+    pub fn from_json(json: &str) -> Result<Self, SculkParseError> {
+        serde_json::from_str(json).map_err(|e| SculkParseError::InvalidJson(e.to_string()))
+    }
+
+    /// Reads and parses a statistics file.
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self, SculkParseError> {
+        StatsRoot::from_json(&std::fs::read_to_string(path)?)
+    }
+
+    /// Converts the statistics back into the JSON of a statistics file.
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(self).unwrap_or_default()
+    }
+}
+
+impl Statistics {
+    /// Parses the statistics of a statistics file, use [`StatsRoot::from_json`] to keep its `DataVersion`.
+    pub fn from_json(json: &str) -> Result<Self, SculkParseError> {
+        StatsRoot::from_json(json).map(|root| root.stats)
+    }
+
+    /// Reads and parses the statistics of a statistics file.
+    pub fn from_path<P: AsRef<Path>>(path: P) -> Result<Self, SculkParseError> {
+        StatsRoot::from_path(path).map(|root| root.stats)
+    }
+
+    /// The statistics of a type.
+    pub fn of(&self, stat_type: StatType) -> Option<&HashMap<String, i32>> {
+        match stat_type {
+            StatType::Custom => self.custom.as_ref(),
+            StatType::Mined => self.mined.as_ref(),
+            StatType::Broken => self.broken.as_ref(),
+            StatType::Crafted => self.crafted.as_ref(),
+            StatType::Used => self.used.as_ref(),
+            StatType::PickedUp => self.picked_up.as_ref(),
+            StatType::Dropped => self.dropped.as_ref(),
+            StatType::Killed => self.killed.as_ref(),
+            StatType::KilledBy => self.killed_by.as_ref(),
+        }
+    }
+
+    fn of_mut(&mut self, stat_type: StatType) -> &mut HashMap<String, i32> {
+        match stat_type {
+            StatType::Custom => &mut self.custom,
+            StatType::Mined => &mut self.mined,
+            StatType::Broken => &mut self.broken,
+            StatType::Crafted => &mut self.crafted,
+            StatType::Used => &mut self.used,
+            StatType::PickedUp => &mut self.picked_up,
+            StatType::Dropped => &mut self.dropped,
+            StatType::Killed => &mut self.killed,
+            StatType::KilledBy => &mut self.killed_by,
+        }
+        .get_or_insert_with(HashMap::new)
+    }
+
+    /// Gets a statistic, 0 if it doesn't exist.
+    /// The `minecraft:` namespace can be left out of the id.
+    pub fn get(&self, stat_type: StatType, id: &str) -> i32 {
+        let stats = match self.of(stat_type) {
+            Some(stats) => stats,
+            None => return 0,
+        };
+
+        match id.contains(':') {
+            true => stats.get(id),
+            false => stats.get(&format!("minecraft:{id}")),
+        }
+        .copied()
+        .unwrap_or(0)
+    }
+
+    /// The total of every statistic of a type, like every block mined.
+    pub fn total(&self, stat_type: StatType) -> i64 {
+        self.of(stat_type)
+            .map_or(0, |stats| stats.values().map(|v| *v as i64).sum())
+    }
+
+    /// A custom statistic, like `minecraft:jump`.
+    pub fn custom(&self, id: &str) -> i32 {
+        self.get(StatType::Custom, id)
+    }
+
+    /// How many times a block has been mined.
+    pub fn mined(&self, id: &str) -> i32 {
+        self.get(StatType::Mined, id)
+    }
+
+    /// How many times an item has run out of durability.
+    pub fn broken(&self, id: &str) -> i32 {
+        self.get(StatType::Broken, id)
+    }
+
+    /// How many times an item has been crafted, smelted or traded for.
+    pub fn crafted(&self, id: &str) -> i32 {
+        self.get(StatType::Crafted, id)
+    }
+
+    /// How many times a block or item has been used.
+    pub fn used(&self, id: &str) -> i32 {
+        self.get(StatType::Used, id)
+    }
+
+    /// How many of an item have been picked up.
+    pub fn picked_up(&self, id: &str) -> i32 {
+        self.get(StatType::PickedUp, id)
+    }
+
+    /// How many of an item have been dropped.
+    pub fn dropped(&self, id: &str) -> i32 {
+        self.get(StatType::Dropped, id)
+    }
+
+    /// How many times an entity has been killed.
+    pub fn killed(&self, id: &str) -> i32 {
+        self.get(StatType::Killed, id)
+    }
+
+    /// How many times an entity has killed the player.
+    pub fn killed_by(&self, id: &str) -> i32 {
+        self.get(StatType::KilledBy, id)
+    }
+
+    /// The time played in ticks, `minecraft:play_time`.
+    /// Worlds from before 1.17 store it as `minecraft:play_one_minute`.
+    pub fn play_time(&self) -> i32 {
+        match self.custom("minecraft:play_time") {
+            0 => self.custom("minecraft:play_one_minute"),
+            ticks => ticks,
+        }
+    }
+
+    /// `minecraft:deaths`
+    pub fn deaths(&self) -> i32 {
+        self.custom("minecraft:deaths")
+    }
+
+    /// `minecraft:mob_kills`
+    pub fn mob_kills(&self) -> i32 {
+        self.custom("minecraft:mob_kills")
+    }
+
+    /// `minecraft:player_kills`
+    pub fn player_kills(&self) -> i32 {
+        self.custom("minecraft:player_kills")
+    }
+
+    /// `minecraft:jump`
+    pub fn jumps(&self) -> i32 {
+        self.custom("minecraft:jump")
+    }
+
+    /// The damage dealt in tenths of a heart, `minecraft:damage_dealt`.
+    pub fn damage_dealt(&self) -> i32 {
+        self.custom("minecraft:damage_dealt")
+    }
+
+    /// The damage taken in tenths of a heart, `minecraft:damage_taken`.
+    pub fn damage_taken(&self) -> i32 {
+        self.custom("minecraft:damage_taken")
+    }
+
+    /// Adds every statistic of `other` to these statistics.
+    pub fn add(&mut self, other: &Statistics) {
+        for stat_type in StatType::ALL {
+            if let Some(stats) = other.of(stat_type) {
+                let sum = self.of_mut(stat_type);
+                for (id, value) in stats {
+                    let total = sum.entry(id.clone()).or_insert(0);
+                    *total = total.saturating_add(*value);
+                }
+            }
+        }
+    }
+
+    /// The sum of the statistics of every player.
+    pub fn sum<'a, I: IntoIterator<Item = &'a Statistics>>(statistics: I) -> Statistics {
+        let mut sum = Statistics::default();
+        for stats in statistics {
+            sum.add(stats);
+        }
+
+        sum
+    }
+
+    /// The `n` players with the highest value of a statistic, highest first.
+    /// Players without the statistic are left out.
+    pub fn leaderboard<'a, K: Clone + 'a, I: IntoIterator<Item = (&'a K, &'a Statistics)>>(
+        statistics: I,
+        stat_type: StatType,
+        id: &str,
+        n: usize,
+    ) -> Vec<(K, i32)> {
+        Statistics::leaderboard_by(statistics, |stats| stats.get(stat_type, id), n)
+    }
+
+    /// The `n` players with the highest value returned by `value`, like [`Statistics::play_time`], highest first.
+    /// Players with a value of 0 are left out.
+    pub fn leaderboard_by<'a, K, I, F>(statistics: I, value: F, n: usize) -> Vec<(K, i32)>
+    where
+        K: Clone + 'a,
+        I: IntoIterator<Item = (&'a K, &'a Statistics)>,
+        F: Fn(&Statistics) -> i32,
+    {
+        let mut leaderboard: Vec<(K, i32)> = statistics
+            .into_iter()
+            .map(|(key, stats)| (key.clone(), value(stats)))
+            .filter(|(_, value)| *value != 0)
+            .collect();
+        // Stable, so ties keep the order they were given in.
+        leaderboard.sort_by_key(|(_, value)| std::cmp::Reverse(*value));
+        leaderboard.truncate(n);
+
+        leaderboard
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn statistics_test() {
+    let steve = Statistics::from_json(
+        r#"{"stats": {"minecraft:mined": {"minecraft:stone": 100, "minecraft:diamond_ore": 3}, "minecraft:custom": {"minecraft:play_one_minute": 500}}, "DataVersion": 2586}"#,
+    )
+    .unwrap();
+    let alex = Statistics::from_json(
+        r#"{"stats": {"minecraft:mined": {"minecraft:diamond_ore": 9}, "minecraft:custom": {"minecraft:play_time": 200}}, "DataVersion": 3953}"#,
+    )
+    .unwrap();
+    let notch = Statistics::default();
+
+    assert_eq!(steve.mined("diamond_ore"), 3);
+    assert_eq!(steve.play_time(), 500);
+    assert_eq!(alex.play_time(), 200);
+    assert_eq!(steve.killed("minecraft:zombie"), 0);
+    assert_eq!(steve.total(StatType::Mined), 103);
+
+    let sum = Statistics::sum([&steve, &alex, &notch]);
+    assert_eq!(sum.mined("minecraft:diamond_ore"), 12);
+    assert_eq!(sum.mined("minecraft:stone"), 100);
+
+    let players = [("Steve", steve), ("Alex", alex), ("Notch", notch)];
+    let players = players.iter().map(|(name, stats)| (name, stats));
+    assert_eq!(
+        Statistics::leaderboard(players.clone(), StatType::Mined, "diamond_ore", 5),
+        vec![("Alex", 9), ("Steve", 3)]
+    );
+    assert_eq!(
+        Statistics::leaderboard_by(players, Statistics::play_time, 1),
+        vec![("Steve", 500)]
+    );
+
+    let root = StatsRoot::from_json(
+        &StatsRoot {
+            stats: sum.clone(),
+            data_version: 3953,
+        }
+        .to_json(),
+    )
+    .unwrap();
+    assert_eq!(root.stats, sum);
+}
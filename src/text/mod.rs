@@ -0,0 +1,989 @@
+//! JSON text components, used for custom names, lore, signs, books and boss bars.
+//! [Minecraft Wiki](https://minecraft.wiki/w/Raw_JSON_text_format)
+//!
+//! ```rust
+//! use sculk::text::{TextComponent, TextContent};
+//!
+//! let text = TextComponent::from_json(r#"{"text": "Hello ", "extra": [{"text": "world", "bold": true}]}"#).unwrap();
+//!
+//! assert_eq!(text.content, TextContent::Text("Hello ".into()));
+//! assert_eq!(text.extra[0].style.bold, Some(true));
+//! ```
+
+use crate::{error::SculkParseError, uuid::Uuid};
+use serde_json::{json, Map, Value};
+
+mod render;
+
+pub use render::*;
+
+/// A text component, its content and style, followed by its `extra` children.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TextComponent {
+    /// What the component displays.
+    pub content: TextContent,
+
+    /// The formatting of the component, inherited by its children.
+    pub style: Style,
+
+    /// Components appended after this one.
+    /// `extra`
+    pub extra: Vec<TextComponent>,
+}
+
+/// The content of a text component.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TextContent {
+    /// Plain text.
+    /// `text`
+    Text(String),
+
+    /// A translation key, resolved with the language of the client.
+    Translatable {
+        /// The translation key, like `block.minecraft.stone`.
+        /// `translate`
+        translate: String,
+
+        /// Used when the translation key doesn't exist.
+        /// `fallback`
+        fallback: Option<String>,
+
+        /// The arguments inserted into the `%s` placeholders of the translation.
+        /// `with`
+        with: Vec<TextComponent>,
+    },
+
+    /// The score of an entity in an objective.
+    /// `score`
+    Score {
+        /// The score holder, a player name or an entity selector.
+        /// `name`
+        name: String,
+
+        /// The objective the score is in.
+        /// `objective`
+        objective: String,
+    },
+
+    /// The names of the entities found by a selector.
+    Selector {
+        /// The entity selector, like `@p`.
+        /// `selector`
+        selector: String,
+
+        /// Placed between the names of the entities, defaults to `, `.
+        /// `separator`
+        separator: Option<Box<TextComponent>>,
+    },
+
+    /// The key bound to a control, like `key.jump`.
+    /// `keybind`
+    Keybind(String),
+
+    /// Nbt values read from a block, entity or storage.
+    Nbt {
+        /// The Nbt path to read.
+        /// `nbt`
+        path: String,
+
+        /// If true, the values are parsed as text components.
+        /// `interpret`
+        interpret: bool,
+
+        /// Placed between the values, defaults to `, `.
+        /// `separator`
+        separator: Option<Box<TextComponent>>,
+
+        /// Where the Nbt is read from.
+        source: NbtSource,
+    },
+}
+
+/// Where the values of an Nbt text component are read from.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NbtSource {
+    /// The coordinates of a block entity.
+    /// `block`
+    Block(String),
+
+    /// An entity selector.
+    /// `entity`
+    Entity(String),
+
+    /// The resource location of a command storage.
+    /// `storage`
+    Storage(String),
+}
+
+/// The formatting of a text component.
+/// `None` means the value is inherited from the parent component.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Style {
+    /// `color`
+    pub color: Option<TextColor>,
+
+    /// The resource location of the font.
+    /// `font`
+    pub font: Option<String>,
+
+    /// `bold`
+    pub bold: Option<bool>,
+
+    /// `italic`
+    pub italic: Option<bool>,
+
+    /// `underlined`
+    pub underlined: Option<bool>,
+
+    /// `strikethrough`
+    pub strikethrough: Option<bool>,
+
+    /// `obfuscated`
+    pub obfuscated: Option<bool>,
+
+    /// Inserted into the chat input when the text is shift clicked.
+    /// `insertion`
+    pub insertion: Option<String>,
+
+    /// `clickEvent`
+    pub click_event: Option<ClickEvent>,
+
+    /// `hoverEvent`
+    pub hover_event: Option<HoverEvent>,
+}
+
+/// The color of a text component, either one of the 16 named colors or any RGB color.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TextColor {
+    /// A named color, like `red`.
+    Named(NamedColor),
+
+    /// A hex color, like `#ff8800`.
+    Rgb(u32),
+}
+
+/// The 16 named text colors.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NamedColor {
+    Black,
+    DarkBlue,
+    DarkGreen,
+    DarkAqua,
+    DarkRed,
+    DarkPurple,
+    Gold,
+    Gray,
+    DarkGray,
+    Blue,
+    Green,
+    Aqua,
+    Red,
+    LightPurple,
+    Yellow,
+    White,
+}
+
+/// What happens when a text component is clicked.
+/// `clickEvent`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ClickEvent {
+    /// `action`
+    pub action: ClickAction,
+
+    /// The url, command, page or text, depending on the action.
+    /// `value`
+    pub value: String,
+}
+
+/// The action of a [`ClickEvent`].
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ClickAction {
+    /// `open_url`
+    OpenUrl,
+    /// `open_file`
+    OpenFile,
+    /// `run_command`
+    RunCommand,
+    /// `suggest_command`
+    SuggestCommand,
+    /// `change_page`
+    ChangePage,
+    /// `copy_to_clipboard`
+    CopyToClipboard,
+    /// Any action not known by sculk.
+    Unknown(String),
+}
+
+/// What is shown when a text component is hovered.
+/// `hoverEvent`
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum HoverEvent {
+    /// Another text component.
+    /// `show_text`
+    ShowText(Box<TextComponent>),
+
+    /// The tooltip of an item.
+    /// `show_item`
+    ShowItem {
+        /// `id`
+        id: String,
+
+        /// `count`
+        count: Option<i32>,
+
+        /// The data components of the item, kept as they are in the JSON.
+        /// `components`
+        #[cfg_attr(feature = "serde", serde(skip))]
+        components: Option<Value>,
+    },
+
+    /// The name, type and uuid of an entity.
+    /// `show_entity`
+    ShowEntity {
+        /// The entity type, like `minecraft:pig`.
+        /// `type`
+        entity_type: String,
+
+        /// `id`
+        id: Uuid,
+
+        /// `name`
+        name: Option<Box<TextComponent>>,
+    },
+}
+
+impl TextComponent {
+    /// A plain text component without any style.
+    pub fn text<S: Into<String>>(text: S) -> Self {
+        TextContent::Text(text.into()).into()
+    }
+
+    /// A translatable component without any style.
+    pub fn translatable<S: Into<String>>(key: S, with: Vec<TextComponent>) -> Self {
+        TextContent::Translatable {
+            translate: key.into(),
+            fallback: None,
+            with,
+        }
+        .into()
+    }
+
+    /// A keybind component without any style.
+    pub fn keybind<S: Into<String>>(key: S) -> Self {
+        TextContent::Keybind(key.into()).into()
+    }
+
+    /// Sets the style of the component.
+    pub fn with_style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+
+    /// Appends a child component.
+    pub fn append(mut self, child: TextComponent) -> Self {
+        self.extra.push(child);
+        self
+    }
+
+    /// Parses a JSON text component.
+    pub fn from_json(json: &str) -> Result<Self, SculkParseError> {
+        let value: Value = serde_json::from_str(json)
+            .map_err(|e| SculkParseError::InvalidTextComponent(e.to_string()))?;
+
+        TextComponent::from_value(&value)
+    }
+
+    /// Parses a JSON text component, falling back to plain text if it isn't valid JSON text.
+    /// Useful for data that can be either, like the pages of written books or signs from older versions.
+    pub fn parse(text: &str) -> Self {
+        TextComponent::from_json(text).unwrap_or_else(|_| TextComponent::text(text))
+    }
+
+    /// Parses a text component from a JSON value.
+    /// Strings, numbers and booleans are plain text, and arrays are the first element with the rest appended to it.
+    pub fn from_value(value: &Value) -> Result<Self, SculkParseError> {
+        match value {
+            Value::String(text) => Ok(TextComponent::text(text.as_str())),
+            Value::Number(_) | Value::Bool(_) => Ok(TextComponent::text(value.to_string())),
+            Value::Array(values) => {
+                let (first, rest) = values
+                    .split_first()
+                    .ok_or(invalid("A text component array can't be empty"))?;
+
+                let mut component = TextComponent::from_value(first)?;
+                for value in rest {
+                    component.extra.push(TextComponent::from_value(value)?);
+                }
+
+                Ok(component)
+            }
+            Value::Object(map) => TextComponent::from_map(map),
+            Value::Null => Err(invalid("A text component can't be null")),
+        }
+    }
+
+    fn from_map(map: &Map<String, Value>) -> Result<Self, SculkParseError> {
+        let extra = match map.get("extra") {
+            Some(Value::Array(values)) => values
+                .iter()
+                .map(TextComponent::from_value)
+                .collect::<Result<_, _>>()?,
+            Some(_) => return Err(invalid("extra must be an array")),
+            None => vec![],
+        };
+
+        Ok(TextComponent {
+            content: TextContent::from_map(map)?,
+            style: Style::from_map(map)?,
+            extra,
+        })
+    }
+
+    /// Converts the component into a JSON value.
+    /// Plain text without style or children is written as a string.
+    pub fn to_value(&self) -> Value {
+        if let TextContent::Text(text) = &self.content {
+            if self.style == Style::default() && self.extra.is_empty() {
+                return Value::String(text.clone());
+            }
+        }
+
+        let mut map = Map::new();
+        self.content.write(&mut map);
+        self.style.write(&mut map);
+        if !self.extra.is_empty() {
+            map.insert(
+                "extra".into(),
+                Value::Array(self.extra.iter().map(TextComponent::to_value).collect()),
+            );
+        }
+
+        Value::Object(map)
+    }
+
+    /// Converts the component into a JSON string.
+    pub fn to_json(&self) -> String {
+        self.to_value().to_string()
+    }
+
+    /// Iterates over this component and every component inside of it, depth first.
+    /// Includes translation arguments, separators, hover text and hovered entity names.
+    pub fn iter(&self) -> impl Iterator<Item = &TextComponent> {
+        let mut stack = vec![self];
+
+        std::iter::from_fn(move || {
+            let component = stack.pop()?;
+
+            // Pushed in reverse, so they are visited in order.
+            stack.extend(component.extra.iter().rev());
+            match &component.style.hover_event {
+                Some(HoverEvent::ShowText(text))
+                | Some(HoverEvent::ShowEntity {
+                    name: Some(text), ..
+                }) => stack.push(text),
+                _ => {}
+            }
+            match &component.content {
+                TextContent::Translatable { with, .. } => stack.extend(with.iter().rev()),
+                TextContent::Selector {
+                    separator: Some(separator),
+                    ..
+                }
+                | TextContent::Nbt {
+                    separator: Some(separator),
+                    ..
+                } => stack.push(separator),
+                _ => {}
+            }
+
+            Some(component)
+        })
+    }
+}
+
+impl From<TextContent> for TextComponent {
+    fn from(content: TextContent) -> Self {
+        TextComponent {
+            content,
+            style: Style::default(),
+            extra: vec![],
+        }
+    }
+}
+
+impl From<&str> for TextComponent {
+    fn from(text: &str) -> Self {
+        TextComponent::text(text)
+    }
+}
+
+impl TextContent {
+    fn from_map(map: &Map<String, Value>) -> Result<Self, SculkParseError> {
+        // `type` is optional, without it the content is found by its keys.
+        let kind = match map.get("type").and_then(Value::as_str) {
+            Some(kind) => kind,
+            None => ["text", "translate", "score", "selector", "keybind", "nbt"]
+                .into_iter()
+                .find(|key| map.contains_key(*key))
+                .map(|key| match key {
+                    "translate" => "translatable",
+                    key => key,
+                })
+                .ok_or(invalid("No text component content"))?,
+        };
+
+        Ok(match kind {
+            "text" => TextContent::Text(match map.get("text") {
+                Some(Value::String(text)) => text.clone(),
+                Some(value @ (Value::Number(_) | Value::Bool(_))) => value.to_string(),
+                _ => return Err(invalid("text must be a string")),
+            }),
+            "translatable" => TextContent::Translatable {
+                translate: get_string(map, "translate")?,
+                fallback: get_optional_string(map, "fallback")?,
+                with: match map.get("with") {
+                    Some(Value::Array(values)) => values
+                        .iter()
+                        .map(TextComponent::from_value)
+                        .collect::<Result<_, _>>()?,
+                    Some(_) => return Err(invalid("with must be an array")),
+                    None => vec![],
+                },
+            },
+            "score" => {
+                let score = match map.get("score") {
+                    Some(Value::Object(score)) => score,
+                    _ => return Err(invalid("score must be an object")),
+                };
+
+                TextContent::Score {
+                    name: get_string(score, "name")?,
+                    objective: get_string(score, "objective")?,
+                }
+            }
+            "selector" => TextContent::Selector {
+                selector: get_string(map, "selector")?,
+                separator: get_optional_component(map, "separator")?,
+            },
+            "keybind" => TextContent::Keybind(get_string(map, "keybind")?),
+            "nbt" => {
+                let source = if let Some(block) = get_optional_string(map, "block")? {
+                    NbtSource::Block(block)
+                } else if let Some(entity) = get_optional_string(map, "entity")? {
+                    NbtSource::Entity(entity)
+                } else if let Some(storage) = get_optional_string(map, "storage")? {
+                    NbtSource::Storage(storage)
+                } else {
+                    return Err(invalid("nbt needs a block, entity or storage"));
+                };
+
+                TextContent::Nbt {
+                    path: get_string(map, "nbt")?,
+                    interpret: map
+                        .get("interpret")
+                        .and_then(Value::as_bool)
+                        .unwrap_or(false),
+                    separator: get_optional_component(map, "separator")?,
+                    source,
+                }
+            }
+            kind => return Err(invalid(&format!("Unknown text component type: {kind}"))),
+        })
+    }
+
+    fn write(&self, map: &mut Map<String, Value>) {
+        match self {
+            TextContent::Text(text) => {
+                map.insert("text".into(), text.as_str().into());
+            }
+            TextContent::Translatable {
+                translate,
+                fallback,
+                with,
+            } => {
+                map.insert("translate".into(), translate.as_str().into());
+                if let Some(fallback) = fallback {
+                    map.insert("fallback".into(), fallback.as_str().into());
+                }
+                if !with.is_empty() {
+                    map.insert(
+                        "with".into(),
+                        Value::Array(with.iter().map(TextComponent::to_value).collect()),
+                    );
+                }
+            }
+            TextContent::Score { name, objective } => {
+                map.insert(
+                    "score".into(),
+                    json!({ "name": name, "objective": objective }),
+                );
+            }
+            TextContent::Selector {
+                selector,
+                separator,
+            } => {
+                map.insert("selector".into(), selector.as_str().into());
+                if let Some(separator) = separator {
+                    map.insert("separator".into(), separator.to_value());
+                }
+            }
+            TextContent::Keybind(key) => {
+                map.insert("keybind".into(), key.as_str().into());
+            }
+            TextContent::Nbt {
+                path,
+                interpret,
+                separator,
+                source,
+            } => {
+                map.insert("nbt".into(), path.as_str().into());
+                if *interpret {
+                    map.insert("interpret".into(), true.into());
+                }
+                if let Some(separator) = separator {
+                    map.insert("separator".into(), separator.to_value());
+                }
+                let (key, value) = match source {
+                    NbtSource::Block(block) => ("block", block),
+                    NbtSource::Entity(entity) => ("entity", entity),
+                    NbtSource::Storage(storage) => ("storage", storage),
+                };
+                map.insert(key.into(), value.as_str().into());
+            }
+        }
+    }
+}
+
+impl Style {
+    fn from_map(map: &Map<String, Value>) -> Result<Self, SculkParseError> {
+        let color = get_optional_string(map, "color")?
+            .map(|color| {
+                TextColor::parse(&color).ok_or(invalid(&format!("Invalid color: {color}")))
+            })
+            .transpose()?;
+
+        // 1.21.5 renamed the events to snake case.
+        let click_event = match map.get("clickEvent").or(map.get("click_event")) {
+            Some(Value::Object(event)) => Some(ClickEvent::from_map(event)?),
+            Some(_) => return Err(invalid("clickEvent must be an object")),
+            None => None,
+        };
+        let hover_event = match map.get("hoverEvent").or(map.get("hover_event")) {
+            Some(Value::Object(event)) => Some(HoverEvent::from_map(event)?),
+            Some(_) => return Err(invalid("hoverEvent must be an object")),
+            None => None,
+        };
+
+        Ok(Style {
+            color,
+            font: get_optional_string(map, "font")?,
+            bold: map.get("bold").and_then(Value::as_bool),
+            italic: map.get("italic").and_then(Value::as_bool),
+            underlined: map.get("underlined").and_then(Value::as_bool),
+            strikethrough: map.get("strikethrough").and_then(Value::as_bool),
+            obfuscated: map.get("obfuscated").and_then(Value::as_bool),
+            insertion: get_optional_string(map, "insertion")?,
+            click_event,
+            hover_event,
+        })
+    }
+
+    fn write(&self, map: &mut Map<String, Value>) {
+        if let Some(color) = self.color {
+            map.insert("color".into(), color.to_string().into());
+        }
+        if let Some(font) = &self.font {
+            map.insert("font".into(), font.as_str().into());
+        }
+        for (key, value) in [
+            ("bold", self.bold),
+            ("italic", self.italic),
+            ("underlined", self.underlined),
+            ("strikethrough", self.strikethrough),
+            ("obfuscated", self.obfuscated),
+        ] {
+            if let Some(value) = value {
+                map.insert(key.into(), value.into());
+            }
+        }
+        if let Some(insertion) = &self.insertion {
+            map.insert("insertion".into(), insertion.as_str().into());
+        }
+        if let Some(click_event) = &self.click_event {
+            map.insert(
+                "clickEvent".into(),
+                json!({ "action": click_event.action.name(), "value": click_event.value }),
+            );
+        }
+        if let Some(hover_event) = &self.hover_event {
+            map.insert("hoverEvent".into(), hover_event.to_value());
+        }
+    }
+
+    /// Fills every unset value of this style with the value of `parent`.
+    pub fn inherit(&self, parent: &Style) -> Style {
+        Style {
+            color: self.color.or(parent.color),
+            font: self.font.clone().or_else(|| parent.font.clone()),
+            bold: self.bold.or(parent.bold),
+            italic: self.italic.or(parent.italic),
+            underlined: self.underlined.or(parent.underlined),
+            strikethrough: self.strikethrough.or(parent.strikethrough),
+            obfuscated: self.obfuscated.or(parent.obfuscated),
+            insertion: self.insertion.clone().or_else(|| parent.insertion.clone()),
+            click_event: self
+                .click_event
+                .clone()
+                .or_else(|| parent.click_event.clone()),
+            hover_event: self
+                .hover_event
+                .clone()
+                .or_else(|| parent.hover_event.clone()),
+        }
+    }
+}
+
+impl TextColor {
+    /// Parses a color name, like `red`, or a hex color, like `#ff8800`.
+    pub fn parse(color: &str) -> Option<Self> {
+        match color.strip_prefix('#') {
+            Some(hex) if hex.len() == 6 => u32::from_str_radix(hex, 16).ok().map(TextColor::Rgb),
+            Some(_) => None,
+            None => NamedColor::from_name(color).map(TextColor::Named),
+        }
+    }
+
+    /// The RGB value of the color, as `0xRRGGBB`.
+    pub fn rgb(&self) -> u32 {
+        match self {
+            TextColor::Named(color) => color.rgb(),
+            TextColor::Rgb(rgb) => *rgb,
+        }
+    }
+}
+
+impl std::fmt::Display for TextColor {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            TextColor::Named(color) => f.write_str(color.name()),
+            TextColor::Rgb(rgb) => write!(f, "#{rgb:06x}"),
+        }
+    }
+}
+
+impl NamedColor {
+    /// Every named color, ordered by their formatting code.
+    pub const ALL: [NamedColor; 16] = [
+        NamedColor::Black,
+        NamedColor::DarkBlue,
+        NamedColor::DarkGreen,
+        NamedColor::DarkAqua,
+        NamedColor::DarkRed,
+        NamedColor::DarkPurple,
+        NamedColor::Gold,
+        NamedColor::Gray,
+        NamedColor::DarkGray,
+        NamedColor::Blue,
+        NamedColor::Green,
+        NamedColor::Aqua,
+        NamedColor::Red,
+        NamedColor::LightPurple,
+        NamedColor::Yellow,
+        NamedColor::White,
+    ];
+
+    /// The name used in JSON, like `dark_red`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            NamedColor::Black => "black",
+            NamedColor::DarkBlue => "dark_blue",
+            NamedColor::DarkGreen => "dark_green",
+            NamedColor::DarkAqua => "dark_aqua",
+            NamedColor::DarkRed => "dark_red",
+            NamedColor::DarkPurple => "dark_purple",
+            NamedColor::Gold => "gold",
+            NamedColor::Gray => "gray",
+            NamedColor::DarkGray => "dark_gray",
+            NamedColor::Blue => "blue",
+            NamedColor::Green => "green",
+            NamedColor::Aqua => "aqua",
+            NamedColor::Red => "red",
+            NamedColor::LightPurple => "light_purple",
+            NamedColor::Yellow => "yellow",
+            NamedColor::White => "white",
+        }
+    }
+
+    /// Gets the color from its JSON name.
+    pub fn from_name(name: &str) -> Option<Self> {
+        NamedColor::ALL
+            .into_iter()
+            .find(|color| color.name() == name)
+    }
+
+    /// The formatting code of the color, `0` to `f`.
+    pub fn code(&self) -> char {
+        let index = NamedColor::ALL.iter().position(|c| c == self).unwrap_or(0);
+        char::from_digit(index as u32, 16).unwrap_or('0')
+    }
+
+    /// The RGB value of the color, as `0xRRGGBB`.
+    pub fn rgb(&self) -> u32 {
+        match self {
+            NamedColor::Black => 0x000000,
+            NamedColor::DarkBlue => 0x0000AA,
+            NamedColor::DarkGreen => 0x00AA00,
+            NamedColor::DarkAqua => 0x00AAAA,
+            NamedColor::DarkRed => 0xAA0000,
+            NamedColor::DarkPurple => 0xAA00AA,
+            NamedColor::Gold => 0xFFAA00,
+            NamedColor::Gray => 0xAAAAAA,
+            NamedColor::DarkGray => 0x555555,
+            NamedColor::Blue => 0x5555FF,
+            NamedColor::Green => 0x55FF55,
+            NamedColor::Aqua => 0x55FFFF,
+            NamedColor::Red => 0xFF5555,
+            NamedColor::LightPurple => 0xFF55FF,
+            NamedColor::Yellow => 0xFFFF55,
+            NamedColor::White => 0xFFFFFF,
+        }
+    }
+}
+
+impl ClickEvent {
+    fn from_map(map: &Map<String, Value>) -> Result<Self, SculkParseError> {
+        let action = get_string(map, "action")?;
+
+        // The value is a number for `change_page`, and 1.21.5 renamed it per action.
+        let value = ["value", "url", "command", "page", "path"]
+            .into_iter()
+            .find_map(|key| match map.get(key)? {
+                Value::String(value) => Some(value.clone()),
+                value => Some(value.to_string()),
+            })
+            .ok_or(invalid("clickEvent needs a value"))?;
+
+        Ok(ClickEvent {
+            action: ClickAction::from_name(&action),
+            value,
+        })
+    }
+}
+
+impl ClickAction {
+    /// Gets the action from its JSON name.
+    pub fn from_name(name: &str) -> Self {
+        match name {
+            "open_url" => ClickAction::OpenUrl,
+            "open_file" => ClickAction::OpenFile,
+            "run_command" => ClickAction::RunCommand,
+            "suggest_command" => ClickAction::SuggestCommand,
+            "change_page" => ClickAction::ChangePage,
+            "copy_to_clipboard" => ClickAction::CopyToClipboard,
+            name => ClickAction::Unknown(name.to_string()),
+        }
+    }
+
+    /// The name used in JSON, like `run_command`.
+    pub fn name(&self) -> &str {
+        match self {
+            ClickAction::OpenUrl => "open_url",
+            ClickAction::OpenFile => "open_file",
+            ClickAction::RunCommand => "run_command",
+            ClickAction::SuggestCommand => "suggest_command",
+            ClickAction::ChangePage => "change_page",
+            ClickAction::CopyToClipboard => "copy_to_clipboard",
+            ClickAction::Unknown(name) => name,
+        }
+    }
+}
+
+impl HoverEvent {
+    fn from_map(map: &Map<String, Value>) -> Result<Self, SculkParseError> {
+        let action = get_string(map, "action")?;
+        // `value` is the pre 1.16 name of `contents`.
+        let contents = map
+            .get("contents")
+            .or(map.get("value"))
+            .ok_or(invalid("hoverEvent needs contents"))?;
+
+        Ok(match action.as_str() {
+            "show_text" => HoverEvent::ShowText(Box::new(TextComponent::from_value(contents)?)),
+            "show_item" => match contents {
+                Value::String(id) => HoverEvent::ShowItem {
+                    id: id.clone(),
+                    count: None,
+                    components: None,
+                },
+                Value::Object(item) => HoverEvent::ShowItem {
+                    id: get_string(item, "id")?,
+                    count: item
+                        .get("count")
+                        .and_then(Value::as_i64)
+                        .map(|count| count as i32),
+                    components: item.get("components").cloned(),
+                },
+                _ => return Err(invalid("show_item contents must be an object")),
+            },
+            "show_entity" => {
+                let entity = match contents {
+                    Value::Object(entity) => entity,
+                    _ => return Err(invalid("show_entity contents must be an object")),
+                };
+                let id = match entity.get("id") {
+                    Some(Value::String(id)) => Uuid::parse(id).ok(),
+                    Some(Value::Array(ints)) if ints.len() == 4 => {
+                        let mut uuid = [0; 4];
+                        for (part, int) in uuid.iter_mut().zip(ints) {
+                            *part = int.as_i64().unwrap_or(0) as i32;
+                        }
+                        Some(Uuid(uuid))
+                    }
+                    _ => None,
+                }
+                .ok_or(invalid("show_entity needs a valid id"))?;
+
+                HoverEvent::ShowEntity {
+                    entity_type: get_string(entity, "type")?,
+                    id,
+                    name: get_optional_component(entity, "name")?,
+                }
+            }
+            action => return Err(invalid(&format!("Unknown hover action: {action}"))),
+        })
+    }
+
+    fn to_value(&self) -> Value {
+        match self {
+            HoverEvent::ShowText(text) => {
+                json!({ "action": "show_text", "contents": text.to_value() })
+            }
+            HoverEvent::ShowItem {
+                id,
+                count,
+                components,
+            } => {
+                let mut item = Map::new();
+                item.insert("id".into(), id.as_str().into());
+                if let Some(count) = count {
+                    item.insert("count".into(), (*count).into());
+                }
+                if let Some(components) = components {
+                    item.insert("components".into(), components.clone());
+                }
+
+                json!({ "action": "show_item", "contents": item })
+            }
+            HoverEvent::ShowEntity {
+                entity_type,
+                id,
+                name,
+            } => {
+                let mut entity = Map::new();
+                entity.insert("type".into(), entity_type.as_str().into());
+                entity.insert("id".into(), id.to_string().into());
+                if let Some(name) = name {
+                    entity.insert("name".into(), name.to_value());
+                }
+
+                json!({ "action": "show_entity", "contents": entity })
+            }
+        }
+    }
+}
+
+fn invalid(message: &str) -> SculkParseError {
+    SculkParseError::InvalidTextComponent(message.to_string())
+}
+
+fn get_string(map: &Map<String, Value>, key: &str) -> Result<String, SculkParseError> {
+    get_optional_string(map, key)?.ok_or(invalid(&format!("Missing {key}")))
+}
+
+fn get_optional_string(
+    map: &Map<String, Value>,
+    key: &str,
+) -> Result<Option<String>, SculkParseError> {
+    match map.get(key) {
+        Some(Value::String(value)) => Ok(Some(value.clone())),
+        Some(_) => Err(invalid(&format!("{key} must be a string"))),
+        None => Ok(None),
+    }
+}
+
+fn get_optional_component(
+    map: &Map<String, Value>,
+    key: &str,
+) -> Result<Option<Box<TextComponent>>, SculkParseError> {
+    map.get(key)
+        .map(|value| TextComponent::from_value(value).map(Box::new))
+        .transpose()
+}
+
+#[cfg(test)]
+#[test]
+fn text_component_test() {
+    let json = r##"[
+        {"text": "Hi ", "color": "#ff8800", "bold": true},
+        {"translate": "chat.type.text", "with": [{"selector": "@p"}, 5], "italic": false},
+        {"keybind": "key.jump", "clickEvent": {"action": "run_command", "value": "/say hi"}},
+        {"score": {"name": "Steve", "objective": "kills"}},
+        {"nbt": "Items[0]", "block": "~ ~ ~", "interpret": true},
+        {"text": "", "hoverEvent": {"action": "show_entity", "contents": {"type": "minecraft:pig", "id": [1, 2, 3, 4], "name": "Pig"}}},
+        {"text": "", "hoverEvent": {"action": "show_item", "contents": {"id": "minecraft:stone", "count": 2}}}
+    ]"##;
+    let text = TextComponent::from_json(json).unwrap();
+
+    assert_eq!(text.content, TextContent::Text("Hi ".into()));
+    assert_eq!(text.style.color, Some(TextColor::Rgb(0xff8800)));
+    assert_eq!(text.extra.len(), 6);
+    assert_eq!(
+        text.extra[0].content,
+        TextContent::Translatable {
+            translate: "chat.type.text".into(),
+            fallback: None,
+            with: vec![
+                TextContent::Selector {
+                    selector: "@p".into(),
+                    separator: None
+                }
+                .into(),
+                TextComponent::text("5"),
+            ],
+        }
+    );
+    assert_eq!(
+        text.extra[1].style.click_event.as_ref().unwrap().action,
+        ClickAction::RunCommand
+    );
+    assert!(matches!(
+        &text.extra[4].style.hover_event,
+        Some(HoverEvent::ShowEntity {
+            id: Uuid([1, 2, 3, 4]),
+            ..
+        })
+    ));
+
+    // Every component, including translation arguments and hover names.
+    assert_eq!(text.iter().count(), 10);
+
+    let written = text.to_json();
+    assert_eq!(TextComponent::from_json(&written).unwrap(), text);
+
+    assert_eq!(TextComponent::text("plain").to_json(), "\"plain\"");
+    assert_eq!(
+        TextComponent::parse("not json {"),
+        TextComponent::text("not json {")
+    );
+    assert!(TextComponent::from_json("{}").is_err());
+    assert!(TextComponent::from_json(r#"{"text": "a", "color": "rainbow"}"#).is_err());
+}
@@ -0,0 +1,444 @@
+//! Rendering text components as plain text, ANSI colored terminal output, HTML or legacy formatting codes.
+
+use super::{ClickAction, HoverEvent, NamedColor, Style, TextColor, TextComponent, TextContent};
+use crate::error::SculkParseError;
+use std::collections::HashMap;
+
+/// The formatting code prefix used by legacy text.
+pub const FORMATTING_PREFIX: char = '§';
+
+/// A language file, like `en_us.json`, used to resolve translatable components.
+#[derive(Debug, Clone, PartialEq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Language(pub HashMap<String, String>);
+
+impl Language {
+    /// Parses a language file, a JSON object of translation keys to translations.
+    pub fn from_json(json: &str) -> Result<Self, SculkParseError> {
+        serde_json::from_str(json)
+            .map(Language)
+            .map_err(|e| SculkParseError::InvalidTextComponent(e.to_string()))
+    }
+
+    /// Reads and parses a language file.
+    pub fn from_path<P: AsRef<std::path::Path>>(path: P) -> Result<Self, SculkParseError> {
+        Language::from_json(&std::fs::read_to_string(path)?)
+    }
+
+    /// Gets the translation of a key.
+    pub fn get(&self, key: &str) -> Option<&str> {
+        self.0.get(key).map(String::as_str)
+    }
+}
+
+/// A piece of text with its fully resolved style.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Span {
+    /// The text of the span.
+    pub text: String,
+
+    /// The style of the span, with every inherited value filled in.
+    pub style: Style,
+}
+
+impl TextComponent {
+    /// Flattens the component into spans of text, each with their resolved style.
+    /// Translatable components and keybinds are looked up in `lang`, falling back to their key.
+    /// Scores and Nbt can't be resolved without a running game, so they are left empty.
+    pub fn spans(&self, lang: Option<&Language>) -> Vec<Span> {
+        let mut spans = vec![];
+        self.collect_spans(&Style::default(), lang, &mut spans);
+
+        spans
+    }
+
+    fn collect_spans(&self, parent: &Style, lang: Option<&Language>, spans: &mut Vec<Span>) {
+        let style = self.style.inherit(parent);
+        let push = |text: &str, spans: &mut Vec<Span>| {
+            if !text.is_empty() {
+                spans.push(Span {
+                    text: text.to_string(),
+                    style: style.clone(),
+                });
+            }
+        };
+
+        match &self.content {
+            TextContent::Text(text) => push(text, spans),
+            TextContent::Translatable {
+                translate,
+                fallback,
+                with,
+            } => {
+                let format = lang
+                    .and_then(|lang| lang.get(translate))
+                    .or(fallback.as_deref())
+                    .unwrap_or(translate);
+
+                for part in parse_format(format) {
+                    match part {
+                        FormatPart::Literal(text) => push(&text, spans),
+                        FormatPart::Argument(index) => {
+                            if let Some(argument) = with.get(index) {
+                                argument.collect_spans(&style, lang, spans);
+                            }
+                        }
+                    }
+                }
+            }
+            TextContent::Selector { selector, .. } => push(selector, spans),
+            TextContent::Keybind(key) => {
+                push(lang.and_then(|lang| lang.get(key)).unwrap_or(key), spans)
+            }
+            TextContent::Score { .. } | TextContent::Nbt { .. } => {}
+        }
+
+        for child in &self.extra {
+            child.collect_spans(&style, lang, spans);
+        }
+    }
+
+    /// Renders the component as plain text, without any formatting.
+    pub fn to_plain(&self, lang: Option<&Language>) -> String {
+        self.spans(lang).into_iter().map(|span| span.text).collect()
+    }
+
+    /// Renders the component with ANSI escape codes, for terminal output.
+    /// Colors are written as 24 bit colors, so they match the vanilla colors exactly.
+    pub fn to_ansi(&self, lang: Option<&Language>) -> String {
+        let mut ansi = String::new();
+
+        for span in self.spans(lang) {
+            let style = &span.style;
+            let mut codes = vec!["0".to_string()];
+            if style.bold == Some(true) {
+                codes.push("1".into());
+            }
+            if style.italic == Some(true) {
+                codes.push("3".into());
+            }
+            if style.underlined == Some(true) {
+                codes.push("4".into());
+            }
+            if style.strikethrough == Some(true) {
+                codes.push("9".into());
+            }
+            if let Some(color) = style.color {
+                let rgb = color.rgb();
+                codes.push(format!(
+                    "38;2;{};{};{}",
+                    rgb >> 16,
+                    (rgb >> 8) & 0xFF,
+                    rgb & 0xFF
+                ));
+            }
+
+            ansi.push_str(&format!("\x1b[{}m{}", codes.join(";"), span.text));
+        }
+
+        if !ansi.is_empty() {
+            ansi.push_str("\x1b[0m");
+        }
+
+        ansi
+    }
+
+    /// Renders the component as HTML, with a `<span>` and inline style for every piece of text.
+    /// Line breaks become `<br>`, `open_url` click events become links and `show_text` hover events become titles.
+    /// Obfuscated text gets the `obfuscated` class, so it can be styled or animated by the page.
+    pub fn to_html(&self, lang: Option<&Language>) -> String {
+        let mut html = String::new();
+
+        for span in self.spans(lang) {
+            let style = &span.style;
+            let mut css = vec![];
+            if let Some(color) = style.color {
+                css.push(format!("color: #{:06x}", color.rgb()));
+            }
+            if style.bold == Some(true) {
+                css.push("font-weight: bold".into());
+            }
+            if style.italic == Some(true) {
+                css.push("font-style: italic".into());
+            }
+            let decorations: Vec<&str> = [
+                (style.underlined, "underline"),
+                (style.strikethrough, "line-through"),
+            ]
+            .into_iter()
+            .filter(|(enabled, _)| *enabled == Some(true))
+            .map(|(_, decoration)| decoration)
+            .collect();
+            if !decorations.is_empty() {
+                css.push(format!("text-decoration: {}", decorations.join(" ")));
+            }
+
+            let mut attributes = String::new();
+            if !css.is_empty() {
+                attributes.push_str(&format!(" style=\"{}\"", css.join("; ")));
+            }
+            if style.obfuscated == Some(true) {
+                attributes.push_str(" class=\"obfuscated\"");
+            }
+            if let Some(HoverEvent::ShowText(hover)) = &style.hover_event {
+                attributes.push_str(&format!(
+                    " title=\"{}\"",
+                    escape_html(&hover.to_plain(lang))
+                ));
+            }
+
+            let text = escape_html(&span.text).replace('\n', "<br>");
+            let text = if attributes.is_empty() {
+                text
+            } else {
+                format!("<span{attributes}>{text}</span>")
+            };
+
+            match &style.click_event {
+                Some(click)
+                    if click.action == ClickAction::OpenUrl
+                        && (click.value.starts_with("http://")
+                            || click.value.starts_with("https://")) =>
+                {
+                    html.push_str(&format!(
+                        "<a href=\"{}\">{text}</a>",
+                        escape_html(&click.value)
+                    ))
+                }
+                _ => html.push_str(&text),
+            }
+        }
+
+        html
+    }
+
+    /// Renders the component with legacy `§` formatting codes, as used by pre 1.13 signs and books.
+    /// RGB colors don't have a formatting code, so the closest named color is used instead.
+    pub fn to_legacy(&self, lang: Option<&Language>) -> String {
+        let mut legacy = String::new();
+
+        for span in self.spans(lang) {
+            let style = &span.style;
+            // A color code resets the formatting, so it always comes first.
+            let color = match style.color {
+                Some(TextColor::Named(color)) => Some(color),
+                Some(TextColor::Rgb(rgb)) => Some(NamedColor::closest(rgb)),
+                None => None,
+            };
+            match color {
+                Some(color) => legacy.extend([FORMATTING_PREFIX, color.code()]),
+                None if !legacy.is_empty() => legacy.extend([FORMATTING_PREFIX, 'r']),
+                None => {}
+            }
+
+            for (enabled, code) in [
+                (style.obfuscated, 'k'),
+                (style.bold, 'l'),
+                (style.strikethrough, 'm'),
+                (style.underlined, 'n'),
+                (style.italic, 'o'),
+            ] {
+                if enabled == Some(true) {
+                    legacy.extend([FORMATTING_PREFIX, code]);
+                }
+            }
+
+            legacy.push_str(&span.text);
+        }
+
+        legacy
+    }
+
+    /// Parses text with legacy `§` formatting codes into a component, with every formatted run as a child.
+    /// Unknown codes are kept as text.
+    pub fn from_legacy(text: &str) -> Self {
+        let mut root = TextComponent::text("");
+        let mut style = Style::default();
+        let mut current = String::new();
+        let mut chars = text.chars().peekable();
+
+        while let Some(c) = chars.next() {
+            let code = match chars.peek() {
+                Some(code) if c == FORMATTING_PREFIX => code.to_ascii_lowercase(),
+                _ => {
+                    current.push(c);
+                    continue;
+                }
+            };
+
+            let mut next = style.clone();
+            match code {
+                'k' => next.obfuscated = Some(true),
+                'l' => next.bold = Some(true),
+                'm' => next.strikethrough = Some(true),
+                'n' => next.underlined = Some(true),
+                'o' => next.italic = Some(true),
+                'r' => next = Style::default(),
+                code => match code.to_digit(16) {
+                    // Colors reset the formatting as well.
+                    Some(index) => {
+                        next = Style {
+                            color: Some(TextColor::Named(NamedColor::ALL[index as usize])),
+                            ..Default::default()
+                        }
+                    }
+                    None => {
+                        current.push(c);
+                        continue;
+                    }
+                },
+            }
+            chars.next();
+
+            if !current.is_empty() {
+                root.extra
+                    .push(TextComponent::text(std::mem::take(&mut current)).with_style(style));
+            }
+            style = next;
+        }
+
+        if !current.is_empty() {
+            root.extra
+                .push(TextComponent::text(current).with_style(style));
+        }
+
+        // Unformatted text doesn't need the children.
+        if let [child] = root.extra.as_slice() {
+            if child.style == Style::default() {
+                return child.clone();
+            }
+        }
+
+        root
+    }
+}
+
+impl NamedColor {
+    /// The named color closest to an RGB color.
+    pub fn closest(rgb: u32) -> NamedColor {
+        let channels = |rgb: u32| {
+            [
+                (rgb >> 16) as i32 & 0xFF,
+                (rgb >> 8) as i32 & 0xFF,
+                rgb as i32 & 0xFF,
+            ]
+        };
+        let target = channels(rgb);
+
+        NamedColor::ALL
+            .into_iter()
+            .min_by_key(|color| {
+                channels(color.rgb())
+                    .iter()
+                    .zip(target)
+                    .map(|(a, b)| (a - b).pow(2))
+                    .sum::<i32>()
+            })
+            .unwrap_or(NamedColor::White)
+    }
+}
+
+enum FormatPart {
+    Literal(String),
+    Argument(usize),
+}
+
+/// Splits a translation into text and arguments, supporting `%s`, positional `%1$s` and `%%`.
+fn parse_format(format: &str) -> Vec<FormatPart> {
+    let mut parts = vec![];
+    let mut literal = String::new();
+    let mut next_argument = 0;
+    let mut chars = format.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        if c != '%' {
+            literal.push(c);
+            continue;
+        }
+
+        match chars.peek() {
+            Some('%') => {
+                chars.next();
+                literal.push('%');
+            }
+            Some('s') | Some('d') => {
+                chars.next();
+                parts.push(FormatPart::Literal(std::mem::take(&mut literal)));
+                parts.push(FormatPart::Argument(next_argument));
+                next_argument += 1;
+            }
+            Some(digit) if digit.is_ascii_digit() => {
+                let mut lookahead = chars.clone();
+                let mut index = String::new();
+                while let Some(digit) = lookahead.next_if(char::is_ascii_digit) {
+                    index.push(digit);
+                }
+
+                match (lookahead.next(), lookahead.next(), index.parse::<usize>()) {
+                    (Some('$'), Some('s' | 'd'), Ok(index)) if index > 0 => {
+                        chars = lookahead;
+                        parts.push(FormatPart::Literal(std::mem::take(&mut literal)));
+                        parts.push(FormatPart::Argument(index - 1));
+                    }
+                    _ => literal.push(c),
+                }
+            }
+            _ => literal.push(c),
+        }
+    }
+
+    parts.push(FormatPart::Literal(literal));
+    parts
+}
+
+fn escape_html(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            c => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+#[cfg(test)]
+#[test]
+fn render_test() {
+    let lang = Language::from_json(
+        r#"{"chat.type.text": "<%s> %s", "death.attack.player": "%2$s killed %1$s (100%%)", "key.jump": "Jump"}"#,
+    )
+    .unwrap();
+
+    let text = TextComponent::from_json(
+        r##"{"translate": "chat.type.text", "color": "red", "with": [{"text": "Steve", "bold": true}, "hi <3"], "extra": [{"keybind": "key.jump", "color": "#50ff50"}]}"##,
+    )
+    .unwrap();
+    assert_eq!(text.to_plain(Some(&lang)), "<Steve> hi <3Jump");
+    assert_eq!(text.to_plain(None), "chat.type.textkey.jump");
+    assert_eq!(text.to_legacy(Some(&lang)), "§c<§c§lSteve§c> §chi <3§aJump");
+    assert_eq!(
+        text.to_ansi(Some(&lang)),
+        "\x1b[0;38;2;255;85;85m<\x1b[0;1;38;2;255;85;85mSteve\x1b[0;38;2;255;85;85m> \x1b[0;38;2;255;85;85mhi <3\x1b[0;38;2;80;255;80mJump\x1b[0m"
+    );
+    assert!(text
+        .to_html(Some(&lang))
+        .starts_with("<span style=\"color: #ff5555\">&lt;</span><span style=\"color: #ff5555; font-weight: bold\">Steve</span>"));
+
+    let death =
+        TextComponent::translatable("death.attack.player", vec!["Alex".into(), "Steve".into()]);
+    assert_eq!(death.to_plain(Some(&lang)), "Steve killed Alex (100%)");
+
+    let legacy = TextComponent::from_legacy("§6Gold §lbold§r plain");
+    assert_eq!(legacy.extra.len(), 3);
+    assert_eq!(legacy.to_legacy(None), "§6Gold §6§lbold§r plain");
+    assert_eq!(
+        TextComponent::from_legacy("plain"),
+        TextComponent::text("plain")
+    );
+}
@@ -22,3 +22,33 @@ pub trait FromCompoundNbt<Output = Self> {
         unimplemented!()
     }
 }
+
+/// Used on the borrowed structs in [`crate::borrowed`], which keep slices into the Nbt input buffer.
+/// The borrowed counterpart of [`FromCompoundNbt`].
+pub trait FromBorrowedCompoundNbt<'a> {
+    fn from_borrowed_compound_nbt(nbt: &NbtCompound<'a, 'a>) -> Result<Self, SculkParseError>
+    where
+        Self: Sized;
+}
+
+/// Used on any struct that can be serialized back into an Nbt compound.  
+/// The inverse of [`FromCompoundNbt`].
+pub trait ToCompoundNbt {
+    /// Converts the struct into an owned Nbt compound.
+    fn to_compound_nbt(&self) -> simdnbt::owned::NbtCompound;
+
+    /// Serializes the struct into uncompressed Nbt bytes.
+    fn to_bytes(&self) -> Vec<u8> {
+        crate::util::write_nbt(&self.to_compound_nbt())
+    }
+
+    /// Formats the struct as compact SNBT, like `/data get` does.
+    fn to_snbt(&self) -> String {
+        crate::snbt::to_snbt(&self.to_compound_nbt())
+    }
+
+    /// Formats the struct as indented SNBT.
+    fn to_snbt_pretty(&self) -> String {
+        crate::snbt::to_snbt_pretty(&self.to_compound_nbt())
+    }
+}
@@ -1,10 +1,17 @@
-use crate::{components::Components, error::SculkParseError, traits::FromCompoundNbt};
-use simdnbt::borrow::{NbtCompound, NbtList};
+use crate::{
+    components::Components,
+    error::SculkParseError,
+    traits::{FromCompoundNbt, ToCompoundNbt},
+    version::{self, DataVersion},
+};
+use simdnbt::{
+    borrow::{NbtCompound, NbtList},
+    owned, ToNbtTag,
+};
 
-/// The version of Minecraft that this library is designed to work with.  
-/// Formatted exactly as minecraft versions are.
-#[allow(dead_code)]
-pub const MC_VERSION: &str = "1.21";
+/// The newest version of Minecraft that this library is designed to work with.  
+/// Formatted exactly as minecraft versions are, see [`DataVersion::LATEST_SUPPORTED`](crate::version::DataVersion::LATEST_SUPPORTED) for its data version.
+pub const MC_VERSION: &str = "1.21.4";
 
 pub struct LootTableData {
     pub loot_table: Option<String>,
@@ -145,6 +152,233 @@ pub fn get_optional_components(nbt: &NbtCompound) -> Result<Option<Components>,
     }
 }
 
+/// The count of an item, `count` since 1.20.5 and the byte `Count` before, which some tools wrote as an int.
+pub fn get_item_count(nbt: &NbtCompound) -> i32 {
+    nbt.int("count")
+        .or_else(|| nbt.byte("Count").map(i32::from))
+        .or_else(|| nbt.int("Count"))
+        .unwrap_or(1)
+}
+
+/// Same as [`get_optional_components`] but for items, which store their data in the `tag` compound before 1.20.5.  
+/// That compound is converted into components with [`Components::from_legacy_tag`].
+pub fn get_optional_item_components(
+    nbt: &NbtCompound,
+) -> Result<Option<Components>, SculkParseError> {
+    let legacy = match version::current() {
+        Some(version) => version < DataVersion::ITEM_COMPONENTS,
+        None => !nbt.contains("components"),
+    };
+    if legacy {
+        if let Some(tag) = nbt.compound("tag") {
+            let id = nbt.string("id").map(|id| id.to_str()).unwrap_or_default();
+            return Components::from_legacy_tag(&id, &tag).map(Some);
+        }
+    }
+
+    get_optional_components(nbt)
+}
+
+pub fn insert_optional<T: ToNbtTag>(
+    nbt: &mut owned::NbtCompound,
+    key: &'static str,
+    value: Option<T>,
+) {
+    if let Some(value) = value {
+        nbt.insert(key, value);
+    }
+}
+
+pub fn insert_loot_table_data(
+    nbt: &mut owned::NbtCompound,
+    loot_table: &Option<String>,
+    loot_table_seed: Option<i64>,
+) {
+    insert_optional(nbt, "LootTable", loot_table.clone());
+    insert_optional(nbt, "LootTableSeed", loot_table_seed);
+}
+
+pub fn insert_optional_components(nbt: &mut owned::NbtCompound, components: &Option<Components>) {
+    if let Some(components) = components {
+        nbt.insert("components", components.to_compound_nbt());
+    }
+}
+
+/// Vanilla writes empty lists with the end tag as its type.
+pub fn compound_list<T: ToCompoundNbt>(values: &[T]) -> owned::NbtList {
+    if values.is_empty() {
+        return owned::NbtList::Empty;
+    }
+
+    owned::NbtList::Compound(values.iter().map(|v| v.to_compound_nbt()).collect())
+}
+
+pub fn int_list(values: &[i32]) -> owned::NbtList {
+    if values.is_empty() {
+        return owned::NbtList::Empty;
+    }
+
+    owned::NbtList::Int(values.to_vec())
+}
+
+pub fn float_list(values: &[f32]) -> owned::NbtList {
+    if values.is_empty() {
+        return owned::NbtList::Empty;
+    }
+
+    owned::NbtList::Float(values.to_vec())
+}
+
+pub fn string_list(values: &[String]) -> owned::NbtList {
+    if values.is_empty() {
+        return owned::NbtList::Empty;
+    }
+
+    owned::NbtList::String(values.iter().map(|v| v.as_str().into()).collect())
+}
+
+/// Copies every tag whose key isn't in `known`, so tags sculk doesn't parse can be written back as they were.
+pub fn get_unknown_tags(nbt: &NbtCompound, known: &[&str]) -> owned::NbtCompound {
+    let mut unknown = owned::NbtCompound::new();
+    for (key, value) in nbt.iter() {
+        let key = key.to_str();
+        if !known.contains(&key.as_ref()) {
+            unknown.insert(key.to_string(), value.to_owned());
+        }
+    }
+
+    unknown
+}
+
+/// Writes the tags kept by [`get_unknown_tags`] back, without replacing the ones that were already written.
+pub fn insert_unknown_tags(nbt: &mut owned::NbtCompound, unknown: &owned::NbtCompound) {
+    for (key, value) in unknown.iter() {
+        if !nbt.contains(&key.to_str()) {
+            nbt.insert(key.to_owned(), value.clone());
+        }
+    }
+}
+
+/// Writes an owned compound as an uncompressed, unnamed root Nbt tag.  
+/// `simdnbt`'s own writer encodes array lengths as a `usize`, which corrupts any tag following an array.
+pub fn write_nbt(nbt: &owned::NbtCompound) -> Vec<u8> {
+    let mut data = Vec::new();
+
+    data.push(owned::NbtTag::Compound(owned::NbtCompound::new()).id());
+    data.extend_from_slice(&0u16.to_be_bytes());
+    write_nbt_compound(&mut data, nbt);
+
+    data
+}
+
+fn write_nbt_str(data: &mut Vec<u8>, value: &simdnbt::Mutf8Str) {
+    data.extend_from_slice(&(value.len() as u16).to_be_bytes());
+    data.extend_from_slice(value.as_bytes());
+}
+
+fn write_nbt_len(data: &mut Vec<u8>, len: usize) {
+    data.extend_from_slice(&(len as i32).to_be_bytes());
+}
+
+fn write_nbt_compound(data: &mut Vec<u8>, nbt: &owned::NbtCompound) {
+    for (key, value) in nbt.iter() {
+        data.push(value.id());
+        write_nbt_str(data, key);
+        write_nbt_tag(data, value);
+    }
+
+    data.push(0);
+}
+
+fn write_nbt_tag(data: &mut Vec<u8>, tag: &owned::NbtTag) {
+    match tag {
+        owned::NbtTag::Byte(v) => data.push(*v as u8),
+        owned::NbtTag::Short(v) => data.extend_from_slice(&v.to_be_bytes()),
+        owned::NbtTag::Int(v) => data.extend_from_slice(&v.to_be_bytes()),
+        owned::NbtTag::Long(v) => data.extend_from_slice(&v.to_be_bytes()),
+        owned::NbtTag::Float(v) => data.extend_from_slice(&v.to_be_bytes()),
+        owned::NbtTag::Double(v) => data.extend_from_slice(&v.to_be_bytes()),
+        owned::NbtTag::ByteArray(v) => write_nbt_byte_array(data, v),
+        owned::NbtTag::String(v) => write_nbt_str(data, v.as_str()),
+        owned::NbtTag::List(v) => write_nbt_list(data, v),
+        owned::NbtTag::Compound(v) => write_nbt_compound(data, v),
+        owned::NbtTag::IntArray(v) => write_nbt_int_array(data, v),
+        owned::NbtTag::LongArray(v) => write_nbt_long_array(data, v),
+    }
+}
+
+fn write_nbt_byte_array(data: &mut Vec<u8>, values: &[u8]) {
+    write_nbt_len(data, values.len());
+    data.extend_from_slice(values);
+}
+
+fn write_nbt_int_array(data: &mut Vec<u8>, values: &[i32]) {
+    write_nbt_len(data, values.len());
+    values
+        .iter()
+        .for_each(|v| data.extend_from_slice(&v.to_be_bytes()));
+}
+
+fn write_nbt_long_array(data: &mut Vec<u8>, values: &[i64]) {
+    write_nbt_len(data, values.len());
+    values
+        .iter()
+        .for_each(|v| data.extend_from_slice(&v.to_be_bytes()));
+}
+
+fn write_nbt_list(data: &mut Vec<u8>, list: &owned::NbtList) {
+    data.push(list.id());
+
+    match list {
+        owned::NbtList::Empty => write_nbt_len(data, 0),
+        owned::NbtList::Byte(v) => {
+            write_nbt_len(data, v.len());
+            v.iter().for_each(|v| data.push(*v as u8));
+        }
+        owned::NbtList::Short(v) => {
+            write_nbt_len(data, v.len());
+            v.iter()
+                .for_each(|v| data.extend_from_slice(&v.to_be_bytes()));
+        }
+        owned::NbtList::Int(v) => write_nbt_int_array(data, v),
+        owned::NbtList::Long(v) => write_nbt_long_array(data, v),
+        owned::NbtList::Float(v) => {
+            write_nbt_len(data, v.len());
+            v.iter()
+                .for_each(|v| data.extend_from_slice(&v.to_be_bytes()));
+        }
+        owned::NbtList::Double(v) => {
+            write_nbt_len(data, v.len());
+            v.iter()
+                .for_each(|v| data.extend_from_slice(&v.to_be_bytes()));
+        }
+        owned::NbtList::ByteArray(v) => {
+            write_nbt_len(data, v.len());
+            v.iter().for_each(|v| write_nbt_byte_array(data, v));
+        }
+        owned::NbtList::String(v) => {
+            write_nbt_len(data, v.len());
+            v.iter().for_each(|v| write_nbt_str(data, v.as_str()));
+        }
+        owned::NbtList::List(v) => {
+            write_nbt_len(data, v.len());
+            v.iter().for_each(|v| write_nbt_list(data, v));
+        }
+        owned::NbtList::Compound(v) => {
+            write_nbt_len(data, v.len());
+            v.iter().for_each(|v| write_nbt_compound(data, v));
+        }
+        owned::NbtList::IntArray(v) => {
+            write_nbt_len(data, v.len());
+            v.iter().for_each(|v| write_nbt_int_array(data, v));
+        }
+        owned::NbtList::LongArray(v) => {
+            write_nbt_len(data, v.len());
+            v.iter().for_each(|v| write_nbt_long_array(data, v));
+        }
+    }
+}
+
 #[allow(dead_code)]
 pub fn dump_nbt(nbt: &NbtCompound) {
     for (key, value) in nbt.iter() {
@@ -153,3 +387,74 @@ pub fn dump_nbt(nbt: &NbtCompound) {
 
     println!();
 }
+
+/// Reads a file from `test_data`, decompressing it when it's gzipped.
+#[cfg(test)]
+pub(crate) fn read_test_data(path: &str) -> Vec<u8> {
+    use std::io::Read;
+
+    let contents = std::fs::read(path).unwrap();
+    let mut input = Vec::new();
+    match flate2::read::GzDecoder::new(&contents[..]).read_to_end(&mut input) {
+        Ok(_) => input,
+        Err(_) => contents,
+    }
+}
+
+/// Parses uncompressed Nbt bytes, writes the result back and asserts that no tag was lost, added or changed.
+#[cfg(test)]
+pub(crate) fn assert_round_trip<T: FromCompoundNbt + ToCompoundNbt>(bytes: &[u8]) -> T {
+    use std::io::Cursor;
+
+    let nbt = simdnbt::borrow::read(&mut Cursor::new(bytes))
+        .unwrap()
+        .unwrap();
+    let value = T::from_compound_nbt(&nbt.as_compound()).unwrap();
+
+    let original = owned::read(&mut Cursor::new(bytes)).unwrap().unwrap();
+    let written = owned::read(&mut Cursor::new(&value.to_bytes()[..]))
+        .unwrap()
+        .unwrap();
+    assert_same_nbt(&original, &written);
+
+    value
+}
+
+/// Compares two compounds without caring about the order of their keys, panicking with the path of the first difference.
+#[cfg(test)]
+pub(crate) fn assert_same_nbt(expected: &owned::NbtCompound, actual: &owned::NbtCompound) {
+    assert_same_compound("", expected, actual);
+}
+
+#[cfg(test)]
+fn assert_same_compound(path: &str, expected: &owned::NbtCompound, actual: &owned::NbtCompound) {
+    for (key, value) in expected.iter() {
+        let path = format!("{path}.{key}");
+        match actual.get(&key.to_str()) {
+            Some(other) => assert_same_tag(&path, value, other),
+            None => panic!("{path} was lost"),
+        }
+    }
+    for (key, _) in actual.iter() {
+        assert!(expected.contains(&key.to_str()), "{path}.{key} was added");
+    }
+}
+
+#[cfg(test)]
+fn assert_same_tag(path: &str, expected: &owned::NbtTag, actual: &owned::NbtTag) {
+    match (expected, actual) {
+        (owned::NbtTag::Compound(expected), owned::NbtTag::Compound(actual)) => {
+            assert_same_compound(path, expected, actual)
+        }
+        (
+            owned::NbtTag::List(owned::NbtList::Compound(expected)),
+            owned::NbtTag::List(owned::NbtList::Compound(actual)),
+        ) => {
+            assert_eq!(expected.len(), actual.len(), "{path} changed length");
+            for (i, (expected, actual)) in expected.iter().zip(actual).enumerate() {
+                assert_same_compound(&format!("{path}[{i}]"), expected, actual);
+            }
+        }
+        _ => assert_eq!(expected, actual, "{path} changed"),
+    }
+}
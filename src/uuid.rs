@@ -1,38 +1,164 @@
 use crate::error::SculkParseError;
-use simdnbt::borrow::NbtCompound;
+use simdnbt::{
+    borrow::NbtCompound,
+    owned::{NbtList, NbtTag},
+    ToNbtTag,
+};
 use std::ops::Deref;
 
 /// A UUID, internally represented as an array of 4 integers.
-#[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
-#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+/// With the `serde` feature it is (de)serialized as a hyphenated string, like `069a79f4-44e9-4726-a5be-fca90e38aaf5`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default)]
 pub struct Uuid(pub [i32; 4]);
 
 impl Uuid {
     /// Creates a new `Uuid` from an i128.
-    #[allow(unused_variables)]
     pub fn from_i128(i: i128) -> Self {
-        unimplemented!()
+        Uuid::from_u128(i as u128)
     }
 
     /// Converts the `Uuid` to an i128.
     pub fn to_i128(&self) -> i128 {
-        unimplemented!()
+        self.to_u128() as i128
     }
 
-    /// Creates a hex string representation of the `Uuid`.
+    /// Creates a new `Uuid` from an u128.
+    pub fn from_u128(u: u128) -> Self {
+        Self([
+            (u >> 96) as i32,
+            (u >> 64) as i32,
+            (u >> 32) as i32,
+            u as i32,
+        ])
+    }
+
+    /// Converts the `Uuid` to an u128.
+    pub fn to_u128(&self) -> u128 {
+        self.0
+            .iter()
+            .fold(0, |acc, part| (acc << 32) | *part as u32 as u128)
+    }
+
+    /// Creates a new `Uuid` from its most and least significant bits, as stored in older Nbt like `DragonUUIDMost` and `DragonUUIDLeast`.
+    pub fn from_most_least(most: i64, least: i64) -> Self {
+        Uuid::from_u128(((most as u64 as u128) << 64) | least as u64 as u128)
+    }
+
+    /// Splits the `Uuid` into its most and least significant bits.
+    pub fn to_most_least(&self) -> (i64, i64) {
+        let u = self.to_u128();
+        ((u >> 64) as i64, u as i64)
+    }
+
+    /// Creates a `Uuid` from an int array, `None` if it doesn't have exactly 4 integers.
+    pub fn from_slice(ints: &[i32]) -> Option<Self> {
+        ints.try_into().ok().map(Self)
+    }
+
+    /// Reads the int array at `key`, `None` if it's missing.
+    /// Errors with [`SculkParseError::InvalidField`] if it doesn't have exactly 4 integers.
+    pub(crate) fn from_int_array(
+        nbt: &NbtCompound,
+        key: &str,
+    ) -> Result<Option<Self>, SculkParseError> {
+        nbt.int_array(key)
+            .map(|ints| Uuid::try_from(ints.as_slice()))
+            .transpose()
+    }
+
+    /// Creates a hex string representation of the `Uuid`, without hyphens.
     pub fn to_hex_string(&self) -> String {
-        unimplemented!()
+        format!("{:032x}", self.to_u128())
+    }
+
+    /// Creates the hyphenated string representation of the `Uuid`, `xxxxxxxx-xxxx-xxxx-xxxx-xxxxxxxxxxxx`.  
+    /// This is also what player data files are named after.
+    pub fn to_hyphenated_string(&self) -> String {
+        let hex = self.to_hex_string();
+
+        format!(
+            "{}-{}-{}-{}-{}",
+            &hex[0..8],
+            &hex[8..12],
+            &hex[12..16],
+            &hex[16..20],
+            &hex[20..32]
+        )
+    }
+
+    /// Parses a `Uuid` from its hyphenated or plain hex string representation.
+    pub fn parse(s: &str) -> Result<Self, SculkParseError> {
+        let invalid = || SculkParseError::InvalidUuid(s.into());
+
+        let hex = match s.len() {
+            32 => s.to_string(),
+            36 if [8, 13, 18, 23].iter().all(|i| s.as_bytes()[*i] == b'-') => s.replace('-', ""),
+            _ => return Err(invalid()),
+        };
+        if hex.len() != 32 || !hex.bytes().all(|b| b.is_ascii_hexdigit()) {
+            return Err(invalid());
+        }
+
+        u128::from_str_radix(&hex, 16)
+            .map(Uuid::from_u128)
+            .map_err(|_| invalid())
+    }
+
+    /// The `Uuid` an offline mode server gives a player, a version 3 uuid of the MD5 hash of `OfflinePlayer:<name>`.
+    pub fn offline_player(name: &str) -> Self {
+        let mut bytes = md5::compute(format!("OfflinePlayer:{name}")).0;
+        bytes[6] = (bytes[6] & 0x0f) | 0x30;
+        bytes[8] = (bytes[8] & 0x3f) | 0x80;
+
+        Uuid::from_u128(u128::from_be_bytes(bytes))
+    }
+
+    /// The version of the `Uuid`, 4 for random uuids of online players and 3 for offline players.
+    pub fn version(&self) -> u8 {
+        ((self.0[1] >> 12) & 0xf) as u8
     }
 }
 
+impl std::fmt::Display for Uuid {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.to_hyphenated_string())
+    }
+}
+
+impl std::str::FromStr for Uuid {
+    type Err = SculkParseError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Uuid::parse(s)
+    }
+}
+
+/// Missing integers are filled with 0 and extra integers are ignored, use [`Uuid::try_from`] to reject them instead.
 impl From<Vec<i32>> for Uuid {
     fn from(v: Vec<i32>) -> Self {
         let mut arr = [0; 4];
-        arr.copy_from_slice(&v);
+        for (part, int) in arr.iter_mut().zip(v) {
+            *part = int;
+        }
         Self(arr)
     }
 }
 
+/// Errors with [`SculkParseError::InvalidField`] if the array doesn't have exactly 4 integers.
+impl TryFrom<&[i32]> for Uuid {
+    type Error = SculkParseError;
+
+    fn try_from(ints: &[i32]) -> Result<Self, Self::Error> {
+        Uuid::from_slice(ints).ok_or(SculkParseError::InvalidField("UUID".into()))
+    }
+}
+
+impl From<i128> for Uuid {
+    fn from(i: i128) -> Self {
+        Uuid::from_i128(i)
+    }
+}
+
 impl From<[i32; 4]> for Uuid {
     fn from(arr: [i32; 4]) -> Self {
         Self(arr)
@@ -63,6 +189,24 @@ impl Uuid {
     }
 }
 
+impl Uuid {
+    /// Converts a list of `Uuid`s to a list of int arrays.
+    pub fn to_nbt_list(uuids: &[Uuid]) -> NbtList {
+        if uuids.is_empty() {
+            return NbtList::Empty;
+        }
+
+        NbtList::IntArray(uuids.iter().map(|uuid| uuid.0.to_vec()).collect())
+    }
+}
+
+/// Uuids are stored as an int array of 4 integers.
+impl ToNbtTag for Uuid {
+    fn to_nbt_tag(self) -> NbtTag {
+        NbtTag::IntArray(self.0.to_vec())
+    }
+}
+
 impl Deref for Uuid {
     type Target = [i32; 4];
 
@@ -70,3 +214,72 @@ impl Deref for Uuid {
         &self.0
     }
 }
+
+#[cfg(feature = "serde")]
+impl serde::Serialize for Uuid {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.to_hyphenated_string())
+    }
+}
+
+/// Accepts the hyphenated string, and the int array for data serialized by older versions.
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Uuid {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct UuidVisitor;
+
+        impl<'de> serde::de::Visitor<'de> for UuidVisitor {
+            type Value = Uuid;
+
+            fn expecting(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
+                f.write_str("a uuid string or an array of 4 integers")
+            }
+
+            fn visit_str<E: serde::de::Error>(self, v: &str) -> Result<Uuid, E> {
+                Uuid::parse(v).map_err(E::custom)
+            }
+
+            fn visit_seq<A: serde::de::SeqAccess<'de>>(self, mut seq: A) -> Result<Uuid, A::Error> {
+                let mut ints = vec![];
+                while let Some(int) = seq.next_element::<i32>()? {
+                    ints.push(int);
+                }
+
+                Uuid::from_slice(&ints)
+                    .ok_or_else(|| serde::de::Error::invalid_length(ints.len(), &self))
+            }
+        }
+
+        deserializer.deserialize_any(UuidVisitor)
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn uuid_test() {
+    let uuid = Uuid::parse("069a79f4-44e9-4726-a5be-fca90e38aaf5").unwrap();
+    assert_eq!(uuid.0, [110787060, 1156138790, -1514210135, 238594805]);
+    assert_eq!(uuid.to_string(), "069a79f4-44e9-4726-a5be-fca90e38aaf5");
+    assert_eq!(uuid.to_hex_string(), "069a79f444e94726a5befca90e38aaf5");
+    assert_eq!(Uuid::parse(&uuid.to_hex_string()).unwrap(), uuid);
+    assert_eq!(uuid.version(), 4);
+
+    assert_eq!(Uuid::from_i128(uuid.to_i128()), uuid);
+    let (most, least) = uuid.to_most_least();
+    assert_eq!(Uuid::from_most_least(most, least), uuid);
+    assert_eq!(Uuid::from_i128(-1).0, [-1; 4]);
+
+    assert!(Uuid::parse("069a79f4-44e9-4726-a5be-fca90e38aaf").is_err());
+    assert!(Uuid::parse("069a79f4044e904726-a5be-fca90e38aaf5").is_err());
+    assert_eq!(Uuid::from(vec![1, 2]).0, [1, 2, 0, 0]);
+    assert_eq!(Uuid::from_slice(&[1, 2]), None);
+    assert_eq!(Uuid::try_from(&[1, 2, 3, 4][..]).unwrap().0, [1, 2, 3, 4]);
+    assert!(matches!(
+        Uuid::try_from(&[1, 2, 3, 4, 5][..]),
+        Err(SculkParseError::InvalidField(field)) if field == "UUID"
+    ));
+
+    let offline = Uuid::offline_player("Notch");
+    assert_eq!(offline.to_string(), "b50ad385-829d-3141-a216-7e7d7539ba7f");
+    assert_eq!(offline.version(), 3);
+}
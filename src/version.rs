@@ -0,0 +1,390 @@
+//! Data versions, the number every save file stores to know which version of Minecraft wrote it.
+//! [Minecraft Wiki](https://minecraft.wiki/w/Data_version)
+//!
+//! ```rust
+//! use sculk::version::DataVersion;
+//!
+//! let version = DataVersion(3700);
+//!
+//! assert_eq!(version.name(), Some("1.20.4"));
+//! assert!(version < DataVersion::V1_20_5);
+//! assert!(version.check().is_ok());
+//! ```
+//!
+//! Every parser reading a file with a data version branches on it where the format changed,
+//! and falls back to looking at which keys are present when there is none.
+//! [`Versioned::from_compound_nbt_strict`] fails instead of parsing data newer than sculk knows.
+
+use crate::{error::SculkParseError, traits::FromCompoundNbt};
+use simdnbt::borrow::NbtCompound;
+use std::cell::Cell;
+
+/// The data version of a file, stored as `DataVersion`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DataVersion(pub i32);
+
+/// If a known version is a full release or a snapshot.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum VersionKind {
+    /// A full release, like `1.20.4`.
+    Release,
+    /// A snapshot, like `24w09a`.
+    Snapshot,
+}
+
+/// A version of Minecraft and its data version.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct KnownVersion {
+    /// The data version of the version.
+    pub data_version: DataVersion,
+
+    /// The name of the version, like `1.20.4` or `24w09a`.
+    pub name: &'static str,
+
+    /// If it's a release or a snapshot.
+    pub kind: VersionKind,
+}
+
+const fn release(data_version: i32, name: &'static str) -> KnownVersion {
+    KnownVersion {
+        data_version: DataVersion(data_version),
+        name,
+        kind: VersionKind::Release,
+    }
+}
+
+const fn snapshot(data_version: i32, name: &'static str) -> KnownVersion {
+    KnownVersion {
+        data_version: DataVersion(data_version),
+        name,
+        kind: VersionKind::Snapshot,
+    }
+}
+
+/// Every release since data versions were added in 1.9, and the snapshots that changed a format sculk reads.
+/// Sorted by data version.
+pub const KNOWN_VERSIONS: &[KnownVersion] = &[
+    release(169, "1.9"),
+    release(175, "1.9.1"),
+    release(176, "1.9.2"),
+    release(184, "1.9.4"),
+    release(510, "1.10"),
+    release(511, "1.10.1"),
+    release(512, "1.10.2"),
+    release(819, "1.11"),
+    release(921, "1.11.1"),
+    release(922, "1.11.2"),
+    release(1139, "1.12"),
+    release(1241, "1.12.1"),
+    release(1343, "1.12.2"),
+    snapshot(1451, "17w47a"),
+    release(1519, "1.13"),
+    release(1628, "1.13.1"),
+    release(1631, "1.13.2"),
+    release(1952, "1.14"),
+    release(1957, "1.14.1"),
+    release(1963, "1.14.2"),
+    release(1968, "1.14.3"),
+    release(1976, "1.14.4"),
+    release(2225, "1.15"),
+    release(2227, "1.15.1"),
+    release(2230, "1.15.2"),
+    snapshot(2529, "20w17a"),
+    release(2566, "1.16"),
+    release(2567, "1.16.1"),
+    release(2578, "1.16.2"),
+    release(2580, "1.16.3"),
+    release(2584, "1.16.4"),
+    release(2586, "1.16.5"),
+    release(2724, "1.17"),
+    release(2730, "1.17.1"),
+    snapshot(2844, "21w43a"),
+    release(2860, "1.18"),
+    release(2865, "1.18.1"),
+    release(2975, "1.18.2"),
+    release(3105, "1.19"),
+    release(3117, "1.19.1"),
+    release(3120, "1.19.2"),
+    release(3218, "1.19.3"),
+    release(3337, "1.19.4"),
+    release(3463, "1.20"),
+    release(3465, "1.20.1"),
+    release(3578, "1.20.2"),
+    release(3698, "1.20.3"),
+    release(3700, "1.20.4"),
+    snapshot(3819, "24w09a"),
+    release(3837, "1.20.5"),
+    release(3839, "1.20.6"),
+    release(3953, "1.21"),
+    release(3955, "1.21.1"),
+    release(4080, "1.21.2"),
+    release(4082, "1.21.3"),
+    release(4189, "1.21.4"),
+    release(4325, "1.21.5"),
+    release(4435, "1.21.6"),
+    release(4438, "1.21.7"),
+    release(4440, "1.21.8"),
+];
+
+impl DataVersion {
+    /// 17w47a, the flattening. Blocks are stored by name instead of by numeric id.
+    pub const FLATTENING: DataVersion = DataVersion(1451);
+    /// 20w17a, packed block states and heightmaps no longer span across longs.
+    pub const NON_SPANNING_ARRAYS: DataVersion = DataVersion(2529);
+    /// 21w43a, chunks lost their `Level` wrapper and got lowercase keys.
+    pub const CHUNKS_WITHOUT_LEVEL: DataVersion = DataVersion(2844);
+    /// 24w09a, items store their data as components instead of in the `tag` compound.
+    pub const ITEM_COMPONENTS: DataVersion = DataVersion(3819);
+    /// 1.20.4, the last version storing item data in the `tag` compound.
+    pub const V1_20_4: DataVersion = DataVersion(3700);
+    /// 1.20.5, items store their data as components.
+    pub const V1_20_5: DataVersion = DataVersion(3837);
+    /// 1.21
+    pub const V1_21: DataVersion = DataVersion(3953);
+    /// 1.21.2, eating and equipping moved to the `consumable` and `equippable` components.
+    pub const V1_21_2: DataVersion = DataVersion(4080);
+    /// 1.21.4, custom model data became a compound of lists.
+    pub const V1_21_4: DataVersion = DataVersion(4189);
+
+    /// The newest version sculk knows the formats of.
+    pub const LATEST_SUPPORTED: DataVersion = DataVersion::V1_21_4;
+
+    /// Reads the `DataVersion` of a compound.
+    pub fn from_nbt(nbt: &NbtCompound) -> Option<DataVersion> {
+        nbt.int("DataVersion").map(DataVersion)
+    }
+
+    /// Gets the data version of a version name, like `1.20.4`.
+    pub fn from_name(name: &str) -> Option<DataVersion> {
+        KNOWN_VERSIONS
+            .iter()
+            .find(|version| version.name == name)
+            .map(|version| version.data_version)
+    }
+
+    /// The known version with exactly this data version.
+    pub fn known(&self) -> Option<&'static KnownVersion> {
+        KNOWN_VERSIONS
+            .binary_search_by_key(self, |version| version.data_version)
+            .ok()
+            .map(|i| &KNOWN_VERSIONS[i])
+    }
+
+    /// The name of the version with exactly this data version, like `1.20.4`.
+    pub fn name(&self) -> Option<&'static str> {
+        self.known().map(|version| version.name)
+    }
+
+    /// The newest release at or before this data version.
+    /// Snapshots belong to the release after them, so `24w09a` gives `1.20.4`.
+    pub fn release(&self) -> Option<&'static str> {
+        KNOWN_VERSIONS
+            .iter()
+            .rev()
+            .find(|version| version.kind == VersionKind::Release && version.data_version <= *self)
+            .map(|version| version.name)
+    }
+
+    /// true if the data version isn't newer than [`DataVersion::LATEST_SUPPORTED`].
+    pub fn is_supported(&self) -> bool {
+        *self <= DataVersion::LATEST_SUPPORTED
+    }
+
+    /// Errors if the data version is newer than [`DataVersion::LATEST_SUPPORTED`].
+    /// Parsing newer data is still attempted by every parser, this is for callers that would rather fail early.
+    pub fn check(self) -> Result<DataVersion, SculkParseError> {
+        match self.is_supported() {
+            true => Ok(self),
+            false => Err(SculkParseError::UnsupportedDataVersion(
+                self.0,
+                format!(
+                    "newer than the latest supported version {}",
+                    DataVersion::LATEST_SUPPORTED
+                ),
+            )),
+        }
+    }
+}
+
+/// Data that stores the [`DataVersion`] it was written with.
+pub trait Versioned {
+    /// The [`DataVersion`] of the data.
+    fn version(&self) -> DataVersion;
+
+    /// Reads the [`DataVersion`] from the Nbt the data is parsed from.
+    fn version_of(nbt: &NbtCompound) -> Option<DataVersion>
+    where
+        Self: Sized,
+    {
+        DataVersion::from_nbt(nbt)
+    }
+
+    /// Parses the data, but errors with [`SculkParseError::UnsupportedDataVersion`] if it's newer than [`DataVersion::LATEST_SUPPORTED`]
+    /// instead of attempting to parse it anyway.
+    fn from_compound_nbt_strict(nbt: &NbtCompound) -> Result<Self, SculkParseError>
+    where
+        Self: FromCompoundNbt + Sized,
+    {
+        if let Some(version) = Self::version_of(nbt) {
+            version.check()?;
+        }
+
+        Self::from_compound_nbt(nbt)
+    }
+}
+
+macro_rules! impl_versioned {
+    ($($t:ty),*) => {
+        $(impl Versioned for $t {
+            fn version(&self) -> DataVersion {
+                DataVersion(self.data_version)
+            }
+        })*
+    };
+}
+
+impl_versioned!(
+    crate::chunk::Chunk,
+    crate::chunk::EntityChunk,
+    crate::chunk::LazyChunk<'_>,
+    crate::chunk::MinimalChunk,
+    crate::map::Map,
+    crate::player::Player,
+    crate::poi::PoiChunk
+);
+
+impl Versioned for crate::level::Level {
+    fn version(&self) -> DataVersion {
+        DataVersion(self.data_version)
+    }
+
+    fn version_of(nbt: &NbtCompound) -> Option<DataVersion> {
+        nbt.compound("Data")
+            .and_then(|nbt| DataVersion::from_nbt(&nbt))
+    }
+}
+
+thread_local! {
+    static CURRENT: Cell<Option<DataVersion>> = const { Cell::new(None) };
+}
+
+/// Restores the previous data version when parsing finishes, even on early returns.
+struct CurrentGuard(Option<DataVersion>);
+
+impl Drop for CurrentGuard {
+    fn drop(&mut self) {
+        CURRENT.with(|current| current.set(self.0));
+    }
+}
+
+/// Runs a parser or writer with the data version of its file, so nested parsers and writers can branch on it.
+pub(crate) fn with_version<T>(version: Option<DataVersion>, f: impl FnOnce() -> T) -> T {
+    let _guard = CurrentGuard(CURRENT.with(|current| current.replace(version)));
+    f()
+}
+
+/// The data version of the file being parsed, `None` if it has none or nothing is being parsed.
+pub(crate) fn current() -> Option<DataVersion> {
+    CURRENT.with(|current| current.get())
+}
+
+impl std::fmt::Display for DataVersion {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.name() {
+            Some(name) => write!(f, "{name} ({})", self.0),
+            None => write!(f, "{}", self.0),
+        }
+    }
+}
+
+impl From<i32> for DataVersion {
+    fn from(version: i32) -> Self {
+        DataVersion(version)
+    }
+}
+
+#[cfg(test)]
+#[test]
+fn data_version_test() {
+    assert!(KNOWN_VERSIONS
+        .windows(2)
+        .all(|w| w[0].data_version < w[1].data_version));
+
+    assert_eq!(DataVersion::from_name("1.21"), Some(DataVersion::V1_21));
+    assert_eq!(DataVersion(3819).release(), Some("1.20.4"));
+    assert_eq!(
+        DataVersion(3819).known().unwrap().kind,
+        VersionKind::Snapshot
+    );
+    assert_eq!(DataVersion(3950).name(), None);
+    assert_eq!(DataVersion(3950).release(), Some("1.20.6"));
+    assert_eq!(DataVersion(100).release(), None);
+    assert_eq!(DataVersion(3700).to_string(), "1.20.4 (3700)");
+
+    assert!(DataVersion(4189).check().is_ok());
+    assert!(matches!(
+        DataVersion(4325).check(),
+        Err(SculkParseError::UnsupportedDataVersion(4325, _))
+    ));
+}
+
+#[cfg(test)]
+#[test]
+fn versioned_test() {
+    use crate::{components::custom_model_data::CustomModelData, level::Level, util::write_nbt};
+    use simdnbt::owned;
+    use std::io::Cursor;
+
+    let input = crate::util::read_test_data("test_data/level.dat");
+    let nbt = simdnbt::borrow::read(&mut Cursor::new(&input[..]))
+        .unwrap()
+        .unwrap();
+    let level = Level::from_compound_nbt_strict(&nbt.as_compound()).unwrap();
+    assert_eq!(Level::version_of(&nbt.as_compound()), Some(level.version()));
+
+    let mut newer = nbt.as_compound().to_owned();
+    *newer
+        .compound_mut("Data")
+        .and_then(|data| data.int_mut("DataVersion"))
+        .unwrap() = 4325;
+    let bytes = write_nbt(&newer);
+    let nbt = simdnbt::borrow::read(&mut Cursor::new(&bytes[..]))
+        .unwrap()
+        .unwrap();
+    assert!(matches!(
+        Level::from_compound_nbt_strict(&nbt.as_compound()),
+        Err(SculkParseError::UnsupportedDataVersion(4325, _))
+    ));
+    assert_eq!(
+        Level::from_compound_nbt(&nbt.as_compound())
+            .unwrap()
+            .version(),
+        DataVersion(4325)
+    );
+
+    let mut nbt = owned::NbtCompound::new();
+    nbt.insert("minecraft:custom_model_data", 1);
+    let bytes = write_nbt(&nbt);
+    let nbt = simdnbt::borrow::read(&mut Cursor::new(&bytes[..]))
+        .unwrap()
+        .unwrap();
+    let tag = nbt
+        .as_compound()
+        .get("minecraft:custom_model_data")
+        .unwrap();
+
+    assert_eq!(
+        CustomModelData::from_nbt_tag(&tag).unwrap(),
+        CustomModelData::Legacy(1)
+    );
+    with_version(Some(DataVersion::V1_21_4), || {
+        assert!(CustomModelData::from_nbt_tag(&tag).is_err());
+        with_version(Some(DataVersion::V1_21), || {
+            assert_eq!(current(), Some(DataVersion::V1_21));
+        });
+        assert_eq!(current(), Some(DataVersion::V1_21_4));
+    });
+    assert_eq!(current(), None);
+}
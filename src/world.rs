@@ -0,0 +1,579 @@
+//! Access to an entire save folder.
+//!
+//! A [`World`] ties together the `level.dat`, the player data, the maps and the regions of every dimension.
+//! Nothing is read until it's asked for.
+
+use crate::{
+    chunk::{Chunk, EntityChunk},
+    error::SculkParseError,
+    level::Level,
+    map::Map,
+    player::Player,
+    poi::{PoiChunk, PoiRecord},
+    region::{Region, REGION_WIDTH},
+    traits::FromCompoundNbt,
+    uuid::Uuid,
+};
+use flate2::read::GzDecoder;
+use std::{
+    io::{Cursor, Read},
+    path::{Path, PathBuf},
+    sync::{Arc, Mutex, OnceLock},
+};
+
+/// The resource location of the overworld.
+pub const OVERWORLD: &str = "minecraft:overworld";
+/// The resource location of the nether.
+pub const THE_NETHER: &str = "minecraft:the_nether";
+/// The resource location of the end.
+pub const THE_END: &str = "minecraft:the_end";
+
+/// How many region files a [`Dimension`] keeps in memory by default.
+pub const REGION_CACHE_SIZE: usize = 8;
+
+/// A Minecraft save folder.
+#[derive(Debug)]
+pub struct World {
+    path: PathBuf,
+    level: OnceLock<Level>,
+}
+
+/// A dimension inside of a world, and the folder its regions are stored in.
+///
+/// The region files opened by [`Dimension::chunk`], [`Dimension::entity_chunk`] and [`Dimension::poi_chunk`]
+/// are kept in memory, so reading many chunks of the same region only reads the file once.
+/// At most [`REGION_CACHE_SIZE`] regions are kept, the least recently used one is dropped first.
+/// Use [`Dimension::set_region_cache_size`] to change the limit and [`Dimension::clear_region_cache`] to free them.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Dimension {
+    /// The resource location of the dimension, like `minecraft:overworld`.
+    pub id: String,
+
+    /// The folder containing the `region`, `entities` and `poi` folders of the dimension.
+    pub path: PathBuf,
+
+    regions: RegionCache,
+}
+
+/// The region files opened by a [`Dimension`] with their path, the most recently used last.
+/// Cloning a dimension starts with an empty cache of the same size, and the cache is ignored when comparing dimensions.
+#[derive(Debug)]
+struct RegionCache {
+    regions: Mutex<Vec<(PathBuf, Arc<Region>)>>,
+    size: usize,
+}
+
+impl RegionCache {
+    fn new(size: usize) -> Self {
+        RegionCache {
+            regions: Mutex::new(Vec::new()),
+            size,
+        }
+    }
+
+    /// Returns the cached region file, opening it if it isn't cached yet.
+    fn get_or_open(
+        &self,
+        directory: PathBuf,
+        x: i32,
+        z: i32,
+    ) -> Result<Option<Arc<Region>>, SculkParseError> {
+        let path = directory.join(format!("r.{x}.{z}.mca"));
+
+        {
+            let mut regions = self.lock();
+            if let Some(i) = regions.iter().position(|(cached, _)| *cached == path) {
+                let entry = regions.remove(i);
+                let region = entry.1.clone();
+                regions.push(entry);
+                return Ok(Some(region));
+            }
+        }
+
+        let region = match open_region(directory, x, z)? {
+            Some(region) => Arc::new(region),
+            None => return Ok(None),
+        };
+
+        if self.size > 0 {
+            let mut regions = self.lock();
+            regions.retain(|(cached, _)| *cached != path);
+            regions.push((path, region.clone()));
+            let excess = regions.len().saturating_sub(self.size);
+            regions.drain(..excess);
+        }
+
+        Ok(Some(region))
+    }
+
+    fn lock(&self) -> std::sync::MutexGuard<'_, Vec<(PathBuf, Arc<Region>)>> {
+        self.regions.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+impl Default for RegionCache {
+    fn default() -> Self {
+        RegionCache::new(REGION_CACHE_SIZE)
+    }
+}
+
+impl Clone for RegionCache {
+    fn clone(&self) -> Self {
+        RegionCache::new(self.size)
+    }
+}
+
+impl PartialEq for RegionCache {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+impl Eq for RegionCache {}
+
+impl World {
+    /// Opens a save folder, the folder must contain a `level.dat` file.
+    pub fn open<P: AsRef<Path>>(path: P) -> Result<Self, SculkParseError> {
+        let path = path.as_ref().to_path_buf();
+
+        if !path.join("level.dat").is_file() {
+            return Err(SculkParseError::IoError(std::io::Error::new(
+                std::io::ErrorKind::NotFound,
+                format!("No level.dat in {path:?}"),
+            )));
+        }
+
+        Ok(World {
+            path,
+            level: OnceLock::new(),
+        })
+    }
+
+    /// The path to the save folder.
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Returns the parsed `level.dat`, it's only read the first time it's requested.
+    pub fn level(&self) -> Result<&Level, SculkParseError> {
+        if let Some(level) = self.level.get() {
+            return Ok(level);
+        }
+
+        let level = read_nbt_file(self.path.join("level.dat"))?;
+        Ok(self.level.get_or_init(|| level))
+    }
+
+    /// Returns every dimension in the world.
+    /// The vanilla dimensions are included if their folder exists,
+    /// and any datapack dimension listed in `WorldGenSettings.dimensions`.
+    pub fn dimensions(&self) -> Result<Vec<Dimension>, SculkParseError> {
+        let mut dimensions: Vec<Dimension> = [OVERWORLD, THE_NETHER, THE_END]
+            .into_iter()
+            .map(|id| self.dimension(id))
+            .filter(|dimension| dimension.id == OVERWORLD || dimension.path.is_dir())
+            .collect();
+
+        let mut custom: Vec<&String> = self.level()?.world_gen_settings.dimensions.keys().collect();
+        custom.sort();
+
+        for id in custom {
+            if dimensions.iter().all(|dimension| &dimension.id != id) {
+                dimensions.push(self.dimension(id));
+            }
+        }
+
+        Ok(dimensions)
+    }
+
+    /// Returns the dimension with the given resource location.
+    /// The dimension isn't required to exist.
+    pub fn dimension(&self, id: &str) -> Dimension {
+        let path = match id {
+            OVERWORLD => self.path.clone(),
+            THE_NETHER => self.path.join("DIM-1"),
+            THE_END => self.path.join("DIM1"),
+            _ => {
+                let (namespace, path) = id.split_once(':').unwrap_or(("minecraft", id));
+                self.path.join("dimensions").join(namespace).join(path)
+            }
+        };
+
+        Dimension {
+            id: id.to_string(),
+            path,
+            regions: RegionCache::default(),
+        }
+    }
+
+    /// Returns the overworld.
+    pub fn overworld(&self) -> Dimension {
+        self.dimension(OVERWORLD)
+    }
+
+    /// Returns the nether.
+    pub fn nether(&self) -> Dimension {
+        self.dimension(THE_NETHER)
+    }
+
+    /// Returns the end.
+    pub fn end(&self) -> Dimension {
+        self.dimension(THE_END)
+    }
+
+    /// Returns the uuids of every player with a `playerdata/<uuid>.dat` file.
+    pub fn player_uuids(&self) -> Result<Vec<Uuid>, SculkParseError> {
+        let mut uuids = vec![];
+
+        for name in read_dir_names(self.path.join("playerdata"))? {
+            if let Some(uuid) = name
+                .strip_suffix(".dat")
+                .and_then(|uuid| Uuid::parse(uuid).ok())
+            {
+                uuids.push(uuid);
+            }
+        }
+
+        Ok(uuids)
+    }
+
+    /// Returns the data of the player with the given uuid, `None` if the player has never joined.
+    pub fn player(&self, uuid: &Uuid) -> Result<Option<Player>, SculkParseError> {
+        let path = self.path.join("playerdata").join(format!("{}.dat", uuid));
+
+        if !path.is_file() {
+            return Ok(None);
+        }
+
+        read_nbt_file(path).map(Some)
+    }
+
+    /// Returns the advancement progress of the player with the given uuid, `None` if the player has no advancements file.
+    #[cfg(feature = "advancements")]
+    pub fn advancements(
+        &self,
+        uuid: &Uuid,
+    ) -> Result<Option<crate::advancements::Advancements>, SculkParseError> {
+        let path = self
+            .path
+            .join("advancements")
+            .join(format!("{}.json", uuid));
+
+        if !path.is_file() {
+            return Ok(None);
+        }
+
+        crate::advancements::Advancements::from_path(path).map(Some)
+    }
+
+    /// Returns the statistics of the player with the given uuid, `None` if the player has no statistics file.
+    #[cfg(feature = "stats")]
+    pub fn statistics(
+        &self,
+        uuid: &Uuid,
+    ) -> Result<Option<crate::statistics::Statistics>, SculkParseError> {
+        let path = self.path.join("stats").join(format!("{}.json", uuid));
+
+        if !path.is_file() {
+            return Ok(None);
+        }
+
+        crate::statistics::Statistics::from_path(path).map(Some)
+    }
+
+    /// Returns the statistics of every player with a `stats/<uuid>.json` file.
+    #[cfg(feature = "stats")]
+    pub fn all_statistics(
+        &self,
+    ) -> Result<Vec<(Uuid, crate::statistics::Statistics)>, SculkParseError> {
+        let mut statistics = vec![];
+
+        for name in read_dir_names(self.path.join("stats"))? {
+            if let Some(uuid) = name
+                .strip_suffix(".json")
+                .and_then(|uuid| Uuid::parse(uuid).ok())
+            {
+                let stats =
+                    crate::statistics::Statistics::from_path(self.path.join("stats").join(name))?;
+                statistics.push((uuid, stats));
+            }
+        }
+
+        Ok(statistics)
+    }
+
+    /// Returns the ids of every map with a `data/map_<id>.dat` file.
+    pub fn map_ids(&self) -> Result<Vec<i32>, SculkParseError> {
+        let mut ids: Vec<i32> = read_dir_names(self.path.join("data"))?
+            .iter()
+            .filter_map(|name| {
+                name.strip_prefix("map_")?
+                    .strip_suffix(".dat")?
+                    .parse()
+                    .ok()
+            })
+            .collect();
+        ids.sort();
+
+        Ok(ids)
+    }
+
+    /// Returns the map with the given id, `None` if the map doesn't exist.
+    pub fn map(&self, id: i32) -> Result<Option<Map>, SculkParseError> {
+        let path = self.path.join("data").join(format!("map_{id}.dat"));
+
+        if !path.is_file() {
+            return Ok(None);
+        }
+
+        read_nbt_file(path).map(Some)
+    }
+}
+
+impl Dimension {
+    /// The folder containing the region files of the dimension.
+    pub fn region_path(&self) -> PathBuf {
+        self.path.join("region")
+    }
+
+    /// Returns the coordinates of every region file in the dimension.
+    pub fn region_coordinates(&self) -> Result<Vec<(i32, i32)>, SculkParseError> {
+        let mut coordinates: Vec<(i32, i32)> = read_dir_names(self.region_path())?
+            .iter()
+            .filter_map(|name| Region::coordinates_from_path(name).ok())
+            .collect();
+        coordinates.sort();
+
+        Ok(coordinates)
+    }
+
+    /// Opens the region at the given region coordinates, `None` if it doesn't exist.
+    /// The whole file is read on every call, the region isn't cached.
+    pub fn region(&self, x: i32, z: i32) -> Result<Option<Region>, SculkParseError> {
+        open_region(self.region_path(), x, z)
+    }
+
+    /// Iterates over every region in the dimension, opening them one at a time.
+    /// The regions aren't cached, so only one is kept in memory at once.
+    pub fn regions(
+        &self,
+    ) -> Result<impl Iterator<Item = Result<Region, SculkParseError>> + '_, SculkParseError> {
+        Ok(self
+            .region_coordinates()?
+            .into_iter()
+            .filter_map(|(x, z)| self.region(x, z).transpose()))
+    }
+
+    /// Returns the chunk at the given absolute chunk coordinates, `None` if it hasn't been generated.
+    pub fn chunk(&self, chunk_x: i32, chunk_z: i32) -> Result<Option<Chunk>, SculkParseError> {
+        let (x, z) = Region::region_of(chunk_x, chunk_z);
+
+        match self.regions.get_or_open(self.region_path(), x, z)? {
+            Some(region) => region.get_chunk(
+                chunk_x.rem_euclid(REGION_WIDTH as i32) as usize,
+                chunk_z.rem_euclid(REGION_WIDTH as i32) as usize,
+            ),
+            None => Ok(None),
+        }
+    }
+
+    /// The folder containing the entity region files of the dimension.
+    pub fn entities_path(&self) -> PathBuf {
+        self.path.join("entities")
+    }
+
+    /// Opens the entity region at the given region coordinates, `None` if it doesn't exist.
+    pub fn entity_region(&self, x: i32, z: i32) -> Result<Option<Region>, SculkParseError> {
+        open_region(self.entities_path(), x, z)
+    }
+
+    /// Returns the entities of the chunk at the given absolute chunk coordinates, `None` if there are none saved.
+    pub fn entity_chunk(
+        &self,
+        chunk_x: i32,
+        chunk_z: i32,
+    ) -> Result<Option<EntityChunk>, SculkParseError> {
+        let (x, z) = Region::region_of(chunk_x, chunk_z);
+
+        match self.regions.get_or_open(self.entities_path(), x, z)? {
+            Some(region) => region.get_entity_chunk_at(chunk_x, chunk_z),
+            None => Ok(None),
+        }
+    }
+
+    /// The folder containing the poi region files of the dimension.
+    pub fn poi_path(&self) -> PathBuf {
+        self.path.join("poi")
+    }
+
+    /// Opens the poi region at the given region coordinates, `None` if it doesn't exist.
+    pub fn poi_region(&self, x: i32, z: i32) -> Result<Option<Region>, SculkParseError> {
+        open_region(self.poi_path(), x, z)
+    }
+
+    /// Returns the points of interest of the chunk at the given absolute chunk coordinates.
+    pub fn poi_chunk(
+        &self,
+        chunk_x: i32,
+        chunk_z: i32,
+    ) -> Result<Option<PoiChunk>, SculkParseError> {
+        let (x, z) = Region::region_of(chunk_x, chunk_z);
+
+        match self.regions.get_or_open(self.poi_path(), x, z)? {
+            Some(region) => region.get_poi_chunk_at(chunk_x, chunk_z),
+            None => Ok(None),
+        }
+    }
+
+    /// Drops every region file kept in memory by the chunk accessors.
+    pub fn clear_region_cache(&self) {
+        self.regions.lock().clear();
+    }
+
+    /// Sets how many region files are kept in memory, 0 disables the cache.
+    /// Drops the least recently used regions if more are cached.
+    pub fn set_region_cache_size(&mut self, size: usize) {
+        self.regions.size = size;
+        let mut regions = self.regions.lock();
+        let excess = regions.len().saturating_sub(size);
+        regions.drain(..excess);
+    }
+
+    /// Returns the point of interest at the given block position.
+    pub fn poi_record_at(
+        &self,
+        x: i32,
+        y: i32,
+        z: i32,
+    ) -> Result<Option<PoiRecord>, SculkParseError> {
+        Ok(self
+            .poi_chunk(x >> 4, z >> 4)?
+            .and_then(|chunk| chunk.record_at(x, y, z).cloned()))
+    }
+}
+
+/// Opens the region file at the given region coordinates in a folder, `None` if it doesn't exist.
+fn open_region<P: AsRef<Path>>(
+    directory: P,
+    x: i32,
+    z: i32,
+) -> Result<Option<Region>, SculkParseError> {
+    let path = directory.as_ref().join(format!("r.{x}.{z}.mca"));
+
+    if !path.is_file() {
+        return Ok(None);
+    }
+
+    Region::open(path).map(Some)
+}
+
+/// Reads a gzip compressed or uncompressed Nbt file.
+fn read_nbt_file<T: FromCompoundNbt, P: AsRef<Path>>(path: P) -> Result<T, SculkParseError> {
+    let contents = std::fs::read(path)?;
+
+    let mut bytes = Vec::new();
+    if GzDecoder::new(&contents[..])
+        .read_to_end(&mut bytes)
+        .is_err()
+    {
+        bytes = contents;
+    }
+
+    let nbt = match simdnbt::borrow::read(&mut Cursor::new(&bytes[..]))? {
+        simdnbt::borrow::Nbt::Some(nbt) => nbt,
+        simdnbt::borrow::Nbt::None => return Err(SculkParseError::NoNbt),
+    };
+
+    T::from_compound_nbt(&nbt.as_compound())
+}
+
+/// Returns the file names in a directory, a missing directory is treated as empty.
+fn read_dir_names<P: AsRef<Path>>(path: P) -> Result<Vec<String>, SculkParseError> {
+    let entries = match std::fs::read_dir(path) {
+        Ok(entries) => entries,
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(vec![]),
+        Err(e) => return Err(e.into()),
+    };
+
+    let mut names = vec![];
+    for entry in entries {
+        if let Some(name) = entry?.file_name().to_str() {
+            names.push(name.to_string());
+        }
+    }
+
+    Ok(names)
+}
+
+#[cfg(test)]
+#[test]
+fn world_test() {
+    let directory = std::env::temp_dir().join(format!("sculk_world_{}", std::process::id()));
+    std::fs::create_dir_all(directory.join("playerdata")).unwrap();
+    std::fs::create_dir_all(directory.join("data")).unwrap();
+    std::fs::create_dir_all(directory.join("DIM-1/region")).unwrap();
+    std::fs::copy("test_data/level.dat", directory.join("level.dat")).unwrap();
+    std::fs::copy("test_data/map_0.dat", directory.join("data/map_0.dat")).unwrap();
+
+    let player: Player = read_nbt_file("test_data/player_data.dat").unwrap();
+    let uuid = player.entity.uuid;
+    std::fs::copy(
+        "test_data/player_data.dat",
+        directory.join("playerdata").join(format!("{}.dat", uuid)),
+    )
+    .unwrap();
+
+    let mut region = Region::new(-1, 0);
+    region
+        .set_chunk_bytes(
+            31,
+            0,
+            &std::fs::read("test_data/chest.nbt").unwrap(),
+            crate::region::CompressionType::Zlib,
+        )
+        .unwrap();
+    region
+        .save(directory.join("DIM-1/region/r.-1.0.mca"))
+        .unwrap();
+
+    let world = World::open(&directory).unwrap();
+    assert!(std::ptr::eq(world.level().unwrap(), world.level().unwrap()));
+
+    let dimensions = world.dimensions().unwrap();
+    assert_eq!(dimensions[0], world.overworld());
+    assert_eq!(dimensions[1], world.nether());
+    assert_eq!(
+        world.dimension("example:mining").path,
+        directory.join("dimensions/example/mining")
+    );
+
+    assert_eq!(world.player_uuids().unwrap(), vec![uuid]);
+    assert_eq!(world.player(&uuid).unwrap(), Some(player));
+    assert_eq!(world.player(&Uuid([0; 4])).unwrap(), None);
+
+    assert_eq!(world.map_ids().unwrap(), vec![0]);
+    assert!(world.map(0).unwrap().is_some());
+
+    let nether = world.nether();
+    assert_eq!(nether.region_coordinates().unwrap(), vec![(-1, 0)]);
+    assert!(nether.region(-1, 0).unwrap().unwrap().has_chunk(31, 0));
+    assert!(nether.chunk(-1, 1).unwrap().is_none());
+    assert_eq!(nether.regions.lock().len(), 1);
+    nether.clear_region_cache();
+    assert!(nether.regions.lock().is_empty());
+    assert_eq!(world.overworld().region_coordinates().unwrap(), vec![]);
+
+    std::fs::create_dir_all(directory.join("region")).unwrap();
+    region.save(directory.join("region/r.0.0.mca")).unwrap();
+    region.save(directory.join("region/r.1.0.mca")).unwrap();
+    let mut overworld = world.overworld();
+    overworld.set_region_cache_size(1);
+    assert!(overworld.chunk(0, 1).unwrap().is_none());
+    assert!(overworld.chunk(32, 1).unwrap().is_none());
+    let regions = overworld.regions.lock();
+    assert_eq!(regions.len(), 1);
+    assert_eq!(regions[0].0, directory.join("region/r.1.0.mca"));
+    drop(regions);
+
+    std::fs::remove_dir_all(directory).unwrap();
+}